@@ -0,0 +1,266 @@
+//! Client side of the container attach socket and of attach over RPC.
+
+use crate::{
+    error::{Error, Result},
+    socket::shorten_socket_path,
+    stream::RpcTask,
+};
+use conmon_common::conmon_capnp::conmon::stream_sink;
+use futures::{channel::mpsc::Receiver, StreamExt};
+use nix::sys::socket::{connect, socket, AddressFamily, SockFlag, SockType, UnixAddr};
+use std::{
+    io::ErrorKind,
+    os::unix::{io::FromRawFd, net},
+    path::Path,
+};
+use tokio::net::UnixStream;
+
+/// The size of an attach packet, which has to be in sync with the server.
+pub const ATTACH_PACKET_BUF_SIZE: usize = 8192;
+
+/// The packet type prefix of standard output data.
+const PACKET_TYPE_STDOUT: u8 = 2;
+
+/// The packet type prefix of standard error data.
+const PACKET_TYPE_STDERR: u8 = 3;
+
+/// The control packet type to resize the terminal.
+const PACKET_TYPE_RESIZE: u8 = 4;
+
+/// The control packet type to close the standard input.
+const PACKET_TYPE_CLOSE_STDIN: u8 = 5;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// The output pipe of an attach packet.
+pub enum AttachPipe {
+    /// Standard output.
+    Stdout,
+
+    /// Standard error.
+    Stderr,
+}
+
+#[derive(Debug)]
+/// AttachStream is a connection to a container attach socket created by the server.
+pub struct AttachStream {
+    stream: UnixStream,
+}
+
+impl AttachStream {
+    /// Connect to the attach socket on the provided path.
+    pub async fn connect(path: &Path) -> Result<Self> {
+        let err = |source| Error::Connect {
+            path: path.into(),
+            source,
+        };
+
+        let fd = socket(
+            AddressFamily::Unix,
+            SockType::SeqPacket,
+            SockFlag::SOCK_CLOEXEC,
+            None,
+        )?;
+        // Take ownership to close the socket on failure.
+        let stream = unsafe { net::UnixStream::from_raw_fd(fd) };
+
+        let (shortened_path, _parent) = shorten_socket_path(path).map_err(err)?;
+        let addr = UnixAddr::new(&shortened_path)?;
+        connect(fd, &addr).map_err(|e| err(e.into()))?;
+
+        stream.set_nonblocking(true)?;
+        Ok(Self {
+            stream: UnixStream::from_std(stream)?,
+        })
+    }
+
+    /// Read the next packet from the attach socket, where `None` indicates that the server
+    /// closed the connection.
+    pub async fn read(&self) -> Result<Option<(AttachPipe, Vec<u8>)>> {
+        let mut buf = vec![0; ATTACH_PACKET_BUF_SIZE];
+        let n = loop {
+            self.stream.readable().await?;
+            match self.stream.try_read(&mut buf) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+                Err(e) => return Err(e.into()),
+            }
+        };
+        if n == 0 {
+            return Ok(None);
+        }
+
+        let pipe = match buf[0] {
+            PACKET_TYPE_STDOUT => AttachPipe::Stdout,
+            PACKET_TYPE_STDERR => AttachPipe::Stderr,
+            x => return Err(Error::AttachPacket(x)),
+        };
+
+        // The server pads every packet with zeros, whereas the pipe byte is never zero.
+        let end = buf[..n].iter().rposition(|&x| x != 0).unwrap_or_default() + 1;
+        Ok(Some((pipe, buf[1..end].to_vec())))
+    }
+
+    /// Write the provided standard input data to the attach socket. The server stops reading a
+    /// packet at the first zero byte, which means that the data should not contain any.
+    pub async fn write(&self, data: &[u8]) -> Result<()> {
+        for packet in data.chunks(ATTACH_PACKET_BUF_SIZE) {
+            self.write_packet(packet).await?;
+        }
+        Ok(())
+    }
+
+    /// Resize the terminal of the container, which is ignored by the server for containers
+    /// without a terminal.
+    pub async fn resize(&self, width: u16, height: u16) -> Result<()> {
+        let mut packet = vec![0, PACKET_TYPE_RESIZE];
+        packet.extend_from_slice(&width.to_be_bytes());
+        packet.extend_from_slice(&height.to_be_bytes());
+        self.write_packet(&packet).await
+    }
+
+    /// Close the standard input of the container.
+    pub async fn close_stdin(&self) -> Result<()> {
+        self.write_packet(&[0, PACKET_TYPE_CLOSE_STDIN]).await
+    }
+
+    async fn write_packet(&self, packet: &[u8]) -> Result<()> {
+        loop {
+            self.stream.writable().await?;
+            match self.stream.try_write(packet) {
+                Ok(_) => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// RpcAttachStream is an attach session which uses a dedicated RPC connection instead of an
+/// attach socket. The connection gets closed on drop.
+pub struct RpcAttachStream {
+    stdin_sink: stream_sink::Client,
+    output_rx: Receiver<(AttachPipe, Vec<u8>)>,
+    _rpc_task: RpcTask,
+}
+
+impl RpcAttachStream {
+    pub(crate) fn new(
+        stdin_sink: stream_sink::Client,
+        output_rx: Receiver<(AttachPipe, Vec<u8>)>,
+        rpc_task: RpcTask,
+    ) -> Self {
+        Self {
+            stdin_sink,
+            output_rx,
+            _rpc_task: rpc_task,
+        }
+    }
+
+    /// Read the next output of the container, where `None` indicates that the server closed the
+    /// attach session.
+    pub async fn read(&mut self) -> Option<(AttachPipe, Vec<u8>)> {
+        self.output_rx.next().await
+    }
+
+    /// Write the provided standard input data to the container. Contrary to the attach socket,
+    /// the data is allowed to contain zero bytes.
+    pub async fn write(&self, data: &[u8]) -> Result<()> {
+        let mut request = self.stdin_sink.write_request();
+        let mut params = request.get();
+        params.set_pipe(stream_sink::Pipe::Stdin);
+        params.set_data(data);
+        request.send().promise.await?;
+        Ok(())
+    }
+
+    /// Close the standard input of the container.
+    pub async fn close_stdin(&self) -> Result<()> {
+        self.stdin_sink.close_request().send().promise.await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::sys::socket::{bind, listen};
+    use std::os::unix::io::RawFd;
+    use tempfile::tempdir;
+    use tokio::net::UnixListener;
+
+    fn listen_seqpacket(path: &Path) -> Result<UnixListener> {
+        let fd: RawFd = socket(
+            AddressFamily::Unix,
+            SockType::SeqPacket,
+            SockFlag::SOCK_NONBLOCK | SockFlag::SOCK_CLOEXEC,
+            None,
+        )?;
+        bind(fd, &UnixAddr::new(path)?)?;
+        listen(fd, 1)?;
+        Ok(UnixListener::from_std(unsafe {
+            net::UnixListener::from_raw_fd(fd)
+        })?)
+    }
+
+    fn packet(pipe: u8, data: &[u8]) -> Vec<u8> {
+        let mut packet = vec![pipe];
+        packet.extend_from_slice(data);
+        packet.resize(ATTACH_PACKET_BUF_SIZE, 0);
+        packet
+    }
+
+    #[tokio::test]
+    async fn read_write_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("attach");
+        let listener = listen_seqpacket(&path)?;
+
+        let sut = AttachStream::connect(&path).await?;
+        let (server, _) = listener.accept().await?;
+
+        server.writable().await?;
+        server.try_write(&packet(2, b"out"))?;
+        server.try_write(&packet(3, b"err"))?;
+        server.try_write(&packet(2, b""))?;
+        assert_eq!(
+            sut.read().await?,
+            Some((AttachPipe::Stdout, b"out".to_vec()))
+        );
+        assert_eq!(
+            sut.read().await?,
+            Some((AttachPipe::Stderr, b"err".to_vec()))
+        );
+        assert_eq!(sut.read().await?, Some((AttachPipe::Stdout, vec![])));
+
+        sut.write(b"in").await?;
+        let mut buf = [0; 16];
+        server.readable().await?;
+        let n = server.try_read(&mut buf)?;
+        assert_eq!(&buf[..n], b"in");
+
+        sut.resize(80, 256).await?;
+        server.readable().await?;
+        let n = server.try_read(&mut buf)?;
+        assert_eq!(&buf[..n], &[0, 4, 0, 80, 1, 0]);
+
+        sut.close_stdin().await?;
+        server.readable().await?;
+        let n = server.try_read(&mut buf)?;
+        assert_eq!(&buf[..n], &[0, 5]);
+
+        server.try_write(&packet(1, b"wrong"))?;
+        assert!(matches!(sut.read().await, Err(Error::AttachPacket(1))));
+
+        drop(server);
+        assert_eq!(sut.read().await?, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn connect_failure() -> Result<()> {
+        let dir = tempdir()?;
+        let res = AttachStream::connect(&dir.path().join("attach")).await;
+        assert!(matches!(res, Err(Error::Connect { .. })));
+        Ok(())
+    }
+}
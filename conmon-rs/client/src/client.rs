@@ -0,0 +1,786 @@
+//! The main client implementation.
+
+use crate::{
+    attach::RpcAttachStream,
+    config::{LogDriver, ServerConfig},
+    error::{Error, Result},
+    port_forward::PortForwardStream,
+    socket::shorten_socket_path,
+    stream::{OutputSink, RpcTask},
+    types::{
+        AttachConfig, ContainerInfo, CreateContainerConfig, CreateContainerResponse,
+        CreateNamespacesConfig, ExecSyncConfig, ExecSyncResponse, LogDriverConfig, LogDriverType,
+        Namespace, NamespaceInfo, ProbeHandler, ProbeResult, ProbeStatus, RegisterProbeConfig,
+        RequestMetadata, RestartPolicy, ShutdownMode, SlowClientPolicy, TerminalOptions,
+        VersionResponse,
+    },
+};
+use capnp_rpc::{rpc_twoparty_capnp::Side, twoparty::VatNetwork, RpcSystem};
+use conmon_common::conmon_capnp::conmon::{
+    self, attach_request, log_driver, restart_policy, shutdown_request,
+};
+use futures::{AsyncReadExt, Future};
+use getset::{CopyGetters, Getters};
+use nix::{
+    sys::signal::{kill, Signal},
+    unistd::{setpgid, Pid},
+};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process::Stdio,
+    time::Duration,
+};
+use tokio::{fs, net::UnixStream, process::Command, time};
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+/// The maximum time to wait for a freshly spawned server to accept connections.
+const SERVER_UP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The interval used to poll the server while waiting for it to be up.
+const SERVER_UP_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Clone, Debug, CopyGetters, Getters)]
+/// ConmonClient is a typed client for the conmon server RPC interface.
+///
+/// Every method opens a dedicated RPC connection to the server. The returned futures are not
+/// `Send`, which means that they have to be awaited on the current thread, for example by using
+/// a `tokio::task::LocalSet`.
+pub struct ConmonClient {
+    #[getset(get_copy = "pub")]
+    /// The process ID of the server.
+    server_pid: u32,
+
+    #[getset(get = "pub")]
+    /// The socket path of the server.
+    socket: PathBuf,
+
+    #[getset(get = "pub")]
+    /// The metadata attached to every request.
+    metadata: RequestMetadata,
+}
+
+impl ConmonClient {
+    /// Start a new server for the provided configuration and connect to it.
+    ///
+    /// An already running server for the same runtime directory is reused instead.
+    pub async fn new(config: &ServerConfig) -> Result<Self> {
+        fs::create_dir_all(config.runtime_dir()).await?;
+
+        if let Ok(client) = Self::connect(config.socket()).await {
+            return Ok(client);
+        }
+
+        Self::start_server(config).await?;
+        let pid = Self::read_pid(&config.pidfile()).await?;
+
+        let client = match Self::wait_until_server_up(config.socket()).await {
+            Ok(client) => client,
+            Err(e) => {
+                // Do not leave a background server behind which never got ready.
+                kill(Pid::from_raw(pid as i32), Signal::SIGINT).ok();
+                return Err(e);
+            }
+        };
+
+        fs::remove_file(config.pidfile()).await?;
+        Ok(client)
+    }
+
+    /// Connect to an already running server on the provided socket path.
+    pub async fn connect<T>(socket: T) -> Result<Self>
+    where
+        T: Into<PathBuf>,
+    {
+        let mut client = Self {
+            server_pid: 0,
+            socket: socket.into(),
+            metadata: Default::default(),
+        };
+        client.server_pid = client.version().await?.process_id;
+        Ok(client)
+    }
+
+    /// Create a copy of the client, which attaches the provided metadata to every request. This
+    /// allows the server to correlate its tracing spans with the ones of the caller.
+    pub fn with_metadata(&self, metadata: RequestMetadata) -> Self {
+        Self {
+            metadata,
+            ..self.clone()
+        }
+    }
+
+    fn init_metadata(&self, mut builder: conmon::metadata::Builder) {
+        let metadata = &self.metadata;
+        builder.set_trace_id(metadata.trace_id.as_deref().unwrap_or_default());
+        builder.set_span_id(metadata.span_id.as_deref().unwrap_or_default());
+        builder.set_request_id(metadata.request_id.as_deref().unwrap_or_default());
+        builder.set_caller_name(metadata.caller_name.as_deref().unwrap_or_default());
+    }
+
+    async fn start_server(config: &ServerConfig) -> Result<()> {
+        let mut cmd = Command::new(config.server_path());
+        cmd.args(config.args()?).stdin(Stdio::null());
+
+        if !matches!(config.log_driver(), LogDriver::Stdout | LogDriver::Json) {
+            cmd.stdout(Stdio::null()).stderr(Stdio::null());
+        }
+
+        // Put the server into its own process group to not receive the signals of the client.
+        unsafe {
+            cmd.pre_exec(|| {
+                setpgid(Pid::from_raw(0), Pid::from_raw(0))
+                    .map_err(|e| io::Error::from_raw_os_error(e as i32))
+            });
+        }
+
+        // The server forks and the parent exits as soon as the pid file got written.
+        let status = cmd.status().await.map_err(|source| Error::Spawn {
+            path: config.server_path().into(),
+            source,
+        })?;
+        if !status.success() {
+            return Err(Error::ServerExit(status));
+        }
+        Ok(())
+    }
+
+    async fn read_pid(path: &Path) -> Result<u32> {
+        let content = fs::read_to_string(path)
+            .await
+            .map_err(|source| Error::PidFile {
+                path: path.into(),
+                source,
+            })?;
+        Ok(content.trim().parse()?)
+    }
+
+    async fn wait_until_server_up(socket: PathBuf) -> Result<Self> {
+        time::timeout(SERVER_UP_TIMEOUT, async {
+            loop {
+                if let Ok(client) = Self::connect(socket.clone()).await {
+                    return client;
+                }
+                time::sleep(SERVER_UP_INTERVAL).await;
+            }
+        })
+        .await
+        .map_err(|_| Error::ServerNotUp(SERVER_UP_TIMEOUT))
+    }
+
+    async fn connect_stream(&self) -> Result<UnixStream> {
+        let err = |source| Error::Connect {
+            path: self.socket().clone(),
+            source,
+        };
+        let (path, _parent) = shorten_socket_path(self.socket()).map_err(err)?;
+        UnixStream::connect(path).await.map_err(err)
+    }
+
+    /// Open a new RPC connection to the server, which has to be driven by polling the returned
+    /// RPC system.
+    async fn rpc_system(&self) -> Result<(RpcSystem<Side>, conmon::Client)> {
+        let stream = self.connect_stream().await?;
+        let (reader, writer) = TokioAsyncReadCompatExt::compat(stream).split();
+        let network = Box::new(VatNetwork::new(
+            reader,
+            writer,
+            Side::Client,
+            Default::default(),
+        ));
+        let mut rpc_system = RpcSystem::new(network, None);
+        let client: conmon::Client = rpc_system.bootstrap(Side::Server);
+        Ok((rpc_system, client))
+    }
+
+    /// Run the provided function on a new RPC connection to the server.
+    async fn call<F, Fut, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(conmon::Client) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let (mut rpc_system, client) = self.rpc_system().await?;
+
+        tokio::select! {
+            res = f(client) => res,
+            res = &mut rpc_system => {
+                res?;
+                Err(Error::Disconnected)
+            }
+        }
+    }
+
+    /// Retrieve all available version information of the server.
+    pub async fn version(&self) -> Result<VersionResponse> {
+        self.call(|client| async move {
+            let response = client.version_request().send().promise.await?;
+            let response = response.get()?.get_response()?;
+            Ok(VersionResponse {
+                version: response.get_version()?.into(),
+                tag: response.get_tag()?.into(),
+                commit: response.get_commit()?.into(),
+                build_date: response.get_build_date()?.into(),
+                rust_version: response.get_rust_version()?.into(),
+                process_id: response.get_process_id(),
+                reaped_orphans: response.get_reaped_orphans(),
+            })
+        })
+        .await
+    }
+
+    /// Create a new running container instance.
+    pub async fn create_container(
+        &self,
+        config: &CreateContainerConfig,
+    ) -> Result<CreateContainerResponse> {
+        self.call(|client| async move {
+            let mut request = client.create_container_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_id(&config.id);
+            req.set_bundle_path(&config.bundle_path.display().to_string());
+            req.set_terminal(config.terminal);
+            req.set_stdin(config.stdin);
+            req.set_stdin_once(config.stdin_once);
+            if let Some(path) = &config.recording_path {
+                req.set_recording_path(&path.display().to_string());
+            }
+            req.set_record_stdin(config.record_stdin);
+            Self::init_terminal_options(
+                req.reborrow().init_terminal_options(),
+                &config.terminal_options,
+            );
+
+            let mut exit_paths = req
+                .reborrow()
+                .init_exit_paths(config.exit_paths.len() as u32);
+            for (i, path) in config.exit_paths.iter().enumerate() {
+                exit_paths.set(i as u32, &path.display().to_string());
+            }
+
+            let mut oom_exit_paths = req
+                .reborrow()
+                .init_oom_exit_paths(config.oom_exit_paths.len() as u32);
+            for (i, path) in config.oom_exit_paths.iter().enumerate() {
+                oom_exit_paths.set(i as u32, &path.display().to_string());
+            }
+
+            let mut restart_policy = req.reborrow().init_restart_policy();
+            match config.restart_policy {
+                RestartPolicy::No => restart_policy.set_mode(restart_policy::Mode::No),
+                RestartPolicy::OnFailure { max_retries } => {
+                    restart_policy.set_mode(restart_policy::Mode::OnFailure);
+                    restart_policy.set_max_retries(max_retries);
+                }
+                RestartPolicy::Always => restart_policy.set_mode(restart_policy::Mode::Always),
+            }
+            req.set_timeout_sec(config.timeout.map(Self::ceil_secs).unwrap_or_default());
+            req.set_scrollback_size(config.scrollback_size);
+
+            let mut log_drivers = req.init_log_drivers(config.log_drivers.len() as u32);
+            for (i, driver) in config.log_drivers.iter().enumerate() {
+                let mut n = log_drivers.reborrow().get(i as u32);
+                n.set_type(match driver.typ {
+                    LogDriverType::ContainerRuntimeInterface => {
+                        log_driver::Type::ContainerRuntimeInterface
+                    }
+                });
+                n.set_path(&driver.path.display().to_string());
+            }
+
+            let response = request.send().promise.await?;
+            Ok(CreateContainerResponse {
+                pid: response.get()?.get_response()?.get_container_pid(),
+            })
+        })
+        .await
+    }
+
+    /// Execute a command within a running container and wait for its result.
+    pub async fn exec_sync_container(&self, config: &ExecSyncConfig) -> Result<ExecSyncResponse> {
+        self.call(|client| async move {
+            let mut request = client.exec_sync_container_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_id(&config.id);
+            req.set_timeout_sec(config.timeout.map(Self::ceil_secs).unwrap_or_default());
+            req.set_terminal(config.terminal);
+            if let Some(path) = &config.recording_path {
+                req.set_recording_path(&path.display().to_string());
+            }
+            Self::init_terminal_options(
+                req.reborrow().init_terminal_options(),
+                &config.terminal_options,
+            );
+
+            let mut command = req.init_command(config.command.len() as u32);
+            for (i, arg) in config.command.iter().enumerate() {
+                command.set(i as u32, arg);
+            }
+
+            let response = request.send().promise.await?;
+            let response = response.get()?.get_response()?;
+            Ok(ExecSyncResponse {
+                exit_code: response.get_exit_code(),
+                stdout: response.get_stdout()?.to_vec(),
+                stderr: response.get_stderr()?.to_vec(),
+                timed_out: response.get_timed_out(),
+            })
+        })
+        .await
+    }
+
+    /// Create an attach socket for a running container.
+    pub async fn attach_container(&self, config: &AttachConfig) -> Result<()> {
+        self.call(|client| async move {
+            let mut request = client.attach_container_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            Self::init_attach_request(&mut req, config);
+            req.set_socket_path(&config.socket_path.display().to_string());
+
+            request.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Attach to a running container over a dedicated RPC connection instead of an attach
+    /// socket, which means that the `socket_path` of the configuration is ignored.
+    ///
+    /// The connection is driven by a local task, which requires the stream to be used within a
+    /// `tokio::task::LocalSet`.
+    pub async fn attach_container_stream(&self, config: &AttachConfig) -> Result<RpcAttachStream> {
+        let (rpc_system, client) = self.rpc_system().await?;
+        let rpc_task = RpcTask::spawn(rpc_system);
+        let (output_sink, output_rx) = OutputSink::new();
+
+        let mut request = client.attach_container_request();
+        let mut req = request.get().init_request();
+        self.init_metadata(req.reborrow().init_metadata());
+        Self::init_attach_request(&mut req, config);
+        req.set_output_sink(capnp_rpc::new_client(output_sink));
+
+        let response = request.send().promise.await?;
+        let stdin_sink = response.get()?.get_response()?.get_stdin_sink()?;
+        Ok(RpcAttachStream::new(stdin_sink, output_rx, rpc_task))
+    }
+
+    /// Connect to a port on localhost within the network namespace of a running container.
+    ///
+    /// The connection is driven by a local task, which requires the stream to be used within a
+    /// `tokio::task::LocalSet`.
+    pub async fn port_forward(&self, id: &str, port: u16) -> Result<PortForwardStream> {
+        let (rpc_system, client) = self.rpc_system().await?;
+        let rpc_task = RpcTask::spawn(rpc_system);
+        let (output_sink, output_rx) = OutputSink::new();
+
+        let mut request = client.port_forward_request();
+        let mut req = request.get().init_request();
+        self.init_metadata(req.reborrow().init_metadata());
+        req.set_id(id);
+        req.set_port(port);
+        req.set_output_sink(capnp_rpc::new_client(output_sink));
+
+        let response = request.send().promise.await?;
+        let input_sink = response.get()?.get_response()?.get_input_sink()?;
+        Ok(PortForwardStream::new(input_sink, output_rx, rpc_task))
+    }
+
+    fn init_terminal_options(
+        mut builder: conmon::terminal_options::Builder,
+        options: &TerminalOptions,
+    ) {
+        builder.set_width(options.width);
+        builder.set_height(options.height);
+        builder.set_raw(options.raw);
+        builder.set_disable_echo(options.disable_echo);
+    }
+
+    fn init_attach_request(req: &mut attach_request::Builder, config: &AttachConfig) {
+        req.set_id(&config.id);
+        req.set_exec_session_id(&config.exec_session_id);
+        req.set_scrollback_size(config.scrollback_size);
+        req.set_detach_keys(&config.detach_keys);
+        req.set_slow_client_policy(match config.slow_client_policy {
+            SlowClientPolicy::Drop => attach_request::SlowClientPolicy::Drop,
+            SlowClientPolicy::Block => attach_request::SlowClientPolicy::Block,
+            SlowClientPolicy::Disconnect => attach_request::SlowClientPolicy::Disconnect,
+        });
+    }
+
+    /// Rotate all configured container log drivers.
+    pub async fn reopen_log_container(&self, id: &str) -> Result<()> {
+        self.call(|client| async move {
+            let mut request = client.reopen_log_container_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_id(id);
+
+            request.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Set the terminal window size of a running container.
+    pub async fn set_window_size_container(&self, id: &str, width: u16, height: u16) -> Result<()> {
+        self.call(|client| async move {
+            let mut request = client.set_window_size_container_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_id(id);
+            req.set_width(width);
+            req.set_height(height);
+
+            request.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// List all running containers and exec sessions.
+    pub async fn list_containers(&self) -> Result<Vec<ContainerInfo>> {
+        self.call(|client| async move {
+            let mut request = client.list_containers_request();
+            self.init_metadata(request.get().init_request().init_metadata());
+
+            let response = request.send().promise.await?;
+            let mut containers = vec![];
+            for container in response.get()?.get_response()?.get_containers()?.iter() {
+                let mut log_drivers = vec![];
+                for driver in container.get_log_drivers()?.iter() {
+                    log_drivers.push(LogDriverConfig {
+                        typ: match driver.get_type()? {
+                            log_driver::Type::ContainerRuntimeInterface => {
+                                LogDriverType::ContainerRuntimeInterface
+                            }
+                        },
+                        path: driver.get_path()?.into(),
+                    });
+                }
+                let mut probes = vec![];
+                for probe in container.get_probes()?.iter() {
+                    probes.push(ProbeStatus {
+                        name: probe.get_name()?.into(),
+                        result: match probe.get_result()? {
+                            conmon::ProbeResult::Unknown => ProbeResult::Unknown,
+                            conmon::ProbeResult::Success => ProbeResult::Success,
+                            conmon::ProbeResult::Failure => ProbeResult::Failure,
+                        },
+                        message: probe.get_message()?.into(),
+                    });
+                }
+                containers.push(ContainerInfo {
+                    id: container.get_id()?.into(),
+                    pid: container.get_pid(),
+                    log_drivers,
+                    probes,
+                    restart_count: container.get_restart_count(),
+                    last_exit_code: container.get_last_exit_code(),
+                });
+            }
+            Ok(containers)
+        })
+        .await
+    }
+
+    /// Register a health probe which the server runs periodically against the container. The
+    /// probe state gets returned by the `list_containers` method.
+    pub async fn register_probe(&self, config: &RegisterProbeConfig) -> Result<()> {
+        self.call(|client| async move {
+            let mut request = client.register_probe_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_id(&config.id);
+            req.set_name(&config.name);
+            match &config.handler {
+                ProbeHandler::Exec(command) => {
+                    let mut list = req.reborrow().init_command(command.len() as u32);
+                    for (i, arg) in command.iter().enumerate() {
+                        list.set(i as u32, arg);
+                    }
+                }
+                ProbeHandler::Tcp(port) => req.set_tcp_port(*port),
+            }
+            req.set_initial_delay_sec(config.initial_delay.as_secs() as u32);
+            req.set_period_sec(config.period.as_secs() as u32);
+            req.set_timeout_sec(config.timeout.as_secs() as u32);
+            req.set_success_threshold(config.success_threshold);
+            req.set_failure_threshold(config.failure_threshold);
+
+            request.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Create and pin the namespaces of a pod.
+    pub async fn create_namespaces(
+        &self,
+        config: &CreateNamespacesConfig,
+    ) -> Result<Vec<NamespaceInfo>> {
+        self.call(|client| async move {
+            let mut request = client.create_namespaces_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_base_path(&config.base_path.display().to_string());
+            req.set_pod_id(&config.pod_id);
+
+            let mut namespaces = req
+                .reborrow()
+                .init_namespaces(config.namespaces.len() as u32);
+            for (i, namespace) in config.namespaces.iter().enumerate() {
+                namespaces.set(
+                    i as u32,
+                    match namespace {
+                        Namespace::Ipc => conmon::Namespace::Ipc,
+                        Namespace::Net => conmon::Namespace::Net,
+                        Namespace::Uts => conmon::Namespace::Uts,
+                        Namespace::User => conmon::Namespace::User,
+                        Namespace::Cgroup => conmon::Namespace::Cgroup,
+                    },
+                );
+            }
+
+            let mut uid_mappings = req
+                .reborrow()
+                .init_uid_mappings(config.uid_mappings.len() as u32);
+            for (i, mapping) in config.uid_mappings.iter().enumerate() {
+                uid_mappings.set(i as u32, mapping);
+            }
+
+            let mut gid_mappings = req
+                .reborrow()
+                .init_gid_mappings(config.gid_mappings.len() as u32);
+            for (i, mapping) in config.gid_mappings.iter().enumerate() {
+                gid_mappings.set(i as u32, mapping);
+            }
+
+            let mut sysctls = req.init_sysctls(config.sysctls.len() as u32);
+            for (i, (key, value)) in config.sysctls.iter().enumerate() {
+                let mut sysctl = sysctls.reborrow().get(i as u32);
+                sysctl.set_key(key);
+                sysctl.set_value(value);
+            }
+
+            let response = request.send().promise.await?;
+            let mut namespaces = vec![];
+            for namespace in response.get()?.get_response()?.get_namespaces()?.iter() {
+                namespaces.push(NamespaceInfo {
+                    typ: match namespace.get_type()? {
+                        conmon::Namespace::Ipc => Namespace::Ipc,
+                        conmon::Namespace::Net => Namespace::Net,
+                        conmon::Namespace::Uts => Namespace::Uts,
+                        conmon::Namespace::User => Namespace::User,
+                        conmon::Namespace::Cgroup => Namespace::Cgroup,
+                    },
+                    path: namespace.get_path()?.into(),
+                });
+            }
+            Ok(namespaces)
+        })
+        .await
+    }
+
+    /// Remove the pinned namespaces of a pod.
+    pub async fn remove_namespaces(&self, base_path: &Path, pod_id: &str) -> Result<()> {
+        self.call(|client| async move {
+            let mut request = client.remove_namespaces_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_base_path(&base_path.display().to_string());
+            req.set_pod_id(pod_id);
+
+            request.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Send the provided signal to a running container.
+    pub async fn kill_container(&self, id: &str, signal: Signal) -> Result<()> {
+        self.call(|client| async move {
+            let mut request = client.kill_container_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_id(id);
+            req.set_signal(signal as u32);
+
+            request.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Change the log level of the server to the provided level or comma separated filter
+    /// directives like `conmon::attach=trace,info`. Every directive is either a `level` or a
+    /// `target=level`, where span and field directives are not supported.
+    ///
+    /// The previous log level gets restored after the timeout, where `None` keeps the new one.
+    pub async fn set_log_level(&self, filter: &str, timeout: Option<Duration>) -> Result<()> {
+        self.call(|client| async move {
+            let mut request = client.set_log_level_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_filter(filter);
+            req.set_timeout_sec(timeout.map(Self::ceil_secs).unwrap_or_default());
+
+            request.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Shutdown the server by using the provided mode.
+    ///
+    /// The grace period is the time to wait before killing the containers with `SIGKILL` when
+    /// using `ShutdownMode::KillAll`, where `None` disables the escalation.
+    pub async fn shutdown(&self, mode: ShutdownMode, grace_period: Option<Duration>) -> Result<()> {
+        self.call(|client| async move {
+            let mut request = client.shutdown_request();
+            let mut req = request.get().init_request();
+            self.init_metadata(req.reborrow().init_metadata());
+            req.set_mode(match mode {
+                ShutdownMode::KillAll => shutdown_request::Mode::KillAll,
+                ShutdownMode::Detach => shutdown_request::Mode::Detach,
+                ShutdownMode::WaitForExit => shutdown_request::Mode::WaitForExit,
+            });
+            req.set_grace_period_sec(grace_period.map(Self::ceil_secs).unwrap_or_default());
+
+            request.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Convert the duration into seconds while rounding up, because zero disables timeouts.
+    fn ceil_secs(duration: Duration) -> u64 {
+        duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use capnp::capability::Promise;
+    use capnp_rpc::pry;
+    use futures::FutureExt;
+    use tempfile::tempdir;
+    use tokio::{net::UnixListener, task};
+
+    struct FakeServer;
+
+    impl conmon::Server for FakeServer {
+        fn version(
+            &mut self,
+            _: conmon::VersionParams,
+            mut results: conmon::VersionResults,
+        ) -> Promise<(), capnp::Error> {
+            let mut response = results.get().init_response();
+            response.set_version("1.2.3");
+            response.set_process_id(42);
+            Promise::ok(())
+        }
+
+        fn kill_container(
+            &mut self,
+            params: conmon::KillContainerParams,
+            _: conmon::KillContainerResults,
+        ) -> Promise<(), capnp::Error> {
+            let req = pry!(pry!(params.get()).get_request());
+            let metadata = pry!(req.get_metadata());
+            if pry!(metadata.get_caller_name()) != "test" {
+                return Promise::err(capnp::Error::failed("unknown caller".into()));
+            }
+            Promise::ok(())
+        }
+    }
+
+    fn serve(listener: UnixListener) {
+        task::spawn_local(async move {
+            let client: conmon::Client = capnp_rpc::new_client(FakeServer);
+            while let Ok((stream, _)) = listener.accept().await {
+                let (reader, writer) = TokioAsyncReadCompatExt::compat(stream).split();
+                let network = Box::new(VatNetwork::new(
+                    reader,
+                    writer,
+                    Side::Server,
+                    Default::default(),
+                ));
+                let rpc_system = RpcSystem::new(network, Some(client.clone().client));
+                task::spawn_local(rpc_system.map(|_| ()));
+            }
+        });
+    }
+
+    #[tokio::test]
+    async fn connect_success() -> Result<()> {
+        task::LocalSet::new()
+            .run_until(async {
+                let dir = tempdir()?;
+                let socket = dir.path().join("conmon.sock");
+                serve(UnixListener::bind(&socket)?);
+
+                let sut = ConmonClient::connect(&socket).await?;
+                assert_eq!(sut.server_pid(), 42);
+                assert_eq!(sut.socket(), &socket);
+                assert_eq!(sut.version().await?.version, "1.2.3");
+                assert!(matches!(
+                    sut.reopen_log_container("id").await,
+                    Err(Error::Rpc(_))
+                ));
+
+                assert!(sut.kill_container("id", Signal::SIGTERM).await.is_err());
+                let sut = sut.with_metadata(RequestMetadata {
+                    caller_name: Some("test".into()),
+                    ..Default::default()
+                });
+                assert_eq!(sut.server_pid(), 42);
+                sut.kill_container("id", Signal::SIGTERM).await?;
+                Ok(())
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn connect_failure() -> Result<()> {
+        let dir = tempdir()?;
+        let res = ConmonClient::connect(dir.path().join("conmon.sock")).await;
+        assert!(matches!(res, Err(Error::Connect { .. })));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_pid_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("pidfile");
+        fs::write(&path, "1234").await?;
+        assert_eq!(ConmonClient::read_pid(&path).await?, 1234);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_pid_failure() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("pidfile");
+        assert!(matches!(
+            ConmonClient::read_pid(&path).await,
+            Err(Error::PidFile { .. })
+        ));
+
+        fs::write(&path, "wrong").await?;
+        assert!(matches!(
+            ConmonClient::read_pid(&path).await,
+            Err(Error::InvalidPid(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn ceil_secs() {
+        assert_eq!(ConmonClient::ceil_secs(Duration::from_secs(2)), 2);
+        assert_eq!(ConmonClient::ceil_secs(Duration::from_millis(2001)), 3);
+        assert_eq!(ConmonClient::ceil_secs(Duration::from_millis(1)), 1);
+    }
+}
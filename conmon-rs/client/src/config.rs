@@ -0,0 +1,340 @@
+//! Configuration of the conmon server spawned by the client.
+
+use crate::error::{Error, Result};
+use getset::{CopyGetters, Getters, Setters};
+use std::path::PathBuf;
+use strum::{AsRefStr, Display, EnumString};
+
+/// The default binary name of the server, which gets looked up in `$PATH`.
+pub const DEFAULT_SERVER_PATH: &str = "conmonrs";
+
+/// The name of the socket file within the runtime directory.
+pub const SOCKET: &str = "conmon.sock";
+
+/// The name of the pid file within the runtime directory.
+pub const PIDFILE: &str = "pidfile";
+
+#[derive(Clone, Debug, CopyGetters, Getters, Setters)]
+/// The configuration used to start a new conmon server instance.
+pub struct ServerConfig {
+    #[getset(get = "pub", set = "pub")]
+    /// Binary path of the conmon server.
+    server_path: PathBuf,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// The logging level of the conmon server.
+    log_level: LogLevel,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// The logging driver used by the conmon server.
+    log_driver: LogDriver,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Path of the log file used by the file log driver.
+    log_file: Option<PathBuf>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Size in bytes after which the log file gets rotated, where zero disables the rotation.
+    /// Uses the server default if not set.
+    log_file_max_size: Option<u64>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Number of rotated log files to keep. Uses the server default if not set.
+    log_file_max_backups: Option<u32>,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Binary path of the OCI runtime to use to operate on the containers.
+    runtime: PathBuf,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Root directory used by the OCI runtime to operate on containers.
+    runtime_root: Option<PathBuf>,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Path of the directory for the server to hold files at runtime.
+    runtime_dir: PathBuf,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// OOM score adjustment of the server. Uses the server default if not set.
+    oom_score_adj: Option<i32>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Soft and hard limit of open file descriptors of the server.
+    rlimit_nofile: Option<u64>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Soft and hard limit of the core dump size of the server.
+    rlimit_core: Option<u64>,
+
+    #[getset(get = "pub", set = "pub")]
+    /// The cgroup v2 path to move the server into, or the name of the systemd scope when using
+    /// the systemd cgroup manager.
+    cgroup: Option<String>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// The cgroup manager used by the server to move itself into its cgroup.
+    cgroup_manager: CgroupManager,
+}
+
+#[derive(AsRefStr, Clone, Copy, Debug, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+/// Available log levels of the server.
+pub enum LogLevel {
+    /// Trace level.
+    Trace,
+
+    /// Debug level.
+    Debug,
+
+    /// Info level.
+    Info,
+
+    /// Warning level.
+    Warn,
+
+    /// Error level.
+    Error,
+
+    /// Disable logging.
+    Off,
+}
+
+#[derive(AsRefStr, Clone, Copy, Debug, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+/// Available log drivers of the server.
+pub enum LogDriver {
+    /// Log to standard output, which gets inherited from the client.
+    Stdout,
+
+    /// Log to the systemd journal.
+    Systemd,
+
+    /// Log JSON lines to standard output, which gets inherited from the client.
+    Json,
+
+    /// Log JSON lines to the log file, which gets rotated by size.
+    File,
+}
+
+#[derive(AsRefStr, Clone, Copy, Debug, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+/// Available cgroup managers of the server.
+pub enum CgroupManager {
+    /// Write to the cgroup v2 filesystem directly.
+    Cgroupfs,
+
+    /// Create a transient systemd scope.
+    Systemd,
+}
+
+impl ServerConfig {
+    /// Create a new server configuration for the provided runtime and runtime directory.
+    pub fn new<T, U>(runtime: T, runtime_dir: U) -> Self
+    where
+        T: Into<PathBuf>,
+        U: Into<PathBuf>,
+    {
+        Self {
+            server_path: DEFAULT_SERVER_PATH.into(),
+            log_level: LogLevel::Info,
+            log_driver: LogDriver::Systemd,
+            log_file: None,
+            log_file_max_size: None,
+            log_file_max_backups: None,
+            runtime: runtime.into(),
+            runtime_root: None,
+            runtime_dir: runtime_dir.into(),
+            oom_score_adj: None,
+            rlimit_nofile: None,
+            rlimit_core: None,
+            cgroup: None,
+            cgroup_manager: CgroupManager::Cgroupfs,
+        }
+    }
+
+    /// The socket path of the server.
+    pub fn socket(&self) -> PathBuf {
+        self.runtime_dir().join(SOCKET)
+    }
+
+    /// The pid file path of the server.
+    pub fn pidfile(&self) -> PathBuf {
+        self.runtime_dir().join(PIDFILE)
+    }
+
+    /// Generate the server command line arguments from the configuration.
+    pub fn args(&self) -> Result<Vec<String>> {
+        if self.runtime().as_os_str().is_empty() {
+            return Err(Error::InvalidConfig("runtime must be specified".into()));
+        }
+        if self.runtime_dir().as_os_str().is_empty() {
+            return Err(Error::InvalidConfig(
+                "runtime directory must be specified".into(),
+            ));
+        }
+        if self.log_driver() == LogDriver::File && self.log_file().is_none() {
+            return Err(Error::InvalidConfig(
+                "log file must be specified for the file log driver".into(),
+            ));
+        }
+
+        let mut args = vec![
+            "--runtime".into(),
+            self.runtime().display().to_string(),
+            "--runtime-dir".into(),
+            self.runtime_dir().display().to_string(),
+        ];
+
+        if let Some(runtime_root) = self.runtime_root() {
+            args.push("--runtime-root".into());
+            args.push(runtime_root.display().to_string());
+        }
+
+        args.push("--log-level".into());
+        args.push(self.log_level().to_string());
+        args.push("--log-driver".into());
+        args.push(self.log_driver().to_string());
+
+        if let Some(log_file) = self.log_file() {
+            args.push("--log-file".into());
+            args.push(log_file.display().to_string());
+        }
+        if let Some(max_size) = self.log_file_max_size() {
+            args.push("--log-file-max-size".into());
+            args.push(max_size.to_string());
+        }
+        if let Some(max_backups) = self.log_file_max_backups() {
+            args.push("--log-file-max-backups".into());
+            args.push(max_backups.to_string());
+        }
+
+        if let Some(oom_score_adj) = self.oom_score_adj() {
+            args.push(format!("--oom-score-adj={}", oom_score_adj));
+        }
+        if let Some(limit) = self.rlimit_nofile() {
+            args.push("--rlimit-nofile".into());
+            args.push(limit.to_string());
+        }
+        if let Some(limit) = self.rlimit_core() {
+            args.push("--rlimit-core".into());
+            args.push(limit.to_string());
+        }
+        if let Some(cgroup) = self.cgroup() {
+            args.push("--cgroup".into());
+            args.push(cgroup.clone());
+            args.push("--cgroup-manager".into());
+            args.push(self.cgroup_manager().to_string());
+        }
+
+        Ok(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn args_success() -> Result<()> {
+        let mut sut = ServerConfig::new("/usr/bin/runc", "/run/conmon");
+        sut.set_runtime_root(Some("/run/runc".into()))
+            .set_log_level(LogLevel::Debug)
+            .set_log_driver(LogDriver::Stdout);
+
+        assert_eq!(
+            sut.args()?,
+            vec![
+                "--runtime",
+                "/usr/bin/runc",
+                "--runtime-dir",
+                "/run/conmon",
+                "--runtime-root",
+                "/run/runc",
+                "--log-level",
+                "debug",
+                "--log-driver",
+                "stdout",
+            ]
+        );
+        assert_eq!(sut.socket(), PathBuf::from("/run/conmon/conmon.sock"));
+        assert_eq!(sut.pidfile(), PathBuf::from("/run/conmon/pidfile"));
+        Ok(())
+    }
+
+    #[test]
+    fn args_success_log_file() -> Result<()> {
+        let mut sut = ServerConfig::new("/usr/bin/runc", "/run/conmon");
+        sut.set_log_driver(LogDriver::File)
+            .set_log_file(Some("/var/log/conmonrs.log".into()))
+            .set_log_file_max_size(Some(1024));
+
+        assert_eq!(
+            sut.args()?,
+            vec![
+                "--runtime",
+                "/usr/bin/runc",
+                "--runtime-dir",
+                "/run/conmon",
+                "--log-level",
+                "info",
+                "--log-driver",
+                "file",
+                "--log-file",
+                "/var/log/conmonrs.log",
+                "--log-file-max-size",
+                "1024",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn args_success_resources() -> Result<()> {
+        let mut sut = ServerConfig::new("/usr/bin/runc", "/run/conmon");
+        sut.set_oom_score_adj(Some(-999))
+            .set_rlimit_nofile(Some(4096))
+            .set_cgroup(Some("conmonrs-pod.scope".into()))
+            .set_cgroup_manager(CgroupManager::Systemd);
+
+        assert_eq!(
+            sut.args()?,
+            vec![
+                "--runtime",
+                "/usr/bin/runc",
+                "--runtime-dir",
+                "/run/conmon",
+                "--log-level",
+                "info",
+                "--log-driver",
+                "systemd",
+                "--oom-score-adj=-999",
+                "--rlimit-nofile",
+                "4096",
+                "--cgroup",
+                "conmonrs-pod.scope",
+                "--cgroup-manager",
+                "systemd",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn args_failure_no_log_file() {
+        let mut sut = ServerConfig::new("/usr/bin/runc", "/run/conmon");
+        sut.set_log_driver(LogDriver::File);
+        assert!(matches!(sut.args(), Err(Error::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn args_failure_no_runtime() {
+        let sut = ServerConfig::new("", "/run/conmon");
+        assert!(matches!(sut.args(), Err(Error::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn args_failure_no_runtime_dir() {
+        let sut = ServerConfig::new("/usr/bin/runc", "");
+        assert!(matches!(sut.args(), Err(Error::InvalidConfig(_))));
+    }
+}
@@ -0,0 +1,136 @@
+//! Interactive container attach.
+
+use crate::terminal::{self, RawMode};
+use anyhow::{Context, Result};
+use conmon_client::{AttachConfig, AttachPipe, AttachStream, ConmonClient};
+use std::{env, fs, os::unix::io::AsRawFd, path::Path, process};
+use tokio::{
+    io::{self, AsyncReadExt, AsyncWriteExt},
+    signal::unix::{signal, SignalKind},
+};
+
+/// Attach to the container and forward standard input, output and terminal resizes until the
+/// server closes the connection. The socket path of the config gets set to a temporary location.
+pub async fn run(client: &ConmonClient, mut config: AttachConfig) -> Result<()> {
+    let socket_path = env::temp_dir().join(format!("conmonrs-ctl-{}-{}", config.id, process::id()));
+    config.socket_path = socket_path.clone();
+    client
+        .attach_container(&config)
+        .await
+        .context("create attach socket")?;
+
+    let res = forward(&socket_path).await;
+
+    // The server forgets about attach endpoints whose socket path does not exist any more.
+    fs::remove_file(&socket_path).ok();
+    res
+}
+
+async fn forward(socket_path: &Path) -> Result<()> {
+    let stream = AttachStream::connect(socket_path)
+        .await
+        .context("connect to attach socket")?;
+
+    let stdin_fd = std::io::stdin().as_raw_fd();
+    let stdout_fd = std::io::stdout().as_raw_fd();
+    let _raw_mode = RawMode::enable(stdin_fd)?;
+
+    // The server ignores resizes for containers without a terminal.
+    let resize = || async {
+        match terminal::size(stdout_fd) {
+            Some((width, height)) => stream.resize(width, height).await,
+            None => Ok(()),
+        }
+    };
+    resize().await?;
+
+    let mut sigwinch = signal(SignalKind::window_change())?;
+    let mut stdin = io::stdin();
+    let mut stdin_open = true;
+    let mut buf = vec![0; 1024];
+
+    loop {
+        tokio::select! {
+            packet = stream.read() => match packet? {
+                Some((pipe, data)) => write_output(pipe, &data).await?,
+                None => break,
+            },
+            n = stdin.read(&mut buf), if stdin_open => match n? {
+                0 => {
+                    stdin_open = false;
+                    stream.close_stdin().await?;
+                }
+                n => stream.write(&buf[..n]).await?,
+            },
+            _ = sigwinch.recv() => resize().await?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Attach to the container over the RPC connection and forward standard input, output and
+/// terminal resizes until the server closes the attach session.
+pub async fn run_rpc(client: &ConmonClient, config: AttachConfig) -> Result<()> {
+    let mut stream = client
+        .attach_container_stream(&config)
+        .await
+        .context("attach over rpc")?;
+
+    let stdin_fd = std::io::stdin().as_raw_fd();
+    let stdout_fd = std::io::stdout().as_raw_fd();
+    let _raw_mode = RawMode::enable(stdin_fd)?;
+
+    // Containers without a terminal and exec sessions cannot be resized, which is not fatal.
+    let resize = || async {
+        if let (Some((width, height)), true) =
+            (terminal::size(stdout_fd), config.exec_session_id.is_empty())
+        {
+            client
+                .set_window_size_container(&config.id, width, height)
+                .await
+                .ok();
+        }
+    };
+    resize().await;
+
+    let mut sigwinch = signal(SignalKind::window_change())?;
+    let mut stdin = io::stdin();
+    let mut stdin_open = true;
+    let mut buf = vec![0; 1024];
+
+    loop {
+        tokio::select! {
+            output = stream.read() => match output {
+                Some((pipe, data)) => write_output(pipe, &data).await?,
+                None => break,
+            },
+            n = stdin.read(&mut buf), if stdin_open => match n? {
+                0 => {
+                    stdin_open = false;
+                    stream.close_stdin().await?;
+                }
+                n => stream.write(&buf[..n]).await?,
+            },
+            _ = sigwinch.recv() => resize().await,
+        }
+    }
+
+    Ok(())
+}
+
+async fn write_output(pipe: AttachPipe, data: &[u8]) -> Result<()> {
+    match pipe {
+        AttachPipe::Stdout => {
+            let mut stdout = io::stdout();
+            stdout.write_all(data).await?;
+            stdout.flush().await?;
+        }
+        AttachPipe::Stderr => {
+            let mut stderr = io::stderr();
+            stderr.write_all(data).await?;
+            stderr.flush().await?;
+        }
+    }
+    Ok(())
+}
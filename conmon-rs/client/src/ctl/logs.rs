@@ -0,0 +1,138 @@
+//! Container log printing.
+
+use anyhow::{bail, Context, Result};
+use conmon_client::{ConmonClient, LogDriverType};
+use std::{
+    io::SeekFrom,
+    os::unix::fs::MetadataExt,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::{
+    fs::{self, File},
+    io::{self, AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+    time,
+};
+
+/// The interval used to poll the log file for new content when following.
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Print the CRI log of the container and keep printing new entries if `follow` is true.
+pub async fn run(client: &ConmonClient, id: &str, follow: bool) -> Result<()> {
+    let path = log_path(client, id).await?;
+    let mut printer = Printer::default();
+    let mut file = File::open(&path).await.context("open log file")?;
+    let mut buf = vec![0; 8192];
+
+    loop {
+        let n = file.read(&mut buf).await?;
+        if n > 0 {
+            printer.print(&buf[..n]).await?;
+            continue;
+        }
+        if !follow {
+            break;
+        }
+
+        time::sleep(FOLLOW_INTERVAL).await;
+        if rotated(&file, &path).await {
+            file = File::open(&path).await.context("reopen log file")?;
+        } else if file.stream_position().await? > file.metadata().await?.len() {
+            file.seek(SeekFrom::Start(0)).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn log_path(client: &ConmonClient, id: &str) -> Result<PathBuf> {
+    let containers = client.list_containers().await?;
+    let path = containers
+        .into_iter()
+        .filter(|x| x.id == id)
+        .flat_map(|x| x.log_drivers)
+        .find(|x| x.typ == LogDriverType::ContainerRuntimeInterface)
+        .map(|x| x.path);
+    match path {
+        Some(path) => Ok(path),
+        None => bail!("no CRI log found for container {}", id),
+    }
+}
+
+/// Returns true if the file on the path got replaced, for example by a log rotation.
+async fn rotated(file: &File, path: &Path) -> bool {
+    match (file.metadata().await, fs::metadata(path).await) {
+        (Ok(a), Ok(b)) => a.ino() != b.ino() || a.dev() != b.dev(),
+        _ => false,
+    }
+}
+
+#[derive(Default)]
+/// Printer converts CRI log lines into their plain content.
+struct Printer {
+    line: Vec<u8>,
+}
+
+impl Printer {
+    async fn print(&mut self, data: &[u8]) -> Result<()> {
+        for &b in data {
+            if b != b'\n' {
+                self.line.push(b);
+                continue;
+            }
+            if let Some((stderr, content)) = Self::parse(&self.line) {
+                if stderr {
+                    io::stderr().write_all(&content).await?;
+                } else {
+                    io::stdout().write_all(&content).await?;
+                }
+            }
+            self.line.clear();
+        }
+        io::stdout().flush().await?;
+        io::stderr().flush().await?;
+        Ok(())
+    }
+
+    /// Parse a line in the format `<timestamp> <stream> <tag> <content>`, whereas the tag is `P`
+    /// for partial and `F` for full lines. Returns if the line belongs to stderr and its content.
+    fn parse(line: &[u8]) -> Option<(bool, Vec<u8>)> {
+        let mut parts = line.splitn(4, |&x| x == b' ');
+        let _timestamp = parts.next()?;
+        let stderr = parts.next()? == b"stderr";
+        let tag = parts.next()?;
+        let mut content = parts.next().unwrap_or_default().to_vec();
+        if tag != b"P" {
+            content.push(b'\n');
+        }
+        Some((stderr, content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_full_line() {
+        let res = Printer::parse(b"2022-01-01T00:00:00.000000000+00:00 stdout F hello world");
+        assert_eq!(res, Some((false, b"hello world\n".to_vec())));
+    }
+
+    #[test]
+    fn parse_partial_line() {
+        let res = Printer::parse(b"2022-01-01T00:00:00.000000000+00:00 stderr P hello");
+        assert_eq!(res, Some((true, b"hello".to_vec())));
+    }
+
+    #[test]
+    fn parse_empty_line() {
+        let res = Printer::parse(b"2022-01-01T00:00:00.000000000+00:00 stdout F");
+        assert_eq!(res, Some((false, b"\n".to_vec())));
+    }
+
+    #[test]
+    fn parse_invalid_line() {
+        assert_eq!(Printer::parse(b"invalid"), None);
+    }
+}
@@ -0,0 +1,357 @@
+//! conmonrs-ctl is a command line tool for debugging a running conmon-rs server.
+
+use anyhow::{Context, Result};
+use clap::{AppSettings, Parser, Subcommand};
+use conmon_client::{
+    AttachConfig, ConmonClient, ExecSyncConfig, ProbeHandler, RegisterProbeConfig, Signal,
+    SlowClientPolicy, TerminalOptions,
+};
+use std::{os::unix::io::AsRawFd, path::PathBuf, process, time::Duration};
+use tokio::{io::AsyncWriteExt, task::LocalSet};
+
+mod attach;
+mod logs;
+mod port_forward;
+mod terminal;
+
+#[derive(Debug, Parser)]
+#[clap(
+    after_help("More info at: https://github.com/containers/conmon-rs"),
+    global_setting(AppSettings::DeriveDisplayOrder)
+)]
+/// Debug a running conmon-rs server.
+struct Opts {
+    #[clap(env("CONMON_SOCKET"), long("socket"), short('s'), value_name("PATH"))]
+    /// Path of the server socket.
+    socket: PathBuf,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Show the version information of the server.
+    Version,
+
+    /// List all running containers and exec sessions.
+    List,
+
+    /// Execute a command synchronously within a running container.
+    Exec {
+        /// The container identifier.
+        id: String,
+
+        #[clap(long("timeout"), value_name("SECONDS"))]
+        /// The maximum time the command is allowed to run.
+        timeout: Option<u64>,
+
+        #[clap(long("tty"), short('t'))]
+        /// Allocate a terminal for the command.
+        tty: bool,
+
+        #[clap(long("record"), value_name("PATH"), requires("tty"))]
+        /// Record the terminal session to an asciicast v2 file.
+        record: Option<PathBuf>,
+
+        #[clap(last(true), required(true))]
+        /// The command to be executed.
+        command: Vec<String>,
+    },
+
+    /// Attach interactively to a running container.
+    Attach {
+        /// The container identifier.
+        id: String,
+
+        #[clap(long("exec-session-id"), default_value(""))]
+        /// The exec session identifier, if this is an attach for an exec.
+        exec_session_id: String,
+
+        #[clap(long("scrollback"), value_name("BYTES"), default_value("0"))]
+        /// Replay up to this amount of recent output after attaching, at most 64 KiB.
+        scrollback: u64,
+
+        #[clap(
+            long("detach-keys"),
+            value_name("KEYS"),
+            default_value("ctrl-p,ctrl-q")
+        )]
+        /// The key sequence to detach from the container, disabled if empty.
+        detach_keys: String,
+
+        #[clap(
+            long("slow-client-policy"),
+            default_value("drop"),
+            possible_values(["drop", "block", "disconnect"])
+        )]
+        /// The behavior of the server if the output cannot be written fast enough.
+        slow_client_policy: SlowClientPolicy,
+
+        #[clap(long("rpc"))]
+        /// Attach over the RPC connection instead of an attach socket.
+        rpc: bool,
+    },
+
+    /// Print the logs of a container.
+    Logs {
+        /// The container identifier.
+        id: String,
+
+        #[clap(long("follow"), short('f'))]
+        /// Keep printing new log lines as they get written.
+        follow: bool,
+    },
+
+    /// Rotate all log drivers of a container.
+    ReopenLog {
+        /// The container identifier.
+        id: String,
+    },
+
+    /// Set the terminal window size of a container.
+    Resize {
+        /// The container identifier.
+        id: String,
+
+        /// Columns in characters.
+        width: u16,
+
+        /// Rows in characters.
+        height: u16,
+    },
+
+    /// Forward standard input and output to a port within the network namespace of a container.
+    PortForward {
+        /// The container identifier.
+        id: String,
+
+        /// The port on localhost.
+        port: u16,
+    },
+
+    /// Register a health probe, which the server runs periodically against a container.
+    Probe {
+        /// The container identifier.
+        id: String,
+
+        /// The name of the probe, which replaces an existing probe of the same name.
+        name: String,
+
+        #[clap(long("tcp"), value_name("PORT"), conflicts_with("command"))]
+        /// Connect to the port within the network namespace instead of executing a command.
+        tcp: Option<u16>,
+
+        #[clap(long("initial-delay"), value_name("SECONDS"), default_value("0"))]
+        /// The delay before the first run.
+        initial_delay: u64,
+
+        #[clap(long("period"), value_name("SECONDS"), default_value("10"))]
+        /// The time between two runs.
+        period: u64,
+
+        #[clap(long("timeout"), value_name("SECONDS"), default_value("1"))]
+        /// The time after which a single run fails.
+        timeout: u64,
+
+        #[clap(long("success-threshold"), default_value("1"))]
+        /// The number of consecutive successful runs until the probe succeeds.
+        success_threshold: u32,
+
+        #[clap(long("failure-threshold"), default_value("3"))]
+        /// The number of consecutive failed runs until the probe fails.
+        failure_threshold: u32,
+
+        #[clap(last(true), required_unless_present("tcp"))]
+        /// The command to be executed.
+        command: Vec<String>,
+    },
+
+    /// Send a signal to a container.
+    Kill {
+        /// The container identifier.
+        id: String,
+
+        #[clap(long("signal"), short('s'), default_value("SIGTERM"))]
+        /// The signal to be sent.
+        signal: Signal,
+    },
+
+    /// Change the log level of the server.
+    LogLevel {
+        /// The log level or comma separated filter directives like `conmon::attach=trace,info`.
+        /// Every directive is either a `level` or a `target=level`, where span and field
+        /// directives are not supported.
+        filter: String,
+
+        #[clap(long("timeout"), value_name("SECONDS"))]
+        /// Revert to the previous log level after the timeout.
+        timeout: Option<u64>,
+    },
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let opts = Opts::parse();
+    let code = match LocalSet::new().run_until(run(opts)).await {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            1
+        }
+    };
+    // Exit directly, because a pending read from standard input would block the runtime shutdown.
+    process::exit(code)
+}
+
+async fn run(opts: Opts) -> Result<i32> {
+    let client = ConmonClient::connect(opts.socket)
+        .await
+        .context("connect to server")?;
+
+    match opts.command {
+        Command::Version => {
+            let version = client.version().await?;
+            println!("version: {}", version.version);
+            println!("tag: {}", version.tag);
+            println!("commit: {}", version.commit);
+            println!("build date: {}", version.build_date);
+            println!("rust version: {}", version.rust_version);
+            println!("process id: {}", version.process_id);
+            println!("reaped orphans: {}", version.reaped_orphans);
+        }
+
+        Command::List => {
+            println!("ID\tPID\tRESTARTS\tLOGS\tPROBES");
+            for container in client.list_containers().await? {
+                let logs = container
+                    .log_drivers
+                    .iter()
+                    .map(|x| x.path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let probes = container
+                    .probes
+                    .iter()
+                    .map(|x| format!("{}={:?}", x.name, x.result).to_lowercase())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let restarts = match container.restart_count {
+                    0 => "0".into(),
+                    count => format!("{} (last exit {})", count, container.last_exit_code),
+                };
+                println!(
+                    "{}\t{}\t{}\t{}\t{}",
+                    container.id, container.pid, restarts, logs, probes
+                );
+            }
+        }
+
+        Command::Exec {
+            id,
+            timeout,
+            tty,
+            record,
+            command,
+        } => {
+            // Start with the size of the local terminal to not garble the output.
+            let (width, height) = terminal::size(std::io::stdout().as_raw_fd()).unwrap_or_default();
+            let terminal_options = TerminalOptions {
+                width,
+                height,
+                ..Default::default()
+            };
+            let response = client
+                .exec_sync_container(&ExecSyncConfig {
+                    id,
+                    command,
+                    timeout: timeout.map(Duration::from_secs),
+                    terminal: tty,
+                    recording_path: record,
+                    terminal_options,
+                })
+                .await?;
+            let mut stdout = tokio::io::stdout();
+            stdout.write_all(&response.stdout).await?;
+            stdout.flush().await?;
+            let mut stderr = tokio::io::stderr();
+            stderr.write_all(&response.stderr).await?;
+            stderr.flush().await?;
+            if response.timed_out {
+                eprintln!("Command timed out");
+            }
+            return Ok(response.exit_code);
+        }
+
+        Command::Attach {
+            id,
+            exec_session_id,
+            scrollback,
+            detach_keys,
+            slow_client_policy,
+            rpc,
+        } => {
+            let config = AttachConfig {
+                id,
+                socket_path: PathBuf::new(),
+                exec_session_id,
+                scrollback_size: scrollback,
+                detach_keys,
+                slow_client_policy,
+            };
+            if rpc {
+                attach::run_rpc(&client, config).await?
+            } else {
+                attach::run(&client, config).await?
+            }
+        }
+
+        Command::Logs { id, follow } => logs::run(&client, &id, follow).await?,
+
+        Command::ReopenLog { id } => client.reopen_log_container(&id).await?,
+
+        Command::Resize { id, width, height } => {
+            client.set_window_size_container(&id, width, height).await?
+        }
+
+        Command::PortForward { id, port } => port_forward::run(&client, &id, port).await?,
+
+        Command::Probe {
+            id,
+            name,
+            tcp,
+            initial_delay,
+            period,
+            timeout,
+            success_threshold,
+            failure_threshold,
+            command,
+        } => {
+            client
+                .register_probe(&RegisterProbeConfig {
+                    id,
+                    name,
+                    handler: match tcp {
+                        Some(port) => ProbeHandler::Tcp(port),
+                        None => ProbeHandler::Exec(command),
+                    },
+                    initial_delay: Duration::from_secs(initial_delay),
+                    period: Duration::from_secs(period),
+                    timeout: Duration::from_secs(timeout),
+                    success_threshold,
+                    failure_threshold,
+                })
+                .await?
+        }
+
+        Command::Kill { id, signal } => client.kill_container(&id, signal).await?,
+
+        Command::LogLevel { filter, timeout } => {
+            client
+                .set_log_level(&filter, timeout.map(Duration::from_secs))
+                .await?
+        }
+    }
+
+    Ok(0)
+}
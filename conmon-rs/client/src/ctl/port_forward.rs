@@ -0,0 +1,40 @@
+//! Port forwarding to standard input and output.
+
+use anyhow::{Context, Result};
+use conmon_client::ConmonClient;
+use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
+
+/// Connect to the port of the container and forward standard input and output until the
+/// connection gets closed.
+pub async fn run(client: &ConmonClient, id: &str, port: u16) -> Result<()> {
+    let mut stream = client
+        .port_forward(id, port)
+        .await
+        .context("forward port")?;
+
+    let mut stdin = io::stdin();
+    let mut stdout = io::stdout();
+    let mut stdin_open = true;
+    let mut buf = vec![0; 8192];
+
+    loop {
+        tokio::select! {
+            data = stream.read() => match data {
+                Some(data) => {
+                    stdout.write_all(&data).await?;
+                    stdout.flush().await?;
+                }
+                None => break,
+            },
+            n = stdin.read(&mut buf), if stdin_open => match n? {
+                0 => {
+                    stdin_open = false;
+                    stream.close_write().await?;
+                }
+                n => stream.write(&buf[..n]).await?,
+            },
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Local terminal handling.
+
+use anyhow::{Context, Result};
+use nix::{
+    sys::termios::{cfmakeraw, tcgetattr, tcsetattr, SetArg, Termios},
+    unistd::isatty,
+};
+use std::os::unix::io::RawFd;
+
+/// RawMode puts the terminal into raw mode and restores the previous state when being dropped.
+pub struct RawMode {
+    fd: RawFd,
+    termios: Termios,
+}
+
+impl RawMode {
+    /// Enable raw mode for the provided file descriptor if it refers to a terminal.
+    pub fn enable(fd: RawFd) -> Result<Option<Self>> {
+        if !isatty(fd).unwrap_or_default() {
+            return Ok(None);
+        }
+
+        let termios = tcgetattr(fd).context("get terminal attributes")?;
+        let mut raw = termios.clone();
+        cfmakeraw(&mut raw);
+        tcsetattr(fd, SetArg::TCSANOW, &raw).context("set terminal raw mode")?;
+
+        Ok(Some(Self { fd, termios }))
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        tcsetattr(self.fd, SetArg::TCSANOW, &self.termios).ok();
+    }
+}
+
+/// Retrieve the window size (width, height) of the terminal referred to by the file descriptor.
+pub fn size(fd: RawFd) -> Option<(u16, u16)> {
+    let mut ws: libc::winsize = unsafe { std::mem::zeroed() };
+    match unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut ws) } {
+        0 => Some((ws.ws_col, ws.ws_row)),
+        _ => None,
+    }
+}
@@ -0,0 +1,81 @@
+//! Error types of the client library.
+
+use std::{io, num::ParseIntError, path::PathBuf, process::ExitStatus, time::Duration};
+use thiserror::Error;
+
+/// A specialized `Result` type for client operations.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+/// All possible errors returned by the client.
+pub enum Error {
+    #[error("invalid server config: {0}")]
+    /// The provided server configuration is not valid.
+    InvalidConfig(String),
+
+    #[error("unable to connect to socket {}", path.display())]
+    /// The server socket is not reachable.
+    Connect {
+        /// The path of the socket.
+        path: PathBuf,
+
+        /// The underlying IO error.
+        source: io::Error,
+    },
+
+    #[error("unable to spawn server {}", path.display())]
+    /// The server binary could not be executed.
+    Spawn {
+        /// The path of the server binary.
+        path: PathBuf,
+
+        /// The underlying IO error.
+        source: io::Error,
+    },
+
+    #[error("server exited unsuccessfully: {0}")]
+    /// The server process failed to start.
+    ServerExit(ExitStatus),
+
+    #[error("unable to read server pid file {}", path.display())]
+    /// The pid file written by the server could not be read.
+    PidFile {
+        /// The path of the pid file.
+        path: PathBuf,
+
+        /// The underlying IO error.
+        source: io::Error,
+    },
+
+    #[error("invalid server pid")]
+    /// The pid file written by the server contains an invalid pid.
+    InvalidPid(#[from] ParseIntError),
+
+    #[error("server is not up after {0:?}")]
+    /// The server did not become available within the expected time.
+    ServerNotUp(Duration),
+
+    #[error("invalid attach packet pipe: {0}")]
+    /// The attach socket returned a packet with an unknown pipe type.
+    AttachPacket(u8),
+
+    #[error("rpc connection disconnected")]
+    /// The RPC connection got closed before the response arrived.
+    Disconnected,
+
+    #[error(transparent)]
+    /// The remote procedure call failed.
+    Rpc(#[from] capnp::Error),
+
+    #[error(transparent)]
+    /// The response contained an enum value which is not part of the schema.
+    NotInSchema(#[from] capnp::NotInSchema),
+
+    #[error(transparent)]
+    /// A generic IO error.
+    Io(#[from] io::Error),
+
+    #[error("unable to signal server")]
+    /// The server process could not be signaled.
+    Signal(#[from] nix::Error),
+}
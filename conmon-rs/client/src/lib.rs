@@ -0,0 +1,22 @@
+//! A typed async client library for the conmon-rs server.
+//!
+//! The client is able to spawn and manage the lifecycle of a server instance as well as to
+//! call every available remote procedure.
+#![deny(missing_docs)]
+
+pub use attach::{AttachPipe, AttachStream, RpcAttachStream};
+pub use client::ConmonClient;
+pub use config::{CgroupManager, LogDriver, LogLevel, ServerConfig};
+pub use error::{Error, Result};
+pub use nix::sys::signal::Signal;
+pub use port_forward::PortForwardStream;
+pub use types::*;
+
+mod attach;
+mod client;
+mod config;
+mod error;
+mod port_forward;
+mod socket;
+mod stream;
+mod types;
@@ -0,0 +1,49 @@
+//! Client side of port forwarding over RPC.
+
+use crate::{attach::AttachPipe, error::Result, stream::RpcTask};
+use conmon_common::conmon_capnp::conmon::stream_sink;
+use futures::{channel::mpsc::Receiver, StreamExt};
+
+/// PortForwardStream is a connection to a port within the network namespace of a container,
+/// which uses a dedicated RPC connection. The connection gets closed on drop.
+pub struct PortForwardStream {
+    input_sink: stream_sink::Client,
+    output_rx: Receiver<(AttachPipe, Vec<u8>)>,
+    _rpc_task: RpcTask,
+}
+
+impl PortForwardStream {
+    pub(crate) fn new(
+        input_sink: stream_sink::Client,
+        output_rx: Receiver<(AttachPipe, Vec<u8>)>,
+        rpc_task: RpcTask,
+    ) -> Self {
+        Self {
+            input_sink,
+            output_rx,
+            _rpc_task: rpc_task,
+        }
+    }
+
+    /// Read the next data received from the port, where `None` indicates that the connection got
+    /// closed.
+    pub async fn read(&mut self) -> Option<Vec<u8>> {
+        self.output_rx.next().await.map(|(_, data)| data)
+    }
+
+    /// Write the provided data to the port.
+    pub async fn write(&self, data: &[u8]) -> Result<()> {
+        let mut request = self.input_sink.write_request();
+        let mut params = request.get();
+        params.set_pipe(stream_sink::Pipe::Stdin);
+        params.set_data(data);
+        request.send().promise.await?;
+        Ok(())
+    }
+
+    /// Close the writing side of the connection, while data can still be read.
+    pub async fn close_write(&self) -> Result<()> {
+        self.input_sink.close_request().send().promise.await?;
+        Ok(())
+    }
+}
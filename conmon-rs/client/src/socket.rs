@@ -0,0 +1,57 @@
+//! Unix domain socket helpers.
+
+use std::{
+    fs::File,
+    io,
+    os::unix::io::AsRawFd,
+    path::{Path, PathBuf},
+};
+
+/// Shorten the provided socket path by using the `/proc/self/fd` entry of its parent directory,
+/// which allows socket paths longer than the `sun_path` limit.
+///
+/// The returned parent directory has to be kept open until the socket got connected.
+pub fn shorten_socket_path(path: &Path) -> io::Result<(PathBuf, File)> {
+    let invalid = || io::Error::from(io::ErrorKind::InvalidInput);
+
+    let parent = match path.parent().ok_or_else(invalid)? {
+        p if p.as_os_str().is_empty() => Path::new("."),
+        p => p,
+    };
+    let name = path.file_name().ok_or_else(invalid)?;
+    let parent = File::open(parent)?;
+
+    Ok((
+        PathBuf::from("/proc/self/fd")
+            .join(parent.as_raw_fd().to_string())
+            .join(name),
+        parent,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shorten_socket_path_success() -> io::Result<()> {
+        let (path, parent) = shorten_socket_path(Path::new("/tmp/conmon.sock"))?;
+        assert_eq!(
+            path,
+            PathBuf::from(format!("/proc/self/fd/{}/conmon.sock", parent.as_raw_fd()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn shorten_socket_path_relative() -> io::Result<()> {
+        let (path, _parent) = shorten_socket_path(Path::new("conmon.sock"))?;
+        assert!(path.ends_with("conmon.sock"));
+        Ok(())
+    }
+
+    #[test]
+    fn shorten_socket_path_failure() {
+        assert!(shorten_socket_path(Path::new("/")).is_err());
+    }
+}
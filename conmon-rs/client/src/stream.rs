@@ -0,0 +1,115 @@
+//! Shared building blocks of streams over a dedicated RPC connection.
+
+use crate::attach::AttachPipe;
+use capnp::capability::Promise;
+use capnp_rpc::{pry, rpc_twoparty_capnp::Side, RpcSystem};
+use conmon_common::conmon_capnp::conmon::stream_sink;
+use futures::{
+    channel::mpsc::{self, Receiver, Sender},
+    SinkExt,
+};
+use tokio::task::{self, JoinHandle};
+
+/// The amount of output chunks buffered by a stream before the server has to wait.
+const OUTPUT_QUEUE_SIZE: usize = 64;
+
+/// RpcTask drives an RPC connection on the local task set and closes it on drop.
+pub(crate) struct RpcTask(JoinHandle<Result<(), capnp::Error>>);
+
+impl RpcTask {
+    /// Spawn the RPC system, which requires a `tokio::task::LocalSet`.
+    pub(crate) fn spawn(rpc_system: RpcSystem<Side>) -> Self {
+        Self(task::spawn_local(rpc_system))
+    }
+}
+
+impl Drop for RpcTask {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// OutputSink receives the output of a stream from the server.
+pub(crate) struct OutputSink {
+    output_tx: Option<Sender<(AttachPipe, Vec<u8>)>>,
+}
+
+impl OutputSink {
+    /// Create a new output sink and the receiver of its output.
+    pub(crate) fn new() -> (Self, Receiver<(AttachPipe, Vec<u8>)>) {
+        let (output_tx, output_rx) = mpsc::channel(OUTPUT_QUEUE_SIZE);
+        let sink = Self {
+            output_tx: Some(output_tx),
+        };
+        (sink, output_rx)
+    }
+}
+
+impl stream_sink::Server for OutputSink {
+    fn write(
+        &mut self,
+        params: stream_sink::WriteParams,
+        _: stream_sink::WriteResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        let pipe = match pry!(params.get_pipe()) {
+            stream_sink::Pipe::Stdout => AttachPipe::Stdout,
+            stream_sink::Pipe::Stderr => AttachPipe::Stderr,
+            stream_sink::Pipe::Stdin => {
+                return Promise::err(capnp::Error::failed("stdin cannot be written".into()))
+            }
+        };
+        let data = pry!(params.get_data()).to_vec();
+
+        // The server waits for the promise, which applies backpressure if the client is slow.
+        let output_tx = self.output_tx.clone();
+        Promise::from_future(async move {
+            if let Some(mut output_tx) = output_tx {
+                output_tx
+                    .send((pipe, data))
+                    .await
+                    .map_err(|_| capnp::Error::disconnected("stream dropped".into()))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn close(
+        &mut self,
+        _: stream_sink::CloseParams,
+        _: stream_sink::CloseResults,
+    ) -> Promise<(), capnp::Error> {
+        self.output_tx = None;
+        Promise::ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Result;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn output_sink_success() -> Result<()> {
+        let (sink, mut output_rx) = OutputSink::new();
+        let client: stream_sink::Client = capnp_rpc::new_client(sink);
+
+        let mut request = client.write_request();
+        request.get().set_pipe(stream_sink::Pipe::Stderr);
+        request.get().set_data(b"err");
+        request.send().promise.await?;
+        assert_eq!(
+            output_rx.next().await,
+            Some((AttachPipe::Stderr, b"err".to_vec()))
+        );
+
+        let mut request = client.write_request();
+        request.get().set_pipe(stream_sink::Pipe::Stdin);
+        assert!(request.send().promise.await.is_err());
+
+        client.close_request().send().promise.await?;
+        assert_eq!(output_rx.next().await, None);
+        Ok(())
+    }
+}
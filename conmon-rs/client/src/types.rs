@@ -0,0 +1,385 @@
+//! Request and response types of the client methods.
+
+use std::{path::PathBuf, time::Duration};
+use strum::{AsRefStr, Display, EnumString};
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// The response of the `version` method.
+pub struct VersionResponse {
+    /// The actual version string of the server.
+    pub version: String,
+
+    /// The git tag of the server, empty if no tag is available.
+    pub tag: String,
+
+    /// The git commit SHA of the build.
+    pub commit: String,
+
+    /// The date of the build.
+    pub build_date: String,
+
+    /// The used Rust version.
+    pub rust_version: String,
+
+    /// The process ID of the server.
+    pub process_id: u32,
+
+    /// The number of orphaned processes reaped by the server so far.
+    pub reaped_orphans: u64,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// Metadata of the caller, which gets attached to the tracing spans of the server.
+pub struct RequestMetadata {
+    /// The W3C trace ID of the caller as 32 hex characters.
+    pub trace_id: Option<String>,
+
+    /// The W3C span ID of the caller as 16 hex characters, which becomes the parent of the
+    /// server span.
+    pub span_id: Option<String>,
+
+    /// The identifier of the request, which is random if not provided.
+    pub request_id: Option<String>,
+
+    /// The name of the calling application.
+    pub caller_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// The configuration for creating a new container.
+pub struct CreateContainerConfig {
+    /// The container identifier.
+    pub id: String,
+
+    /// The path to the filesystem bundle.
+    pub bundle_path: PathBuf,
+
+    /// Indicates if a tty should be used or not.
+    pub terminal: bool,
+
+    /// Paths to write the exit status to.
+    pub exit_paths: Vec<PathBuf>,
+
+    /// Paths which should be created if the container is OOM killed.
+    pub oom_exit_paths: Vec<PathBuf>,
+
+    /// The selected log drivers.
+    pub log_drivers: Vec<LogDriverConfig>,
+
+    /// Indicates if the standard input should be available for attach clients.
+    pub stdin: bool,
+
+    /// Indicates if the standard input should be closed after the first attach client
+    /// disconnected.
+    pub stdin_once: bool,
+
+    /// The path to record the terminal session to in the asciicast v2 format, if any. Requires
+    /// `terminal` to be set.
+    pub recording_path: Option<PathBuf>,
+
+    /// Indicates if the standard input should be part of the terminal session recording.
+    pub record_stdin: bool,
+
+    /// The initial settings of the terminal, if `terminal` is true.
+    pub terminal_options: TerminalOptions,
+
+    /// The policy for restarting the container on exit. The exit paths only get written as soon
+    /// as the container will not be restarted any more.
+    pub restart_policy: RestartPolicy,
+
+    /// The maximum time the runtime is allowed to take for creating the container, where `None`
+    /// means no timeout.
+    pub timeout: Option<Duration>,
+
+    /// The number of recent output bytes kept by the server for replaying them to new attach
+    /// clients, at most 64 KiB. Nothing is kept if zero.
+    pub scrollback_size: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// The policy for restarting a container on exit, with an exponential backoff between the
+/// restarts.
+pub enum RestartPolicy {
+    #[default]
+    /// Never restart the container.
+    No,
+
+    /// Restart the container on a non-zero exit code.
+    OnFailure {
+        /// The maximum number of restarts, unlimited if zero.
+        max_retries: u32,
+    },
+
+    /// Always restart the container, until the server shuts down.
+    Always,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// The initial settings of a terminal, which get applied as soon as it is connected.
+pub struct TerminalOptions {
+    /// The width of the terminal, which keeps the kernel default if zero.
+    pub width: u16,
+
+    /// The height of the terminal, which keeps the kernel default if zero.
+    pub height: u16,
+
+    /// Put the terminal into raw mode.
+    pub raw: bool,
+
+    /// Disable the echo of the terminal input.
+    pub disable_echo: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A selected container logging mechanism.
+pub struct LogDriverConfig {
+    /// The log driver variant.
+    pub typ: LogDriverType,
+
+    /// The filesystem path of the log driver.
+    pub path: PathBuf,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Available container log drivers.
+pub enum LogDriverType {
+    /// The Kubernetes CRI logger.
+    ContainerRuntimeInterface,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// The response of the `create_container` method.
+pub struct CreateContainerResponse {
+    /// The container process identifier.
+    pub pid: u32,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// The configuration for executing a command synchronously within a container.
+pub struct ExecSyncConfig {
+    /// The container identifier.
+    pub id: String,
+
+    /// The command line arguments to be executed.
+    pub command: Vec<String>,
+
+    /// The maximum time the command is allowed to run, where `None` means no timeout.
+    pub timeout: Option<Duration>,
+
+    /// Specifies if a tty should be used.
+    pub terminal: bool,
+
+    /// The path to record the terminal session to in the asciicast v2 format, if any. Requires
+    /// `terminal` to be set.
+    pub recording_path: Option<PathBuf>,
+
+    /// The initial settings of the terminal, if `terminal` is true.
+    pub terminal_options: TerminalOptions,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// The response of the `exec_sync_container` method.
+pub struct ExecSyncResponse {
+    /// The returned exit status.
+    pub exit_code: i32,
+
+    /// The standard output of the command.
+    pub stdout: Vec<u8>,
+
+    /// The standard error of the command.
+    pub stderr: Vec<u8>,
+
+    /// Indicates if the command timed out.
+    pub timed_out: bool,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// The configuration for attaching to a container.
+pub struct AttachConfig {
+    /// The container identifier.
+    pub id: String,
+
+    /// The path of the attach socket to be created by the server.
+    pub socket_path: PathBuf,
+
+    /// The exec session identifier, if this is an attach for an exec.
+    pub exec_session_id: String,
+
+    /// The number of recent output bytes replayed after connecting to the attach socket, at most
+    /// 64 KiB. The server only replays the output kept according to the scrollback size of the
+    /// container.
+    pub scrollback_size: u64,
+
+    /// The comma separated key sequence to detach from the container, like `ctrl-p,ctrl-q`.
+    /// Detaching is disabled if empty.
+    pub detach_keys: String,
+
+    /// The behavior of the server if the client does not keep up with the container output.
+    pub slow_client_policy: SlowClientPolicy,
+}
+
+#[derive(AsRefStr, Clone, Copy, Debug, Default, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+/// The behavior of the server if an attach client does not keep up with the container output.
+pub enum SlowClientPolicy {
+    #[default]
+    /// Drop the output for the client.
+    Drop,
+
+    /// Block the container output until the client caught up.
+    Block,
+
+    /// Disconnect the client.
+    Disconnect,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// A running container or exec session as returned by the `list_containers` method.
+pub struct ContainerInfo {
+    /// The container identifier.
+    pub id: String,
+
+    /// The process identifier, which is zero while the container waits to be restarted.
+    pub pid: u32,
+
+    /// The configured log drivers.
+    pub log_drivers: Vec<LogDriverConfig>,
+
+    /// The registered health probes.
+    pub probes: Vec<ProbeStatus>,
+
+    /// The number of restarts according to the restart policy.
+    pub restart_count: u32,
+
+    /// The exit code before the last restart, if `restart_count` is not zero.
+    pub last_exit_code: i32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// The way the server treats running containers when shutting down.
+pub enum ShutdownMode {
+    /// Kill all containers and exit.
+    KillAll,
+
+    /// Leave all containers running and exit.
+    Detach,
+
+    /// Wait for all containers to exit before exiting.
+    WaitForExit,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Namespaces which can be pinned for a pod.
+pub enum Namespace {
+    /// IPC namespace.
+    Ipc,
+
+    /// Network namespace.
+    Net,
+
+    /// UTS namespace.
+    Uts,
+
+    /// User namespace.
+    User,
+
+    /// Cgroup namespace.
+    Cgroup,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// The configuration for creating the namespaces of a pod.
+pub struct CreateNamespacesConfig {
+    /// The directory where the namespaces get bind mounted to `<type>ns/<pod_id>`.
+    pub base_path: PathBuf,
+
+    /// The pod identifier.
+    pub pod_id: String,
+
+    /// The namespaces to be created.
+    pub namespaces: Vec<Namespace>,
+
+    /// The mappings of the user namespace, like "0 1000 65536".
+    pub uid_mappings: Vec<String>,
+
+    /// The group mappings of the user namespace.
+    pub gid_mappings: Vec<String>,
+
+    /// Sysctls to be applied within the namespaces as key and value.
+    pub sysctls: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A pinned namespace as returned by the `create_namespaces` method.
+pub struct NamespaceInfo {
+    /// The namespace type.
+    pub typ: Namespace,
+
+    /// The path of the bind mount.
+    pub path: PathBuf,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// The way a probe checks the container.
+pub enum ProbeHandler {
+    /// Execute the command within the container, which succeeds on exit code zero.
+    Exec(Vec<String>),
+
+    /// Connect to the TCP port within the network namespace of the container.
+    Tcp(u16),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// The configuration for registering a health probe of a container.
+pub struct RegisterProbeConfig {
+    /// The container identifier.
+    pub id: String,
+
+    /// The name of the probe, which replaces an existing probe of the same name.
+    pub name: String,
+
+    /// The way the probe checks the container.
+    pub handler: ProbeHandler,
+
+    /// The delay before the first run.
+    pub initial_delay: Duration,
+
+    /// The time between two runs, where zero means the server default of 10 seconds.
+    pub period: Duration,
+
+    /// The time after which a single run fails, where zero means the server default of 1 second.
+    pub timeout: Duration,
+
+    /// The number of consecutive successful runs until the probe succeeds, at least 1.
+    pub success_threshold: u32,
+
+    /// The number of consecutive failed runs until the probe fails, at least 1.
+    pub failure_threshold: u32,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// The result of a probe after applying the thresholds.
+pub enum ProbeResult {
+    #[default]
+    /// The probe did not reach any threshold yet.
+    Unknown,
+
+    /// The probe reached the success threshold.
+    Success,
+
+    /// The probe reached the failure threshold.
+    Failure,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// The state of a probe as returned by the `list_containers` method.
+pub struct ProbeStatus {
+    /// The name of the probe.
+    pub name: String,
+
+    /// The result after applying the thresholds.
+    pub result: ProbeResult,
+
+    /// The output or error of the last failed run.
+    pub message: String,
+}
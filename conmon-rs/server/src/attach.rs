@@ -1,87 +1,610 @@
-use crate::{container_io::Pipe, listener};
+use crate::{container_io::Pipe, listener, peer::PeerAuth};
 use anyhow::{bail, Context, Result};
 use nix::sys::socket::{bind, listen, socket, AddressFamily, SockFlag, SockType, UnixAddr};
 use std::{
+    collections::VecDeque,
     os::unix::{
         fs::PermissionsExt,
         io::{FromRawFd, RawFd},
         net,
     },
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
 };
 use tokio::{
-    io::{ErrorKind, Interest, Ready},
+    io::ErrorKind,
     net::{UnixListener, UnixStream},
-    sync::RwLock,
+    sync::{
+        mpsc::{self, error::TrySendError, Receiver, Sender, UnboundedReceiver, UnboundedSender},
+        Mutex, Notify,
+    },
     task,
-    time::{timeout, Duration},
 };
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, debug_span, error, Instrument};
 
-#[derive(Debug, Clone, Default)]
+/// The amount of output chunks queued for every client before the slow client policy applies.
+const CLIENT_QUEUE_SIZE: usize = 1024;
+
+#[derive(Debug, Clone)]
 /// A shared container attach abstraction.
-pub struct SharedContainerAttach(Arc<RwLock<Vec<Attach>>>);
+pub struct SharedContainerAttach {
+    attaches: Arc<Mutex<Vec<Attach>>>,
+    fan_out: FanOut,
+    input_rx: Arc<Mutex<UnboundedReceiver<AttachInput>>>,
+    stdin: bool,
+    stdin_once: bool,
+}
+
+impl Default for SharedContainerAttach {
+    fn default() -> Self {
+        Self::new(false, false, 0)
+    }
+}
 
 impl SharedContainerAttach {
+    /// Create a new shared container attach instance. Attach clients are only able to write to
+    /// the standard input of the container if `stdin` is true. It gets closed after the first
+    /// client disconnected if `stdin_once` is true, or after the last one otherwise. The last
+    /// `scrollback_size` bytes of output are kept for replaying them to new clients.
+    pub fn new(stdin: bool, stdin_once: bool, scrollback_size: usize) -> Self {
+        let (input_tx, mut input_rx) = mpsc::unbounded_channel();
+        if !stdin {
+            input_rx.close();
+        }
+        Self {
+            attaches: Default::default(),
+            fan_out: FanOut {
+                state: Arc::new(Mutex::new(FanOutState {
+                    scrollback: Scrollback::new(scrollback_size),
+                    subscribers: vec![],
+                })),
+                input_tx,
+                connections: Default::default(),
+            },
+            input_rx: Arc::new(Mutex::new(input_rx)),
+            stdin,
+            stdin_once,
+        }
+    }
+
     /// Add a new attach endpoint to this shared container attach instance.
     pub async fn add(&self, attach: Attach) {
-        self.0.write().await.push(attach);
+        self.attaches.lock().await.push(attach);
     }
 
-    /// Try to read from all attach endpoints standard input and return the first result.
-    pub async fn try_read(&self) -> Result<Option<Vec<u8>>> {
-        self.cleanup().await;
-        for attach in self.0.read().await.iter() {
-            if let Some(data) = attach.try_read().await? {
-                return Ok(data.into());
-            }
+    /// Returns true if the standard input of the container should be opened.
+    pub fn stdin(&self) -> bool {
+        self.stdin
+    }
+
+    /// Returns true if the standard input of the container should be closed, because the
+    /// relevant attach clients disconnected.
+    pub fn stdin_finished(&self) -> bool {
+        let connections = &self.fan_out.connections;
+        let connected = connections.connected.load(Ordering::SeqCst);
+        let disconnected = connections.disconnected.load(Ordering::SeqCst);
+        if connected == 0 {
+            return false;
+        }
+        if self.stdin_once {
+            disconnected > 0
+        } else {
+            disconnected == connected
         }
-        Ok(None)
     }
 
-    /// Write a buffer to all attach endpoints.
+    /// Wait until the next attach client disconnects.
+    pub async fn disconnected(&self) {
+        self.fan_out.connections.notify.notified().await
+    }
+
+    /// Read the next input of all attach clients, returns `None` if the input got closed.
+    pub async fn read(&self) -> Option<AttachInput> {
+        self.input_rx.lock().await.recv().await
+    }
+
+    /// Close the input, which lets clients discard any further standard input.
+    pub async fn close_input(&self) {
+        self.input_rx.lock().await.close()
+    }
+
+    /// Subscribe a client which does not use an attach socket, like an RPC stream. The client
+    /// stays subscribed until the output half gets dropped or returns `None`.
+    pub async fn subscribe(&self, options: &AttachOptions) -> (ClientOutput, ClientInput) {
+        let token = CancellationToken::new();
+        self.attaches.lock().await.push(Attach {
+            path: None,
+            token: token.clone(),
+        });
+        self.fan_out.subscribe(options, token).await
+    }
+
+    /// Write a buffer to all attach clients.
     pub async fn write<T>(&self, pipe: Pipe, buf: T) -> Result<()>
     where
         T: AsRef<[u8]>,
     {
         self.cleanup().await;
-        for attach in self.0.read().await.iter() {
-            attach
-                .write(pipe, &buf)
-                .await
-                .context("write to attach endpoint")?;
-        }
+        self.fan_out.write(pipe, buf.as_ref()).await;
         Ok(())
     }
 
     /// Remove attach endpoints which do not exist any more.
     async fn cleanup(&self) {
-        self.0.write().await.retain(|x| {
-            let exists = x.path.exists();
-            if !exists {
-                debug!("Cleanup attach endpoint: {}", x.path.display())
+        self.attaches.lock().await.retain(|x| match &x.path {
+            _ if x.token.is_cancelled() => false,
+            Some(path) if !path.exists() => {
+                debug!("Cleanup attach endpoint: {}", path.display());
+                false
+            }
+            _ => true,
+        });
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// The behavior if an attach client does not keep up with the container output.
+pub enum SlowClientPolicy {
+    #[default]
+    /// Drop the output for the client.
+    Drop,
+
+    /// Block the container output until the client caught up.
+    Block,
+
+    /// Disconnect the client.
+    Disconnect,
+}
+
+#[derive(Clone, Debug, Default)]
+/// The options of the clients of an attach endpoint.
+pub struct AttachOptions {
+    /// The amount of recent output bytes replayed to newly connected clients, which is limited
+    /// by the scrollback of the container.
+    pub scrollback_size: usize,
+
+    /// The key sequence which disconnects a client.
+    pub detach_keys: DetachKeys,
+
+    /// The behavior for clients which do not keep up with the output.
+    pub slow_client_policy: SlowClientPolicy,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A chunk of container output.
+pub struct Output {
+    /// The pipe the output was written to.
+    pub pipe: Pipe,
+
+    /// The output data.
+    pub data: Arc<[u8]>,
+}
+
+#[derive(Clone, Debug)]
+/// FanOut distributes the container output to all subscribed clients and merges their input.
+struct FanOut {
+    state: Arc<Mutex<FanOutState>>,
+    input_tx: UnboundedSender<AttachInput>,
+    connections: Arc<Connections>,
+}
+
+#[derive(Debug)]
+struct FanOutState {
+    scrollback: Scrollback,
+    subscribers: Vec<Subscriber>,
+}
+
+#[derive(Debug)]
+struct Subscriber {
+    tx: Sender<Output>,
+    policy: SlowClientPolicy,
+}
+
+/// The output half of an attach client.
+pub struct ClientOutput {
+    replay: VecDeque<Output>,
+    rx: Receiver<Output>,
+    token: CancellationToken,
+    _guard: ConnectionGuard,
+}
+
+impl ClientOutput {
+    /// Receive the next output for the client, starting with the scrollback. Returns `None` if
+    /// the client should be disconnected, for example because it detached or the container
+    /// attach went away.
+    pub async fn next(&mut self) -> Option<Output> {
+        if let Some(output) = self.replay.pop_front() {
+            return output.into();
+        }
+        tokio::select! {
+            _ = self.token.cancelled() => None,
+            output = self.rx.recv() => output,
+        }
+    }
+}
+
+impl Drop for ClientOutput {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
+
+/// The input half of an attach client.
+pub struct ClientInput {
+    fan_out: FanOut,
+    detach_keys: DetachKeys,
+    token: CancellationToken,
+}
+
+impl ClientInput {
+    /// Returns true if the client detached or got disconnected.
+    pub fn is_detached(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Forward the input of the client to the container. Returns false if the client detached,
+    /// which disconnects its output as well.
+    pub fn send(&mut self, input: AttachInput) -> bool {
+        if self.is_detached() {
+            return false;
+        }
+        let input = match input {
+            AttachInput::Stdin(data) => {
+                let (data, detach) = self.detach_keys.process(&data);
+                if detach {
+                    debug!("Detaching client");
+                    if !data.is_empty() {
+                        self.fan_out.send(AttachInput::Stdin(data));
+                    }
+                    self.token.cancel();
+                    return false;
+                }
+                if data.is_empty() {
+                    return true;
+                }
+                AttachInput::Stdin(data)
             }
-            exists
+            input => input,
+        };
+        self.fan_out.send(input);
+        true
+    }
+}
+
+impl FanOut {
+    /// Subscribe a new client to the container output, which gets disconnected if the token
+    /// gets cancelled.
+    async fn subscribe(
+        &self,
+        options: &AttachOptions,
+        token: CancellationToken,
+    ) -> (ClientOutput, ClientInput) {
+        let (tx, rx) = mpsc::channel(CLIENT_QUEUE_SIZE);
+
+        // Keep the state locked to prevent new clients from either missing the output or
+        // receiving it twice.
+        let mut state = self.state.lock().await;
+        let replay = state
+            .scrollback
+            .tail(options.scrollback_size)
+            .into_iter()
+            .map(|(pipe, data)| Output {
+                pipe,
+                data: data.into(),
+            })
+            .collect();
+        state.subscribers.push(Subscriber {
+            tx,
+            policy: options.slow_client_policy,
         });
+
+        let output = ClientOutput {
+            replay,
+            rx,
+            token: token.clone(),
+            _guard: ConnectionGuard::new(self.connections.clone()),
+        };
+        let input = ClientInput {
+            fan_out: self.clone(),
+            detach_keys: options.detach_keys.clone(),
+            token,
+        };
+        (output, input)
+    }
+
+    /// Forward the input of a client to the container.
+    fn send(&self, input: AttachInput) {
+        if self.input_tx.send(input).is_err() {
+            debug!("Discarding attach input because stdin is closed");
+        }
+    }
+
+    /// Write the output to the scrollback and all subscribed clients.
+    async fn write(&self, pipe: Pipe, buf: &[u8]) {
+        let mut state = self.state.lock().await;
+        state.scrollback.push(pipe, buf);
+
+        let output = Output {
+            pipe,
+            data: buf.into(),
+        };
+        let mut subscribers = Vec::with_capacity(state.subscribers.len());
+        for subscriber in state.subscribers.drain(..) {
+            let keep = match subscriber.policy {
+                SlowClientPolicy::Block => subscriber.tx.send(output.clone()).await.is_ok(),
+                policy => match subscriber.tx.try_send(output.clone()) {
+                    Ok(()) => true,
+                    Err(TrySendError::Full(_)) if policy == SlowClientPolicy::Drop => {
+                        debug!("Dropping output for slow attach client");
+                        true
+                    }
+                    Err(TrySendError::Full(_)) => {
+                        debug!("Disconnecting slow attach client");
+                        false
+                    }
+                    Err(TrySendError::Closed(_)) => false,
+                },
+            };
+            if keep {
+                subscribers.push(subscriber);
+            }
+        }
+        state.subscribers = subscribers;
     }
 }
 
 /// The size of an attach packet.
 const ATTACH_PACKET_BUF_SIZE: usize = 8192;
 
-type Clients = Arc<RwLock<Vec<UnixStream>>>;
+/// The packet type prefix of standard output data sent to the clients.
+const PACKET_TYPE_STDOUT: u8 = 2;
 
-#[derive(Clone, Debug)]
-/// Attach handles the attach socket IO of a container.
+/// The packet type prefix of standard error data sent to the clients.
+const PACKET_TYPE_STDERR: u8 = 3;
+
+/// The control packet type to resize the terminal, followed by the width and height as big endian
+/// 16 bit integers.
+const PACKET_TYPE_RESIZE: u8 = 4;
+
+/// The control packet type to close the standard input of the container.
+const PACKET_TYPE_CLOSE_STDIN: u8 = 5;
+
+#[derive(Debug, Eq, PartialEq)]
+/// Input received from attach clients.
+///
+/// Clients send standard input data as plain packets, which get truncated at the first zero byte.
+/// This means that a packet starting with a zero byte cannot contain data, which is why it marks
+/// a control packet whose packet type is stored in the second byte.
+pub enum AttachInput {
+    /// Data for the standard input of the container.
+    Stdin(Vec<u8>),
+
+    /// Resize the terminal of the container to the width and height.
+    Resize(u16, u16),
+
+    /// Close the standard input of the container.
+    CloseStdin,
+}
+
+impl AttachInput {
+    /// Parse a packet received from a client, returns `None` for invalid control packets.
+    fn parse(mut packet: Vec<u8>) -> Option<Self> {
+        match packet.as_slice() {
+            [0, PACKET_TYPE_RESIZE, w1, w2, h1, h2, ..] => Some(Self::Resize(
+                u16::from_be_bytes([*w1, *w2]),
+                u16::from_be_bytes([*h1, *h2]),
+            )),
+            [0, PACKET_TYPE_CLOSE_STDIN, ..] => Some(Self::CloseStdin),
+            [0, ..] => None,
+            _ => {
+                if let Some(first_zero_idx) = packet.iter().position(|&x| x == 0) {
+                    packet.truncate(first_zero_idx);
+                }
+                Some(Self::Stdin(packet))
+            }
+        }
+    }
+}
+
+/// The maximum amount of output bytes kept for replaying them to new attach clients.
+pub const MAX_SCROLLBACK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Default)]
+/// Scrollback is a bounded ring buffer of the recent container output. The buffer only grows
+/// with the output and keeps nothing if its capacity is zero.
+pub struct Scrollback {
+    chunks: VecDeque<(Pipe, Vec<u8>)>,
+    len: usize,
+    capacity: usize,
+}
+
+impl Scrollback {
+    /// Create a new scrollback which keeps at most `capacity` bytes of output.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    /// Validate a requested scrollback size, which must not exceed `MAX_SCROLLBACK_SIZE`.
+    pub fn validate_size(size: u64) -> Result<usize> {
+        if size > MAX_SCROLLBACK_SIZE as u64 {
+            bail!(
+                "scrollback size {} exceeds the maximum of {} bytes",
+                size,
+                MAX_SCROLLBACK_SIZE
+            )
+        }
+        Ok(size as usize)
+    }
+
+    /// Append the buffer for the provided pipe and drop the oldest output if the buffer exceeds
+    /// its capacity.
+    pub fn push(&mut self, pipe: Pipe, buf: &[u8]) {
+        if buf.is_empty() || self.capacity == 0 {
+            return;
+        }
+        let buf = &buf[buf.len().saturating_sub(self.capacity)..];
+        self.chunks.push_back((pipe, buf.to_vec()));
+        self.len += buf.len();
+
+        while self.len > self.capacity {
+            let excess = self.len - self.capacity;
+            if let Some((_, front)) = self.chunks.front_mut() {
+                if front.len() > excess {
+                    front.drain(..excess);
+                    self.len -= excess;
+                } else {
+                    self.len -= front.len();
+                    self.chunks.pop_front();
+                }
+            }
+        }
+    }
+
+    /// Retrieve the most recent `size` bytes of output together with their pipes.
+    pub fn tail(&self, size: usize) -> Vec<(Pipe, &[u8])> {
+        let mut remaining = size.min(self.len);
+        let mut res = vec![];
+        for (pipe, chunk) in self.chunks.iter().rev() {
+            if remaining == 0 {
+                break;
+            }
+            let start = chunk.len().saturating_sub(remaining);
+            remaining -= chunk.len() - start;
+            res.push((*pipe, &chunk[start..]));
+        }
+        res.reverse();
+        res
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// DetachKeys recognizes the key sequence which detaches a client from the container.
+pub struct DetachKeys {
+    keys: Vec<u8>,
+    matched: usize,
+}
+
+impl DetachKeys {
+    /// Parse a comma separated detach key sequence like `ctrl-p,ctrl-q`, whereas every key is
+    /// either a single character or `ctrl-` followed by one of `a-z`, `[`, `\`, `]`, `^` or `_`.
+    /// An empty sequence disables detaching.
+    pub fn parse(sequence: &str) -> Result<Self> {
+        let mut keys = vec![];
+        if !sequence.is_empty() {
+            for key in sequence.split(',') {
+                keys.push(Self::parse_key(key).with_context(|| format!("parse key {:?}", key))?);
+            }
+        }
+        Ok(Self { keys, matched: 0 })
+    }
+
+    fn parse_key(key: &str) -> Result<u8> {
+        match (key.strip_prefix("ctrl-"), key.as_bytes()) {
+            (Some(c), _) => match c.as_bytes() {
+                [c @ b'a'..=b'z'] => Ok(c - b'a' + 1),
+                [c @ (b'[' | b'\\' | b']' | b'^' | b'_')] => Ok(c - b'@'),
+                _ => bail!("unknown control key"),
+            },
+            (None, [c]) if c.is_ascii() => Ok(*c),
+            _ => bail!("key is not a single character"),
+        }
+    }
+
+    /// Process the standard input data of the client and return the data to be forwarded as
+    /// well as if the client requested to detach. Possible starts of the sequence are held
+    /// back until they either match or not. On a mismatch, matching continues with the longest
+    /// held back suffix which is a start of the sequence, to support overlapping sequences.
+    fn process(&mut self, data: &[u8]) -> (Vec<u8>, bool) {
+        let mut res = vec![];
+        for &b in data {
+            if self.keys.is_empty() {
+                res.push(b);
+                continue;
+            }
+            let mut matched = self.matched;
+            while matched > 0 && self.keys[matched] != b {
+                matched = self.border(matched);
+            }
+            // The held back bytes equal the matched start of the sequence.
+            res.extend_from_slice(&self.keys[..self.matched - matched]);
+            if self.keys[matched] == b {
+                matched += 1;
+            } else {
+                res.push(b);
+            }
+            self.matched = matched;
+            if self.matched == self.keys.len() {
+                self.matched = 0;
+                return (res, true);
+            }
+        }
+        (res, false)
+    }
+
+    /// The length of the longest proper start of the sequence, which is also a suffix of the
+    /// first `len` keys.
+    fn border(&self, len: usize) -> usize {
+        (1..len)
+            .rev()
+            .find(|&k| self.keys[..k] == self.keys[len - k..len])
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Default)]
+/// Counters of all clients which ever connected to the attach endpoints of a container.
+struct Connections {
+    connected: AtomicUsize,
+    disconnected: AtomicUsize,
+    notify: Notify,
+}
+
+#[derive(Debug)]
+/// ConnectionGuard counts a client as connected until being dropped.
+struct ConnectionGuard(Arc<Connections>);
+
+impl ConnectionGuard {
+    fn new(connections: Arc<Connections>) -> Self {
+        connections.connected.fetch_add(1, Ordering::SeqCst);
+        Self(connections)
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.0.disconnected.fetch_add(1, Ordering::SeqCst);
+        self.0.notify.notify_one();
+    }
+}
+
+#[derive(Debug)]
+/// Attach handles the attach socket IO of a container, which stops serving its clients on drop.
 pub struct Attach {
-    clients: Clients,
-    path: PathBuf,
+    path: Option<PathBuf>,
+    token: CancellationToken,
+}
+
+impl Drop for Attach {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
 }
 
 impl Attach {
-    /// Create a new attach instance.
-    pub fn new(socket_path: &Path) -> Result<Self> {
+    /// Create a new attach endpoint for the shared container attach.
+    pub fn new(
+        socket_path: &Path,
+        peer_auth: PeerAuth,
+        shared: &SharedContainerAttach,
+        options: AttachOptions,
+    ) -> Result<Self> {
         debug!("Creating attach socket: {}", socket_path.display());
 
         if socket_path.exists() {
@@ -110,11 +633,14 @@ impl Attach {
 
         listen(fd, 10).context("listen on socket fd")?;
 
-        let clients = Arc::new(RwLock::new(vec![]));
-        let clients_clone = clients.clone();
+        let token = CancellationToken::new();
+        let token_clone = token.clone();
+        let fan_out = shared.fan_out.clone();
         task::spawn(
             async move {
-                if let Err(e) = Self::start_listening(fd, clients_clone).await {
+                if let Err(e) =
+                    Self::start_listening(fd, peer_auth, fan_out, options, token_clone).await
+                {
                     error!("Attach failure: {:#}", e);
                 }
             }
@@ -122,129 +648,386 @@ impl Attach {
         );
 
         Ok(Self {
-            clients,
-            path: socket_path.into(),
+            path: Some(socket_path.into()),
+            token,
         })
     }
 
-    async fn start_listening(fd: RawFd, clients: Clients) -> Result<()> {
+    async fn start_listening(
+        fd: RawFd,
+        peer_auth: PeerAuth,
+        fan_out: FanOut,
+        options: AttachOptions,
+        token: CancellationToken,
+    ) -> Result<()> {
         debug!("Start listening on attach socket");
         let listener = UnixListener::from_std(unsafe { net::UnixListener::from_raw_fd(fd) })?;
         loop {
-            match listener.accept().await {
-                Ok((stream, _)) => {
-                    debug!("Got new attach stream connection");
-                    clients.write().await.push(stream);
+            let stream = tokio::select! {
+                _ = token.cancelled() => {
+                    debug!("Stop listening on attach socket");
+                    return Ok(());
                 }
-                Err(e) => error!("Unable to accept attach stream: {}", e),
+                res = listener.accept() => match res {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        error!("Unable to accept attach stream: {}", e);
+                        continue;
+                    }
+                },
+            };
+            if let Err(e) = peer_auth.verify(&stream) {
+                error!("Rejected attach stream connection: {:#}", e);
+                continue;
             }
+
+            debug!("Got new attach stream connection");
+            let (output, input) = fan_out.subscribe(&options, token.child_token()).await;
+            task::spawn(
+                async move {
+                    if let Err(e) = Self::serve(stream, output, input).await {
+                        error!("Attach client failure: {:#}", e);
+                    }
+                    debug!("Attach client disconnected");
+                }
+                .instrument(debug_span!("attach_client")),
+            );
         }
     }
 
-    /// Try to read from all streams standard input and return the first result.
-    pub async fn try_read(&self) -> Result<Option<Vec<u8>>> {
-        for stream in self.clients.read().await.iter() {
-            let ready = if let Some(ready) =
-                Self::default_readiness_timeout(Interest::READABLE, stream).await?
-            {
-                ready
-            } else {
-                continue;
-            };
-
-            if ready.is_readable() {
-                let mut buf = vec![0; ATTACH_PACKET_BUF_SIZE];
-                match stream.try_read(&mut buf) {
-                    Ok(n) if n > 0 => {
-                        if let Some(first_zero_idx) = buf.iter().position(|&x| x == 0) {
-                            buf.resize(first_zero_idx, 0);
+    /// Serve a single client by forwarding the output to the stream and its input to the
+    /// container until either side disconnects.
+    async fn serve(
+        stream: UnixStream,
+        mut output: ClientOutput,
+        mut input: ClientInput,
+    ) -> Result<()> {
+        let mut buf = vec![0; ATTACH_PACKET_BUF_SIZE];
+        loop {
+            tokio::select! {
+                res = output.next() => match res {
+                    Some(output) => Self::write(&stream, &output).await?,
+                    None => return Ok(()),
+                },
+                res = stream.readable() => {
+                    res.context("wait for stream to become readable")?;
+                    let n = match stream.try_read(&mut buf) {
+                        Ok(0) => return Ok(()),
+                        Ok(n) => n,
+                        Err(ref e) if e.kind() == ErrorKind::WouldBlock => continue,
+                        Err(e) => return Err(e).context("read from attach stream"),
+                    };
+                    match AttachInput::parse(buf[..n].to_vec()) {
+                        Some(data) => {
+                            debug!("Read input from client: {:?}", data);
+                            if !input.send(data) {
+                                return Ok(());
+                            }
                         }
-                        debug!("Read {} stdin bytes from client", buf.len());
-                        return Ok(buf.into());
-                    }
-                    Err(ref e) if e.kind() == ErrorKind::WouldBlock => continue,
-                    Err(e) => {
-                        return Err(e.into());
+                        None => debug!("Ignoring invalid control packet from client"),
                     }
-                    _ => {}
                 }
             }
         }
+    }
 
-        Ok(None)
+    /// Write the output as packets to the stream.
+    async fn write(stream: &UnixStream, output: &Output) -> Result<()> {
+        for packet in Self::packets(output.pipe, &output.data) {
+            loop {
+                stream
+                    .writable()
+                    .await
+                    .context("wait for stream to become writable")?;
+                match stream.try_write(&packet) {
+                    Ok(_) => break,
+                    Err(ref e) if e.kind() == ErrorKind::WouldBlock => continue,
+                    Err(e) => return Err(e).context("write to attach stream"),
+                }
+            }
+        }
+        debug!("Wrote {} packet to client", output.pipe.as_ref());
+        Ok(())
     }
 
-    /// Write a buffer to all attached clients.
-    pub async fn write<T>(&self, pipe: Pipe, buf: T) -> Result<()>
-    where
-        T: AsRef<[u8]>,
-    {
-        let packets = buf
-            .as_ref()
-            .chunks(ATTACH_PACKET_BUF_SIZE - 1)
+    /// Split the buffer into zero padded attach packets prefixed by the pipe type.
+    fn packets(pipe: Pipe, buf: &[u8]) -> Vec<Vec<u8>> {
+        buf.chunks(ATTACH_PACKET_BUF_SIZE - 1)
             .map(|x| {
                 let mut y = x.to_vec();
                 let p = match pipe {
-                    Pipe::StdOut => 2,
-                    Pipe::StdErr => 3,
+                    Pipe::StdOut => PACKET_TYPE_STDOUT,
+                    Pipe::StdErr => PACKET_TYPE_STDERR,
                 };
                 y.insert(0, p);
                 y.resize(ATTACH_PACKET_BUF_SIZE, 0);
                 y
             })
-            .collect::<Vec<_>>();
+            .collect()
+    }
+}
 
-        let mut cleanup_idxs = vec![];
-        let mut clients = self.clients.write().await;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
 
-        for (idx, stream) in clients.iter().enumerate() {
-            let ready = if let Some(ready) =
-                Self::default_readiness_timeout(Interest::WRITABLE, stream).await?
-            {
-                ready
-            } else {
-                continue;
-            };
+    #[test]
+    fn parse_input() {
+        assert_eq!(
+            AttachInput::parse(b"hello".to_vec()),
+            Some(AttachInput::Stdin(b"hello".to_vec()))
+        );
+        assert_eq!(
+            AttachInput::parse(b"\x04\0\0".to_vec()),
+            Some(AttachInput::Stdin(b"\x04".to_vec()))
+        );
+        assert_eq!(
+            AttachInput::parse(vec![0, PACKET_TYPE_RESIZE, 0, 80, 1, 0]),
+            Some(AttachInput::Resize(80, 256))
+        );
+        assert_eq!(
+            AttachInput::parse(vec![0, PACKET_TYPE_CLOSE_STDIN, 0, 0]),
+            Some(AttachInput::CloseStdin)
+        );
+        assert_eq!(AttachInput::parse(vec![0, PACKET_TYPE_RESIZE, 0]), None);
+        assert_eq!(AttachInput::parse(vec![0, 0]), None);
+    }
 
-            if ready.is_write_closed() {
-                cleanup_idxs.push(idx);
-                continue;
-            }
+    #[test]
+    fn parse_detach_keys() -> Result<()> {
+        assert_eq!(DetachKeys::parse("ctrl-p,ctrl-q")?.keys, vec![0x10, 0x11]);
+        assert_eq!(
+            DetachKeys::parse("ctrl-[,ctrl-_,x")?.keys,
+            vec![0x1b, 0x1f, b'x']
+        );
+        assert!(DetachKeys::parse("")?.keys.is_empty());
+        assert!(DetachKeys::parse("ctrl-1").is_err());
+        assert!(DetachKeys::parse("ctrl-@").is_err());
+        assert!(DetachKeys::parse("ab").is_err());
+        assert!(DetachKeys::parse("a,").is_err());
+        Ok(())
+    }
 
-            if ready.is_writable() {
-                for packet in &packets {
-                    match stream.try_write(packet) {
-                        Ok(_) => {
-                            debug!("Wrote {} packet to client", &pipe.as_ref());
-                        }
-                        Err(ref e) if e.kind() == ErrorKind::WouldBlock => continue,
-                        Err(e) => {
-                            Self::cleanup_clients(&mut clients, &cleanup_idxs).await;
-                            return Err(e.into());
-                        }
-                    }
-                }
-            }
-        }
+    #[test]
+    fn detach_keys_process() -> Result<()> {
+        let mut sut = DetachKeys::parse("ctrl-p,ctrl-q")?;
+        assert_eq!(sut.process(b"ab"), (b"ab".to_vec(), false));
+        assert_eq!(sut.process(b"a\x10"), (b"a".to_vec(), false));
+        assert_eq!(sut.process(b"\x10"), (b"\x10".to_vec(), false));
+        assert_eq!(sut.process(b"b"), (b"\x10b".to_vec(), false));
+        assert_eq!(sut.process(b"c\x10\x11d"), (b"c".to_vec(), true));
+
+        let mut sut = DetachKeys::default();
+        assert_eq!(sut.process(b"\x10\x11"), (b"\x10\x11".to_vec(), false));
+        Ok(())
+    }
+
+    #[test]
+    fn detach_keys_process_overlapping() -> Result<()> {
+        let mut sut = DetachKeys::parse("ctrl-p,ctrl-p,ctrl-q")?;
+        assert_eq!(sut.process(b"\x10\x10\x10\x11"), (b"\x10".to_vec(), true));
+        assert_eq!(sut.process(b"\x10\x10\x10"), (b"\x10".to_vec(), false));
+        assert_eq!(sut.process(b"\x11"), (vec![], true));
+
+        let mut sut = DetachKeys::parse("a,b,a,c")?;
+        assert_eq!(sut.process(b"xabab"), (b"xab".to_vec(), false));
+        assert_eq!(sut.process(b"ac"), (vec![], true));
+        assert_eq!(sut.process(b"abax"), (b"abax".to_vec(), false));
+        Ok(())
+    }
+
+    #[test]
+    fn scrollback_tail() {
+        let mut sut = Scrollback::new(MAX_SCROLLBACK_SIZE);
+        sut.push(Pipe::StdOut, b"hello ");
+        sut.push(Pipe::StdErr, b"");
+        sut.push(Pipe::StdErr, b"world");
+
+        assert_eq!(
+            sut.tail(7),
+            vec![(Pipe::StdOut, &b"o "[..]), (Pipe::StdErr, &b"world"[..])]
+        );
+        assert_eq!(sut.tail(100).len(), 2);
+        assert!(sut.tail(0).is_empty());
+    }
+
+    #[test]
+    fn scrollback_bounded() {
+        let mut sut = Scrollback::new(MAX_SCROLLBACK_SIZE);
+        sut.push(Pipe::StdOut, b"old");
+        sut.push(Pipe::StdErr, &vec![b'a'; MAX_SCROLLBACK_SIZE - 1]);
+        sut.push(Pipe::StdOut, b"new");
+
+        assert_eq!(sut.len, MAX_SCROLLBACK_SIZE);
+        let tail = sut.tail(MAX_SCROLLBACK_SIZE);
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail[0].1.len(), MAX_SCROLLBACK_SIZE - 3);
+        assert_eq!(tail[1], (Pipe::StdOut, &b"new"[..]));
+
+        sut.push(Pipe::StdOut, &vec![b'b'; MAX_SCROLLBACK_SIZE + 1]);
+        assert_eq!(sut.len, MAX_SCROLLBACK_SIZE);
+        assert_eq!(sut.chunks.len(), 1);
+    }
+
+    #[test]
+    fn scrollback_disabled() {
+        let mut sut = Scrollback::default();
+        sut.push(Pipe::StdOut, b"hello");
+
+        assert_eq!(sut.len, 0);
+        assert!(sut.chunks.is_empty());
+        assert!(sut.tail(MAX_SCROLLBACK_SIZE).is_empty());
+    }
+
+    #[test]
+    fn scrollback_validate_size() -> Result<()> {
+        assert_eq!(Scrollback::validate_size(0)?, 0);
+        assert_eq!(
+            Scrollback::validate_size(MAX_SCROLLBACK_SIZE as u64)?,
+            MAX_SCROLLBACK_SIZE
+        );
+        assert!(Scrollback::validate_size(MAX_SCROLLBACK_SIZE as u64 + 1).is_err());
+        Ok(())
+    }
+
+    fn connect(path: &Path) -> Result<RawFd> {
+        let fd = socket(
+            AddressFamily::Unix,
+            SockType::SeqPacket,
+            SockFlag::SOCK_CLOEXEC,
+            None,
+        )?;
+        nix::sys::socket::connect(fd, &UnixAddr::new(path)?)?;
+        Ok(fd)
+    }
+
+    fn read_packet(fd: RawFd) -> Result<Vec<u8>> {
+        let mut buf = vec![0; ATTACH_PACKET_BUF_SIZE];
+        let n = nix::unistd::read(fd, &mut buf)?;
+        assert_eq!(n, ATTACH_PACKET_BUF_SIZE);
+        Ok(buf)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn replay_scrollback_to_new_client() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("attach");
+        let shared = SharedContainerAttach::new(true, false, MAX_SCROLLBACK_SIZE);
+        shared.write(Pipe::StdOut, b"before").await?;
+
+        let options = AttachOptions {
+            scrollback_size: 3,
+            ..Default::default()
+        };
+        let attach = Attach::new(&path, PeerAuth::default(), &shared, options)?;
+        shared.add(attach).await;
+
+        let fd = connect(&path)?;
+        assert_eq!(&read_packet(fd)?[..5], b"\x02ore\0");
+        assert!(!shared.stdin_finished());
+
+        nix::unistd::close(fd)?;
+        shared.disconnected().await;
+        assert!(shared.stdin_finished());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn forward_output_and_input() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("attach");
+        let shared = SharedContainerAttach::new(true, true, 0);
+        let attach = Attach::new(&path, PeerAuth::default(), &shared, Default::default())?;
+        shared.add(attach).await;
+
+        let fd = connect(&path)?;
+        nix::unistd::write(fd, b"in")?;
+        assert_eq!(
+            shared.read().await,
+            Some(AttachInput::Stdin(b"in".to_vec()))
+        );
+
+        shared.write(Pipe::StdErr, b"err").await?;
+        assert_eq!(&read_packet(fd)?[..5], b"\x03err\0");
 
-        Self::cleanup_clients(&mut clients, &cleanup_idxs).await;
+        shared.close_input().await;
+        assert_eq!(shared.read().await, None);
+        nix::unistd::close(fd)?;
         Ok(())
     }
 
-    async fn default_readiness_timeout(
-        interest: Interest,
-        stream: &UnixStream,
-    ) -> Result<Option<Ready>> {
-        match timeout(Duration::from_millis(100), stream.ready(interest)).await {
-            Ok(r) => Ok(Some(r.context("wait for stream to become ready")?)),
-            _ => Ok(None),
+    #[tokio::test(flavor = "multi_thread")]
+    async fn disconnect_slow_client() -> Result<()> {
+        let shared = SharedContainerAttach::default();
+        let options = AttachOptions {
+            slow_client_policy: SlowClientPolicy::Disconnect,
+            ..Default::default()
+        };
+        let (mut output, _input) = shared.subscribe(&options).await;
+
+        for _ in 0..=CLIENT_QUEUE_SIZE {
+            shared.write(Pipe::StdOut, b"x").await?;
+        }
+        for _ in 0..CLIENT_QUEUE_SIZE {
+            assert!(output.next().await.is_some());
         }
+        assert!(output.next().await.is_none());
+        Ok(())
     }
 
-    async fn cleanup_clients(clients: &mut Vec<UnixStream>, idxs: &[usize]) {
-        for i in idxs.iter().rev() {
-            debug!("Cleanup stale attach client with index: {}", i);
-            clients.remove(*i);
+    #[tokio::test(flavor = "multi_thread")]
+    async fn drop_output_for_slow_client() -> Result<()> {
+        let shared = SharedContainerAttach::default();
+        let (mut output, _input) = shared.subscribe(&Default::default()).await;
+
+        for _ in 0..=CLIENT_QUEUE_SIZE {
+            shared.write(Pipe::StdOut, b"x").await?;
         }
+        for _ in 0..CLIENT_QUEUE_SIZE {
+            assert!(output.next().await.is_some());
+        }
+        shared.write(Pipe::StdOut, b"y").await?;
+        let res = output.next().await.context("no output")?;
+        assert_eq!(&*res.data, b"y");
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn subscribe_detach() -> Result<()> {
+        let shared = SharedContainerAttach::new(true, false, MAX_SCROLLBACK_SIZE);
+        let options = AttachOptions {
+            scrollback_size: 10,
+            detach_keys: DetachKeys::parse("ctrl-p,ctrl-q")?,
+            ..Default::default()
+        };
+        shared.write(Pipe::StdOut, b"old").await?;
+        let (mut output, mut input) = shared.subscribe(&options).await;
+
+        assert!(input.send(AttachInput::Stdin(b"in\x10".to_vec())));
+        assert_eq!(
+            shared.read().await,
+            Some(AttachInput::Stdin(b"in".to_vec()))
+        );
+        assert_eq!(
+            output.next().await.context("no replay")?.data.as_ref(),
+            b"old"
+        );
+
+        assert!(!input.send(AttachInput::Stdin(b"\x11".to_vec())));
+        assert!(input.is_detached());
+        assert!(output.next().await.is_none());
+
+        assert!(!input.send(AttachInput::Stdin(b"late".to_vec())));
+        assert!(!input.send(AttachInput::CloseStdin));
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), shared.read())
+                .await
+                .is_err()
+        );
+        drop(output);
+        shared.cleanup().await;
+        assert!(shared.attaches.lock().await.is_empty());
+        Ok(())
     }
 }
@@ -1,9 +1,10 @@
-use crate::container_io::SharedContainerIO;
-use getset::{CopyGetters, Getters};
+use crate::{container_io::SharedContainerIO, probe::SharedProbes};
+use getset::{CopyGetters, Getters, Setters};
 use std::path::PathBuf;
 use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
 
-#[derive(Debug, CopyGetters, Getters)]
+#[derive(Debug, CopyGetters, Getters, Setters)]
 pub struct Child {
     #[getset(get = "pub")]
     id: String,
@@ -22,6 +23,23 @@ pub struct Child {
 
     #[getset(get = "pub")]
     io: SharedContainerIO,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    restart_count: u32,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    last_exit_code: i32,
+
+    #[getset(get = "pub")]
+    token: CancellationToken,
+
+    #[getset(get = "pub", set = "pub")]
+    probes: SharedProbes,
+
+    /// Token to cancel pending restarts, if the child is supervised according to a restart
+    /// policy.
+    #[getset(get = "pub", set = "pub")]
+    restart_token: Option<CancellationToken>,
 }
 
 impl Child {
@@ -40,6 +58,11 @@ impl Child {
             oom_exit_paths,
             timeout,
             io,
+            restart_count: 0,
+            last_exit_code: 0,
+            token: CancellationToken::new(),
+            probes: Default::default(),
+            restart_token: None,
         }
     }
 }
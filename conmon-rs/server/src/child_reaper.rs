@@ -3,8 +3,12 @@ use crate::{
     child::Child,
     container_io::{ContainerIO, ContainerIOType, SharedContainerIO},
     oom_watcher::OOMWatcher,
+    orphan_reaper::OrphanReaper,
+    pidfd::PidFd,
+    probe::SharedProbes,
 };
 use anyhow::{anyhow, format_err, Context, Result};
+use futures::future::join_all;
 use getset::{CopyGetters, Getters, Setters};
 use libc::pid_t;
 use multimap::MultiMap;
@@ -14,29 +18,39 @@ use nix::{
         signal::{kill, Signal},
         wait::{waitpid, WaitStatus},
     },
-    unistd::{getpgid, Pid},
+    unistd::{getpgid, getpgrp, setpgid, Pid},
 };
 use std::{
     ffi::OsStr,
+    io,
     path::{Path, PathBuf},
     process::Stdio,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 use tokio::{
     fs::{self, File},
     io::AsyncWriteExt,
     process::Command,
-    sync::broadcast::{self, Receiver, Sender},
+    sync::broadcast::{self, Receiver},
     task::{self, JoinHandle},
     time::{self, Instant},
 };
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, debug_span, error, Instrument};
+use tracing::{debug, debug_span, error, trace, Instrument};
 
 #[derive(Debug, Default, Getters)]
 pub struct ChildReaper {
     #[getset(get)]
     grandchildren: Arc<Mutex<MultiMap<String, ReapableChild>>>,
+
+    /// Token which gets cancelled as soon as the shutdown starts, to stop restarting containers.
+    #[getset(get = "pub")]
+    shutdown_token: CancellationToken,
+
+    /// Reaper of processes which are neither grandchildren nor spawned by conmonrs itself.
+    #[getset(get = "pub")]
+    orphans: Arc<OrphanReaper>,
 }
 
 macro_rules! lock {
@@ -54,43 +68,77 @@ impl ChildReaper {
         Ok(r)
     }
 
+    /// Run the runtime to create a child and return the process ID of the grandchild. The
+    /// runtime gets killed together with its process group if the timeout expires or the
+    /// returned future gets dropped before completion.
     pub async fn create_child<P, I, S>(
         &self,
         cmd: P,
         args: I,
         container_io: &mut ContainerIO,
         pidfile: &Path,
+        timeout: Option<Duration>,
     ) -> Result<u32>
     where
         P: AsRef<OsStr>,
         I: IntoIterator<Item = S>,
         S: AsRef<OsStr>,
     {
+        // The runtime and the grandchild must not be reaped as orphans until they are waited for.
+        let _pause = self.orphans.pause();
+
         let mut cmd = Command::new(cmd);
         cmd.args(args);
+        let stdin = if container_io.attach().stdin() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        };
+
+        // Put the runtime into its own process group to be able to kill it including its
+        // children, like hooks.
+        unsafe {
+            cmd.pre_exec(|| {
+                setpgid(Pid::from_raw(0), Pid::from_raw(0))
+                    .map_err(|e| io::Error::from_raw_os_error(e as i32))
+            });
+        }
+
         let mut child = cmd
-            .stdin(Stdio::piped())
+            .stdin(stdin)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
             .context("spawn child process: {}")?;
+        let guard = CreateGuard {
+            pgid: child.id().map(|pid| Pid::from_raw(pid as pid_t)),
+            pidfile,
+        };
 
         let stdout = child.stdout.take();
         let stderr = child.stderr.take();
         let stdin = child.stdin.take();
 
-        match container_io.typ_mut() {
-            ContainerIOType::Terminal(ref mut terminal) => {
-                terminal
-                    .wait_connected()
-                    .await
-                    .context("wait for terminal socket connection")?;
-            }
-            ContainerIOType::Streams(streams) => {
-                streams.handle_stdio_receive(stdin, stdout, stderr);
+        let wait = async {
+            match container_io.typ_mut() {
+                ContainerIOType::Terminal(ref mut terminal) => {
+                    terminal
+                        .wait_connected()
+                        .await
+                        .context("wait for terminal socket connection")?;
+                }
+                ContainerIOType::Streams(streams) => {
+                    streams.handle_stdio_receive(stdin, stdout, stderr);
+                }
             }
-        }
-        let status = child.wait().await?;
+            child.wait().await.context("wait for child process")
+        };
+        let status = match timeout {
+            Some(timeout) => time::timeout(timeout, wait)
+                .await
+                .map_err(|_| anyhow!("timed out after {:?} waiting for the runtime", timeout))??,
+            None => wait.await?,
+        };
 
         if !status.success() {
             let code_str = match status.code() {
@@ -108,15 +156,30 @@ impl ChildReaper {
             .parse::<u32>()
             .context(format!("grandchild pid parse error {}", pidfile.display()))?;
 
+        self.orphans.register(grandchild_pid);
+        guard.disarm();
         Ok(grandchild_pid)
     }
 
+    /// Watch the grandchild until it exited. Supervised grandchildren replace the entry of
+    /// their previous process and are kept after their exit, until the supervisor forgets them.
     pub fn watch_grandchild(&self, child: Child) -> Result<Receiver<ExitChannelData>> {
         let locked_grandchildren = &self.grandchildren().clone();
         let mut map = lock!(locked_grandchildren);
         let mut reapable_grandchild = ReapableChild::from_child(&child);
 
-        let (exit_tx, exit_rx) = reapable_grandchild.watch()?;
+        let (mut cleanup_rx, exit_rx) = reapable_grandchild.watch(self.orphans.clone())?;
+
+        if child.restart_token().is_some() {
+            match map
+                .get_vec_mut(child.id())
+                .and_then(|x| x.iter_mut().find(|x| x.restart_token.is_some()))
+            {
+                Some(previous) => *previous = reapable_grandchild,
+                None => map.insert(child.id().clone(), reapable_grandchild),
+            }
+            return Ok(exit_rx);
+        }
 
         map.insert(child.id().clone(), reapable_grandchild);
         let cleanup_grandchildren = locked_grandchildren.clone();
@@ -124,7 +187,7 @@ impl ChildReaper {
 
         task::spawn(
             async move {
-                exit_tx.subscribe().recv().await?;
+                cleanup_rx.recv().await?;
                 Self::forget_grandchild(&cleanup_grandchildren, pid)
             }
             .instrument(debug_span!("watch_grandchild", pid)),
@@ -132,41 +195,228 @@ impl ChildReaper {
         Ok(exit_rx)
     }
 
+    /// Watch the grandchild of an exec session and collect its output until it exited or the
+    /// timeout expired.
+    pub async fn wait_exec_sync(
+        &self,
+        id: String,
+        pid: u32,
+        container_io: ContainerIO,
+        timeout: Option<Duration>,
+    ) -> Result<ExecSyncOutput> {
+        let time_to_timeout = timeout.map(|timeout| Instant::now() + timeout);
+        let io = SharedContainerIO::new(container_io);
+        let child = Child::new(id, pid, vec![], vec![], time_to_timeout, io.clone());
+
+        let mut exit_rx = self.watch_grandchild(child)?;
+        let (stdout, stderr, timed_out) = io.read_all_with_timeout(time_to_timeout).await;
+        let exit_data = exit_rx.recv().await.context("receive exit status")?;
+
+        Ok(ExecSyncOutput {
+            stdout,
+            stderr,
+            exit_code: exit_data.exit_code,
+            timed_out: timed_out || exit_data.timed_out,
+        })
+    }
+
     fn forget_grandchild(
         locked_grandchildren: &Arc<Mutex<MultiMap<String, ReapableChild>>>,
         grandchild_pid: u32,
     ) -> Result<()> {
         let mut map = lock!(locked_grandchildren);
-        map.retain(|_, v| v.pid == grandchild_pid);
+        map.retain(|_, v| v.pid != grandchild_pid);
         Ok(())
     }
 
-    pub fn kill_grandchildren(&self, s: Signal) -> Result<()> {
-        for (_, grandchild) in lock!(self.grandchildren).iter() {
-            let span = debug_span!("kill_grandchildren", pid = grandchild.pid);
-            let _enter = span.enter();
-            debug!("Killing grandchild");
-            kill_grandchild(grandchild.pid, s);
-            futures::executor::block_on(
-                async {
-                    if let Err(e) = grandchild.close().await {
-                        error!("Unable to close grandchild: {}", e)
-                    }
-                }
-                .instrument(debug_span!("close", signal = s.as_str())),
-            );
+    /// Mark the exited supervised grandchild of the container as waiting to be restarted after
+    /// exiting with the provided code.
+    pub fn set_restarting(&self, id: &str, exit_code: i32) -> Result<()> {
+        let mut map = lock!(self.grandchildren);
+        let grandchild = map
+            .get_vec_mut(id)
+            .and_then(|x| x.iter_mut().find(|x| x.restart_token.is_some()))
+            .context("supervised child not available")?;
+        grandchild.restarting = true;
+        grandchild.last_exit_code = exit_code;
+        Ok(())
+    }
+
+    /// Forget the supervised grandchild of the container, as soon as it will not be restarted
+    /// any more.
+    pub fn forget_supervised(&self, id: &str) -> Result<()> {
+        let mut map = lock!(self.grandchildren);
+        map.retain(|k, v| k != id || v.restart_token.is_none());
+        Ok(())
+    }
+
+    /// Retrieve a snapshot of all grandchildren together with their container IDs.
+    pub fn list(&self) -> Result<Vec<(String, ReapableChild)>> {
+        Ok(lock!(self.grandchildren)
+            .iter_all()
+            .flat_map(|(id, grandchildren)| {
+                grandchildren
+                    .iter()
+                    .map(move |grandchild| (id.clone(), grandchild.clone()))
+            })
+            .collect())
+    }
+
+    /// Send the provided signal to the container with the provided ID. Signaling an exited
+    /// container which waits to be restarted cancels the restart instead.
+    pub fn kill(&self, id: &str, s: Signal) -> Result<()> {
+        let grandchild = self.get(id)?;
+        if let Some(restart_token) = grandchild.restart_token() {
+            if grandchild.token().is_cancelled() {
+                debug!(pid = grandchild.pid, "Cancelling restart on {}", s);
+                restart_token.cancel();
+                return Ok(());
+            }
+        }
+        debug!(pid = grandchild.pid, "Sending {} to grandchild", s);
+        match grandchild.pidfd() {
+            Some(pidfd) => pidfd.send_signal(s),
+            None => kill(Pid::from_raw(grandchild.pid as pid_t), s).context("send signal"),
+        }
+    }
+
+    /// Shutdown all grandchildren by using the provided mode and close their logs afterwards.
+    pub async fn shutdown(&self, mode: ShutdownMode) -> Result<()> {
+        self.shutdown_token.cancel();
+
+        // Take a snapshot, because exited grandchildren get removed from the map.
+        let grandchildren = self
+            .list()?
+            .into_iter()
+            .map(|(_, grandchild)| grandchild)
+            .collect::<Vec<_>>();
+
+        match mode {
+            ShutdownMode::KillAll(s, grace_period) => {
+                Self::kill_grandchildren(&grandchildren, s, grace_period).await
+            }
+            ShutdownMode::Detach => {
+                debug!("Leaving {} grandchildren running", grandchildren.len())
+            }
+            ShutdownMode::WaitForExit => {
+                debug!("Waiting for {} grandchildren to exit", grandchildren.len());
+                Self::wait_grandchildren(&grandchildren).await;
+            }
+        }
+
+        for grandchild in &grandchildren {
+            debug!(pid = grandchild.pid, "Closing grandchild logs");
+            if let Err(e) = grandchild.io().logger().await.write().await.close().await {
+                error!(pid = grandchild.pid, "Unable to close logs: {:#}", e)
+            }
         }
         Ok(())
     }
+
+    async fn kill_grandchildren(
+        grandchildren: &[ReapableChild],
+        s: Signal,
+        grace_period: Option<Duration>,
+    ) {
+        for grandchild in grandchildren {
+            debug!(pid = grandchild.pid, "Killing grandchild");
+            kill_grandchild(grandchild.pid, grandchild.pidfd().as_deref(), s);
+        }
+
+        if let Some(grace_period) = grace_period {
+            if time::timeout(grace_period, Self::wait_grandchildren(grandchildren))
+                .await
+                .is_err()
+            {
+                for grandchild in grandchildren.iter().filter(|x| !x.token().is_cancelled()) {
+                    debug!(
+                        pid = grandchild.pid,
+                        "Grace period expired, killing grandchild"
+                    );
+                    kill_grandchild(
+                        grandchild.pid,
+                        grandchild.pidfd().as_deref(),
+                        Signal::SIGKILL,
+                    );
+                }
+            }
+        }
+
+        for grandchild in grandchildren {
+            if let Err(e) = grandchild
+                .close()
+                .instrument(debug_span!("close", signal = s.as_str()))
+                .await
+            {
+                error!(pid = grandchild.pid, "Unable to close grandchild: {}", e)
+            }
+        }
+    }
+
+    /// Wait for all provided grandchildren to exit.
+    async fn wait_grandchildren(grandchildren: &[ReapableChild]) {
+        // The token gets cancelled as soon as the grandchild exited.
+        join_all(grandchildren.iter().map(|x| x.token().cancelled())).await;
+    }
+}
+
+/// Kills the process group of the runtime and removes the pidfile on drop, unless the creation
+/// of the child succeeded.
+struct CreateGuard<'a> {
+    pgid: Option<Pid>,
+    pidfile: &'a Path,
+}
+
+impl CreateGuard<'_> {
+    fn disarm(mut self) {
+        self.pgid = None;
+    }
+}
+
+impl Drop for CreateGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(pgid) = self.pgid {
+            debug!("Aborting child creation, killing process group {}", pgid);
+            if let Err(e) = kill(Pid::from_raw(-pgid.as_raw()), Signal::SIGKILL) {
+                debug!("Unable to kill process group {}: {}", pgid, e);
+            }
+            if let Err(e) = std::fs::remove_file(self.pidfile) {
+                trace!("Unable to remove pidfile {}: {}", self.pidfile.display(), e);
+            }
+        }
+    }
 }
 
-pub fn kill_grandchild(raw_pid: u32, s: Signal) {
+#[derive(Clone, Copy, Debug)]
+/// Available modes for shutting down grandchildren.
+pub enum ShutdownMode {
+    /// Kill all grandchildren with the provided signal, followed by SIGKILL if the grace period
+    /// expired.
+    KillAll(Signal, Option<Duration>),
+
+    /// Leave all grandchildren running.
+    Detach,
+
+    /// Wait for all grandchildren to exit.
+    WaitForExit,
+}
+
+/// Kill the grandchild and its process group. The pidfd, if available, ensures that no other
+/// process gets signaled if the pid got reused after the grandchild exited.
+pub fn kill_grandchild(raw_pid: u32, pidfd: Option<&PidFd>, s: Signal) {
     let pid = Pid::from_raw(raw_pid as pid_t);
+    if let Some(Err(e)) = pidfd.map(|x| x.send_signal(None)) {
+        debug!(raw_pid, "Not killing exited grandchild: {:#}", e);
+        return;
+    }
     if let Ok(pgid) = getpgid(Some(pid)) {
         // If process_group is 1, we will end up calling
         // kill(-1), which kills everything conmon is allowed to.
+        // A grandchild without its own process group shares the one of conmon, which must not
+        // be killed either.
+        let own_group = pgid == getpgrp();
         let pgid = i32::from(pgid);
-        if pgid > 1 {
+        if pgid > 1 && !own_group {
             if let Err(e) = kill(Pid::from_raw(-pgid), s) {
                 error!(
                     raw_pid,
@@ -175,11 +425,18 @@ pub fn kill_grandchild(raw_pid: u32, s: Signal) {
             }
         }
     }
-    if let Err(e) = kill(pid, s) {
-        debug!("Failed killing pid: {}", e);
+    let res = match pidfd {
+        Some(pidfd) => pidfd.send_signal(s),
+        None => kill(pid, s).context("kill"),
+    };
+    if let Err(e) = res {
+        debug!("Failed killing pid: {:#}", e);
     }
 }
 
+/// The exit code used if waiting for the grandchild failed.
+const FAILED_EXIT_CODE: i32 = -3;
+
 type TaskHandle = Arc<Mutex<Option<Vec<JoinHandle<()>>>>>;
 
 #[derive(Clone, CopyGetters, Debug, Getters, Setters)]
@@ -190,7 +447,7 @@ pub struct ReapableChild {
     #[getset(get)]
     oom_exit_paths: Vec<PathBuf>,
 
-    #[getset(get_copy)]
+    #[getset(get_copy = "pub")]
     pid: u32,
 
     #[getset(get = "pub")]
@@ -202,6 +459,27 @@ pub struct ReapableChild {
     #[getset(get = "pub")]
     token: CancellationToken,
 
+    #[getset(get = "pub")]
+    probes: SharedProbes,
+
+    #[getset(get_copy = "pub")]
+    restart_count: u32,
+
+    #[getset(get_copy = "pub")]
+    last_exit_code: i32,
+
+    /// Token to cancel pending restarts, if the grandchild is supervised.
+    #[getset(get = "pub")]
+    restart_token: Option<CancellationToken>,
+
+    /// Whether the grandchild exited and waits to be restarted.
+    #[getset(get_copy = "pub")]
+    restarting: bool,
+
+    /// The pidfd of the grandchild, if supported by the kernel.
+    #[getset(get = "pub")]
+    pidfd: Option<Arc<PidFd>>,
+
     task: Option<TaskHandle>,
 }
 
@@ -217,6 +495,22 @@ pub struct ExitChannelData {
     pub timed_out: bool,
 }
 
+#[derive(Debug)]
+/// The result of an exec session which ran to completion.
+pub struct ExecSyncOutput {
+    /// The collected standard output.
+    pub stdout: Vec<u8>,
+
+    /// The collected standard error.
+    pub stderr: Vec<u8>,
+
+    /// The exit code of the session.
+    pub exit_code: i32,
+
+    /// Whether the session got killed because the timeout expired.
+    pub timed_out: bool,
+}
+
 impl ReapableChild {
     pub fn from_child(child: &Child) -> Self {
         Self {
@@ -225,7 +519,13 @@ impl ReapableChild {
             pid: child.pid(),
             io: child.io().clone(),
             timeout: *child.timeout(),
-            token: CancellationToken::new(),
+            token: child.token().clone(),
+            probes: child.probes().clone(),
+            restart_count: child.restart_count(),
+            last_exit_code: child.last_exit_code(),
+            restart_token: child.restart_token().clone(),
+            restarting: false,
+            pidfd: None,
             task: None,
         }
     }
@@ -234,7 +534,8 @@ impl ReapableChild {
         debug!("Grandchild close");
         self.token.cancel();
         if let Some(t) = self.task.clone() {
-            for t in lock!(t).take().context("no tasks available")?.into_iter() {
+            let tasks = lock!(t).take().context("no tasks available")?;
+            for t in tasks.into_iter() {
                 debug!("Grandchild await");
                 t.await?;
             }
@@ -242,16 +543,32 @@ impl ReapableChild {
         Ok(())
     }
 
-    fn watch(&mut self) -> Result<(Sender<ExitChannelData>, Receiver<ExitChannelData>)> {
+    /// Watch the grandchild for its exit. Both returned receivers get subscribed before the
+    /// watch starts, to not miss grandchildren which exit immediately. The grandchild has to be
+    /// registered at the orphan reaper, which gets released as soon as the exit got handled.
+    fn watch(
+        &mut self,
+        orphans: Arc<OrphanReaper>,
+    ) -> Result<(Receiver<ExitChannelData>, Receiver<ExitChannelData>)> {
         let exit_paths = self.exit_paths().clone();
         let oom_exit_paths = self.oom_exit_paths().clone();
         let pid = self.pid();
         // Only one exit code will be written.
         let (exit_tx, exit_rx) = broadcast::channel(1);
-        let exit_tx_clone = exit_tx.clone();
+        let cleanup_rx = exit_tx.subscribe();
         let timeout = *self.timeout();
         let stop_token = self.token().clone();
 
+        // Fall back to a blocking waitpid thread on kernels without pidfd support.
+        self.pidfd = match PidFd::open(pid) {
+            Ok(pidfd) => Some(Arc::new(pidfd)),
+            Err(e) => {
+                debug!(pid, "Unable to open pidfd, falling back to waitpid: {}", e);
+                None
+            }
+        };
+        let pidfd = self.pidfd.clone();
+
         let task = task::spawn(
             async move {
                 let mut exit_code: i32 = -1;
@@ -260,11 +577,20 @@ impl ReapableChild {
                 let (oom_tx, mut oom_rx) = tokio::sync::mpsc::channel(1);
                 let oom_watcher = OOMWatcher::new(&stop_token, pid, &oom_exit_paths, oom_tx).await;
 
-                let span = debug_span!("wait_for_exit_code");
-                let wait_for_exit_code = task::spawn_blocking(move || {
-                    let _enter = span.enter();
-                    Self::wait_for_exit_code(&stop_token, pid)
-                });
+                let wait_for_exit_code = async {
+                    match &pidfd {
+                        Some(pidfd) => Ok(Self::wait_for_exit_code_pidfd(&stop_token, pidfd).await),
+                        None => {
+                            let span = debug_span!("wait_for_exit_code");
+                            let stop_token = stop_token.clone();
+                            task::spawn_blocking(move || {
+                                let _enter = span.enter();
+                                Self::wait_for_exit_code(&stop_token, pid)
+                            })
+                            .await
+                        }
+                    }
+                };
 
                 let closure = async {
                     let (code, oom) = tokio::join!(wait_for_exit_code, oom_rx.recv());
@@ -279,11 +605,17 @@ impl ReapableChild {
                     if time::timeout_at(timeout, closure).await.is_err() {
                         timed_out = true;
                         exit_code = -3;
-                        kill_grandchild(pid, Signal::SIGKILL);
+                        kill_grandchild(pid, pidfd.as_deref(), Signal::SIGKILL);
+                        // The killed grandchild still has to be reaped, which the dropped pidfd
+                        // wait would have done.
+                        if let Some(pidfd) = &pidfd {
+                            Self::wait_for_exit_code_pidfd(&stop_token, pidfd).await;
+                        }
                     }
                 } else {
                     closure.await;
                 }
+                orphans.unregister(pid);
                 oom_watcher.stop().await;
                 let exit_channel_data = ExitChannelData {
                     exit_code,
@@ -291,7 +623,7 @@ impl ReapableChild {
                     timed_out,
                 };
                 debug!("Sending exit struct to channel: {:?}", exit_channel_data);
-                if exit_tx_clone.send(exit_channel_data).is_err() {
+                if exit_tx.send(exit_channel_data).is_err() {
                     debug!("Unable to send exit status");
                 }
                 debug!(
@@ -316,11 +648,22 @@ impl ReapableChild {
             .push(task);
         self.task = Some(tasks);
 
-        Ok((exit_tx, exit_rx))
+        Ok((cleanup_rx, exit_rx))
+    }
+
+    async fn wait_for_exit_code_pidfd(token: &CancellationToken, pidfd: &PidFd) -> i32 {
+        let exit_code = match pidfd.wait().await {
+            Ok(exit_code) => exit_code,
+            Err(e) => {
+                error!("Unable to wait for pidfd: {:#}", e);
+                FAILED_EXIT_CODE
+            }
+        };
+        token.cancel();
+        exit_code
     }
 
     fn wait_for_exit_code(token: &CancellationToken, pid: u32) -> i32 {
-        const FAILED_EXIT_CODE: i32 = -3;
         loop {
             match waitpid(Pid::from_raw(pid as pid_t), None) {
                 Ok(WaitStatus::Exited(_, exit_code)) => {
@@ -349,7 +692,7 @@ impl ReapableChild {
         }
     }
 
-    async fn write_to_exit_paths(code: i32, paths: &[PathBuf]) -> Result<()> {
+    pub async fn write_to_exit_paths(code: i32, paths: &[PathBuf]) -> Result<()> {
         let paths = paths.to_owned();
         let tasks: Vec<_> = paths
             .into_iter()
@@ -382,3 +725,214 @@ impl ReapableChild {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container_log::ContainerLog;
+    use std::os::unix::process::CommandExt;
+    use tempfile::tempdir;
+
+    fn new_child(id: &str, pid: u32) -> Result<Child> {
+        let io = SharedContainerIO::new(ContainerIO::new(
+            None,
+            Default::default(),
+            ContainerLog::new(),
+            Default::default(),
+        )?);
+        Ok(Child::new(id.into(), pid, vec![], vec![], None, io))
+    }
+
+    fn new_reapable_child(id: &str, pid: u32) -> Result<ReapableChild> {
+        Ok(ReapableChild::from_child(&new_child(id, pid)?))
+    }
+
+    /// Spawn a grandchild in its own process group and watch it.
+    fn watch_new_grandchild(
+        sut: &ChildReaper,
+        id: &str,
+        script: &str,
+    ) -> Result<(u32, Receiver<ExitChannelData>)> {
+        let pid = std::process::Command::new("sh")
+            .args(["-c", script])
+            .process_group(0)
+            .spawn()?
+            .id();
+        Ok((pid, sut.watch_grandchild(new_child(id, pid)?)?))
+    }
+
+    fn is_running(pid: u32) -> bool {
+        kill(Pid::from_raw(pid as pid_t), None).is_ok()
+    }
+
+    #[test]
+    fn forget_grandchild() -> Result<()> {
+        let sut = ChildReaper::default();
+        {
+            let mut map = lock!(sut.grandchildren());
+            map.insert("first".into(), new_reapable_child("first", 1)?);
+            map.insert("second".into(), new_reapable_child("second", 2)?);
+        }
+
+        ChildReaper::forget_grandchild(sut.grandchildren(), 1)?;
+
+        let map = lock!(sut.grandchildren());
+        assert!(!map.contains_key("first"));
+        assert!(map.contains_key("second"));
+        Ok(())
+    }
+
+    #[test]
+    fn kill_grandchild_in_own_process_group() -> Result<()> {
+        // Both processes share the process group of the test.
+        let mut grandchild = std::process::Command::new("sleep").arg("10").spawn()?;
+        let mut sibling = std::process::Command::new("sleep").arg("10").spawn()?;
+
+        kill_grandchild(grandchild.id(), None, Signal::SIGKILL);
+
+        assert!(!grandchild.wait()?.success());
+        assert!(sibling.try_wait()?.is_none());
+        sibling.kill()?;
+        sibling.wait()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn watch_grandchild_forget_on_exit() -> Result<()> {
+        let sut = ChildReaper::default();
+        let (_, mut exit_rx) = watch_new_grandchild(&sut, "id", "true")?;
+        assert_eq!(exit_rx.recv().await?.exit_code, 0);
+
+        time::timeout(Duration::from_secs(5), async {
+            while lock!(sut.grandchildren()).contains_key("id") {
+                time::sleep(Duration::from_millis(10)).await;
+            }
+            Ok::<_, anyhow::Error>(())
+        })
+        .await??;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn watch_grandchild_timeout() -> Result<()> {
+        let sut = ChildReaper::default();
+        let pid = std::process::Command::new("sleep")
+            .arg("10")
+            .process_group(0)
+            .spawn()?
+            .id();
+        let io = new_child("id", pid)?.io().clone();
+        let timeout = Instant::now() + Duration::from_millis(100);
+        let child = Child::new("id".into(), pid, vec![], vec![], Some(timeout), io);
+        let token = child.token().clone();
+
+        let mut exit_rx = sut.watch_grandchild(child)?;
+        let exit_data = exit_rx.recv().await?;
+        assert!(exit_data.timed_out);
+        assert!(token.is_cancelled());
+        assert!(!is_running(pid));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_child_timeout() -> Result<()> {
+        let dir = tempdir()?;
+        let pidfile = dir.path().join("pidfile");
+        let hook_pidfile = dir.path().join("hook");
+        let mut container_io = ContainerIO::new(
+            None,
+            Default::default(),
+            ContainerLog::new(),
+            Default::default(),
+        )?;
+        let script = format!(
+            "touch {}; sleep 10 & echo $! > {}; wait",
+            pidfile.display(),
+            hook_pidfile.display()
+        );
+
+        let sut = ChildReaper::default();
+        let err = sut
+            .create_child(
+                "sh",
+                ["-c", &script],
+                &mut container_io,
+                &pidfile,
+                Some(Duration::from_millis(500)),
+            )
+            .await
+            .expect_err("creation should time out");
+        assert!(format!("{:#}", err).contains("timed out"));
+        assert!(!pidfile.exists());
+
+        let hook_pid: pid_t = fs::read_to_string(&hook_pidfile).await?.trim().parse()?;
+        time::sleep(Duration::from_millis(100)).await;
+        let stat = fs::read_to_string(format!("/proc/{}/stat", hook_pid))
+            .await
+            .unwrap_or_default();
+        assert!(stat.is_empty() || stat.contains(") Z "), "{}", stat);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shutdown_kill_all() -> Result<()> {
+        let sut = ChildReaper::default();
+        let (_, mut exit_rx) = watch_new_grandchild(&sut, "id", "sleep 10")?;
+
+        sut.shutdown(ShutdownMode::KillAll(Signal::SIGTERM, None))
+            .await?;
+
+        let exit_data = exit_rx.recv().await?;
+        assert_eq!(exit_data.exit_code, 128 + Signal::SIGTERM as i32);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shutdown_kill_all_grace_period() -> Result<()> {
+        let sut = ChildReaper::default();
+        let (_, mut exit_rx) =
+            watch_new_grandchild(&sut, "id", "trap '' TERM; while :; do sleep 0.1; done")?;
+        // Give the shell some time to set up the trap.
+        time::sleep(Duration::from_millis(200)).await;
+
+        sut.shutdown(ShutdownMode::KillAll(
+            Signal::SIGTERM,
+            Some(Duration::from_millis(200)),
+        ))
+        .await?;
+
+        let exit_data = exit_rx.recv().await?;
+        assert_eq!(exit_data.exit_code, 128 + Signal::SIGKILL as i32);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shutdown_detach() -> Result<()> {
+        let sut = ChildReaper::default();
+        let (pid, _exit_rx) = watch_new_grandchild(&sut, "id", "sleep 10")?;
+
+        sut.shutdown(ShutdownMode::Detach).await?;
+
+        assert!(is_running(pid));
+        assert!(!sut.get("id")?.token().is_cancelled());
+        kill_grandchild(pid, None, Signal::SIGKILL);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shutdown_wait_for_exit() -> Result<()> {
+        let sut = ChildReaper::default();
+        let (pid, _exit_rx) = watch_new_grandchild(&sut, "id", "sleep 0.2")?;
+        let grandchild = sut.get("id")?;
+
+        time::timeout(
+            Duration::from_secs(5),
+            sut.shutdown(ShutdownMode::WaitForExit),
+        )
+        .await??;
+
+        assert!(grandchild.token().is_cancelled());
+        assert!(!is_running(pid));
+        Ok(())
+    }
+}
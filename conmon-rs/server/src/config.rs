@@ -50,6 +50,35 @@ pub struct Config {
     /// The logging driver used by the conmon server.
     log_driver: LogDriver,
 
+    #[get = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "LOG_FILE")),
+        long("log-file"),
+        value_name("PATH")
+    )]
+    /// Path of the log file used by the file log driver.
+    log_file: Option<PathBuf>,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("10485760"),
+        env(concat!(prefix!(), "LOG_FILE_MAX_SIZE")),
+        long("log-file-max-size"),
+        value_name("BYTES")
+    )]
+    /// Size in bytes after which the log file gets rotated, where zero disables the rotation.
+    log_file_max_size: u64,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("5"),
+        env(concat!(prefix!(), "LOG_FILE_MAX_BACKUPS")),
+        long("log-file-max-backups"),
+        value_name("COUNT")
+    )]
+    /// Number of rotated log files to keep.
+    log_file_max_backups: u32,
+
     #[get = "pub"]
     #[clap(
         default_value_if("version", None, Some("")),
@@ -86,8 +115,107 @@ pub struct Config {
         long("skip-fork"),
         value_name("SKIP_FORK")
     )]
-    /// Do not fork if true
+    /// Do not fork if true, which is implied if running as systemd notify service
     skip_fork: bool,
+
+    #[get = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "ALLOWED_UIDS")),
+        long("allowed-uid"),
+        multiple_occurrences(true),
+        use_value_delimiter(true),
+        value_name("UID")
+    )]
+    /// User IDs of clients which are allowed to connect. Allows all users if neither UIDs nor
+    /// GIDs are specified.
+    allowed_uids: Vec<u32>,
+
+    #[get = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "ALLOWED_GIDS")),
+        long("allowed-gid"),
+        multiple_occurrences(true),
+        use_value_delimiter(true),
+        value_name("GID")
+    )]
+    /// Group IDs of clients which are allowed to connect. Allows all groups if neither UIDs nor
+    /// GIDs are specified.
+    allowed_gids: Vec<u32>,
+
+    #[get = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "ALLOWED_EXECUTABLES")),
+        long("allowed-executable"),
+        multiple_occurrences(true),
+        use_value_delimiter(true),
+        value_name("PATH")
+    )]
+    /// Executable paths of clients which are allowed to connect. Allows all executables if not
+    /// specified.
+    allowed_executables: Vec<PathBuf>,
+
+    #[get = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "TRACING_ENDPOINT")),
+        long("tracing-endpoint"),
+        value_name("URL")
+    )]
+    /// OTLP/HTTP collector endpoint to export the tracing spans to, like
+    /// `http://localhost:4318`. Spans are not exported if not specified.
+    tracing_endpoint: Option<String>,
+
+    #[get_copy = "pub"]
+    #[clap(
+        allow_hyphen_values(true),
+        default_value("-1000"),
+        env(concat!(prefix!(), "OOM_SCORE_ADJ")),
+        long("oom-score-adj"),
+        value_name("SCORE")
+    )]
+    /// OOM score adjustment of the conmon server, between -1000 and 1000.
+    oom_score_adj: i32,
+
+    #[get_copy = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "RLIMIT_NOFILE")),
+        long("rlimit-nofile"),
+        value_name("LIMIT")
+    )]
+    /// Soft and hard limit of open file descriptors of the conmon server. Inherited if not
+    /// specified.
+    rlimit_nofile: Option<u64>,
+
+    #[get_copy = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "RLIMIT_CORE")),
+        long("rlimit-core"),
+        value_name("BYTES")
+    )]
+    /// Soft and hard limit of the core dump size of the conmon server. Inherited if not
+    /// specified.
+    rlimit_core: Option<u64>,
+
+    #[get = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "CGROUP")),
+        long("cgroup"),
+        value_name("CGROUP")
+    )]
+    /// The cgroup v2 path relative to the cgroup mount to move the conmon server into, or the
+    /// name of the systemd scope like `conmonrs.scope` when using the systemd cgroup manager.
+    /// The server stays in the cgroup of its parent if not specified.
+    cgroup: Option<String>,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value(CgroupManager::Cgroupfs.into()),
+        env(concat!(prefix!(), "CGROUP_MANAGER")),
+        long("cgroup-manager"),
+        possible_values(CgroupManager::iter().map(|x| x.into()).collect::<Vec<&str>>()),
+        value_name("MANAGER")
+    )]
+    /// The cgroup manager used to move the conmon server into its cgroup.
+    cgroup_manager: CgroupManager,
 }
 
 #[derive(
@@ -111,6 +239,35 @@ pub enum LogDriver {
 
     /// Use systemd journald as log driver
     Systemd,
+
+    /// Log JSON lines including the span fields to stdout
+    Json,
+
+    /// Log JSON lines to the rotated log file
+    File,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    EnumIter,
+    EnumString,
+    Eq,
+    IntoStaticStr,
+    Hash,
+    PartialEq,
+    Serialize,
+)]
+#[strum(serialize_all = "lowercase")]
+/// Available cgroup managers.
+pub enum CgroupManager {
+    /// Write to the cgroup v2 filesystem directly
+    Cgroupfs,
+
+    /// Create a transient systemd scope
+    Systemd,
 }
 
 impl Default for Config {
@@ -134,6 +291,13 @@ impl Config {
             fs::create_dir_all(self.runtime_dir())?;
         }
 
+        if !(-1000..=1000).contains(&self.oom_score_adj()) {
+            bail!(
+                "OOM score adjustment {} is not between -1000 and 1000",
+                self.oom_score_adj()
+            )
+        }
+
         if let Some(rr) = self.runtime_root() {
             if !rr.exists() {
                 fs::create_dir_all(rr)?;
@@ -142,10 +306,6 @@ impl Config {
             }
         }
 
-        if self.socket().exists() {
-            fs::remove_file(self.socket())?;
-        }
-
         Ok(())
     }
     pub fn socket(&self) -> PathBuf {
@@ -1,10 +1,16 @@
 use crate::{
-    attach::SharedContainerAttach, container_log::SharedContainerLog, streams::Streams,
-    terminal::Terminal,
+    attach::{AttachInput, SharedContainerAttach},
+    container_log::SharedContainerLog,
+    recording::SharedRecording,
+    streams::Streams,
+    terminal::{Terminal, TerminalOptions},
 };
 use anyhow::{bail, Context, Result};
 use getset::{Getters, MutGetters};
-use nix::errno::Errno;
+use nix::{
+    errno::Errno,
+    sys::termios::{self, SpecialCharacterIndices},
+};
 use std::{
     os::unix::io::{FromRawFd, RawFd},
     path::{Path, PathBuf},
@@ -17,10 +23,11 @@ use tokio::{
     io::{AsyncReadExt, AsyncWriteExt, BufReader},
     sync::{
         mpsc::{UnboundedReceiver, UnboundedSender},
-        RwLock,
+        RwLock, RwLockWriteGuard,
     },
     time::{self, Instant},
 };
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error};
 
 /// A shared container IO abstraction.
@@ -58,6 +65,11 @@ impl SharedContainerIO {
     pub async fn attach(&self) -> SharedContainerAttach {
         self.0.read().await.attach().clone()
     }
+
+    /// Lock the container IO for exclusive access, like for restarting the container.
+    pub async fn write(&self) -> RwLockWriteGuard<'_, ContainerIO> {
+        self.0.write().await
+    }
 }
 
 #[derive(Debug, Getters, MutGetters)]
@@ -86,7 +98,7 @@ pub enum Message {
     Done,
 }
 
-#[derive(AsRefStr, Clone, Copy, Debug)]
+#[derive(AsRefStr, Clone, Copy, Debug, Eq, PartialEq)]
 #[strum(serialize_all = "lowercase")]
 /// Available pipe types.
 pub enum Pipe {
@@ -110,13 +122,20 @@ impl From<Streams> for ContainerIOType {
 }
 
 impl ContainerIO {
-    /// Create a new container IO instance.
-    pub fn new(terminal: bool, logger: SharedContainerLog) -> Result<Self> {
+    /// Create a new container IO instance, which uses a terminal with the provided options if
+    /// `terminal` is set. The attach instance decides if the standard input of the container
+    /// gets opened. Terminal sessions get recorded to the provided recording, which is unused for
+    /// streams.
+    pub fn new(
+        terminal: Option<TerminalOptions>,
+        attach: SharedContainerAttach,
+        logger: SharedContainerLog,
+        recording: SharedRecording,
+    ) -> Result<Self> {
         let logger_clone = logger.clone();
-        let attach = SharedContainerAttach::default();
         let attach_clone = attach.clone();
-        let typ = if terminal {
-            Terminal::new(logger_clone, attach_clone)
+        let typ = if let Some(options) = terminal {
+            Terminal::new(logger_clone, attach_clone, recording, options)
                 .context("create new terminal")?
                 .into()
         } else {
@@ -131,6 +150,18 @@ impl ContainerIO {
         })
     }
 
+    /// Prepare the IO for restarting the container, while keeping the logs and attach clients.
+    /// Terminals get replaced, whereas streams get reused for the new process.
+    pub fn renew(&mut self) -> Result<()> {
+        if let ContainerIOType::Terminal(terminal) = &self.typ {
+            let terminal = terminal
+                .renew(self.logger.clone(), self.attach.clone())
+                .context("renew terminal")?;
+            self.typ = terminal.into();
+        }
+        Ok(())
+    }
+
     /// Generate a the temp file name without creating the file.
     pub fn temp_file_name(directory: Option<&Path>, prefix: &str, suffix: &str) -> Result<PathBuf> {
         let mut file = Builder::new();
@@ -206,6 +237,7 @@ impl ContainerIO {
         logger: SharedContainerLog,
         message_tx: UnboundedSender<Message>,
         attach: SharedContainerAttach,
+        recording: SharedRecording,
     ) -> Result<()> {
         let stream = unsafe { File::from_raw_fd(fd) };
         let mut reader = BufReader::new(stream);
@@ -216,6 +248,7 @@ impl ContainerIO {
                 Ok(n) if n > 0 => {
                     debug!("fd:{}:read {} bytes", fd, n);
                     let data = &buf[..n];
+                    recording.output(data);
 
                     let mut locked_logger = logger.write().await;
                     locked_logger
@@ -266,19 +299,78 @@ impl ContainerIO {
         }
     }
 
-    pub async fn read_loop_stdin(fd: RawFd, attach: SharedContainerAttach) -> Result<()> {
+    /// Forward the attach input to the standard input of the container until the attach clients
+    /// are done or the token gets cancelled. A cancelled token keeps the attach input open, so
+    /// that the process replacing the current one is able to use it.
+    pub async fn read_loop_stdin(
+        fd: RawFd,
+        attach: SharedContainerAttach,
+        terminal: bool,
+        recording: SharedRecording,
+        token: CancellationToken,
+    ) -> Result<()> {
+        let res = tokio::select! {
+            res = Self::forward_stdin(fd, &attach, terminal, &recording) => res,
+            _ = token.cancelled() => {
+                debug!("Stopping stdin read loop");
+                return Ok(());
+            }
+        };
+        attach.close_input().await;
+        res
+    }
+
+    async fn forward_stdin(
+        fd: RawFd,
+        attach: &SharedContainerAttach,
+        terminal: bool,
+        recording: &SharedRecording,
+    ) -> Result<()> {
         let mut writer = unsafe { File::from_raw_fd(fd) };
         loop {
-            if let Some(data) = attach
-                .try_read()
-                .await
-                .context("read from stdin attach endpoints")?
-            {
-                writer
-                    .write_all(&data)
-                    .await
-                    .context("write attach stdin to stream")?;
+            if attach.stdin_finished() {
+                debug!("Attach clients disconnected");
+                return Self::close_stdin(fd, writer, terminal).await;
+            }
+            let input = tokio::select! {
+                input = attach.read() => input,
+                _ = attach.disconnected() => continue,
+            };
+            match input {
+                Some(AttachInput::Stdin(data)) => {
+                    recording.input(&data);
+                    writer
+                        .write_all(&data)
+                        .await
+                        .context("write attach stdin to stream")?
+                }
+                Some(AttachInput::Resize(width, height)) if terminal => {
+                    match Terminal::resize_fd(fd, width, height) {
+                        Ok(()) => recording.resize(width, height),
+                        Err(e) => error!("Unable to resize terminal: {:#}", e),
+                    }
+                }
+                Some(AttachInput::Resize(..)) => debug!("Ignoring resize without terminal"),
+                Some(AttachInput::CloseStdin) | None => {
+                    return Self::close_stdin(fd, writer, terminal).await;
+                }
             }
         }
     }
+
+    /// Signal the end of file to the container by closing the writer.
+    async fn close_stdin(fd: RawFd, mut writer: File, terminal: bool) -> Result<()> {
+        if terminal {
+            // Closing the writer does not close the terminal, which is why the end of file gets
+            // signaled by the terminal control character.
+            let term = termios::tcgetattr(fd).context("get terminal attributes")?;
+            let eof = term.control_chars[SpecialCharacterIndices::VEOF as usize];
+            writer
+                .write_all(&[eof])
+                .await
+                .context("write end of file to terminal")?;
+        }
+        debug!("Closing stdin");
+        Ok(())
+    }
 }
@@ -3,7 +3,7 @@ use anyhow::Result;
 use capnp::struct_list::Reader;
 use conmon_common::conmon_capnp::conmon::log_driver::{Owned, Type};
 use futures::future::join_all;
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 use tokio::{io::AsyncBufRead, sync::RwLock};
 
 pub type SharedContainerLog = Arc<RwLock<ContainerLog>>;
@@ -71,6 +71,34 @@ impl ContainerLog {
         Ok(())
     }
 
+    /// Flush and close all container logs.
+    pub async fn close(&mut self) -> Result<()> {
+        join_all(
+            self.drivers
+                .iter_mut()
+                .map(|x| match x {
+                    LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => cri_logger.close(),
+                })
+                .collect::<Vec<_>>(),
+        )
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+        Ok(())
+    }
+
+    /// Retrieve the types and paths of all loggers.
+    pub fn paths(&self) -> Vec<(Type, PathBuf)> {
+        self.drivers
+            .iter()
+            .map(|x| match x {
+                LogDriver::ContainerRuntimeInterface(cri_logger) => {
+                    (Type::ContainerRuntimeInterface, cri_logger.path().clone())
+                }
+            })
+            .collect()
+    }
+
     /// Write the contents of the provided reader into all loggers.
     pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<()>
     where
@@ -18,7 +18,7 @@ use tracing::{debug, trace};
 #[derive(Debug, CopyGetters, Getters, Setters)]
 /// The main structure used for container log handling.
 pub struct CriLogger {
-    #[getset(get)]
+    #[getset(get = "pub")]
     /// Path to the file on disk.
     path: PathBuf,
 
@@ -146,6 +146,16 @@ impl CriLogger {
             .context("flush file writer")
     }
 
+    /// Flush all pending content to disk and close the log file.
+    pub async fn close(&mut self) -> Result<()> {
+        debug!("Close container log {}", self.path().display());
+        self.flush().await?;
+        if let Some(file) = self.file.take() {
+            file.get_ref().sync_all().await?;
+        }
+        Ok(())
+    }
+
     /// Open the provided path with the default options.
     async fn open<T: AsRef<Path>>(path: T) -> Result<BufWriter<File>> {
         Ok(BufWriter::new(
@@ -257,6 +267,22 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn close_success() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "a\n".as_bytes()).await?;
+        sut.close().await?;
+
+        let res = fs::read_to_string(path)?;
+        assert!(res.contains(" stdout F a"));
+        assert!(sut.write(Pipe::StdOut, "b\n".as_bytes()).await.is_err());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn init_failure() -> Result<()> {
         let mut sut = CriLogger::new("/file/does/not/exist", None)?;
@@ -1,10 +1,12 @@
-use anyhow::{bail, Result};
-use libc::{c_char, c_int, setlocale, LC_ALL};
+use anyhow::{bail, Context, Result};
+use libc::{c_char, c_int, rlim_t, setlocale, LC_ALL};
+use nix::sys::resource::{setrlimit, Resource};
 use std::{
     ffi::CString,
-    fs::File,
+    fs::{self, File},
     io::{self, ErrorKind, Write},
     path::Path,
+    process::Command,
 };
 use tracing::info;
 
@@ -43,6 +45,39 @@ where
         }
         Ok(())
     }
+
+    /// Set the soft and hard limit of the resource for the currently running process.
+    pub fn set_rlimit(&self, resource: Resource, limit: u64) -> Result<()> {
+        self.imp
+            .setrlimit(resource, limit, limit)
+            .with_context(|| format!("set {:?} to {}", resource, limit))
+    }
+
+    /// Move the currently running process into the cgroup v2 path relative to `cgroup_root`,
+    /// which gets created if it does not exist.
+    pub fn join_cgroup<P: AsRef<Path>>(&self, cgroup_root: P, path: &str) -> Result<()> {
+        let cgroup = cgroup_root.as_ref().join(path.trim_start_matches('/'));
+        self.imp
+            .create_dir_all(cgroup.clone())
+            .with_context(|| format!("create cgroup {}", cgroup.display()))?;
+        let mut file = self
+            .imp
+            .create_file(cgroup.join("cgroup.procs"))
+            .context("open cgroup procs")?;
+        self.imp
+            .write_all_file(&mut file, std::process::id().to_string().as_bytes())
+            .with_context(|| format!("join cgroup {}", cgroup.display()))
+    }
+
+    /// Move the currently running process into a new transient systemd scope.
+    pub fn join_scope(&self, scope: &str) -> Result<()> {
+        if !scope.ends_with(".scope") {
+            bail!("systemd scope name {} has to end with .scope", scope)
+        }
+        self.imp
+            .start_transient_scope(scope, std::process::id())
+            .with_context(|| format!("start systemd scope {}", scope))
+    }
 }
 
 #[cfg_attr(test, automock)]
@@ -50,6 +85,9 @@ pub trait InitImpl {
     fn setlocale(&self, category: c_int, locale: *const c_char) -> *mut c_char;
     fn create_file<P: 'static + AsRef<Path>>(&self, path: P) -> io::Result<File>;
     fn write_all_file(&self, file: &mut File, buf: &[u8]) -> io::Result<()>;
+    fn create_dir_all<P: 'static + AsRef<Path>>(&self, path: P) -> io::Result<()>;
+    fn setrlimit(&self, resource: Resource, soft: rlim_t, hard: rlim_t) -> nix::Result<()>;
+    fn start_transient_scope(&self, scope: &str, pid: u32) -> Result<()>;
 }
 
 #[derive(Debug, Default)]
@@ -67,12 +105,57 @@ impl InitImpl for DefaultInit {
     fn write_all_file(&self, file: &mut File, buf: &[u8]) -> io::Result<()> {
         file.write_all(buf)
     }
+
+    fn create_dir_all<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::create_dir_all(path)
+    }
+
+    fn setrlimit(&self, resource: Resource, soft: rlim_t, hard: rlim_t) -> nix::Result<()> {
+        setrlimit(resource, soft, hard)
+    }
+
+    fn start_transient_scope(&self, scope: &str, pid: u32) -> Result<()> {
+        // Rootless servers use the systemd user instance.
+        let bus = if nix::unistd::geteuid().is_root() {
+            "--system"
+        } else {
+            "--user"
+        };
+        let output = Command::new("busctl")
+            .args([
+                "call",
+                bus,
+                "org.freedesktop.systemd1",
+                "/org/freedesktop/systemd1",
+                "org.freedesktop.systemd1.Manager",
+                "StartTransientUnit",
+                "ssa(sv)a(sa(sv))",
+                scope,
+                "fail",
+                "1",
+                "PIDs",
+                "au",
+                "1",
+                &pid.to_string(),
+                "0",
+            ])
+            .output()
+            .context("run busctl")?;
+        if !output.status.success() {
+            bail!(
+                "busctl failed with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::{ptr, str};
+    use std::{path::PathBuf, ptr, str};
     use tempfile::tempfile;
 
     fn new_sut(mock: MockInitImpl) -> Init<MockInitImpl> {
@@ -123,6 +206,46 @@ mod tests {
         sut.set_oom_score("-1000")
     }
 
+    #[test]
+    fn set_rlimit_success() -> Result<()> {
+        let mut mock = MockInitImpl::new();
+
+        mock.expect_setrlimit()
+            .with(eq(Resource::RLIMIT_NOFILE), eq(4096), eq(4096))
+            .returning(|_, _, _| Ok(()));
+
+        let sut = new_sut(mock);
+        sut.set_rlimit(Resource::RLIMIT_NOFILE, 4096)
+    }
+
+    #[test]
+    fn join_cgroup_success() -> Result<()> {
+        let mut mock = MockInitImpl::new();
+
+        mock.expect_create_dir_all()
+            .withf(|x: &PathBuf| x == Path::new("/sys/fs/cgroup/system.slice/conmonrs"))
+            .returning(|_: PathBuf| Ok(()));
+
+        mock.expect_create_file()
+            .withf(|x: &PathBuf| {
+                x == Path::new("/sys/fs/cgroup/system.slice/conmonrs/cgroup.procs")
+            })
+            .returning(|_: PathBuf| tempfile());
+
+        mock.expect_write_all_file()
+            .withf(|_, x| x == std::process::id().to_string().as_bytes())
+            .returning(|_, _| Ok(()));
+
+        let sut = new_sut(mock);
+        sut.join_cgroup("/sys/fs/cgroup", "/system.slice/conmonrs")
+    }
+
+    #[test]
+    fn join_scope_failed_invalid_name() {
+        let sut = new_sut(MockInitImpl::new());
+        assert!(sut.join_scope("conmonrs.service").is_err());
+    }
+
     #[test]
     fn set_oom_failed_create_file() {
         let mut mock = MockInitImpl::new();
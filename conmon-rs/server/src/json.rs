@@ -0,0 +1,37 @@
+//! Helpers for writing JSON without a serialization framework.
+
+use std::fmt::Write;
+
+/// Encode the value as JSON string including the quotes.
+pub fn json_string(value: &str) -> String {
+    let mut res = String::with_capacity(value.len() + 2);
+    res.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if c.is_control() => {
+                write!(res, "\\u{:04x}", c as u32).ok();
+            }
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_string_escape() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+        assert_eq!(json_string("\r\n\t\x1b[0m"), r#""\r\n\t\u001b[0m""#);
+        assert_eq!(json_string("\u{7f}\u{85}"), r#""\u007f\u0085""#);
+        assert_eq!(json_string("äö"), "\"äö\"");
+    }
+}
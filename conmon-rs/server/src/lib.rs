@@ -9,10 +9,23 @@ mod container_io;
 mod container_log;
 mod cri_logger;
 mod init;
+mod json;
 mod listener;
+mod logging;
+mod namespaces;
+mod notifier;
 mod oom_watcher;
+mod orphan_reaper;
+mod peer;
+mod pidfd;
+mod port_forward;
+mod probe;
+mod recording;
+mod restart;
 mod rpc;
 mod server;
+mod stream_sink;
 mod streams;
+mod telemetry;
 mod terminal;
 mod version;
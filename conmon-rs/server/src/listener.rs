@@ -1,10 +1,22 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use nix::{
+    fcntl::{fcntl, FcntlArg, FdFlag},
+    sys::socket::{getsockopt, sockopt, SockType},
+};
 use std::{
-    fs,
-    os::unix::io::AsRawFd,
+    env, fs,
+    os::unix::{
+        io::{AsRawFd, FromRawFd, RawFd},
+        net,
+    },
     path::{Path, PathBuf},
+    process,
 };
 use tokio::net::UnixListener;
+use tracing::debug;
+
+/// The first file descriptor passed by the service manager, see sd_listen_fds(3).
+const SD_LISTEN_FDS_START: RawFd = 3;
 
 pub fn bind_long_path(path: &Path) -> Result<UnixListener> {
     // keep parent_fd in scope until the bind, or else the socket will not work
@@ -32,3 +44,101 @@ pub fn shorten_socket_path(path: &Path) -> Result<(PathBuf, fs::File)> {
         parent,
     ))
 }
+
+/// Retrieve the listener passed by the service manager via socket activation, if any.
+///
+/// This has to be called before forking, because `$LISTEN_PID` has to match the current process.
+/// The related environment variables get unset to not pass them down to child processes.
+pub fn from_listen_fds() -> Result<Option<net::UnixListener>> {
+    let pid = env::var("LISTEN_PID").ok();
+    let fds = env::var("LISTEN_FDS").ok();
+    for key in ["LISTEN_PID", "LISTEN_FDS", "LISTEN_FDNAMES"] {
+        env::remove_var(key);
+    }
+
+    match listen_fds_count(pid.as_deref(), fds.as_deref(), process::id())? {
+        0 => Ok(None),
+        1 => listener_from_fd(SD_LISTEN_FDS_START).map(Some),
+        n => bail!(
+            "expected a single socket activation file descriptor, got {}",
+            n
+        ),
+    }
+}
+
+fn listen_fds_count(pid: Option<&str>, fds: Option<&str>, own_pid: u32) -> Result<usize> {
+    let (pid, fds) = match (pid, fds) {
+        (Some(pid), Some(fds)) => (pid, fds),
+        _ => return Ok(0),
+    };
+
+    if pid.parse::<u32>().context("parse LISTEN_PID")? != own_pid {
+        debug!("Ignoring socket activation for PID {}", pid);
+        return Ok(0);
+    }
+
+    fds.parse().context("parse LISTEN_FDS")
+}
+
+fn listener_from_fd(fd: RawFd) -> Result<net::UnixListener> {
+    if getsockopt(fd, sockopt::SockType).context("get socket type")? != SockType::Stream {
+        bail!(
+            "socket activation file descriptor {} is not a stream socket",
+            fd
+        )
+    }
+    if !getsockopt(fd, sockopt::AcceptConn).context("get socket listening state")? {
+        bail!("socket activation file descriptor {} is not listening", fd)
+    }
+    fcntl(fd, FcntlArg::F_SETFD(FdFlag::FD_CLOEXEC)).context("set close on exec")?;
+
+    let listener = unsafe { net::UnixListener::from_raw_fd(fd) };
+    listener
+        .set_nonblocking(true)
+        .context("set socket non blocking")?;
+    Ok(listener)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::unistd::dup;
+    use tempfile::tempdir;
+
+    #[test]
+    fn listen_fds_count_success() -> Result<()> {
+        assert_eq!(listen_fds_count(Some("1"), Some("1"), 1)?, 1);
+        assert_eq!(listen_fds_count(Some("1"), Some("2"), 1)?, 2);
+        assert_eq!(listen_fds_count(Some("1"), Some("1"), 2)?, 0);
+        assert_eq!(listen_fds_count(None, Some("1"), 1)?, 0);
+        assert_eq!(listen_fds_count(Some("1"), None, 1)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn listen_fds_count_failure() {
+        assert!(listen_fds_count(Some("wrong"), Some("1"), 1).is_err());
+        assert!(listen_fds_count(Some("1"), Some("wrong"), 1).is_err());
+    }
+
+    #[test]
+    fn listener_from_fd_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("conmon.sock");
+        let listener = net::UnixListener::bind(&path)?;
+
+        let sut = listener_from_fd(dup(listener.as_raw_fd())?)?;
+        drop(listener);
+
+        net::UnixStream::connect(&path)?;
+        assert!(sut.accept().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn listener_from_fd_failure() -> Result<()> {
+        let (stream, _) = net::UnixStream::pair()?;
+        assert!(listener_from_fd(dup(stream.as_raw_fd())?).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,410 @@
+//! Structured log output of the conmon server.
+
+use crate::json::json_string;
+use anyhow::{bail, Context, Result};
+use chrono::{SecondsFormat, Utc};
+use std::{
+    fmt::{self, Write as _},
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::Duration,
+};
+use tokio::{task, time};
+use tracing::{
+    error,
+    field::{Field, Visit},
+    info, span, Event, Subscriber,
+};
+use tracing_subscriber::{
+    field::RecordFields,
+    filter::{LevelFilter, Targets},
+    fmt::{format::Writer, FmtContext, FormatEvent, FormatFields, FormattedFields, MakeWriter},
+    registry::LookupSpan,
+    reload, Registry,
+};
+
+#[derive(Clone, Debug)]
+/// LogFilter changes the filter of the server logs at runtime.
+pub struct LogFilter {
+    /// Handle to replace the filter layer.
+    handle: reload::Handle<Targets, Registry>,
+
+    /// Increased on every change, to not revert filters which got replaced in the meantime.
+    generation: Arc<AtomicU64>,
+}
+
+impl LogFilter {
+    /// Create a new filter layer for the provided level, together with its handle.
+    pub fn new(level: LevelFilter) -> (reload::Layer<Targets, Registry>, Self) {
+        let (layer, handle) = reload::Layer::new(Targets::new().with_default(level));
+        (
+            layer,
+            Self {
+                handle,
+                generation: Default::default(),
+            },
+        )
+    }
+
+    /// Replace the filter by the provided level or comma separated directives like
+    /// `conmon::attach=trace,info`. Only `level` and `target=level` directives are supported,
+    /// but no span or field directives. The previous filter gets restored after the timeout, if
+    /// any.
+    pub fn set(&self, directives: &str, timeout: Option<Duration>) -> Result<()> {
+        // The targets would silently never match span directives.
+        if directives.contains('[') {
+            bail!(
+                "span and field directives are not supported: {:?}",
+                directives
+            )
+        }
+        let targets = Targets::from_str(directives)
+            .with_context(|| format!("parse log filter {:?}", directives))?;
+        let previous = self
+            .handle
+            .clone_current()
+            .context("current log filter got dropped")?;
+        self.handle.reload(targets).context("reload log filter")?;
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        info!("Set log filter to: {}", directives);
+
+        if let Some(timeout) = timeout {
+            let this = self.clone();
+            task::spawn(async move {
+                time::sleep(timeout).await;
+                if this.generation.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+                match this.handle.reload(previous) {
+                    Ok(()) => info!("Reverted log filter after {:?}", timeout),
+                    Err(e) => error!("Unable to revert log filter: {}", e),
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// JsonFormat writes every event as single line JSON object, including the fields of all spans
+/// the event is part of.
+pub struct JsonFormat;
+
+impl<S> FormatEvent<S, JsonFields> for JsonFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, JsonFields>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        write!(
+            writer,
+            "{{\"timestamp\":{},\"level\":{},\"target\":{}",
+            json_string(&Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true)),
+            json_string(metadata.level().as_str()),
+            json_string(metadata.target()),
+        )?;
+        if let Some(line) = metadata.line() {
+            write!(writer, ",\"line\":{}", line)?;
+        }
+
+        let mut visitor = JsonVisitor::default();
+        event.record(&mut visitor);
+        write!(writer, ",\"fields\":{{{}}}", visitor.0)?;
+
+        if let Some(scope) = ctx.event_scope() {
+            let spans = scope
+                .from_root()
+                .map(|span| {
+                    let extensions = span.extensions();
+                    let fields = extensions
+                        .get::<FormattedFields<JsonFields>>()
+                        .map(|x| x.fields.as_str())
+                        .unwrap_or_default();
+                    let mut res = format!("{{\"name\":{}", json_string(span.name()));
+                    if !fields.is_empty() {
+                        res.push(',');
+                        res.push_str(fields);
+                    }
+                    res.push('}');
+                    res
+                })
+                .collect::<Vec<_>>();
+            if let Some(span) = spans.last() {
+                write!(writer, ",\"span\":{}", span)?;
+            }
+            write!(writer, ",\"spans\":[{}]", spans.join(","))?;
+        }
+
+        writeln!(writer, "}}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// JsonFields formats the span fields as comma separated JSON members.
+pub struct JsonFields;
+
+impl<'writer> FormatFields<'writer> for JsonFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        mut writer: Writer<'writer>,
+        fields: R,
+    ) -> fmt::Result {
+        let mut visitor = JsonVisitor::default();
+        fields.record(&mut visitor);
+        writer.write_str(&visitor.0)
+    }
+
+    fn add_fields(
+        &self,
+        current: &'writer mut FormattedFields<Self>,
+        fields: &span::Record<'_>,
+    ) -> fmt::Result {
+        let mut visitor = JsonVisitor::default();
+        fields.record(&mut visitor);
+        if !current.fields.is_empty() && !visitor.0.is_empty() {
+            current.fields.push(',');
+        }
+        current.fields.push_str(&visitor.0);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+/// JsonVisitor collects the recorded fields as comma separated JSON members.
+struct JsonVisitor(String);
+
+impl JsonVisitor {
+    fn push(&mut self, field: &Field, value: &str) {
+        if !self.0.is_empty() {
+            self.0.push(',');
+        }
+        write!(self.0, "{}:{}", json_string(field.name()), value).ok();
+    }
+}
+
+impl Visit for JsonVisitor {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.push(field, &value.to_string())
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.push(field, &value.to_string())
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.push(field, &value.to_string())
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, &json_string(value))
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.push(field, &json_string(&format!("{:?}", value)))
+    }
+}
+
+#[derive(Debug)]
+/// RotatingFile is a log file which gets rotated as soon as it would exceed its maximum size.
+/// The rotated files get a numeric suffix, where `.1` is the most recent one.
+pub struct RotatingFile(Mutex<RotatingFileState>);
+
+#[derive(Debug)]
+/// RotatingFileState is the log file together with its rotation settings.
+struct RotatingFileState {
+    /// Path of the current log file.
+    path: PathBuf,
+
+    /// Size in bytes after which the file gets rotated, where zero disables the rotation.
+    max_size: u64,
+
+    /// Number of rotated files to keep.
+    max_backups: u32,
+
+    /// The currently opened log file.
+    file: File,
+
+    /// The current size of the log file.
+    size: u64,
+}
+
+impl RotatingFile {
+    /// Open the log file for appending, including creating its parent directories.
+    pub fn new(path: &Path, max_size: u64, max_backups: u32) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("create log directory {}", parent.display()))?;
+        }
+        let file = Self::open(path).with_context(|| format!("open log file {}", path.display()))?;
+        let size = file.metadata().context("get log file metadata")?.len();
+        Ok(Self(Mutex::new(RotatingFileState {
+            path: path.into(),
+            max_size,
+            max_backups,
+            file,
+            size,
+        })))
+    }
+
+    fn open(path: &Path) -> io::Result<File> {
+        OpenOptions::new().append(true).create(true).open(path)
+    }
+}
+
+impl<'a> MakeWriter<'a> for RotatingFile {
+    type Writer = RotatingFileWriter<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        // Logging must not panic, even if another writer did.
+        RotatingFileWriter(self.0.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+/// RotatingFileWriter holds the lock of the log file while writing a single event.
+pub struct RotatingFileWriter<'a>(MutexGuard<'a, RotatingFileState>);
+
+impl RotatingFileState {
+    fn rotate(&mut self) -> io::Result<()> {
+        for i in (1..self.max_backups).rev() {
+            match fs::rename(self.backup(i), self.backup(i + 1)) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+        }
+        if self.max_backups > 0 {
+            fs::rename(&self.path, self.backup(1))?;
+        } else {
+            fs::remove_file(&self.path)?;
+        }
+        self.file = RotatingFile::open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+
+    fn backup(&self, index: u32) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{}", index));
+        path.into()
+    }
+}
+
+impl Write for RotatingFileWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let state = &mut self.0;
+        if state.max_size > 0 && state.size > 0 && state.size + buf.len() as u64 > state.max_size {
+            state.rotate()?;
+        }
+        let written = state.file.write(buf)?;
+        state.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use tracing::{debug, debug_span, trace};
+    use tracing_subscriber::prelude::*;
+
+    #[test]
+    fn json_format() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("conmon.log");
+        let layer = tracing_subscriber::fmt::layer()
+            .fmt_fields(JsonFields)
+            .event_format(JsonFormat)
+            .with_writer(RotatingFile::new(&path, 0, 0)?);
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = debug_span!("request", uuid = "req-1");
+            let _enter = span.enter();
+            let span = debug_span!("exec", container_id = "ctr", pid = 42);
+            let _enter = span.enter();
+            debug!(exit_code = 3, "Exited \"ok\"");
+        });
+
+        let log = fs::read_to_string(&path)?;
+        assert!(log.starts_with("{\"timestamp\":\""));
+        assert!(log.contains("\"level\":\"DEBUG\",\"target\":\"conmon::logging::tests\""));
+        assert!(log.ends_with(concat!(
+            ",\"fields\":{\"message\":\"Exited \\\"ok\\\"\",\"exit_code\":3},",
+            "\"span\":{\"name\":\"exec\",\"container_id\":\"ctr\",\"pid\":42},",
+            "\"spans\":[{\"name\":\"request\",\"uuid\":\"req-1\"},",
+            "{\"name\":\"exec\",\"container_id\":\"ctr\",\"pid\":42}]}\n"
+        )));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn log_filter() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("conmon.log");
+        let (filter, sut) = LogFilter::new(LevelFilter::INFO);
+        let layer = tracing_subscriber::fmt::layer()
+            .fmt_fields(JsonFields)
+            .event_format(JsonFormat)
+            .with_writer(RotatingFile::new(&path, 0, 0)?);
+        let subscriber = tracing_subscriber::registry().with(filter).with(layer);
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        debug!("first");
+        sut.set(
+            "conmon::logging=debug,warn",
+            Some(Duration::from_millis(100)),
+        )?;
+        debug!("second");
+        trace!("third");
+        assert!(sut.set("conmon=loud", None).is_err());
+        assert!(sut.set("conmon[rpc]=debug", None).is_err());
+        time::sleep(Duration::from_millis(200)).await;
+        debug!("fourth");
+
+        let log = fs::read_to_string(&path)?;
+        assert!(!log.contains("first"));
+        assert!(log.contains("second"));
+        assert!(!log.contains("third"));
+        assert!(!log.contains("fourth"));
+        assert!(log.contains("Reverted log filter"));
+        Ok(())
+    }
+
+    #[test]
+    fn rotating_file() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("conmon.log");
+        let sut = RotatingFile::new(&path, 10, 2)?;
+
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            sut.make_writer().write_all(line.as_bytes())?;
+        }
+
+        assert_eq!(fs::read_to_string(&path)?, "fourth\n");
+        assert_eq!(
+            fs::read_to_string(dir.path().join("conmon.log.1"))?,
+            "third\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("conmon.log.2"))?,
+            "second\n"
+        );
+        assert!(!dir.path().join("conmon.log.3").exists());
+        Ok(())
+    }
+}
@@ -0,0 +1,371 @@
+//! Creation and removal of pinned pod namespaces.
+
+use crate::orphan_reaper::OrphanReaper;
+use anyhow::{bail, Context, Result};
+use nix::{
+    errno::Errno,
+    mount::{mount, umount2, MntFlags, MsFlags},
+    sched::{self, setns, CloneFlags},
+    sys::wait::{waitpid, WaitStatus},
+    unistd::{self, Pid},
+};
+use std::{
+    fs::{self, File},
+    os::unix::io::{AsRawFd, RawFd},
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread,
+};
+use strum::{AsRefStr, EnumIter, IntoEnumIterator};
+use tokio::sync::oneshot;
+use tracing::{debug, error};
+
+/// The stack size of the helper child, which only waits for the parent.
+const HELPER_STACK_SIZE: usize = 64 * 1024;
+
+#[derive(AsRefStr, Clone, Copy, Debug, EnumIter, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+/// Namespaces which can be pinned for a pod.
+pub enum Namespace {
+    /// IPC namespace.
+    Ipc,
+
+    /// Network namespace.
+    Net,
+
+    /// UTS namespace.
+    Uts,
+
+    /// User namespace.
+    User,
+
+    /// Cgroup namespace.
+    Cgroup,
+}
+
+impl Namespace {
+    /// The flag to create the namespace.
+    fn clone_flag(self) -> CloneFlags {
+        match self {
+            Self::Ipc => CloneFlags::CLONE_NEWIPC,
+            Self::Net => CloneFlags::CLONE_NEWNET,
+            Self::Uts => CloneFlags::CLONE_NEWUTS,
+            Self::User => CloneFlags::CLONE_NEWUSER,
+            Self::Cgroup => CloneFlags::CLONE_NEWCGROUP,
+        }
+    }
+
+    /// The path the namespace of the pod gets bind mounted to.
+    pub fn path(self, base_path: &Path, pod_id: &str) -> PathBuf {
+        base_path.join(format!("{}ns", self.as_ref())).join(pod_id)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+/// The configuration of the namespaces of a pod.
+pub struct NamespacesConfig {
+    /// The directory containing the bind mounts.
+    pub base_path: PathBuf,
+
+    /// The pod identifier, which is used as file name of the bind mounts.
+    pub pod_id: String,
+
+    /// The namespaces to be created.
+    pub namespaces: Vec<Namespace>,
+
+    /// The uid mappings of the user namespace.
+    pub uid_mappings: Vec<String>,
+
+    /// The gid mappings of the user namespace.
+    pub gid_mappings: Vec<String>,
+
+    /// Sysctls to be applied within the namespaces as key and value.
+    pub sysctls: Vec<(String, String)>,
+}
+
+/// Create the configured namespaces and bind mount them. Returns the namespaces with their
+/// paths. The orphan reaper gets paused while the helper process is alive.
+pub async fn create(
+    config: NamespacesConfig,
+    orphans: Arc<OrphanReaper>,
+) -> Result<Vec<(Namespace, PathBuf)>> {
+    // Applying the sysctls enters the namespaces, which requires a dedicated thread.
+    let (tx, rx) = oneshot::channel();
+    thread::Builder::new()
+        .name("namespaces".into())
+        .spawn(move || tx.send(create_blocking(&config, &orphans)).ok())
+        .context("spawn namespaces thread")?;
+    rx.await.context("wait for namespaces")?
+}
+
+fn create_blocking(
+    config: &NamespacesConfig,
+    orphans: &OrphanReaper,
+) -> Result<Vec<(Namespace, PathBuf)>> {
+    validate_pod_id(&config.pod_id)?;
+    if config.namespaces.is_empty() {
+        bail!("no namespaces requested")
+    }
+    let flags = config
+        .namespaces
+        .iter()
+        .fold(CloneFlags::empty(), |flags, ns| flags | ns.clone_flag());
+
+    // The helper gets waited for on drop, so it must not be reaped as orphan. The pause ends
+    // after the helper got dropped.
+    let _pause = orphans.pause();
+    let helper = Helper::spawn(flags)?;
+    if config.namespaces.contains(&Namespace::User) {
+        helper.write_mappings("uid_map", &config.uid_mappings)?;
+        helper.write_mappings("gid_map", &config.gid_mappings)?;
+    }
+
+    let mut res: Vec<(Namespace, PathBuf)> = vec![];
+    for ns in &config.namespaces {
+        let path = ns.path(&config.base_path, &config.pod_id);
+        if let Err(e) = bind_mount(&helper.ns_path(*ns), &path) {
+            for (_, path) in &res {
+                unmount(path).ok();
+            }
+            return Err(e.context(format!("pin {} namespace", ns.as_ref())));
+        }
+        res.push((*ns, path));
+    }
+
+    if let Err(e) = apply_sysctls(&helper, config) {
+        for (_, path) in &res {
+            unmount(path).ok();
+        }
+        return Err(e);
+    }
+    Ok(res)
+}
+
+/// Remove all pinned namespaces of the pod. All namespaces are tried to be removed, even if
+/// removing one of them fails, where the first error gets returned.
+pub async fn remove(base_path: PathBuf, pod_id: String) -> Result<()> {
+    validate_pod_id(&pod_id)?;
+    let mut res = Ok(());
+    for ns in Namespace::iter() {
+        let path = ns.path(&base_path, &pod_id);
+        if path.exists() {
+            debug!("Removing {} namespace {}", ns.as_ref(), path.display());
+            if let Err(e) = unmount(&path) {
+                error!("Unable to remove {} namespace: {:#}", ns.as_ref(), e);
+                res = res.and(Err(e));
+            }
+        }
+    }
+    res
+}
+
+/// The pod id becomes part of the namespace paths, so it must not escape the base path.
+fn validate_pod_id(pod_id: &str) -> Result<()> {
+    if pod_id.is_empty() || pod_id == "." || pod_id == ".." || pod_id.contains('/') {
+        bail!("invalid pod id {:?}", pod_id)
+    }
+    Ok(())
+}
+
+fn bind_mount(source: &Path, target: &Path) -> Result<()> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).context("create namespace directory")?;
+    }
+    File::create(target).context("create namespace file")?;
+    if let Err(e) = mount::<_, _, str, str>(Some(source), target, None, MsFlags::MS_BIND, None) {
+        fs::remove_file(target).ok();
+        return Err(e).with_context(|| format!("bind mount to {}", target.display()));
+    }
+    debug!("Pinned namespace {}", target.display());
+    Ok(())
+}
+
+fn unmount(path: &Path) -> Result<()> {
+    match umount2(path, MntFlags::MNT_DETACH) {
+        Ok(()) | Err(Errno::EINVAL) => {}
+        Err(e) => return Err(e).with_context(|| format!("unmount {}", path.display())),
+    }
+    fs::remove_file(path).with_context(|| format!("remove {}", path.display()))
+}
+
+/// Apply the sysctls by entering the namespaces of the helper, which taints the current thread.
+fn apply_sysctls(helper: &Helper, config: &NamespacesConfig) -> Result<()> {
+    if config.sysctls.is_empty() {
+        return Ok(());
+    }
+    for ns in &config.namespaces {
+        // The user and cgroup namespaces do not influence sysctls.
+        if matches!(ns, Namespace::Ipc | Namespace::Net | Namespace::Uts) {
+            let file = File::open(helper.ns_path(*ns)).context("open namespace")?;
+            setns(file.as_raw_fd(), ns.clone_flag())
+                .with_context(|| format!("enter {} namespace", ns.as_ref()))?;
+        }
+    }
+    for (key, value) in &config.sysctls {
+        if key.is_empty() || key.contains("..") || key.contains('/') {
+            bail!("invalid sysctl {:?}", key)
+        }
+        debug!("Setting sysctl {} to {}", key, value);
+        let path = Path::new("/proc/sys").join(key.replace('.', "/"));
+        fs::write(&path, value).with_context(|| format!("set sysctl {}", key))?;
+    }
+    Ok(())
+}
+
+/// Helper is a child process living in the new namespaces until it gets dropped.
+struct Helper {
+    pid: Pid,
+    write_fd: RawFd,
+}
+
+impl Helper {
+    fn spawn(flags: CloneFlags) -> Result<Self> {
+        let (read_fd, write_fd) = unistd::pipe().context("create helper pipe")?;
+        let mut stack = vec![0; HELPER_STACK_SIZE];
+
+        // The child is not allowed to allocate, because the parent is multi threaded. It waits
+        // until the parent closes the pipe.
+        let child = Box::new(move || {
+            let mut buf = [0u8; 1];
+            unsafe {
+                libc::close(write_fd);
+                libc::read(read_fd, buf.as_mut_ptr() as *mut libc::c_void, 1);
+            }
+            0
+        });
+        let res = sched::clone(child, &mut stack, flags, Some(libc::SIGCHLD));
+        unistd::close(read_fd).ok();
+        let pid = match res {
+            Ok(pid) => pid,
+            Err(e) => {
+                unistd::close(write_fd).ok();
+                return Err(e).context("clone namespace helper");
+            }
+        };
+        debug!("Spawned namespace helper {}", pid);
+        Ok(Self { pid, write_fd })
+    }
+
+    fn ns_path(&self, ns: Namespace) -> PathBuf {
+        PathBuf::from(format!("/proc/{}/ns/{}", self.pid, ns.as_ref()))
+    }
+
+    fn write_mappings(&self, file: &str, mappings: &[String]) -> Result<()> {
+        if mappings.is_empty() {
+            return Ok(());
+        }
+        let path = format!("/proc/{}/{}", self.pid, file);
+        fs::write(&path, mappings.join("\n")).with_context(|| format!("write {}", path))
+    }
+}
+
+impl Drop for Helper {
+    fn drop(&mut self) {
+        if let Err(e) = unistd::close(self.write_fd) {
+            error!("Unable to close namespace helper pipe: {}", e);
+        }
+        match waitpid(self.pid, None) {
+            Ok(WaitStatus::Exited(_, 0)) => debug!("Namespace helper {} exited", self.pid),
+            Ok(status) => error!("Namespace helper exited unexpectedly: {:?}", status),
+            Err(e) => error!("Unable to wait for namespace helper {}: {}", self.pid, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::MetadataExt;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn create_remove_success() -> Result<()> {
+        let dir = tempdir()?;
+        let config = NamespacesConfig {
+            base_path: dir.path().into(),
+            pod_id: "pod".into(),
+            namespaces: vec![Namespace::Ipc, Namespace::Net, Namespace::Uts],
+            sysctls: vec![("kernel.hostname".into(), "pinned".into())],
+            ..Default::default()
+        };
+
+        let res = create(config, Default::default()).await?;
+        assert_eq!(res.len(), 3);
+        for (ns, path) in &res {
+            assert_eq!(path, &dir.path().join(format!("{}ns/pod", ns.as_ref())));
+            let own = fs::metadata(format!("/proc/self/ns/{}", ns.as_ref()))?;
+            assert_ne!(fs::metadata(path)?.ino(), own.ino());
+        }
+        let hostname_path = "/proc/sys/kernel/hostname";
+        assert_ne!(fs::read_to_string(hostname_path)?.trim(), "pinned");
+        let uts = File::open(Namespace::Uts.path(dir.path(), "pod"))?;
+        let hostname = thread::spawn(move || -> Result<_> {
+            setns(uts.as_raw_fd(), CloneFlags::CLONE_NEWUTS)?;
+            Ok(fs::read_to_string(hostname_path)?)
+        })
+        .join()
+        .expect("join thread")?;
+        assert_eq!(hostname.trim(), "pinned");
+
+        remove(dir.path().into(), "pod".into()).await?;
+        for (_, path) in &res {
+            assert!(!path.exists());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_user_namespace() -> Result<()> {
+        let dir = tempdir()?;
+        let config = NamespacesConfig {
+            base_path: dir.path().into(),
+            pod_id: "pod".into(),
+            namespaces: vec![Namespace::User, Namespace::Net],
+            uid_mappings: vec!["0 1000 1".into()],
+            gid_mappings: vec!["0 1000 1".into()],
+            sysctls: vec![("net.ipv4.ip_forward".into(), "1".into())],
+        };
+
+        let res = create(config, Default::default()).await?;
+        assert_eq!(res.len(), 2);
+        assert!(Namespace::User.path(dir.path(), "pod").exists());
+
+        remove(dir.path().into(), "pod".into()).await?;
+        assert!(!Namespace::User.path(dir.path(), "pod").exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_failure() {
+        let config = NamespacesConfig {
+            pod_id: "../pod".into(),
+            namespaces: vec![Namespace::Net],
+            ..Default::default()
+        };
+        assert!(create(config, Default::default()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn remove_invalid_pod_id() -> Result<()> {
+        let dir = tempdir()?;
+        for pod_id in &["", ".", "..", "../pod"] {
+            assert!(remove(dir.path().into(), pod_id.to_string()).await.is_err());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn remove_continue_on_failure() -> Result<()> {
+        let dir = tempdir()?;
+        let ipc = Namespace::Ipc.path(dir.path(), "pod");
+        let net = Namespace::Net.path(dir.path(), "pod");
+        fs::create_dir_all(&ipc)?;
+        fs::create_dir_all(net.parent().context("no parent")?)?;
+        File::create(&net)?;
+
+        assert!(remove(dir.path().into(), "pod".into()).await.is_err());
+        assert!(ipc.exists());
+        assert!(!net.exists());
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+//! systemd service manager notification functionalities.
+
+use anyhow::{Context, Result};
+use getset::{CopyGetters, Getters};
+use nix::{
+    sys::socket::{sendto, socket, AddressFamily, MsgFlags, SockFlag, SockType, UnixAddr},
+    unistd::Pid,
+};
+use std::{
+    env,
+    os::unix::{
+        io::{AsRawFd, FromRawFd},
+        net::UnixDatagram,
+    },
+    process,
+    time::Duration,
+};
+use tracing::{debug, trace};
+
+#[derive(Clone, CopyGetters, Debug, Default, Getters)]
+/// Notifier sends state changes to the systemd service manager via `$NOTIFY_SOCKET`.
+///
+/// The server does not fork if the socket is set, because systemd only accepts notifications of
+/// the main process with the default `NotifyAccess=main`. Units therefore have to use
+/// `Type=notify`, optionally together with `WatchdogSec=`.
+pub struct Notifier {
+    #[getset(get = "pub")]
+    /// The notify socket address, where a leading `@` refers to the abstract namespace.
+    socket: Option<String>,
+
+    #[getset(get_copy = "pub")]
+    /// The interval in which watchdog keep-alive pings have to be sent.
+    watchdog_interval: Option<Duration>,
+}
+
+impl Notifier {
+    /// Create a new notifier for the provided socket and watchdog interval.
+    pub fn new(socket: Option<String>, watchdog_interval: Option<Duration>) -> Self {
+        Self {
+            socket,
+            watchdog_interval,
+        }
+    }
+
+    /// Create a new notifier from the environment variables set by systemd.
+    ///
+    /// This has to be called before forking, because the watchdog is only enabled if
+    /// `$WATCHDOG_PID` matches the current process. The related environment variables get unset
+    /// to not pass them down to child processes.
+    pub fn from_env() -> Self {
+        let socket = env::var("NOTIFY_SOCKET").ok().filter(|x| !x.is_empty());
+        let watchdog_pid = env::var("WATCHDOG_PID").ok();
+        let watchdog_usec = env::var("WATCHDOG_USEC").ok();
+        for key in ["NOTIFY_SOCKET", "WATCHDOG_PID", "WATCHDOG_USEC"] {
+            env::remove_var(key);
+        }
+
+        let watchdog_pid_matches = match watchdog_pid {
+            Some(pid) => pid.parse::<i32>().ok() == Some(Pid::this().as_raw()),
+            None => true,
+        };
+        let watchdog_interval = watchdog_usec
+            .and_then(|x| x.parse::<u64>().ok())
+            .filter(|x| *x > 0 && watchdog_pid_matches)
+            // Ping at half of the timeout, which is the recommended practice.
+            .map(|x| Duration::from_micros(x) / 2);
+
+        debug!(
+            "Using notify socket {:?} and watchdog interval {:?}",
+            socket, watchdog_interval
+        );
+        Self::new(socket, watchdog_interval)
+    }
+
+    /// Notify that the server is ready to accept connections.
+    pub fn ready(&self) -> Result<()> {
+        self.notify(&format!("READY=1\nMAINPID={}", process::id()))
+    }
+
+    /// Notify that the server is shutting down.
+    pub fn stopping(&self) -> Result<()> {
+        self.notify("STOPPING=1")
+    }
+
+    /// Send a watchdog keep-alive ping.
+    pub fn watchdog(&self) -> Result<()> {
+        self.notify("WATCHDOG=1")
+    }
+
+    fn notify(&self, state: &str) -> Result<()> {
+        let path = match self.socket() {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        let addr = match path.strip_prefix('@') {
+            Some(name) => UnixAddr::new_abstract(name.as_bytes()),
+            None => UnixAddr::new(path.as_str()),
+        }
+        .context("create notify socket address")?;
+
+        let fd = socket(
+            AddressFamily::Unix,
+            SockType::Datagram,
+            SockFlag::SOCK_CLOEXEC,
+            None,
+        )
+        .context("create notify socket")?;
+        // Take ownership to close the socket when going out of scope.
+        let sock = unsafe { UnixDatagram::from_raw_fd(fd) };
+
+        trace!("Sending {:?} to notify socket {}", state, path);
+        sendto(sock.as_raw_fd(), state.as_bytes(), &addr, MsgFlags::empty())
+            .context("send to notify socket")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn recv(sock: &UnixDatagram) -> Result<String> {
+        let mut buf = [0; 128];
+        let n = sock.recv(&mut buf)?;
+        Ok(String::from_utf8(buf[..n].to_vec())?)
+    }
+
+    #[test]
+    fn notify_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("notify.sock");
+        let sock = UnixDatagram::bind(&path)?;
+
+        let sut = Notifier::new(Some(path.display().to_string()), None);
+
+        sut.ready()?;
+        assert_eq!(recv(&sock)?, format!("READY=1\nMAINPID={}", process::id()));
+
+        sut.watchdog()?;
+        assert_eq!(recv(&sock)?, "WATCHDOG=1");
+
+        sut.stopping()?;
+        assert_eq!(recv(&sock)?, "STOPPING=1");
+        Ok(())
+    }
+
+    #[test]
+    fn notify_abstract_success() -> Result<()> {
+        let name = format!("conmon-test-{}", process::id());
+        let addr = UnixAddr::new_abstract(name.as_bytes())?;
+        let fd = socket(
+            AddressFamily::Unix,
+            SockType::Datagram,
+            SockFlag::SOCK_CLOEXEC,
+            None,
+        )?;
+        let sock = unsafe { UnixDatagram::from_raw_fd(fd) };
+        nix::sys::socket::bind(sock.as_raw_fd(), &addr)?;
+
+        let sut = Notifier::new(Some(format!("@{}", name)), None);
+        sut.ready()?;
+        assert!(recv(&sock)?.starts_with("READY=1"));
+        Ok(())
+    }
+
+    #[test]
+    fn notify_without_socket() -> Result<()> {
+        let sut = Notifier::default();
+        sut.ready()?;
+        sut.watchdog()?;
+        sut.stopping()
+    }
+
+    #[test]
+    fn from_env_success() {
+        env::set_var("NOTIFY_SOCKET", "/run/notify.sock");
+        env::set_var("WATCHDOG_PID", process::id().to_string());
+        env::set_var("WATCHDOG_USEC", "2000000");
+
+        let sut = Notifier::from_env();
+
+        assert_eq!(sut.socket().as_deref(), Some("/run/notify.sock"));
+        assert_eq!(sut.watchdog_interval(), Some(Duration::from_secs(1)));
+        for key in ["NOTIFY_SOCKET", "WATCHDOG_PID", "WATCHDOG_USEC"] {
+            assert!(env::var(key).is_err(), "{} should be unset", key);
+        }
+    }
+
+    #[test]
+    fn notify_failure() {
+        let sut = Notifier::new(Some("/path/does/not/exist".into()), None);
+        assert!(sut.ready().is_err());
+    }
+}
@@ -0,0 +1,286 @@
+//! Reaping of orphaned processes, which got reparented to conmonrs as child subreaper.
+
+use anyhow::{bail, Context, Result};
+use libc::pid_t;
+use nix::{
+    errno::Errno,
+    sys::wait::{waitid, waitpid, Id, WaitPidFlag, WaitStatus},
+    unistd::Pid,
+};
+use std::{
+    collections::HashMap,
+    fs,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::{
+    signal::unix::{signal, SignalKind},
+    sync::Notify,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info};
+
+#[derive(Debug, Default)]
+/// OrphanReaper reaps exited child processes on SIGCHLD, unless they get waited for elsewhere.
+pub struct OrphanReaper {
+    /// The processes which get waited for elsewhere, together with their number of
+    /// registrations.
+    known: Mutex<HashMap<u32, usize>>,
+
+    /// The number of active pauses, during which reaping gets deferred.
+    pauses: AtomicUsize,
+
+    /// Notified as soon as the last pause ended, to reap the orphans which exited meanwhile.
+    resumed: Notify,
+
+    /// The total number of reaped orphans.
+    reaped: AtomicU64,
+}
+
+impl OrphanReaper {
+    /// Defer reaping until the returned guard gets dropped. Has to be held while spawning
+    /// processes which get waited for elsewhere, until they are either registered or waited for.
+    /// Pausing never blocks, so concurrent pauses do not wait for each other.
+    pub fn pause(&self) -> PauseGuard<'_> {
+        self.pauses.fetch_add(1, Ordering::SeqCst);
+        PauseGuard(self)
+    }
+
+    fn paused(&self) -> bool {
+        self.pauses.load(Ordering::SeqCst) > 0
+    }
+
+    fn is_known(&self, pid: u32) -> Result<bool> {
+        match self.known.lock() {
+            Ok(known) => Ok(known.contains_key(&pid)),
+            Err(e) => bail!("check pid {}: {}", pid, e),
+        }
+    }
+
+    /// Register a process which gets waited for elsewhere.
+    pub fn register(&self, pid: u32) {
+        match self.known.lock() {
+            Ok(mut known) => *known.entry(pid).or_default() += 1,
+            Err(e) => error!("Unable to register pid {}: {}", pid, e),
+        }
+    }
+
+    /// Remove a registration of a process, for example after it was waited for.
+    pub fn unregister(&self, pid: u32) {
+        match self.known.lock() {
+            Ok(mut known) => {
+                if let Some(count) = known.get_mut(&pid) {
+                    *count -= 1;
+                    if *count == 0 {
+                        known.remove(&pid);
+                    }
+                }
+            }
+            Err(e) => error!("Unable to unregister pid {}: {}", pid, e),
+        }
+    }
+
+    /// The total number of reaped orphans.
+    pub fn reaped(&self) -> u64 {
+        self.reaped.load(Ordering::Relaxed)
+    }
+
+    /// Reap orphans on every SIGCHLD until the token gets cancelled.
+    pub async fn run(self: Arc<Self>, token: CancellationToken) -> Result<()> {
+        let mut sigchld = signal(SignalKind::child()).context("listen for SIGCHLD")?;
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => return Ok(()),
+                _ = sigchld.recv() => {}
+                _ = self.resumed.notified() => {}
+            }
+            if let Err(e) = self.reap_exited() {
+                error!("Unable to reap orphans: {:#}", e);
+            }
+        }
+    }
+
+    /// Reap all exited children which are not registered. The next exited child gets peeked at
+    /// without reaping it, which avoids scanning /proc as long as it is an orphan.
+    fn reap_exited(&self) -> Result<()> {
+        loop {
+            let flags = WaitPidFlag::WEXITED | WaitPidFlag::WNOHANG | WaitPidFlag::WNOWAIT;
+            let pid = match waitid(Id::All, flags) {
+                Ok(WaitStatus::Exited(pid, _)) | Ok(WaitStatus::Signaled(pid, _, _)) => {
+                    pid.as_raw() as u32
+                }
+                Ok(_) | Err(Errno::ECHILD) => return Ok(()),
+                Err(Errno::EINTR) => continue,
+                Err(e) => return Err(e).context("wait for exited children"),
+            };
+            // The child may belong to a process which is about to be registered. Reaping
+            // continues as soon as the pause ended.
+            if self.paused() {
+                return Ok(());
+            }
+            if self.is_known(pid)? {
+                // The registered child blocks peeking at the others until it gets waited for.
+                let zombies = zombie_children(std::process::id()).context("find orphans")?;
+                self.reap(zombies);
+                return Ok(());
+            }
+            self.reap(vec![(pid, comm(pid))]);
+        }
+    }
+
+    /// Reap the provided exited children, unless they are registered.
+    fn reap(&self, zombies: Vec<(u32, String)>) {
+        for (pid, comm) in zombies {
+            match self.is_known(pid) {
+                Ok(false) => {}
+                Ok(true) => continue,
+                Err(e) => {
+                    error!("Unable to reap orphans: {:#}", e);
+                    return;
+                }
+            }
+            match waitpid(Pid::from_raw(pid as pid_t), Some(WaitPidFlag::WNOHANG)) {
+                Ok(WaitStatus::Exited(_, code)) => {
+                    self.log_reaped(pid, &comm, format!("exit code {}", code))
+                }
+                Ok(WaitStatus::Signaled(_, sig, _)) => {
+                    self.log_reaped(pid, &comm, format!("signal {}", sig))
+                }
+                Ok(_) => {}
+                Err(e) => debug!("Unable to reap orphan {}: {}", pid, e),
+            }
+        }
+    }
+
+    fn log_reaped(&self, pid: u32, comm: &str, status: String) {
+        let total = self.reaped.fetch_add(1, Ordering::Relaxed) + 1;
+        info!(
+            "Reaped orphan {} ({}) with {}, {} orphans reaped in total",
+            pid, comm, status, total
+        );
+    }
+}
+
+/// A pause of the orphan reaper, which resumes reaping when dropped.
+pub struct PauseGuard<'a>(&'a OrphanReaper);
+
+impl Drop for PauseGuard<'_> {
+    fn drop(&mut self) {
+        if self.0.pauses.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.resumed.notify_one();
+        }
+    }
+}
+
+/// Retrieve the name of the process, which is empty if it is not available.
+fn comm(pid: u32) -> String {
+    fs::read_to_string(format!("/proc/{}/comm", pid))
+        .map(|comm| comm.trim_end().into())
+        .unwrap_or_default()
+}
+
+/// Retrieve the pids and names of all zombie children of the provided process.
+fn zombie_children(parent: u32) -> Result<Vec<(u32, String)>> {
+    let mut res = vec![];
+    for entry in fs::read_dir("/proc").context("read /proc")? {
+        let pid = match entry?
+            .file_name()
+            .to_str()
+            .and_then(|x| x.parse::<u32>().ok())
+        {
+            Some(pid) => pid,
+            None => continue,
+        };
+        // The process may be gone already.
+        let stat = match fs::read_to_string(format!("/proc/{}/stat", pid)) {
+            Ok(stat) => stat,
+            Err(_) => continue,
+        };
+        if let Some((comm, state, ppid)) = parse_stat(&stat) {
+            if state == "Z" && ppid == parent {
+                res.push((pid, comm.into()));
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// Parse the name, state and parent pid of `/proc/<pid>/stat`, where the name can contain any
+/// characters.
+fn parse_stat(stat: &str) -> Option<(&str, &str, u32)> {
+    let start = stat.find('(')?;
+    let end = stat.rfind(')')?;
+    let comm = stat.get(start + 1..end)?;
+    let mut fields = stat.get(end + 1..)?.split_whitespace();
+    let state = fields.next()?;
+    let ppid = fields.next()?.parse().ok()?;
+    Some((comm, state, ppid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::time;
+
+    #[test]
+    fn parse_stat_success() {
+        assert_eq!(parse_stat("42 (sh) S 1 42 42 0 -1"), Some(("sh", "S", 1)));
+        assert_eq!(
+            parse_stat("43 (a) b (c) Z 42 43 43 0 -1"),
+            Some(("a) b (c", "Z", 42))
+        );
+        assert_eq!(parse_stat("44 (sh"), None);
+    }
+
+    #[test]
+    fn reap_unknown() -> Result<()> {
+        let known = std::process::Command::new("true").spawn()?;
+        let unknown = std::process::Command::new("true").spawn()?;
+        let sut = OrphanReaper::default();
+        sut.register(known.id());
+
+        let pids = [known.id(), unknown.id()];
+
+        // Other tests spawn children as well, which must not be reaped.
+        let own_zombies = || -> Result<Vec<(u32, String)>> {
+            Ok(zombie_children(std::process::id())?
+                .into_iter()
+                .filter(|(pid, _)| pids.contains(pid))
+                .collect())
+        };
+        while own_zombies()?.len() < 2 {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        sut.reap(own_zombies()?);
+        assert_eq!(sut.reaped(), 1);
+
+        let zombies = own_zombies()?;
+        assert_eq!(zombies, vec![(known.id(), "true".into())]);
+        waitpid(Pid::from_raw(known.id() as pid_t), None)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pause_resume() -> Result<()> {
+        let sut = OrphanReaper::default();
+        let first = sut.pause();
+        let second = sut.pause();
+        assert!(sut.paused());
+
+        drop(first);
+        assert!(sut.paused());
+        drop(second);
+        assert!(!sut.paused());
+        time::timeout(Duration::from_secs(1), sut.resumed.notified()).await?;
+        Ok(())
+    }
+
+    #[test]
+    fn comm_success() {
+        assert!(!comm(std::process::id()).is_empty());
+        assert!(comm(0).is_empty());
+    }
+}
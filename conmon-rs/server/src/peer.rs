@@ -0,0 +1,142 @@
+//! Peer credential based client authentication.
+
+use anyhow::{bail, Context, Result};
+use getset::Getters;
+use std::{fs, path::PathBuf};
+use tokio::net::{unix::UCred, UnixStream};
+use tracing::debug;
+
+#[derive(Clone, Debug, Default, Getters)]
+/// PeerAuth verifies connecting clients by using their `SO_PEERCRED` credentials.
+///
+/// A client is allowed to connect if either its user or group ID is part of the allowlist and
+/// its executable matches one of the allowed paths. Empty allowlists do not restrict anything.
+pub struct PeerAuth {
+    #[getset(get = "pub")]
+    /// Allowed user IDs.
+    uids: Vec<u32>,
+
+    #[getset(get = "pub")]
+    /// Allowed group IDs.
+    gids: Vec<u32>,
+
+    #[getset(get = "pub")]
+    /// Allowed executable paths of the client processes.
+    executables: Vec<PathBuf>,
+}
+
+impl PeerAuth {
+    /// Create a new PeerAuth instance from the provided allowlists.
+    pub fn new(uids: Vec<u32>, gids: Vec<u32>, executables: Vec<PathBuf>) -> Self {
+        Self {
+            uids,
+            gids,
+            executables,
+        }
+    }
+
+    /// Returns true if any allowlist is configured.
+    pub fn is_enabled(&self) -> bool {
+        !self.uids().is_empty() || !self.gids().is_empty() || !self.executables().is_empty()
+    }
+
+    /// Verify that the peer of the provided stream is allowed to connect.
+    pub fn verify(&self, stream: &UnixStream) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        let cred = stream.peer_cred().context("get peer credentials")?;
+        self.verify_cred(&cred)
+    }
+
+    fn verify_cred(&self, cred: &UCred) -> Result<()> {
+        let (uid, gid, pid) = (cred.uid(), cred.gid(), cred.pid());
+
+        let ids_allowed = (self.uids().is_empty() && self.gids().is_empty())
+            || self.uids().contains(&uid)
+            || self.gids().contains(&gid);
+        if !ids_allowed {
+            bail!(
+                "peer with uid {} gid {} pid {:?} is not allowed",
+                uid,
+                gid,
+                pid
+            )
+        }
+
+        if !self.executables().is_empty() {
+            let pid = pid.context("peer has no pid")?;
+            let exe = fs::read_link(format!("/proc/{}/exe", pid))
+                .with_context(|| format!("read executable of peer with pid {}", pid))?;
+            if !self.executables().contains(&exe) {
+                bail!(
+                    "peer with uid {} gid {} pid {} executable {} is not allowed",
+                    uid,
+                    gid,
+                    pid,
+                    exe.display()
+                )
+            }
+        }
+
+        debug!(uid, gid, pid, "Peer is allowed to connect");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::unistd::{getgid, getuid};
+    use std::env;
+
+    fn own_ids() -> (u32, u32) {
+        (getuid().as_raw(), getgid().as_raw())
+    }
+
+    async fn verify(sut: &PeerAuth) -> Result<()> {
+        let (stream, _peer) = UnixStream::pair()?;
+        sut.verify(&stream)
+    }
+
+    #[tokio::test]
+    async fn verify_disabled() -> Result<()> {
+        let sut = PeerAuth::default();
+        assert!(!sut.is_enabled());
+        verify(&sut).await
+    }
+
+    #[tokio::test]
+    async fn verify_uid_success() -> Result<()> {
+        let (uid, _) = own_ids();
+        let sut = PeerAuth::new(vec![uid], vec![], vec![]);
+        assert!(sut.is_enabled());
+        verify(&sut).await
+    }
+
+    #[tokio::test]
+    async fn verify_gid_success() -> Result<()> {
+        let (uid, gid) = own_ids();
+        let sut = PeerAuth::new(vec![uid + 1], vec![gid], vec![]);
+        verify(&sut).await
+    }
+
+    #[tokio::test]
+    async fn verify_ids_failure() {
+        let (uid, gid) = own_ids();
+        let sut = PeerAuth::new(vec![uid + 1], vec![gid + 1], vec![]);
+        assert!(verify(&sut).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn verify_executable_success() -> Result<()> {
+        let sut = PeerAuth::new(vec![], vec![], vec![env::current_exe()?]);
+        verify(&sut).await
+    }
+
+    #[tokio::test]
+    async fn verify_executable_failure() {
+        let sut = PeerAuth::new(vec![], vec![], vec!["/bin/does-not-exist".into()]);
+        assert!(verify(&sut).await.is_err());
+    }
+}
@@ -0,0 +1,109 @@
+//! Process file descriptors, which refer to a process without being affected by pid reuse.
+
+use anyhow::{bail, Context, Result};
+use libc::pid_t;
+use nix::{
+    errno::Errno,
+    sys::{
+        signal::Signal,
+        wait::{waitpid, WaitPidFlag, WaitStatus},
+    },
+    unistd::Pid,
+};
+use std::{
+    io,
+    os::unix::io::{AsRawFd, FromRawFd, OwnedFd},
+    ptr,
+};
+use tokio::io::unix::AsyncFd;
+use tracing::debug;
+
+#[derive(Debug)]
+/// PidFd is a file descriptor of a child process, which becomes readable as soon as the process
+/// exited.
+pub struct PidFd {
+    pid: u32,
+    fd: AsyncFd<OwnedFd>,
+}
+
+impl PidFd {
+    /// Open a file descriptor for the provided process. Fails with `ENOSYS` on kernels older
+    /// than 5.3.
+    pub fn open(pid: u32) -> io::Result<Self> {
+        let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid as pid_t, 0) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(fd as i32) };
+        Ok(Self {
+            pid,
+            fd: AsyncFd::new(fd)?,
+        })
+    }
+
+    /// Send a signal to the process, or only check if it still exists if `signal` is `None`.
+    pub fn send_signal<T: Into<Option<Signal>>>(&self, signal: T) -> Result<()> {
+        let signal = signal.into().map_or(0, |s| s as i32);
+        let res = unsafe {
+            libc::syscall(
+                libc::SYS_pidfd_send_signal,
+                self.fd.as_raw_fd(),
+                signal,
+                ptr::null::<libc::siginfo_t>(),
+                0,
+            )
+        };
+        Errno::result(res)
+            .map(drop)
+            .with_context(|| format!("send signal to pidfd of {}", self.pid))
+    }
+
+    /// Wait for the process to exit and reap it. Returns the exit code, which is 128 plus the
+    /// signal number if the process got killed.
+    pub async fn wait(&self) -> Result<i32> {
+        let pid = Pid::from_raw(self.pid as pid_t);
+        loop {
+            let mut guard = self.fd.readable().await.context("wait for pidfd")?;
+            match waitpid(pid, Some(WaitPidFlag::WNOHANG)) {
+                Ok(WaitStatus::Exited(_, exit_code)) => {
+                    debug!(pid = self.pid, "Exited {}", exit_code);
+                    return Ok(exit_code);
+                }
+                Ok(WaitStatus::Signaled(_, sig, _)) => {
+                    debug!(pid = self.pid, "Signaled");
+                    return Ok((sig as i32) + 128);
+                }
+                Ok(_) => guard.clear_ready(),
+                Err(Errno::EINTR) => continue,
+                Err(e) => bail!("waitpid on {}: {}", self.pid, e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[tokio::test]
+    async fn wait_exit_code() -> Result<()> {
+        let child = Command::new("sh")
+            .args(["-c", "sleep 0.1; exit 3"])
+            .spawn()?;
+        let sut = PidFd::open(child.id())?;
+        assert_eq!(sut.wait().await?, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_signal() -> Result<()> {
+        let child = Command::new("sleep").arg("10").spawn()?;
+        let sut = PidFd::open(child.id())?;
+        sut.send_signal(None)?;
+        sut.send_signal(Signal::SIGKILL)?;
+        assert_eq!(sut.wait().await?, 128 + Signal::SIGKILL as i32);
+        assert!(sut.send_signal(None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+//! Port forwarding into the network namespace of a container.
+
+use anyhow::{Context, Result};
+use capnp::{capability::Promise, Error};
+use capnp_rpc::pry;
+use conmon_common::conmon_capnp::conmon::stream_sink;
+use nix::sched::{setns, CloneFlags};
+use std::{
+    fs::File,
+    net::{self, Ipv4Addr, Ipv6Addr, SocketAddr},
+    os::unix::io::AsRawFd,
+    sync::Arc,
+    thread,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+        TcpStream,
+    },
+    sync::{oneshot, Mutex},
+};
+use tracing::{debug, error};
+
+/// The maximum amount of data forwarded to the client at once.
+const BUF_SIZE: usize = 8192;
+
+/// Connect to the port on localhost within the network namespace of the process.
+pub async fn connect(pid: u32, port: u16) -> Result<TcpStream> {
+    let (tx, rx) = oneshot::channel();
+    thread::Builder::new()
+        .name("port_forward".into())
+        .spawn(move || tx.send(connect_in_netns(pid, port)).ok())
+        .context("spawn port forward thread")?;
+
+    let stream = rx.await.context("wait for port forward connection")??;
+    stream.set_nonblocking(true)?;
+    TcpStream::from_std(stream).context("use port forward connection")
+}
+
+/// Enter the network namespace of the process and connect to the port. This has to run on a
+/// dedicated thread, because the thread stays in the namespace afterwards, whereas the
+/// connection keeps working after the thread exited.
+fn connect_in_netns(pid: u32, port: u16) -> Result<net::TcpStream> {
+    let path = format!("/proc/{}/ns/net", pid);
+    let ns = File::open(&path).with_context(|| format!("open network namespace {}", path))?;
+    setns(ns.as_raw_fd(), CloneFlags::CLONE_NEWNET).context("enter network namespace")?;
+
+    debug!("Connecting to port {} of pid {}", port, pid);
+    let addrs = [
+        SocketAddr::from((Ipv4Addr::LOCALHOST, port)),
+        SocketAddr::from((Ipv6Addr::LOCALHOST, port)),
+    ];
+    net::TcpStream::connect(&addrs[..]).with_context(|| format!("connect to localhost:{}", port))
+}
+
+/// The sink of a port forward client, which writes to the connection.
+pub struct PortForwardSink {
+    writer: Arc<Mutex<Option<OwnedWriteHalf>>>,
+}
+
+impl PortForwardSink {
+    /// Create a new sink for the write half of a port forward connection.
+    pub fn new(writer: OwnedWriteHalf) -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(Some(writer))),
+        }
+    }
+}
+
+impl stream_sink::Server for PortForwardSink {
+    /// Forward data to the port.
+    fn write(
+        &mut self,
+        params: stream_sink::WriteParams,
+        _: stream_sink::WriteResults,
+    ) -> Promise<(), Error> {
+        let params = pry!(params.get());
+        if pry!(params.get_pipe()) != stream_sink::Pipe::Stdin {
+            return Promise::err(Error::failed("only stdin can be written".into()));
+        }
+        let data = pry!(params.get_data()).to_vec();
+
+        let writer = self.writer.clone();
+        Promise::from_future(async move {
+            match writer.lock().await.as_mut() {
+                Some(writer) => writer
+                    .write_all(&data)
+                    .await
+                    .map_err(|e| Error::failed(format!("write to port: {}", e))),
+                None => Err(Error::failed("port forward input is closed".into())),
+            }
+        })
+    }
+
+    /// Close the write half of the connection.
+    fn close(
+        &mut self,
+        _: stream_sink::CloseParams,
+        _: stream_sink::CloseResults,
+    ) -> Promise<(), Error> {
+        let writer = self.writer.clone();
+        Promise::from_future(async move {
+            if let Some(mut writer) = writer.lock().await.take() {
+                writer
+                    .shutdown()
+                    .await
+                    .map_err(|e| Error::failed(format!("shutdown port forward: {}", e)))?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Forward the data received from the port to the client sink until either side closes.
+pub async fn forward_output(mut reader: OwnedReadHalf, sink: stream_sink::Client) {
+    let mut buf = vec![0; BUF_SIZE];
+    loop {
+        let n = match reader.read(&mut buf).await {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) => {
+                error!("Unable to read from port: {}", e);
+                break;
+            }
+        };
+        let mut request = sink.write_request();
+        let mut params = request.get();
+        params.set_pipe(stream_sink::Pipe::Stdout);
+        params.set_data(&buf[..n]);
+        if let Err(e) = request.send().promise.await {
+            debug!("Unable to write to port forward client: {}", e);
+            return;
+        }
+    }
+    debug!("Closing port forward client output");
+    if let Err(e) = sink.close_request().send().promise.await {
+        debug!("Unable to close port forward client output: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader},
+        process::{Command, Stdio},
+    };
+    use tokio::net::TcpListener;
+
+    /// Bind a listener on localhost within the network namespace of the process.
+    fn bind_in_netns(pid: u32) -> Result<net::TcpListener> {
+        thread::spawn(move || -> Result<_> {
+            let ns = File::open(format!("/proc/{}/ns/net", pid))?;
+            setns(ns.as_raw_fd(), CloneFlags::CLONE_NEWNET)?;
+            Ok(net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?)
+        })
+        .join()
+        .expect("join thread")
+    }
+
+    #[tokio::test]
+    async fn connect_success() -> Result<()> {
+        // The child lives in its own network namespace with the loopback device up.
+        let mut child = Command::new("unshare")
+            .args(["--net", "sh", "-c", "ip link set lo up && echo ready && sleep 10"])
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().context("no stdout")?).read_line(&mut line)?;
+        assert_eq!(line, "ready\n");
+
+        let listener = bind_in_netns(child.id())?;
+        listener.set_nonblocking(true)?;
+        let listener = TcpListener::from_std(listener)?;
+        let port = listener.local_addr()?.port();
+
+        assert!(TcpStream::connect((Ipv4Addr::LOCALHOST, port)).await.is_err());
+
+        let mut sut = connect(child.id(), port).await?;
+        let (mut server, _) = listener.accept().await?;
+
+        sut.write_all(b"ping").await?;
+        let mut buf = [0; 4];
+        server.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"ping");
+
+        child.kill()?;
+        child.wait()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn connect_failure() {
+        assert!(connect(u32::MAX, 1).await.is_err());
+    }
+}
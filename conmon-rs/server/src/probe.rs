@@ -0,0 +1,561 @@
+//! Periodic health probes of containers, which run within conmonrs.
+
+use crate::{
+    child_reaper::{ChildReaper, ExecSyncOutput},
+    container_io::ContainerIO,
+    container_log::ContainerLog,
+    port_forward,
+    server::Server,
+};
+use anyhow::{bail, format_err, Context, Result};
+use getset::Getters;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use strum::AsRefStr;
+use tokio::{
+    task,
+    time::{self, Instant, MissedTickBehavior},
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, error, info, Instrument};
+
+/// The maximum size of the probe output kept as status message.
+const MAX_MESSAGE_SIZE: usize = 1024;
+
+/// The period used if none is configured.
+const DEFAULT_PERIOD: Duration = Duration::from_secs(10);
+
+/// The timeout used if none is configured.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// The way a probe checks the container.
+pub enum ProbeHandler {
+    /// Execute the command within the container, which succeeds on exit code zero.
+    Exec(Vec<String>),
+
+    /// Connect to the TCP port within the network namespace of the container.
+    Tcp(u16),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// The configuration of a single probe.
+pub struct ProbeConfig {
+    /// The name of the probe, which is unique per container.
+    pub name: String,
+
+    /// The way the probe checks the container.
+    pub handler: ProbeHandler,
+
+    /// The delay before the first run.
+    pub initial_delay: Duration,
+
+    /// The time between two runs.
+    pub period: Duration,
+
+    /// The time after which a single run fails.
+    pub timeout: Duration,
+
+    /// The number of consecutive successful runs until the probe succeeds.
+    pub success_threshold: u32,
+
+    /// The number of consecutive failed runs until the probe fails.
+    pub failure_threshold: u32,
+}
+
+impl ProbeConfig {
+    /// Validate the probe configuration, where zero values get replaced by their defaults.
+    pub fn validate(mut self) -> Result<Self> {
+        if self.name.is_empty() {
+            bail!("probe name is empty")
+        }
+        match &self.handler {
+            ProbeHandler::Exec(command) if command.is_empty() => bail!("probe command is empty"),
+            ProbeHandler::Tcp(0) => bail!("probe port is zero"),
+            _ => {}
+        }
+        if self.period.is_zero() {
+            self.period = DEFAULT_PERIOD;
+        }
+        if self.timeout.is_zero() {
+            self.timeout = DEFAULT_TIMEOUT;
+        }
+        self.success_threshold = self.success_threshold.max(1);
+        self.failure_threshold = self.failure_threshold.max(1);
+        Ok(self)
+    }
+}
+
+#[derive(AsRefStr, Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+/// The result of a probe after applying the thresholds.
+pub enum ProbeResult {
+    #[default]
+    /// The probe did not reach any threshold yet.
+    Unknown,
+
+    /// The probe reached the success threshold.
+    Success,
+
+    /// The probe reached the failure threshold.
+    Failure,
+}
+
+#[derive(Clone, Debug, Default, Eq, Getters, PartialEq)]
+/// The current state of a probe.
+pub struct ProbeStatus {
+    #[getset(get = "pub")]
+    /// The result after applying the thresholds.
+    result: ProbeResult,
+
+    #[getset(get = "pub")]
+    /// The output or error of the last failed run.
+    message: String,
+
+    successes: u32,
+    failures: u32,
+}
+
+impl ProbeStatus {
+    /// Record the outcome of a single run. Returns true if the result changed.
+    fn update(&mut self, config: &ProbeConfig, outcome: Result<(), String>) -> bool {
+        let result = match outcome {
+            Ok(()) => {
+                self.successes = self.successes.saturating_add(1);
+                self.failures = 0;
+                self.message.clear();
+                if self.successes >= config.success_threshold {
+                    ProbeResult::Success
+                } else {
+                    self.result
+                }
+            }
+            Err(message) => {
+                self.failures = self.failures.saturating_add(1);
+                self.successes = 0;
+                self.message = message;
+                if self.failures >= config.failure_threshold {
+                    ProbeResult::Failure
+                } else {
+                    self.result
+                }
+            }
+        };
+        let changed = result != self.result;
+        self.result = result;
+        changed
+    }
+}
+
+#[derive(Debug)]
+struct Probe {
+    status: ProbeStatus,
+    token: CancellationToken,
+    config: ProbeConfig,
+    runner: ProbeRunner,
+}
+
+#[derive(Clone, Debug, Default)]
+/// The probes of a container, which live as long as the container runs and get carried over to
+/// the new process if it gets restarted.
+pub struct SharedProbes(Arc<Mutex<BTreeMap<String, Probe>>>);
+
+macro_rules! lock {
+    ($x:expr) => {
+        $x.lock().map_err(|e| format_err!("{:#}", e))?
+    };
+}
+
+impl SharedProbes {
+    /// Register the probe and start running it until the container token gets cancelled. An
+    /// existing probe of the same name gets replaced.
+    pub fn register(
+        &self,
+        config: ProbeConfig,
+        runner: ProbeRunner,
+        container_token: &CancellationToken,
+    ) -> Result<()> {
+        let token = container_token.child_token();
+        let probe = Probe {
+            status: Default::default(),
+            token: token.clone(),
+            config: config.clone(),
+            runner: runner.clone(),
+        };
+        if let Some(old) = lock!(self.0).insert(config.name.clone(), probe) {
+            debug!("Replacing probe {}", config.name);
+            old.token.cancel();
+        }
+
+        let span = debug_span!("probe", name = config.name.as_str());
+        task::spawn_local(self.clone().run(config, runner, token).instrument(span));
+        Ok(())
+    }
+
+    /// Register all probes again for the new process of a restarted container, which resets
+    /// their status.
+    pub fn restart(&self, pid: u32, container_token: &CancellationToken) -> Result<()> {
+        let probes: Vec<_> = lock!(self.0)
+            .values()
+            .map(|probe| (probe.config.clone(), probe.runner.clone()))
+            .collect();
+        for (config, mut runner) in probes {
+            runner.pid = pid;
+            self.register(config, runner, container_token)?;
+        }
+        Ok(())
+    }
+
+    /// Retrieve a snapshot of all probes together with their names.
+    pub fn list(&self) -> Result<Vec<(String, ProbeStatus)>> {
+        Ok(lock!(self.0)
+            .iter()
+            .map(|(name, probe)| (name.clone(), probe.status.clone()))
+            .collect())
+    }
+
+    async fn run(self, config: ProbeConfig, runner: ProbeRunner, token: CancellationToken) {
+        tokio::select! {
+            _ = token.cancelled() => return,
+            _ = time::sleep(config.initial_delay) => {}
+        }
+
+        let mut interval = time::interval(config.period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            let outcome = tokio::select! {
+                _ = token.cancelled() => break,
+                outcome = async {
+                    interval.tick().await;
+                    runner.run(&config).await
+                } => outcome,
+            };
+            if let Err(e) = self.update(&config, &token, outcome) {
+                error!("Unable to update probe status: {:#}", e);
+            }
+        }
+        debug!("Stopped probe");
+    }
+
+    fn update(
+        &self,
+        config: &ProbeConfig,
+        token: &CancellationToken,
+        outcome: Result<(), String>,
+    ) -> Result<()> {
+        let mut probes = lock!(self.0);
+        // Replaced probes get cancelled while holding the lock.
+        if token.is_cancelled() {
+            return Ok(());
+        }
+        let probe = probes
+            .get_mut(&config.name)
+            .context("probe not available")?;
+        if let Err(message) = &outcome {
+            debug!("Probe run failed: {}", message);
+        }
+        if probe.status.update(config, outcome) {
+            info!(
+                "Probe {} changed to {}",
+                config.name,
+                probe.status.result.as_ref()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Everything required to run probes against a container.
+pub struct ProbeRunner {
+    id: String,
+    pid: u32,
+    runtime: PathBuf,
+    runtime_root: Option<PathBuf>,
+    runtime_dir: PathBuf,
+    reaper: Arc<ChildReaper>,
+}
+
+impl ProbeRunner {
+    /// Create a new probe runner for the container.
+    pub fn new(
+        id: String,
+        pid: u32,
+        runtime: PathBuf,
+        runtime_root: Option<PathBuf>,
+        runtime_dir: PathBuf,
+        reaper: Arc<ChildReaper>,
+    ) -> Self {
+        Self {
+            id,
+            pid,
+            runtime,
+            runtime_root,
+            runtime_dir,
+            reaper,
+        }
+    }
+
+    /// Run the probe once, where a failure contains the reason as message.
+    async fn run(&self, config: &ProbeConfig) -> Result<(), String> {
+        match &config.handler {
+            ProbeHandler::Exec(command) => self.exec(command, config.timeout).await,
+            ProbeHandler::Tcp(port) => self.tcp(*port, config.timeout).await,
+        }
+    }
+
+    async fn exec(&self, command: &[String], timeout: Duration) -> Result<(), String> {
+        let pidfile = ContainerIO::temp_file_name(Some(&self.runtime_dir), "probe", "pid")
+            .map_err(|e| format!("{:#}", e))?;
+        let res = self.exec_with_pidfile(command, timeout, &pidfile).await;
+        fs::remove_file(&pidfile).ok();
+
+        let output = res.map_err(|e| format!("{:#}", e))?;
+        if output.timed_out {
+            return Err(format!("timed out after {:?}", timeout));
+        }
+        if output.exit_code != 0 {
+            let mut message = output.stdout;
+            message.extend(output.stderr);
+            message.truncate(MAX_MESSAGE_SIZE);
+            return Err(format!(
+                "exit code {}: {}",
+                output.exit_code,
+                String::from_utf8_lossy(&message).trim()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Run the command within a single deadline, where every step gets the remaining time.
+    async fn exec_with_pidfile(
+        &self,
+        command: &[String],
+        timeout: Duration,
+        pidfile: &Path,
+    ) -> Result<ExecSyncOutput> {
+        let deadline = Instant::now() + timeout;
+        time::timeout_at(deadline, self.exec_until(command, deadline, pidfile))
+            .await
+            .map_err(|_| format_err!("timed out after {:?}", timeout))?
+    }
+
+    async fn exec_until(
+        &self,
+        command: &[String],
+        deadline: Instant,
+        pidfile: &Path,
+    ) -> Result<ExecSyncOutput> {
+        let mut container_io = ContainerIO::new(
+            None,
+            Default::default(),
+            ContainerLog::new(),
+            Default::default(),
+        )?;
+        let args = Server::generate_exec_sync_args(
+            self.runtime_root.as_deref(),
+            &self.id,
+            pidfile,
+            &container_io,
+            command,
+        );
+        let pid = self
+            .reaper
+            .create_child(
+                &self.runtime,
+                &args,
+                &mut container_io,
+                pidfile,
+                Some(deadline.saturating_duration_since(Instant::now())),
+            )
+            .await
+            .context("create probe child")?;
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        self.reaper
+            .wait_exec_sync(self.id.clone(), pid, container_io, Some(remaining))
+            .await
+    }
+
+    async fn tcp(&self, port: u16, timeout: Duration) -> Result<(), String> {
+        match time::timeout(timeout, port_forward::connect(self.pid, port)).await {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(e)) => Err(format!("{:#}", e)),
+            Err(_) => Err(format!("timed out after {:?}", timeout)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::net::TcpListener;
+
+    fn config(success_threshold: u32, failure_threshold: u32) -> Result<ProbeConfig> {
+        ProbeConfig {
+            name: "probe".into(),
+            handler: ProbeHandler::Tcp(1),
+            initial_delay: Duration::ZERO,
+            period: Duration::from_millis(10),
+            timeout: Duration::ZERO,
+            success_threshold,
+            failure_threshold,
+        }
+        .validate()
+    }
+
+    #[test]
+    fn config_defaults() -> Result<()> {
+        let sut = config(0, 0)?;
+        assert_eq!(sut.timeout, DEFAULT_TIMEOUT);
+        assert_eq!(sut.success_threshold, 1);
+        assert_eq!(sut.failure_threshold, 1);
+
+        let mut invalid = sut.clone();
+        invalid.handler = ProbeHandler::Exec(vec![]);
+        assert!(invalid.validate().is_err());
+
+        let mut invalid = sut;
+        invalid.name = String::new();
+        assert!(invalid.validate().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn status_thresholds() -> Result<()> {
+        let config = config(2, 3)?;
+        let mut sut = ProbeStatus::default();
+
+        assert!(!sut.update(&config, Ok(())));
+        assert_eq!(sut.result(), &ProbeResult::Unknown);
+        assert!(sut.update(&config, Ok(())));
+        assert_eq!(sut.result(), &ProbeResult::Success);
+
+        assert!(!sut.update(&config, Err("first".into())));
+        assert!(!sut.update(&config, Err("second".into())));
+        assert_eq!(sut.result(), &ProbeResult::Success);
+        assert!(sut.update(&config, Err("third".into())));
+        assert_eq!(sut.result(), &ProbeResult::Failure);
+        assert_eq!(sut.message(), "third");
+
+        assert!(!sut.update(&config, Ok(())));
+        assert_eq!(sut.result(), &ProbeResult::Failure);
+        assert!(sut.message().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn register_tcp_probe() -> Result<()> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let port = listener.local_addr()?.port();
+        let mut config = config(1, 1)?;
+        config.handler = ProbeHandler::Tcp(port);
+
+        let dir = tempfile::tempdir()?;
+        let runner = ProbeRunner::new(
+            "id".into(),
+            std::process::id(),
+            "runtime".into(),
+            None,
+            dir.path().into(),
+            Default::default(),
+        );
+        let token = CancellationToken::new();
+        let sut = SharedProbes::default();
+
+        task::LocalSet::new()
+            .run_until(async {
+                sut.register(config, runner, &token)?;
+                let accept = task::spawn(async move { while listener.accept().await.is_ok() {} });
+                while sut.list()?[0].1.result() != &ProbeResult::Success {
+                    time::sleep(Duration::from_millis(10)).await;
+                }
+                accept.abort();
+                token.cancel();
+                Ok(())
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn restart_tcp_probe() -> Result<()> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let port = listener.local_addr()?.port();
+        let mut config = config(1, 1)?;
+        config.handler = ProbeHandler::Tcp(port);
+
+        let dir = tempfile::tempdir()?;
+        let runner = ProbeRunner::new(
+            "id".into(),
+            0,
+            "runtime".into(),
+            None,
+            dir.path().into(),
+            Default::default(),
+        );
+        let sut = SharedProbes::default();
+
+        task::LocalSet::new()
+            .run_until(async {
+                let token = CancellationToken::new();
+                sut.register(config, runner, &token)?;
+                token.cancel();
+
+                let token = CancellationToken::new();
+                sut.restart(std::process::id(), &token)?;
+                let probes = sut.list()?;
+                assert_eq!(probes.len(), 1);
+                assert_eq!(probes[0].1.result(), &ProbeResult::Unknown);
+
+                let accept = task::spawn(async move { while listener.accept().await.is_ok() {} });
+                while sut.list()?[0].1.result() != &ProbeResult::Success {
+                    time::sleep(Duration::from_millis(10)).await;
+                }
+                accept.abort();
+                token.cancel();
+                Ok(())
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn exec_single_deadline() -> Result<()> {
+        // The grandchild is a child of the test, so it can be waited for.
+        let grandchild = std::process::Command::new("sleep").arg("10").spawn()?.id();
+        let dir = tempfile::tempdir()?;
+        let runtime = dir.path().join("runtime");
+        fs::write(
+            &runtime,
+            format!(
+                "#!/bin/sh\n\
+                 for arg; do case $arg in --pid-file=*) pidfile=${{arg#--pid-file=}};; esac; done\n\
+                 sleep 0.6\n\
+                 printf {} > $pidfile\n",
+                grandchild
+            ),
+        )?;
+        fs::set_permissions(&runtime, fs::Permissions::from_mode(0o755))?;
+        let sut = ProbeRunner::new(
+            "id".into(),
+            std::process::id(),
+            runtime,
+            None,
+            dir.path().into(),
+            Default::default(),
+        );
+
+        let start = Instant::now();
+        let err = sut
+            .exec(&["true".into()], Duration::from_secs(1))
+            .await
+            .expect_err("probe should time out");
+        assert!(err.contains("timed out"), "{}", err);
+        assert!(start.elapsed() < Duration::from_millis(1400));
+        Ok(())
+    }
+}
@@ -0,0 +1,245 @@
+//! Terminal session recording in the asciicast v2 format.
+
+use crate::json::json_string;
+use anyhow::{bail, Context, Result};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::Path,
+    str,
+    sync::{Arc, Mutex},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+use tracing::{debug, error};
+
+#[derive(Clone, Debug, Default)]
+/// A shared terminal session recording, which does nothing if recording is disabled.
+///
+/// Write failures get logged instead of being returned, because they should not interrupt the
+/// container IO. Every event gets formatted into a buffer and flushed with a single write, so the
+/// recording is complete even if the server exits without dropping it.
+pub struct SharedRecording(Option<Arc<Mutex<Recording>>>);
+
+impl SharedRecording {
+    /// Create a new recording which writes to the provided path, or a disabled one if the path
+    /// is empty. Recording requires a `terminal`, and standard input is only recorded if `stdin`
+    /// is true.
+    pub fn new(path: &Path, terminal: bool, stdin: bool) -> Result<Self> {
+        if path.as_os_str().is_empty() {
+            return Ok(Self::default());
+        }
+        if !terminal {
+            bail!("recording requires a terminal");
+        }
+        debug!("Recording terminal session to {}", path.display());
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(path)
+            .with_context(|| format!("create recording {}", path.display()))?;
+        Ok(Self(Some(Arc::new(Mutex::new(Recording {
+            file: BufWriter::new(file),
+            stdin,
+            start: None,
+            output: Utf8Buffer::default(),
+            input: Utf8Buffer::default(),
+        })))))
+    }
+
+    /// Start the recording by writing the header with the initial terminal size.
+    pub fn start(&self, width: u16, height: u16) {
+        self.with(|r| r.start(width, height))
+    }
+
+    /// Record output of the terminal.
+    pub fn output(&self, data: &[u8]) {
+        self.with(|r| {
+            let data = r.output.decode(data);
+            r.event("o", &data)
+        })
+    }
+
+    /// Record input to the terminal, if enabled.
+    pub fn input(&self, data: &[u8]) {
+        self.with(|r| {
+            if !r.stdin {
+                return Ok(());
+            }
+            let data = r.input.decode(data);
+            r.event("i", &data)
+        })
+    }
+
+    /// Record a resize of the terminal.
+    pub fn resize(&self, width: u16, height: u16) {
+        self.with(|r| r.event("r", &format!("{}x{}", width, height)))
+    }
+
+    fn with<F>(&self, f: F)
+    where
+        F: FnOnce(&mut Recording) -> Result<()>,
+    {
+        if let Some(recording) = &self.0 {
+            let res = match recording.lock() {
+                Ok(mut recording) => f(&mut recording),
+                Err(_) => Ok(()),
+            };
+            if let Err(e) = res {
+                error!("Unable to write recording: {:#}", e);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Recording {
+    file: BufWriter<File>,
+    stdin: bool,
+    start: Option<Instant>,
+    output: Utf8Buffer,
+    input: Utf8Buffer,
+}
+
+impl Recording {
+    fn start(&mut self, width: u16, height: u16) -> Result<()> {
+        if self.start.is_some() {
+            return Ok(());
+        }
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        // Terminals without a size would result in an invalid recording.
+        let (width, height) = match (width, height) {
+            (0, _) | (_, 0) => (80, 24),
+            size => size,
+        };
+        writeln!(
+            self.file,
+            r#"{{"version": 2, "width": {}, "height": {}, "timestamp": {}}}"#,
+            width, height, timestamp
+        )
+        .context("write recording header")?;
+        self.file.flush().context("flush recording header")?;
+        self.start = Instant::now().into();
+        Ok(())
+    }
+
+    fn event(&mut self, code: &str, data: &str) -> Result<()> {
+        // Events are only valid after the header.
+        let start = match self.start {
+            Some(start) => start,
+            None => return Ok(()),
+        };
+        if data.is_empty() {
+            return Ok(());
+        }
+        writeln!(
+            self.file,
+            "[{:.6}, \"{}\", {}]",
+            start.elapsed().as_secs_f64(),
+            code,
+            json_string(data)
+        )
+        .context("write recording event")?;
+        self.file.flush().context("flush recording event")
+    }
+}
+
+#[derive(Debug, Default)]
+/// Utf8Buffer decodes a byte stream as UTF-8, while keeping incomplete characters at the end of
+/// a chunk for the next one.
+struct Utf8Buffer(Vec<u8>);
+
+impl Utf8Buffer {
+    fn decode(&mut self, data: &[u8]) -> String {
+        self.0.extend_from_slice(data);
+        let valid = match str::from_utf8(&self.0) {
+            Ok(_) => self.0.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => self.0.len(),
+        };
+        let res = String::from_utf8_lossy(&self.0[..valid]).into_owned();
+        self.0.drain(..valid);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn utf8_buffer_split_characters() {
+        let mut sut = Utf8Buffer::default();
+        let data = "aä".as_bytes();
+        assert_eq!(sut.decode(&data[..2]), "a");
+        assert_eq!(sut.decode(&data[2..]), "ä");
+        assert_eq!(sut.decode(b"\xffb"), "\u{fffd}b");
+    }
+
+    #[test]
+    fn recording_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("rec.cast");
+
+        let sut = SharedRecording::new(&path, true, false)?;
+        sut.output(b"ignored before start");
+        sut.start(0, 0);
+        sut.output(b"out\r\n");
+        sut.input(b"ignored without stdin");
+        sut.resize(100, 50);
+        drop(sut);
+
+        let content = fs::read_to_string(&path)?;
+        let lines: Vec<_> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with(r#"{"version": 2, "width": 80, "height": 24, "timestamp": "#));
+        assert!(lines[1].ends_with(r#", "o", "out\r\n"]"#));
+        assert!(lines[2].ends_with(r#", "r", "100x50"]"#));
+
+        let sut = SharedRecording::new(Path::new(""), false, true)?;
+        assert!(sut.0.is_none());
+
+        let sut = SharedRecording::new(&path, true, true)?;
+        sut.start(120, 40);
+        sut.input(b"ls\r");
+        drop(sut);
+        let content = fs::read_to_string(&path)?;
+        let lines: Vec<_> = content.lines().collect();
+        assert!(lines[0].contains(r#""width": 120, "height": 40"#));
+        assert!(lines[1].ends_with(r#", "i", "ls\r"]"#));
+        Ok(())
+    }
+
+    #[test]
+    fn recording_written_before_drop() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("rec.cast");
+
+        let sut = SharedRecording::new(&path, true, false)?;
+        sut.start(80, 24);
+        sut.output(b"first\r\n");
+        sut.output(b"last\r\n");
+
+        let content = fs::read_to_string(&path)?;
+        let lines: Vec<_> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].ends_with(r#", "o", "last\r\n"]"#));
+        drop(sut);
+        Ok(())
+    }
+
+    #[test]
+    fn recording_without_terminal() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("rec.cast");
+
+        assert!(SharedRecording::new(&path, false, false).is_err());
+        assert!(!path.exists());
+        Ok(())
+    }
+}
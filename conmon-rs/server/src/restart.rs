@@ -0,0 +1,366 @@
+//! Restarting containers on exit according to their restart policy.
+
+use crate::{
+    child::Child,
+    child_reaper::{ChildReaper, ExitChannelData, ReapableChild},
+    container_io::SharedContainerIO,
+    probe::SharedProbes,
+    server::Server,
+};
+use anyhow::{bail, Context, Result};
+use std::{cmp, path::PathBuf, process::Stdio, sync::Arc, time::Duration};
+use tokio::{
+    process::Command,
+    sync::broadcast::Receiver,
+    time::{self, Instant},
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info};
+
+/// The delay before the first restart, which doubles for every consecutive restart.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The maximum delay between two restarts.
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+
+/// The time a container has to run until the backoff gets reset.
+const BACKOFF_RESET: Duration = Duration::from_secs(600);
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// The policy for restarting a container on exit.
+pub enum RestartPolicy {
+    #[default]
+    /// Never restart the container.
+    No,
+
+    /// Restart the container on a non-zero exit code, up to the maximum number of retries if
+    /// not zero.
+    OnFailure(u32),
+
+    /// Always restart the container.
+    Always,
+}
+
+impl RestartPolicy {
+    /// Whether the container should be restarted after exiting with the provided code, where
+    /// `restarts` is the number of restarts so far.
+    pub fn should_restart(self, exit_code: i32, restarts: u32) -> bool {
+        match self {
+            Self::No => false,
+            Self::OnFailure(max_retries) => {
+                exit_code != 0 && (max_retries == 0 || restarts < max_retries)
+            }
+            Self::Always => true,
+        }
+    }
+}
+
+/// The delay before the restart number `step`, starting at zero.
+fn backoff(step: u32) -> Duration {
+    INITIAL_BACKOFF
+        .checked_mul(2u32.saturating_pow(step))
+        .map_or(MAX_BACKOFF, |delay| cmp::min(delay, MAX_BACKOFF))
+}
+
+#[derive(Debug)]
+/// Supervisor restarts a container by using the OCI runtime, while keeping its IO.
+pub struct Supervisor {
+    /// The container identifier.
+    pub id: String,
+
+    /// The bundle path of the container.
+    pub bundle_path: PathBuf,
+
+    /// The pidfile written by the runtime.
+    pub pidfile: PathBuf,
+
+    /// The OCI runtime binary.
+    pub runtime: PathBuf,
+
+    /// The root directory of the OCI runtime, if configured.
+    pub runtime_root: Option<PathBuf>,
+
+    /// The exit paths, which only get written as soon as the container will not be restarted
+    /// any more.
+    pub exit_paths: Vec<PathBuf>,
+
+    /// The OOM exit paths, which get written for every exit.
+    pub oom_exit_paths: Vec<PathBuf>,
+
+    /// The restart policy of the container.
+    pub policy: RestartPolicy,
+
+    /// The maximum time the runtime is allowed to take for creating the container again.
+    pub create_timeout: Option<Duration>,
+
+    /// The IO of the container, which is kept across restarts.
+    pub io: SharedContainerIO,
+
+    /// The probes of the container, which get registered again for every restart.
+    pub probes: SharedProbes,
+
+    /// The reaper watching the restarted processes.
+    pub reaper: Arc<ChildReaper>,
+
+    /// Token to cancel pending restarts, which has to be set on the initial child as well.
+    pub restart_token: CancellationToken,
+}
+
+impl Supervisor {
+    /// Supervise the container until it does not get restarted any more, starting with the exit
+    /// receiver of the initial process. The container stays registered at the reaper while
+    /// waiting to be restarted.
+    pub async fn run(self, exit_rx: Receiver<ExitChannelData>) {
+        let exit_code = self.supervise(exit_rx).await;
+        if let Err(e) = self.reaper.forget_supervised(&self.id) {
+            error!("Unable to forget container: {:#}", e);
+        }
+        if let Some(exit_code) = exit_code {
+            if let Err(e) = ReapableChild::write_to_exit_paths(exit_code, &self.exit_paths).await
+            {
+                error!("Could not write exit paths: {:#}", e);
+            }
+        }
+    }
+
+    /// Restart the container until the policy, a shutdown or a kill during the backoff stops it.
+    /// Returns the last exit code, if available.
+    async fn supervise(&self, mut exit_rx: Receiver<ExitChannelData>) -> Option<i32> {
+        let mut restarts = 0;
+        let mut step = 0;
+        let mut started = Instant::now();
+        let shutdown_token = self.reaper.shutdown_token().clone();
+
+        let exit_code = loop {
+            let exit_code = match exit_rx.recv().await {
+                Ok(data) => data.exit_code,
+                Err(e) => {
+                    error!("Unable to receive exit status: {:#}", e);
+                    return None;
+                }
+            };
+            debug!("Container exited with code {}", exit_code);
+            if shutdown_token.is_cancelled()
+                || self.restart_token.is_cancelled()
+                || !self.policy.should_restart(exit_code, restarts)
+            {
+                break exit_code;
+            }
+            if let Err(e) = self.reaper.set_restarting(&self.id, exit_code) {
+                error!("Unable to mark container as restarting: {:#}", e);
+            }
+
+            if started.elapsed() >= BACKOFF_RESET {
+                step = 0;
+            }
+            let delay = backoff(step);
+            step += 1;
+            info!(
+                "Restarting container after exit code {} in {:?} ({} restarts so far)",
+                exit_code, delay, restarts
+            );
+            tokio::select! {
+                _ = shutdown_token.cancelled() => break exit_code,
+                _ = self.restart_token.cancelled() => {
+                    debug!("Restart got cancelled");
+                    break exit_code;
+                }
+                _ = time::sleep(delay) => {}
+            }
+
+            let pid = match self.restart().await {
+                Ok(pid) => pid,
+                Err(e) => {
+                    error!("Unable to restart container: {:#}", e);
+                    break exit_code;
+                }
+            };
+            restarts += 1;
+
+            let mut child = Child::new(
+                self.id.clone(),
+                pid,
+                vec![],
+                self.oom_exit_paths.clone(),
+                None,
+                self.io.clone(),
+            );
+            child.set_restart_count(restarts);
+            child.set_last_exit_code(exit_code);
+            child.set_probes(self.probes.clone());
+            child.set_restart_token(Some(self.restart_token.clone()));
+            let token = child.token().clone();
+            exit_rx = match self.reaper.watch_grandchild(child) {
+                Ok(exit_rx) => exit_rx,
+                Err(e) => {
+                    error!("Unable to watch restarted container: {:#}", e);
+                    return None;
+                }
+            };
+            if let Err(e) = self.probes.restart(pid, &token) {
+                error!("Unable to restart probes: {:#}", e);
+            }
+            started = Instant::now();
+        };
+
+        debug!(
+            "Not restarting container any more after {} restarts",
+            restarts
+        );
+        Some(exit_code)
+    }
+
+    /// Delete the exited container, create it again and start it. Returns the new process ID.
+    async fn restart(&self) -> Result<u32> {
+        self.runtime_command(&["delete", "--force"])
+            .await
+            .context("delete exited container")?;
+
+        let mut container_io = self.io.write().await;
+        container_io.renew()?;
+        let args = Server::generate_runtime_args(
+            self.runtime_root.as_deref(),
+            &self.id,
+            &self.bundle_path,
+            &container_io,
+            &self.pidfile,
+        );
+        let pid = self
+            .reaper
+            .create_child(
+                &self.runtime,
+                &args,
+                &mut container_io,
+                &self.pidfile,
+                self.create_timeout,
+            )
+            .await
+            .context("create container")?;
+        drop(container_io);
+
+        if let Err(e) = self.runtime_command(&["start"]).await {
+            // The grandchild will not be watched, which leaves it to the orphan reaper.
+            self.reaper.orphans().unregister(pid);
+            return Err(e.context("start container"));
+        }
+        Ok(pid)
+    }
+
+    /// Run the runtime with the provided command and the container ID as last argument.
+    async fn runtime_command(&self, command: &[&str]) -> Result<()> {
+        let mut cmd = Command::new(&self.runtime);
+        if let Some(rr) = &self.runtime_root {
+            cmd.arg(format!("--root={}", rr.display()));
+        }
+        // Tokio has to wait for the runtime, which must not be reaped as orphan.
+        let _pause = self.reaper.orphans().pause();
+        let output = cmd
+            .args(command)
+            .arg(&self.id)
+            .stdin(Stdio::null())
+            .output()
+            .await
+            .with_context(|| format!("run {}", self.runtime.display()))?;
+        if !output.status.success() {
+            bail!(
+                "{} failed with {}: {}",
+                command.join(" "),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container_io::ContainerIO, container_log::ContainerLog};
+    use nix::sys::signal::Signal;
+    use tempfile::tempdir;
+
+    #[test]
+    fn should_restart() {
+        assert!(!RestartPolicy::No.should_restart(1, 0));
+
+        let sut = RestartPolicy::OnFailure(2);
+        assert!(!sut.should_restart(0, 0));
+        assert!(sut.should_restart(1, 0));
+        assert!(sut.should_restart(137, 1));
+        assert!(!sut.should_restart(1, 2));
+        assert!(RestartPolicy::OnFailure(0).should_restart(1, 100));
+
+        assert!(RestartPolicy::Always.should_restart(0, 100));
+    }
+
+    #[test]
+    fn backoff_doubles_until_max() {
+        assert_eq!(backoff(0), Duration::from_secs(1));
+        assert_eq!(backoff(1), Duration::from_secs(2));
+        assert_eq!(backoff(5), Duration::from_secs(32));
+        assert_eq!(backoff(9), MAX_BACKOFF);
+        assert_eq!(backoff(100), MAX_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn kill_during_backoff() -> Result<()> {
+        let dir = tempdir()?;
+        let exit_path = dir.path().join("exit");
+        let reaper = Arc::new(ChildReaper::default());
+        let io = SharedContainerIO::new(ContainerIO::new(
+            None,
+            Default::default(),
+            ContainerLog::new(),
+            Default::default(),
+        )?);
+        let restart_token = CancellationToken::new();
+
+        let pid = std::process::Command::new("sh")
+            .args(["-c", "exit 3"])
+            .spawn()?
+            .id();
+        let mut child = Child::new("id".into(), pid, vec![], vec![], None, io.clone());
+        child.set_restart_token(Some(restart_token.clone()));
+        let exit_rx = reaper.watch_grandchild(child)?;
+
+        let sut = Supervisor {
+            id: "id".into(),
+            bundle_path: dir.path().into(),
+            pidfile: dir.path().join("pidfile"),
+            runtime: "false".into(),
+            runtime_root: None,
+            exit_paths: vec![exit_path.clone()],
+            oom_exit_paths: vec![],
+            policy: RestartPolicy::Always,
+            create_timeout: None,
+            io,
+            probes: Default::default(),
+            reaper: reaper.clone(),
+            restart_token,
+        };
+        let supervisor = tokio::spawn(sut.run(exit_rx));
+
+        // The first backoff takes a second, while the container stays listed.
+        time::timeout(Duration::from_millis(500), async {
+            while !reaper.get("id")?.restarting() {
+                time::sleep(Duration::from_millis(10)).await;
+            }
+            Ok::<_, anyhow::Error>(())
+        })
+        .await??;
+        let containers = reaper.list()?;
+        assert_eq!(containers.len(), 1);
+        assert_eq!(containers[0].0, "id");
+        assert_eq!(containers[0].1.last_exit_code(), 3);
+        assert_eq!(containers[0].1.restart_count(), 0);
+        assert!(!exit_path.exists());
+
+        reaper.kill("id", Signal::SIGTERM)?;
+
+        time::timeout(Duration::from_millis(500), supervisor).await??;
+        assert!(reaper.list()?.is_empty());
+        assert_eq!(tokio::fs::read_to_string(&exit_path).await?, "3");
+        Ok(())
+    }
+}
@@ -1,22 +1,35 @@
 use crate::{
-    attach::Attach,
+    attach::{self, Attach, AttachOptions, DetachKeys, Scrollback, SharedContainerAttach},
     child::Child,
+    child_reaper::ShutdownMode,
     container_io::{ContainerIO, SharedContainerIO},
     container_log::ContainerLog,
+    namespaces::{self, Namespace, NamespacesConfig},
+    port_forward::{self, PortForwardSink},
+    probe::{ProbeConfig, ProbeHandler, ProbeResult, ProbeRunner},
+    recording::SharedRecording,
+    restart::{RestartPolicy, Supervisor},
     server::Server,
+    stream_sink::{self, StdinSink},
+    telemetry::Metadata,
+    terminal::TerminalOptions,
     version::Version,
 };
 use anyhow::Context;
 use capnp::{capability::Promise, Error};
 use capnp_rpc::pry;
-use conmon_common::conmon_capnp::conmon;
+use conmon_common::conmon_capnp::conmon::{
+    self, attach_request::SlowClientPolicy, restart_policy, shutdown_request,
+};
+use nix::sys::signal::Signal;
 use std::{
+    convert::TryFrom,
     path::{Path, PathBuf},
     time::Duration,
 };
-use tokio::time::Instant;
-use tracing::{debug, debug_span, error, Instrument};
-use uuid::Uuid;
+use tokio::task;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, error, field, info_span, Instrument};
 
 macro_rules! pry_err {
     ($x:expr) => {
@@ -31,15 +44,33 @@ macro_rules! capnp_err {
 }
 
 macro_rules! new_root_span {
-    ($name:expr, $container_id:expr) => {
-        debug_span!(
+    (@fields $name:expr, $metadata:expr, $($fields:tt)*) => {{
+        let metadata = Metadata::new($metadata);
+        // The info level keeps the span enabled for the export with the default log level.
+        let span = info_span!(
             $name,
-            container_id = $container_id,
-            uuid = Uuid::new_v4().to_string().as_str()
-        )
+            $($fields)*
+            uuid = metadata.request_id().as_str(),
+            caller = field::Empty,
+            trace_id = field::Empty,
+            parent_span_id = field::Empty
+        );
+        metadata.record(&span);
+        span
+    }};
+    ($name:expr, $metadata:expr) => {
+        new_root_span!(@fields $name, $metadata,)
+    };
+    ($name:expr, $container_id:expr, $metadata:expr) => {
+        new_root_span!(@fields $name, $metadata, container_id = $container_id,)
     };
 }
 
+/// Convert a capnp text list into owned strings.
+fn text_list(list: capnp::text_list::Reader) -> capnp::Result<Vec<String>> {
+    list.iter().map(|x| x.map(String::from)).collect()
+}
+
 impl conmon::Server for Server {
     /// Retrieve version information from the server.
     fn version(
@@ -56,6 +87,7 @@ impl conmon::Server for Server {
         response.set_build_date(version.build_date());
         response.set_rust_version(version.rust_version());
         response.set_process_id(std::process::id());
+        response.set_reaped_orphans(self.reaper().orphans().reaped());
         Promise::ok(())
     }
 
@@ -68,22 +100,47 @@ impl conmon::Server for Server {
         let req = pry!(pry!(params.get()).get_request());
         let id = pry!(req.get_id()).to_string();
 
-        let span = new_root_span!("create_container", id.as_str());
+        let span = new_root_span!("create_container", id.as_str(), pry!(req.get_metadata()));
         let _enter = span.enter();
 
         debug!("Got a create container request");
 
         let log_drivers = pry!(req.get_log_drivers());
         let container_log = pry_err!(ContainerLog::from(log_drivers));
-        let mut container_io =
-            pry_err!(ContainerIO::new(req.get_terminal(), container_log.clone()));
+        let terminal = if req.get_terminal() {
+            Some(TerminalOptions::from(pry!(req.get_terminal_options())))
+        } else {
+            None
+        };
+        let attach = SharedContainerAttach::new(
+            req.get_stdin(),
+            req.get_stdin_once(),
+            pry_err!(Scrollback::validate_size(req.get_scrollback_size())),
+        );
+        let mut container_io = pry_err!(ContainerIO::new(
+            terminal,
+            attach,
+            container_log.clone(),
+            pry_err!(SharedRecording::new(
+                Path::new(pry!(req.get_recording_path())),
+                req.get_terminal(),
+                req.get_record_stdin()
+            ))
+        ));
 
         let bundle_path = Path::new(pry!(req.get_bundle_path()));
         let pidfile = bundle_path.join("pidfile");
         debug!("PID file is {}", pidfile.display());
 
         let child_reaper = self.reaper().clone();
-        let args = pry_err!(self.generate_runtime_args(&id, bundle_path, &container_io, &pidfile));
+        let runtime_root = self.config().runtime_root().clone();
+        let args = Server::generate_runtime_args(
+            runtime_root.as_deref(),
+            &id,
+            bundle_path,
+            &container_io,
+            &pidfile,
+        );
         let runtime = self.config().runtime().clone();
         let exit_paths: Vec<PathBuf> = pry!(pry!(req.get_exit_paths())
             .iter()
@@ -93,6 +150,19 @@ impl conmon::Server for Server {
             .iter()
             .map(|r| r.map(PathBuf::from))
             .collect());
+        let restart_policy = pry!(req.get_restart_policy());
+        let restart_policy = match pry!(restart_policy.get_mode()) {
+            restart_policy::Mode::No => RestartPolicy::No,
+            restart_policy::Mode::OnFailure => {
+                RestartPolicy::OnFailure(restart_policy.get_max_retries())
+            }
+            restart_policy::Mode::Always => RestartPolicy::Always,
+        };
+        let create_timeout = match req.get_timeout_sec() {
+            0 => None,
+            timeout => Some(Duration::from_secs(timeout)),
+        };
+        let bundle_path = bundle_path.to_path_buf();
 
         Promise::from_future(
             async move {
@@ -100,14 +170,51 @@ impl conmon::Server for Server {
 
                 let grandchild_pid = capnp_err!(
                     child_reaper
-                        .create_child(runtime, args, &mut container_io, &pidfile)
+                        .create_child(&runtime, args, &mut container_io, &pidfile, create_timeout)
                         .await
                 )?;
 
                 // register grandchild with server
                 let io = SharedContainerIO::new(container_io);
-                let child = Child::new(id, grandchild_pid, exit_paths, oom_exit_paths, None, io);
-                capnp_err!(child_reaper.watch_grandchild(child))?;
+                if restart_policy == RestartPolicy::No {
+                    let child =
+                        Child::new(id, grandchild_pid, exit_paths, oom_exit_paths, None, io);
+                    capnp_err!(child_reaper.watch_grandchild(child))?;
+                } else {
+                    // The supervisor writes the exit paths once it stops restarting.
+                    let mut child = Child::new(
+                        id.clone(),
+                        grandchild_pid,
+                        vec![],
+                        oom_exit_paths.clone(),
+                        None,
+                        io.clone(),
+                    );
+                    let probes = child.probes().clone();
+                    let restart_token = CancellationToken::new();
+                    child.set_restart_token(Some(restart_token.clone()));
+                    let exit_rx = capnp_err!(child_reaper.watch_grandchild(child))?;
+                    let supervisor = Supervisor {
+                        id,
+                        bundle_path,
+                        pidfile,
+                        runtime,
+                        runtime_root,
+                        exit_paths,
+                        oom_exit_paths,
+                        policy: restart_policy,
+                        create_timeout,
+                        io,
+                        probes,
+                        reaper: child_reaper,
+                        restart_token,
+                    };
+                    task::spawn_local(
+                        supervisor
+                            .run(exit_rx)
+                            .instrument(debug_span!("supervisor")),
+                    );
+                }
 
                 results
                     .get()
@@ -135,7 +242,7 @@ impl conmon::Server for Server {
             "pid"
         ));
 
-        let span = new_root_span!("exec_sync_container", id.as_str());
+        let span = new_root_span!("exec_sync_container", id.as_str(), pry!(req.get_metadata()));
         let _enter = span.enter();
 
         debug!("Got exec sync container request with timeout {}", timeout);
@@ -144,46 +251,54 @@ impl conmon::Server for Server {
         let child_reaper = self.reaper().clone();
 
         let logger = ContainerLog::new();
-        let mut container_io = pry_err!(ContainerIO::new(req.get_terminal(), logger));
+        let recording = pry_err!(SharedRecording::new(
+            Path::new(pry!(req.get_recording_path())),
+            req.get_terminal(),
+            false
+        ));
+        let terminal = if req.get_terminal() {
+            Some(TerminalOptions::from(pry!(req.get_terminal_options())))
+        } else {
+            None
+        };
+        let mut container_io = pry_err!(ContainerIO::new(
+            terminal,
+            Default::default(),
+            logger,
+            recording
+        ));
 
-        let command = pry!(req.get_command());
-        let args = pry_err!(self.generate_exec_sync_args(&id, &pidfile, &container_io, &command));
+        let command = pry!(text_list(pry!(req.get_command())));
+        let args = Server::generate_exec_sync_args(
+            self.config().runtime_root().as_deref(),
+            &id,
+            &pidfile,
+            &container_io,
+            &command,
+        );
 
         Promise::from_future(
             async move {
                 match child_reaper
-                    .create_child(&runtime, &args, &mut container_io, &pidfile)
+                    .create_child(&runtime, &args, &mut container_io, &pidfile, None)
                     .await
                 {
                     Ok(grandchild_pid) => {
-                        let time_to_timeout = if timeout > 0 {
-                            Some(Instant::now() + Duration::from_secs(timeout))
+                        let timeout = if timeout > 0 {
+                            Some(Duration::from_secs(timeout))
                         } else {
                             None
                         };
+                        let output = capnp_err!(
+                            child_reaper
+                                .wait_exec_sync(id, grandchild_pid, container_io, timeout)
+                                .await
+                        )?;
                         let mut resp = results.get().init_response();
-                        // register grandchild with server
-                        let io = SharedContainerIO::new(container_io);
-                        let io_clone = io.clone();
-                        let child = Child::new(
-                            id,
-                            grandchild_pid,
-                            vec![],
-                            vec![],
-                            time_to_timeout,
-                            io_clone,
-                        );
-
-                        let mut exit_rx = capnp_err!(child_reaper.watch_grandchild(child))?;
-
-                        let (stdout, stderr, timed_out) =
-                            io.read_all_with_timeout(time_to_timeout).await;
-
-                        let exit_data = capnp_err!(exit_rx.recv().await)?;
-                        resp.set_stdout(&stdout);
-                        resp.set_stderr(&stderr);
-                        resp.set_exit_code(*exit_data.exit_code());
-                        if timed_out || exit_data.timed_out {
+                        resp.set_stdout(&output.stdout);
+                        resp.set_stderr(&output.stderr);
+                        resp.set_exit_code(output.exit_code);
+                        if output.timed_out {
                             resp.set_timed_out(true);
                         }
                     }
@@ -203,12 +318,12 @@ impl conmon::Server for Server {
     fn attach_container(
         &mut self,
         params: conmon::AttachContainerParams,
-        _: conmon::AttachContainerResults,
+        mut results: conmon::AttachContainerResults,
     ) -> Promise<(), capnp::Error> {
         let req = pry!(pry!(params.get()).get_request());
         let container_id = pry_err!(req.get_id());
 
-        let span = new_root_span!("attach_container", container_id);
+        let span = new_root_span!("attach_container", container_id, pry!(req.get_metadata()));
         let _enter = span.enter();
 
         debug!("Got a attach container request",);
@@ -218,13 +333,57 @@ impl conmon::Server for Server {
             debug!("Using exec session id {}", exec_session_id);
         }
 
-        let socket_path = Path::new(pry!(req.get_socket_path()));
-        let attach = pry_err!(Attach::new(socket_path).context("create attach endpoint"));
+        let socket_path = Path::new(pry!(req.get_socket_path())).to_path_buf();
+        let options = AttachOptions {
+            scrollback_size: pry_err!(Scrollback::validate_size(req.get_scrollback_size())),
+            detach_keys: pry_err!(
+                DetachKeys::parse(pry!(req.get_detach_keys())).context("parse detach keys")
+            ),
+            slow_client_policy: match pry!(req.get_slow_client_policy()) {
+                SlowClientPolicy::Drop => attach::SlowClientPolicy::Drop,
+                SlowClientPolicy::Block => attach::SlowClientPolicy::Block,
+                SlowClientPolicy::Disconnect => attach::SlowClientPolicy::Disconnect,
+            },
+        };
+        let peer_auth = self.peer_auth().clone();
         let child = pry_err!(self.reaper().get(container_id));
 
+        // Clients providing an output sink attach over the RPC connection itself. The output
+        // sink is unset if reading it fails, which is only valid if a socket path is provided.
+        let output_sink = match req.get_output_sink() {
+            Ok(output_sink) => Some(output_sink),
+            Err(e) if socket_path.as_os_str().is_empty() => return Promise::err(e),
+            Err(e) => {
+                debug!("Not using an output sink: {}", e);
+                None
+            }
+        };
+        if let Some(output_sink) = output_sink {
+            debug!("Attaching over the RPC connection");
+            return Promise::from_future(
+                async move {
+                    let shared = child.io().attach().await;
+                    let (output, input) = shared.subscribe(&options).await;
+                    task::spawn_local(
+                        stream_sink::forward_output(output, output_sink)
+                            .instrument(debug_span!("rpc_attach_client")),
+                    );
+                    results
+                        .get()
+                        .init_response()
+                        .set_stdin_sink(capnp_rpc::new_client(StdinSink::new(input)));
+                    Ok(())
+                }
+                .instrument(debug_span!("promise")),
+            );
+        }
+
         Promise::from_future(
             async move {
-                child.io().attach().await.add(attach).await;
+                let shared = child.io().attach().await;
+                let attach = capnp_err!(Attach::new(&socket_path, peer_auth, &shared, options)
+                    .context("create attach endpoint"))?;
+                shared.add(attach).await;
                 Ok(())
             }
             .instrument(debug_span!("promise")),
@@ -240,7 +399,11 @@ impl conmon::Server for Server {
         let req = pry!(pry!(params.get()).get_request());
         let container_id = pry_err!(req.get_id());
 
-        let span = new_root_span!("reopen_log_container", container_id);
+        let span = new_root_span!(
+            "reopen_log_container",
+            container_id,
+            pry!(req.get_metadata())
+        );
         let _enter = span.enter();
 
         debug!("Got a reopen container log request");
@@ -262,7 +425,11 @@ impl conmon::Server for Server {
         let req = pry!(pry!(params.get()).get_request());
         let container_id = pry_err!(req.get_id());
 
-        let span = new_root_span!("set_window_size_container", container_id);
+        let span = new_root_span!(
+            "set_window_size_container",
+            container_id,
+            pry!(req.get_metadata())
+        );
         let _enter = span.enter();
 
         debug!("Got a set window size container request");
@@ -276,4 +443,316 @@ impl conmon::Server for Server {
                 .instrument(debug_span!("promise")),
         )
     }
+
+    /// Shutdown the server and handle the containers by using the provided mode.
+    fn shutdown(
+        &mut self,
+        params: conmon::ShutdownParams,
+        _: conmon::ShutdownResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+
+        let span = new_root_span!("shutdown", pry!(req.get_metadata()));
+        let _enter = span.enter();
+
+        debug!("Got a shutdown request");
+
+        let grace_period = match req.get_grace_period_sec() {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        };
+        let mode = match pry!(req.get_mode()) {
+            shutdown_request::Mode::KillAll => ShutdownMode::KillAll(Signal::SIGTERM, grace_period),
+            shutdown_request::Mode::Detach => ShutdownMode::Detach,
+            shutdown_request::Mode::WaitForExit => ShutdownMode::WaitForExit,
+        };
+
+        let reaper = self.reaper().clone();
+        let notifier = self.notifier().clone();
+        let socket = self.owned_socket();
+        let stop_token = self.stop_token().clone();
+
+        Promise::from_future(
+            async move {
+                let res = Server::teardown(&reaper, &notifier, socket, mode).await;
+                stop_token.cancel();
+                capnp_err!(res)
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// List all running containers and exec sessions.
+    fn list_containers(
+        &mut self,
+        params: conmon::ListContainersParams,
+        mut results: conmon::ListContainersResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+
+        let span = new_root_span!("list_containers", pry!(req.get_metadata()));
+        let _enter = span.enter();
+
+        debug!("Got a list containers request");
+        let grandchildren = pry_err!(self.reaper().list());
+
+        Promise::from_future(
+            async move {
+                let mut containers = results
+                    .get()
+                    .init_response()
+                    .init_containers(grandchildren.len() as u32);
+
+                for (i, (id, grandchild)) in grandchildren.iter().enumerate() {
+                    let mut container = containers.reborrow().get(i as u32);
+                    container.set_id(id);
+                    // There is no process while waiting to be restarted.
+                    container.set_pid(if grandchild.restarting() {
+                        0
+                    } else {
+                        grandchild.pid()
+                    });
+                    container.set_restart_count(grandchild.restart_count());
+                    container.set_last_exit_code(grandchild.last_exit_code());
+
+                    let paths = grandchild.io().logger().await.read().await.paths();
+                    let mut log_drivers = container.reborrow().init_log_drivers(paths.len() as u32);
+                    for (j, (typ, path)) in paths.iter().enumerate() {
+                        let mut log_driver = log_drivers.reborrow().get(j as u32);
+                        log_driver.set_type(*typ);
+                        log_driver.set_path(&path.display().to_string());
+                    }
+
+                    let statuses = capnp_err!(grandchild.probes().list())?;
+                    let mut probes = container.init_probes(statuses.len() as u32);
+                    for (j, (name, status)) in statuses.iter().enumerate() {
+                        let mut probe = probes.reborrow().get(j as u32);
+                        probe.set_name(name);
+                        probe.set_result(match status.result() {
+                            ProbeResult::Unknown => conmon::ProbeResult::Unknown,
+                            ProbeResult::Success => conmon::ProbeResult::Success,
+                            ProbeResult::Failure => conmon::ProbeResult::Failure,
+                        });
+                        probe.set_message(status.message());
+                    }
+                }
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Send a signal to a running container.
+    fn kill_container(
+        &mut self,
+        params: conmon::KillContainerParams,
+        _: conmon::KillContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_err!(req.get_id());
+
+        let span = new_root_span!("kill_container", container_id, pry!(req.get_metadata()));
+        let _enter = span.enter();
+
+        debug!("Got a kill container request");
+
+        let signal = pry_err!(Signal::try_from(req.get_signal() as i32));
+        pry_err!(self.reaper().kill(container_id, signal));
+        Promise::ok(())
+    }
+
+    /// Forward a port from the network namespace of a running container.
+    fn port_forward(
+        &mut self,
+        params: conmon::PortForwardParams,
+        mut results: conmon::PortForwardResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_err!(req.get_id());
+
+        let span = new_root_span!("port_forward", container_id, pry!(req.get_metadata()));
+        let _enter = span.enter();
+
+        let port = req.get_port();
+        debug!("Got a port forward request for port {}", port);
+
+        let output_sink = pry!(req.get_output_sink());
+        let pid = pry_err!(self.reaper().get(container_id)).pid();
+
+        Promise::from_future(
+            async move {
+                let stream = capnp_err!(port_forward::connect(pid, port).await)?;
+                let (reader, writer) = stream.into_split();
+                task::spawn_local(
+                    port_forward::forward_output(reader, output_sink)
+                        .instrument(debug_span!("port_forward_client")),
+                );
+                results
+                    .get()
+                    .init_response()
+                    .set_input_sink(capnp_rpc::new_client(PortForwardSink::new(writer)));
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Create and pin the namespaces of a pod.
+    fn create_namespaces(
+        &mut self,
+        params: conmon::CreateNamespacesParams,
+        mut results: conmon::CreateNamespacesResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let pod_id = pry!(req.get_pod_id());
+
+        let span = new_root_span!("create_namespaces", pod_id, pry!(req.get_metadata()));
+        let _enter = span.enter();
+
+        debug!("Got a create namespaces request");
+
+        let config = NamespacesConfig {
+            base_path: pry!(req.get_base_path()).into(),
+            pod_id: pod_id.into(),
+            namespaces: pry!(pry!(req.get_namespaces())
+                .iter()
+                .map(|x| x.map(|x| match x {
+                    conmon::Namespace::Ipc => Namespace::Ipc,
+                    conmon::Namespace::Net => Namespace::Net,
+                    conmon::Namespace::Uts => Namespace::Uts,
+                    conmon::Namespace::User => Namespace::User,
+                    conmon::Namespace::Cgroup => Namespace::Cgroup,
+                }))
+                .collect::<Result<_, _>>()),
+            uid_mappings: pry!(text_list(pry!(req.get_uid_mappings()))),
+            gid_mappings: pry!(text_list(pry!(req.get_gid_mappings()))),
+            sysctls: pry!(pry!(req.get_sysctls())
+                .iter()
+                .map(|x| Ok((x.get_key()?.into(), x.get_value()?.into())))
+                .collect::<capnp::Result<_>>()),
+        };
+
+        let orphans = self.reaper().orphans().clone();
+        Promise::from_future(
+            async move {
+                let namespaces = capnp_err!(namespaces::create(config, orphans).await)?;
+                let mut response = results
+                    .get()
+                    .init_response()
+                    .init_namespaces(namespaces.len() as u32);
+                for (i, (namespace, path)) in namespaces.iter().enumerate() {
+                    let mut ns = response.reborrow().get(i as u32);
+                    ns.set_type(match namespace {
+                        Namespace::Ipc => conmon::Namespace::Ipc,
+                        Namespace::Net => conmon::Namespace::Net,
+                        Namespace::Uts => conmon::Namespace::Uts,
+                        Namespace::User => conmon::Namespace::User,
+                        Namespace::Cgroup => conmon::Namespace::Cgroup,
+                    });
+                    ns.set_path(&path.display().to_string());
+                }
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Remove the pinned namespaces of a pod.
+    fn remove_namespaces(
+        &mut self,
+        params: conmon::RemoveNamespacesParams,
+        _: conmon::RemoveNamespacesResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let pod_id = pry!(req.get_pod_id()).to_string();
+
+        let span = new_root_span!(
+            "remove_namespaces",
+            pod_id.as_str(),
+            pry!(req.get_metadata())
+        );
+        let _enter = span.enter();
+
+        debug!("Got a remove namespaces request");
+
+        let base_path = PathBuf::from(pry!(req.get_base_path()));
+        Promise::from_future(
+            async move { capnp_err!(namespaces::remove(base_path, pod_id).await) }
+                .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Register a health probe, which runs periodically within the server.
+    fn register_probe(
+        &mut self,
+        params: conmon::RegisterProbeParams,
+        _: conmon::RegisterProbeResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_err!(req.get_id());
+
+        let span = new_root_span!("register_probe", container_id, pry!(req.get_metadata()));
+        let _enter = span.enter();
+
+        debug!("Got a register probe request");
+
+        let command = pry!(text_list(pry!(req.get_command())));
+        let handler = match (command.is_empty(), req.get_tcp_port()) {
+            (false, 0) => ProbeHandler::Exec(command),
+            (true, port) if port > 0 => ProbeHandler::Tcp(port),
+            _ => {
+                return Promise::err(Error::failed(
+                    "either a command or a TCP port is required".into(),
+                ))
+            }
+        };
+        let config = pry_err!(ProbeConfig {
+            name: pry!(req.get_name()).into(),
+            handler,
+            initial_delay: Duration::from_secs(req.get_initial_delay_sec().into()),
+            period: Duration::from_secs(req.get_period_sec().into()),
+            timeout: Duration::from_secs(req.get_timeout_sec().into()),
+            success_threshold: req.get_success_threshold(),
+            failure_threshold: req.get_failure_threshold(),
+        }
+        .validate());
+
+        let child = pry_err!(self.reaper().get(container_id));
+        let runner = ProbeRunner::new(
+            container_id.into(),
+            child.pid(),
+            self.config().runtime().clone(),
+            self.config().runtime_root().clone(),
+            self.config().runtime_dir().clone(),
+            self.reaper().clone(),
+        );
+        pry_err!(child.probes().register(config, runner, child.token()));
+        Promise::ok(())
+    }
+
+    /// Change the filter of the server logs, optionally only until the timeout elapsed.
+    fn set_log_level(
+        &mut self,
+        params: conmon::SetLogLevelParams,
+        _: conmon::SetLogLevelResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+
+        let span = new_root_span!("set_log_level", pry!(req.get_metadata()));
+        let _enter = span.enter();
+
+        let filter = pry!(req.get_filter());
+        debug!("Got a set log level request for {:?}", filter);
+
+        let log_filter = pry_err!(self
+            .log_filter()
+            .as_ref()
+            .context("log filter not initialized"));
+        let timeout = match req.get_timeout_sec() {
+            0 => None,
+            x => Some(Duration::from_secs(x)),
+        };
+        pry_err!(log_filter.set(filter, timeout));
+        Promise::ok(())
+    }
 }
@@ -1,14 +1,18 @@
 #![deny(missing_docs)]
 
 use crate::{
-    child_reaper::ChildReaper,
-    config::{Config, LogDriver},
+    child_reaper::{ChildReaper, ShutdownMode},
+    config::{CgroupManager, Config, LogDriver},
     container_io::{ContainerIO, ContainerIOType},
     init::{DefaultInit, Init},
+    listener,
+    logging::{JsonFields, JsonFormat, LogFilter, RotatingFile},
+    notifier::Notifier,
+    peer::PeerAuth,
+    telemetry::{self, OtlpExporter},
     version::Version,
 };
-use anyhow::{format_err, Context, Result};
-use capnp::text_list::Reader;
+use anyhow::{Context, Result};
 use capnp_rpc::{rpc_twoparty_capnp::Side, twoparty, RpcSystem};
 use conmon_common::conmon_capnp::conmon;
 use futures::{AsyncReadExt, FutureExt};
@@ -16,19 +20,30 @@ use getset::Getters;
 use nix::{
     errno,
     libc::_exit,
-    sys::signal::Signal,
+    sys::{resource::Resource, signal::Signal},
     unistd::{fork, ForkResult},
 };
-use std::{fs::File, io::Write, path::Path, process, str::FromStr, sync::Arc};
+use std::{
+    fs::{self as stdfs, File},
+    io::Write,
+    os::unix::net,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 use tokio::{
     fs,
+    net::UnixListener,
     runtime::{Builder, Handle},
     signal::unix::{signal, SignalKind},
-    sync::oneshot,
+    sync::mpsc,
     task::{self, LocalSet},
+    time,
 };
-use tokio_util::compat::TokioAsyncReadCompatExt;
-use tracing::{debug, error, info};
+use tokio_util::{compat::TokioAsyncReadCompatExt, sync::CancellationToken};
+use tracing::{debug, debug_span, error, info, Instrument};
 use tracing_subscriber::{filter::LevelFilter, prelude::*};
 use twoparty::VatNetwork;
 
@@ -42,14 +57,48 @@ pub struct Server {
     /// Child reaper instance.
     #[getset(get = "pub(crate)")]
     reaper: Arc<ChildReaper>,
+
+    /// Token to stop accepting new connections and drain the existing ones.
+    #[getset(get = "pub(crate)")]
+    stop_token: CancellationToken,
+
+    /// systemd service manager notifier.
+    #[getset(get = "pub(crate)")]
+    notifier: Notifier,
+
+    /// Listener passed by the service manager via socket activation.
+    activated_listener: Option<net::UnixListener>,
+
+    /// Client authentication based on the peer credentials.
+    #[getset(get = "pub(crate)")]
+    peer_auth: PeerAuth,
+
+    /// Exporter of the tracing spans, if a collector endpoint is configured.
+    otlp_exporter: Option<OtlpExporter>,
+
+    /// Filter of the server logs, which can be changed at runtime.
+    #[getset(get = "pub(crate)")]
+    log_filter: Option<LogFilter>,
 }
 
+/// The mount point of the cgroup v2 filesystem.
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+/// The maximum time to wait for open connections to be closed on shutdown.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
 impl Server {
     /// Create a new `Server` instance.
     pub fn new() -> Result<Self> {
-        let server = Self {
+        let mut server = Self {
             config: Default::default(),
             reaper: Default::default(),
+            stop_token: Default::default(),
+            notifier: Notifier::from_env(),
+            activated_listener: None,
+            peer_auth: Default::default(),
+            otlp_exporter: None,
+            log_filter: None,
         };
 
         if server.config().version() {
@@ -59,6 +108,8 @@ impl Server {
 
         server.init_logging().context("set log verbosity")?;
         server.config().validate().context("validate config")?;
+        server.init_listener().context("init listener")?;
+        server.init_peer_auth();
 
         server.init_self()?;
         Ok(server)
@@ -71,7 +122,10 @@ impl Server {
         // We also have to treat the parent as the child (as described in [1]) to ensure we don't
         // interrupt the child's execution.
         // 1: https://docs.rs/nix/0.23.0/nix/unistd/fn.fork.html#safety
-        if !self.config().skip_fork() {
+        // Running as systemd notify service requires the main process to send the readiness
+        // notification, which would exit right after forking.
+        let skip_fork = *self.config().skip_fork() || self.notifier().socket().is_some();
+        if !skip_fork {
             match unsafe { fork()? } {
                 ForkResult::Parent { child, .. } => {
                     let child_str = format!("{}", child);
@@ -87,6 +141,7 @@ impl Server {
         prctl::set_child_subreaper(true)
             .map_err(errno::from_i32)
             .context("set child subreaper")?;
+        self.init_cgroup().context("init cgroup")?;
 
         let rt = Builder::new_multi_thread()
             .enable_io()
@@ -100,58 +155,169 @@ impl Server {
     fn init_self(&self) -> Result<()> {
         let init = Init::<DefaultInit>::default();
         init.unset_locale()?;
-        // While we could configure this, standard practice has it as -1000,
-        // so it may be YAGNI to add configuration.
-        init.set_oom_score("-1000")
+        init.set_oom_score(self.config().oom_score_adj().to_string())?;
+        if let Some(limit) = self.config().rlimit_nofile() {
+            init.set_rlimit(Resource::RLIMIT_NOFILE, limit)?;
+        }
+        if let Some(limit) = self.config().rlimit_core() {
+            init.set_rlimit(Resource::RLIMIT_CORE, limit)?;
+        }
+        Ok(())
     }
 
-    fn init_logging(&self) -> Result<()> {
+    /// Move the server into the configured cgroup, which has to happen after forking.
+    fn init_cgroup(&self) -> Result<()> {
+        let cgroup = match self.config().cgroup() {
+            Some(cgroup) => cgroup,
+            None => return Ok(()),
+        };
+        let init = Init::<DefaultInit>::default();
+        match self.config().cgroup_manager() {
+            CgroupManager::Cgroupfs => init.join_cgroup(CGROUP_ROOT, cgroup)?,
+            CgroupManager::Systemd => init.join_scope(cgroup)?,
+        }
+        info!("Moved into cgroup {}", cgroup);
+        Ok(())
+    }
+
+    fn init_listener(&mut self) -> Result<()> {
+        self.activated_listener =
+            listener::from_listen_fds().context("get socket activation listener")?;
+
+        if self.activated_listener.is_some() {
+            info!("Using socket activation listener");
+        } else if self.config().socket().exists() {
+            debug!("Removing stale socket {}", self.config().socket().display());
+            stdfs::remove_file(self.config().socket()).context("remove stale socket")?;
+        }
+        Ok(())
+    }
+
+    fn init_peer_auth(&mut self) {
+        self.peer_auth = PeerAuth::new(
+            self.config().allowed_uids().clone(),
+            self.config().allowed_gids().clone(),
+            self.config().allowed_executables().clone(),
+        );
+        if self.peer_auth().is_enabled() {
+            info!("Using peer authentication {:?}", self.peer_auth());
+        }
+    }
+
+    /// The socket path to be removed on shutdown, which is not the case if the socket is owned by
+    /// the service manager.
+    pub(crate) fn owned_socket(&self) -> Option<PathBuf> {
+        match self.activated_listener {
+            Some(_) => None,
+            None => Some(self.config().socket()),
+        }
+    }
+
+    fn init_logging(&mut self) -> Result<()> {
         let level =
             LevelFilter::from_str(self.config().log_level()).context("convert log level filter")?;
-        let registry = tracing_subscriber::registry();
+
+        let (filter, log_filter) = LogFilter::new(level);
+        self.log_filter = Some(log_filter);
+
+        // The exporter can only run as soon as the async runtime got started.
+        let otlp_layer = match self.config().tracing_endpoint() {
+            Some(endpoint) => {
+                let (layer, exporter) = telemetry::otlp(endpoint)?;
+                self.otlp_exporter = Some(exporter);
+                Some(layer)
+            }
+            None => None,
+        };
+        let registry = tracing_subscriber::registry().with(filter).with(otlp_layer);
 
         match self.config().log_driver() {
             LogDriver::Stdout => {
                 let layer = tracing_subscriber::fmt::layer()
                     .with_target(true)
-                    .with_line_number(true)
-                    .with_filter(level);
+                    .with_line_number(true);
                 registry.with(layer).init();
                 info!("Using stdout logger");
             }
             LogDriver::Systemd => {
-                let layer = tracing_journald::layer()
-                    .context("unable to connect to journald")?
-                    .with_filter(level);
+                let layer = tracing_journald::layer().context("unable to connect to journald")?;
                 registry.with(layer).init();
                 info!("Using systemd/journald logger");
             }
+            LogDriver::Json => {
+                let layer = tracing_subscriber::fmt::layer()
+                    .fmt_fields(JsonFields)
+                    .event_format(JsonFormat);
+                registry.with(layer).init();
+                info!("Using JSON logger");
+            }
+            LogDriver::File => {
+                let path = self
+                    .config()
+                    .log_file()
+                    .as_ref()
+                    .context("log file required for file log driver")?;
+                let file = RotatingFile::new(
+                    path,
+                    self.config().log_file_max_size(),
+                    self.config().log_file_max_backups(),
+                )?;
+                let layer = tracing_subscriber::fmt::layer()
+                    .fmt_fields(JsonFields)
+                    .event_format(JsonFormat)
+                    .with_writer(file);
+                registry.with(layer).init();
+                info!("Using file logger: {}", path.display());
+            }
         }
         info!("Set log level to: {}", self.config().log_level());
+        if let Some(endpoint) = self.config().tracing_endpoint() {
+            info!("Exporting spans to {}", endpoint);
+        }
         Ok(())
     }
 
     /// Spwans all required tokio tasks.
-    async fn spawn_tasks(self) -> Result<()> {
-        let (shutdown_tx, shutdown_rx) = oneshot::channel();
-        let socket = self.config().socket();
+    async fn spawn_tasks(mut self) -> Result<()> {
+        let otlp_exporter = self
+            .otlp_exporter
+            .take()
+            .map(|exporter| task::spawn(exporter.run(self.stop_token().clone())));
+        let socket = self.owned_socket();
         let reaper = self.reaper.clone();
-        task::spawn(Self::start_signal_handler(reaper, socket, shutdown_tx));
-
-        task::spawn_blocking(move || {
-            Handle::current().block_on(async {
-                LocalSet::new()
-                    .run_until(self.start_backend(shutdown_rx))
-                    .await
-            })
+        let stop_token = self.stop_token().clone();
+        let notifier = self.notifier().clone();
+        task::spawn(
+            reaper
+                .orphans()
+                .clone()
+                .run(stop_token.clone())
+                .instrument(debug_span!("orphan_reaper")),
+        );
+        task::spawn(Self::start_signal_handler(
+            reaper, notifier, socket, stop_token,
+        ));
+
+        let backend_stop_token = self.stop_token().clone();
+        let res = task::spawn_blocking(move || {
+            Handle::current()
+                .block_on(async { LocalSet::new().run_until(self.start_backend()).await })
         })
-        .await?
+        .await?;
+
+        // Flush the remaining spans.
+        if let Some(otlp_exporter) = otlp_exporter {
+            backend_stop_token.cancel();
+            otlp_exporter.await?;
+        }
+        res
     }
 
-    async fn start_signal_handler<T: AsRef<Path>>(
+    async fn start_signal_handler(
         reaper: Arc<ChildReaper>,
-        socket: T,
-        shutdown_tx: oneshot::Sender<()>,
+        notifier: Notifier,
+        socket: Option<PathBuf>,
+        stop_token: CancellationToken,
     ) -> Result<()> {
         let mut sigterm = signal(SignalKind::terminate())?;
         let mut sigint = signal(SignalKind::interrupt())?;
@@ -168,37 +334,71 @@ impl Server {
             }
         };
 
-        shutdown_tx
-            .send(())
-            .map_err(|_| format_err!("unable to send shutdown message"))?;
+        let mode = ShutdownMode::KillAll(handled_sig, None);
+        let res = Self::teardown(&reaper, &notifier, socket, mode).await;
+        stop_token.cancel();
+        res
+    }
 
-        // TODO FIXME Ideally we would drop after socket file is removed,
-        // but the removal is taking longer than 10 seconds, indicating someone
-        // is keeping it open...
-        match reaper.kill_grandchildren(handled_sig) {
-            Ok(_) => (),
-            Err(e) => error!("could not kill grandchildren: {}", e),
+    /// Remove the socket file (if any), shutdown all containers by using the provided mode and
+    /// close their logs.
+    pub(crate) async fn teardown(
+        reaper: &ChildReaper,
+        notifier: &Notifier,
+        socket: Option<PathBuf>,
+        mode: ShutdownMode,
+    ) -> Result<()> {
+        if let Err(e) = notifier.stopping() {
+            error!("Unable to notify stopping state: {:#}", e)
+        }
+
+        // Remove the socket first to ensure that no new clients are able to connect while the
+        // containers are being shut down.
+        if let Some(socket) = socket {
+            debug!("Removing socket file {}", socket.display());
+            if let Err(e) = fs::remove_file(socket).await {
+                error!("Unable to remove socket file: {}", e)
+            }
         }
 
-        debug!("Removing socket file {}", socket.as_ref().display());
-        fs::remove_file(socket)
-            .await
-            .context("remove existing socket file")
+        debug!("Shutting down containers using mode {:?}", mode);
+        reaper.shutdown(mode).await.context("shutdown containers")
     }
 
-    async fn start_backend(self, mut shutdown_rx: oneshot::Receiver<()>) -> Result<()> {
-        let listener = crate::listener::bind_long_path(&self.config().socket())?;
+    async fn start_backend(self) -> Result<()> {
+        let listener = match &self.activated_listener {
+            Some(listener) => {
+                UnixListener::from_std(listener.try_clone()?).context("use activated listener")?
+            }
+            None => listener::bind_long_path(&self.config().socket())?,
+        };
+        let stop_token = self.stop_token().clone();
+        let peer_auth = self.peer_auth().clone();
+
+        let notifier = self.notifier().clone();
+        if let Err(e) = notifier.ready() {
+            error!("Unable to notify ready state: {:#}", e)
+        }
+        Self::start_watchdog(notifier);
+
         let client: conmon::Client = capnp_rpc::new_client(self);
 
+        // Every connection holds a sender, which allows us to wait for all of them on shutdown.
+        let (conn_tx, mut conn_rx) = mpsc::channel::<()>(1);
+
         loop {
             let stream = tokio::select! {
-                _ = &mut shutdown_rx => {
-                    return Ok(())
+                _ = stop_token.cancelled() => {
+                    break
                 }
                 stream = listener.accept() => {
                     stream?.0
                 },
             };
+            if let Err(e) = peer_auth.verify(&stream) {
+                error!("Rejected connection: {:#}", e);
+                continue;
+            }
             let (reader, writer) = TokioAsyncReadCompatExt::compat(stream).split();
             let network = Box::new(VatNetwork::new(
                 reader,
@@ -207,21 +407,55 @@ impl Server {
                 Default::default(),
             ));
             let rpc_system = RpcSystem::new(network, Some(client.clone().client));
-            task::spawn_local(Box::pin(rpc_system.map(|_| ())));
+            let conn_tx = conn_tx.clone();
+            task::spawn_local(Box::pin(rpc_system.map(move |_| drop(conn_tx))));
+        }
+
+        debug!("Stopped accepting new connections, draining existing ones");
+        drop(listener);
+        drop(conn_tx);
+        if time::timeout(DRAIN_TIMEOUT, conn_rx.recv()).await.is_err() {
+            error!("Timed out waiting for open connections to be closed");
         }
+        Ok(())
+    }
+
+    /// Spawns the watchdog keep-alive task if the watchdog is enabled.
+    ///
+    /// The task runs on the same local set as the RPC connections, which means that the pings
+    /// stop as soon as the RPC loop gets stuck.
+    fn start_watchdog(notifier: Notifier) {
+        let interval = match notifier.watchdog_interval() {
+            Some(interval) => interval,
+            None => return,
+        };
+        debug!("Sending watchdog pings every {:?}", interval);
+
+        task::spawn_local(
+            async move {
+                let mut interval = time::interval(interval);
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = notifier.watchdog() {
+                        error!("Unable to send watchdog ping: {:#}", e)
+                    }
+                }
+            }
+            .instrument(debug_span!("watchdog")),
+        );
     }
 
     /// Generate the OCI runtime CLI arguments from the provided parameters.
     pub(crate) fn generate_runtime_args(
-        &self,
+        runtime_root: Option<&Path>,
         id: &str,
         bundle_path: &Path,
         container_io: &ContainerIO,
         pidfile: &Path,
-    ) -> Result<Vec<String>> {
+    ) -> Vec<String> {
         let mut args = vec![];
 
-        if let Some(rr) = self.config().runtime_root() {
+        if let Some(rr) = runtime_root {
             args.push(format!("--root={}", rr.display()));
         }
 
@@ -238,20 +472,20 @@ impl Server {
         }
         args.push(id.into());
         debug!("Runtime args {:?}", args.join(" "));
-        Ok(args)
+        args
     }
 
     /// Generate the OCI runtime CLI arguments from the provided parameters.
     pub(crate) fn generate_exec_sync_args(
-        &self,
+        runtime_root: Option<&Path>,
         id: &str,
         pidfile: &Path,
         container_io: &ContainerIO,
-        command: &Reader,
-    ) -> Result<Vec<String>> {
+        command: &[String],
+    ) -> Vec<String> {
         let mut args = vec![];
 
-        if let Some(rr) = self.config().runtime_root() {
+        if let Some(rr) = runtime_root {
             args.push(format!("--root={}", rr.display()));
         }
 
@@ -266,11 +500,54 @@ impl Server {
         args.push(format!("--pid-file={}", pidfile.display()));
         args.push(id.into());
 
-        for value in command.iter() {
-            args.push(value?.to_string());
-        }
+        args.extend_from_slice(command);
 
         debug!("Exec args {:?}", args.join(" "));
-        Ok(args)
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixDatagram;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn teardown_success() -> Result<()> {
+        let dir = tempdir()?;
+        let notify_path = dir.path().join("notify.sock");
+        let notify_sock = UnixDatagram::bind(&notify_path)?;
+        let notifier = Notifier::new(Some(notify_path.display().to_string()), None);
+        let socket = dir.path().join("conmon.sock");
+        stdfs::write(&socket, "")?;
+
+        Server::teardown(
+            &ChildReaper::default(),
+            &notifier,
+            Some(socket.clone()),
+            ShutdownMode::KillAll(Signal::SIGTERM, None),
+        )
+        .await?;
+
+        assert!(!socket.exists());
+        let mut buf = [0; 32];
+        let n = notify_sock.recv(&mut buf)?;
+        assert_eq!(&buf[..n], b"STOPPING=1");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn teardown_without_socket() -> Result<()> {
+        let dir = tempdir()?;
+        let socket = dir.path().join("conmon.sock");
+
+        Server::teardown(
+            &ChildReaper::default(),
+            &Notifier::default(),
+            Some(socket),
+            ShutdownMode::Detach,
+        )
+        .await
     }
 }
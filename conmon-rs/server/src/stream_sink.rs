@@ -0,0 +1,76 @@
+//! Attach clients which use the RPC connection instead of an attach socket.
+
+use crate::{
+    attach::{AttachInput, ClientInput, ClientOutput},
+    container_io::Pipe,
+};
+use capnp::{capability::Promise, Error};
+use capnp_rpc::pry;
+use conmon_common::conmon_capnp::conmon::stream_sink;
+use tracing::{debug, error};
+
+/// The standard input of an RPC attach client, which gets handed out as capability.
+pub struct StdinSink {
+    input: ClientInput,
+}
+
+impl StdinSink {
+    /// Create a new standard input sink for the provided client input.
+    pub fn new(input: ClientInput) -> Self {
+        Self { input }
+    }
+}
+
+impl stream_sink::Server for StdinSink {
+    /// Forward data to the standard input of the container.
+    fn write(
+        &mut self,
+        params: stream_sink::WriteParams,
+        _: stream_sink::WriteResults,
+    ) -> Promise<(), Error> {
+        if self.input.is_detached() {
+            return Promise::err(Error::disconnected("attach client detached".into()));
+        }
+        let params = pry!(params.get());
+        if pry!(params.get_pipe()) != stream_sink::Pipe::Stdin {
+            return Promise::err(Error::failed("only stdin can be written".into()));
+        }
+        let data = pry!(params.get_data());
+        if !self.input.send(AttachInput::Stdin(data.to_vec())) {
+            debug!("RPC attach client detached");
+        }
+        Promise::ok(())
+    }
+
+    /// Close the standard input of the container.
+    fn close(
+        &mut self,
+        _: stream_sink::CloseParams,
+        _: stream_sink::CloseResults,
+    ) -> Promise<(), Error> {
+        self.input.send(AttachInput::CloseStdin);
+        Promise::ok(())
+    }
+}
+
+/// Forward the container output to the sink of an RPC attach client until either the client
+/// or the container goes away.
+pub async fn forward_output(mut output: ClientOutput, sink: stream_sink::Client) {
+    while let Some(output) = output.next().await {
+        let mut request = sink.write_request();
+        let mut params = request.get();
+        params.set_pipe(match output.pipe {
+            Pipe::StdOut => stream_sink::Pipe::Stdout,
+            Pipe::StdErr => stream_sink::Pipe::Stderr,
+        });
+        params.set_data(&output.data);
+        if let Err(e) = request.send().promise.await {
+            error!("Unable to write to RPC attach client: {:#}", e);
+            return;
+        }
+    }
+    debug!("Closing RPC attach client output");
+    if let Err(e) = sink.close_request().send().promise.await {
+        debug!("Unable to close RPC attach client output: {:#}", e);
+    }
+}
@@ -5,14 +5,19 @@ use crate::{
     container_io::{ContainerIO, Message, Pipe},
     container_log::SharedContainerLog,
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
 use getset::{Getters, MutGetters};
-use std::os::unix::io::AsRawFd;
+use nix::{
+    fcntl::{fcntl, FcntlArg, OFlag},
+    unistd::dup,
+};
+use std::os::unix::io::{AsRawFd, RawFd};
 use tokio::{
     process::{ChildStderr, ChildStdin, ChildStdout},
     sync::mpsc,
     task,
 };
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, debug_span, error, Instrument};
 
 #[derive(Debug, Getters, MutGetters)]
@@ -66,10 +71,20 @@ impl Streams {
         let attach = self.attach().clone();
         let message_tx = self.message_tx_stdout().clone();
 
-        if let Some(stdin) = stdin {
+        if let Some(stdin) = stdin.and_then(Self::dup_fd) {
+            // Streams are kept across restarts, so their stdin never gets stopped.
+            let token = CancellationToken::new();
             task::spawn(
                 async move {
-                    if let Err(e) = ContainerIO::read_loop_stdin(stdin.as_raw_fd(), attach).await {
+                    if let Err(e) = ContainerIO::read_loop_stdin(
+                        stdin,
+                        attach,
+                        false,
+                        Default::default(),
+                        token,
+                    )
+                    .await
+                    {
                         error!("Stdin read loop failure: {:#}", e);
                     }
                 }
@@ -78,15 +93,16 @@ impl Streams {
         }
 
         let attach = self.attach().clone();
-        if let Some(stdout) = stdout {
+        if let Some(stdout) = stdout.and_then(Self::dup_fd) {
             task::spawn(
                 async move {
                     if let Err(e) = ContainerIO::read_loop(
-                        stdout.as_raw_fd(),
+                        stdout,
                         Pipe::StdOut,
                         logger,
                         message_tx,
                         attach,
+                        Default::default(),
                     )
                     .await
                     {
@@ -100,15 +116,16 @@ impl Streams {
         let logger = self.logger().clone();
         let attach = self.attach().clone();
         let message_tx = self.message_tx_stderr().clone();
-        if let Some(stderr) = stderr {
+        if let Some(stderr) = stderr.and_then(Self::dup_fd) {
             task::spawn(
                 async move {
                     if let Err(e) = ContainerIO::read_loop(
-                        stderr.as_raw_fd(),
+                        stderr,
                         Pipe::StdErr,
                         logger,
                         message_tx,
                         attach,
+                        Default::default(),
                     )
                     .await
                     {
@@ -119,4 +136,58 @@ impl Streams {
             );
         }
     }
+
+    /// Duplicate the file descriptor of the provided pipe, because the read loops take ownership
+    /// of the file descriptor and close it on return. The pipe itself gets dropped afterwards.
+    ///
+    /// The duplicate is switched back to blocking mode, because the read loops use files, which
+    /// would otherwise spin on `EAGAIN` while the pipe has no data.
+    fn dup_fd<T: AsRawFd>(pipe: T) -> Option<RawFd> {
+        let res = dup(pipe.as_raw_fd())
+            .context("duplicate pipe file descriptor")
+            .and_then(|fd| {
+                fcntl(fd, FcntlArg::F_SETFL(OFlag::empty())).context("set pipe to blocking")?;
+                Ok(fd)
+            });
+        match res {
+            Ok(fd) => Some(fd),
+            Err(e) => {
+                error!("Unable to read from pipe: {:#}", e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container_log::ContainerLog;
+    use std::{process::Stdio, time::Duration};
+    use tokio::{process::Command, time};
+
+    #[tokio::test]
+    async fn read_after_pipes_dropped() -> Result<()> {
+        let mut sut = Streams::new(ContainerLog::new(), Default::default())?;
+        // The output gets written after the pipes of the child got dropped.
+        let mut child = Command::new("sh")
+            .args(["-c", "sleep 0.2; echo out; echo err >&2"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        sut.handle_stdio_receive(None, child.stdout.take(), child.stderr.take());
+
+        for (rx, expected) in [
+            (&mut sut.message_rx_stdout, "out\n"),
+            (&mut sut.message_rx_stderr, "err\n"),
+        ] {
+            match time::timeout(Duration::from_secs(5), rx.recv()).await? {
+                Some(Message::Data(data)) => assert_eq!(data, expected.as_bytes()),
+                other => panic!("unexpected message {:?}", other),
+            }
+        }
+        child.wait().await?;
+        Ok(())
+    }
 }
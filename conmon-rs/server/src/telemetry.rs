@@ -0,0 +1,443 @@
+//! Tracing context propagation from clients and the optional export of spans via OTLP/HTTP.
+
+use crate::json::json_string;
+use anyhow::{bail, Context, Result};
+use conmon_common::conmon_capnp::conmon::metadata;
+use getset::Getters;
+use std::{
+    fmt::{self, Write as _},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    sync::mpsc::{self, Receiver, Sender},
+    time,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    error,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Span, Subscriber,
+};
+use tracing_subscriber::{layer::Context as LayerContext, registry::LookupSpan, Layer};
+use uuid::Uuid;
+
+/// The span field containing the trace ID of the caller.
+const TRACE_ID_FIELD: &str = "trace_id";
+
+/// The span field containing the span ID of the caller.
+const PARENT_SPAN_ID_FIELD: &str = "parent_span_id";
+
+/// The maximum number of finished spans waiting for the export, newer ones get dropped.
+const QUEUE_SIZE: usize = 2048;
+
+/// The maximum number of spans exported at once.
+const BATCH_SIZE: usize = 512;
+
+/// The interval for exporting the finished spans.
+const EXPORT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The maximum time a single export is allowed to take.
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Default, Getters)]
+/// Metadata of the caller of a request.
+pub struct Metadata {
+    trace_id: Option<String>,
+    span_id: Option<String>,
+
+    /// The identifier of the request, which is random if not provided by the caller.
+    #[getset(get = "pub")]
+    request_id: String,
+
+    caller_name: Option<String>,
+}
+
+impl Metadata {
+    /// Read the metadata of a request, whereas invalid trace and span IDs get ignored.
+    pub fn new(reader: metadata::Reader) -> Self {
+        let text = |x: capnp::Result<&str>| x.ok().filter(|x| !x.is_empty()).map(String::from);
+        let request_id =
+            text(reader.get_request_id()).unwrap_or_else(|| Uuid::new_v4().to_string());
+        Self {
+            trace_id: text(reader.get_trace_id()).filter(|x| is_id(x, 32)),
+            span_id: text(reader.get_span_id()).filter(|x| is_id(x, 16)),
+            request_id,
+            caller_name: text(reader.get_caller_name()),
+        }
+    }
+
+    /// Record the metadata of the caller into the fields of the span.
+    pub fn record(&self, span: &Span) {
+        if let Some(caller) = &self.caller_name {
+            span.record("caller", &caller.as_str());
+        }
+        // A parent span is only meaningful within a trace.
+        if let Some(trace_id) = &self.trace_id {
+            span.record(TRACE_ID_FIELD, &trace_id.as_str());
+            if let Some(span_id) = &self.span_id {
+                span.record(PARENT_SPAN_ID_FIELD, &span_id.as_str());
+            }
+        }
+    }
+}
+
+/// Check if the value is a valid, non-zero W3C trace context ID of the provided length.
+fn is_id(value: &str, len: usize) -> bool {
+    value.len() == len
+        && value.chars().all(|c| c.is_ascii_hexdigit())
+        && value.chars().any(|c| c != '0')
+}
+
+/// Create a new layer collecting the spans, together with the exporter sending them to the
+/// OTLP/HTTP collector at the provided endpoint, like `http://localhost:4318`.
+pub fn otlp(endpoint: &str) -> Result<(OtlpLayer, OtlpExporter)> {
+    let endpoint = Endpoint::parse(endpoint)?;
+    let (tx, rx) = mpsc::channel(QUEUE_SIZE);
+    Ok((OtlpLayer { tx }, OtlpExporter { endpoint, rx }))
+}
+
+#[derive(Debug)]
+/// OtlpLayer collects all closed spans for the export.
+pub struct OtlpLayer {
+    tx: Sender<SpanData>,
+}
+
+impl<S> Layer<S> for OtlpLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: LayerContext<'_, S>) {
+        let span = match ctx.span(id) {
+            Some(span) => span,
+            None => return,
+        };
+        let mut data = SpanData::new(attrs.metadata().name());
+        if let Some(parent) = span.parent() {
+            if let Some(parent) = parent.extensions().get::<SpanData>() {
+                data.trace_id = parent.trace_id.clone();
+                data.parent_span_id = Some(parent.span_id.clone());
+                data.local_parent = true;
+            }
+        }
+        attrs.record(&mut data);
+        span.extensions_mut().insert(data);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: LayerContext<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(data) = span.extensions_mut().get_mut::<SpanData>() {
+                values.record(data);
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: LayerContext<'_, S>) {
+        if let Some(span) = ctx.span(&id) {
+            if let Some(mut data) = span.extensions_mut().remove::<SpanData>() {
+                data.end = SystemTime::now();
+                // Logging is not possible from within the layer, so the span just gets dropped
+                // if the exporter does not keep up.
+                self.tx.try_send(data).ok();
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// The data of a single span to be exported.
+struct SpanData {
+    trace_id: String,
+    span_id: String,
+    parent_span_id: Option<String>,
+    local_parent: bool,
+    name: &'static str,
+    start: SystemTime,
+    end: SystemTime,
+    attributes: Vec<(&'static str, String)>,
+}
+
+impl SpanData {
+    fn new(name: &'static str) -> Self {
+        let now = SystemTime::now();
+        Self {
+            trace_id: Uuid::new_v4().simple().to_string(),
+            span_id: Uuid::new_v4().simple().to_string()[..16].into(),
+            parent_span_id: None,
+            local_parent: false,
+            name,
+            start: now,
+            end: now,
+            attributes: vec![],
+        }
+    }
+
+    fn record(&mut self, key: &'static str, value: String) {
+        // The trace context of the caller only applies to spans without a local parent.
+        if !self.local_parent {
+            if key == TRACE_ID_FIELD && is_id(&value, 32) {
+                self.trace_id = value.to_lowercase();
+            } else if key == PARENT_SPAN_ID_FIELD && is_id(&value, 16) {
+                self.parent_span_id = Some(value.to_lowercase());
+            }
+        }
+        self.attributes.push((key, value));
+    }
+
+    /// Encode the span in the OTLP JSON format.
+    fn to_json(&self) -> String {
+        let mut res = format!(
+            r#"{{"traceId":"{}","spanId":"{}","#,
+            self.trace_id, self.span_id
+        );
+        if let Some(parent_span_id) = &self.parent_span_id {
+            write!(res, r#""parentSpanId":"{}","#, parent_span_id).ok();
+        }
+        // Spans continuing the trace of a caller are server spans, all others internal ones.
+        let kind = if self.parent_span_id.is_some() && !self.local_parent {
+            2
+        } else {
+            1
+        };
+        let attributes = self
+            .attributes
+            .iter()
+            .map(|(key, value)| attribute(key, value))
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            res,
+            r#""name":{},"kind":{},"startTimeUnixNano":"{}","endTimeUnixNano":"{}","attributes":[{}]}}"#,
+            json_string(self.name),
+            kind,
+            unix_nanos(self.start),
+            unix_nanos(self.end),
+            attributes,
+        )
+        .ok();
+        res
+    }
+}
+
+impl Visit for SpanData {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field.name(), value.into())
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field.name(), format!("{:?}", value))
+    }
+}
+
+#[derive(Debug)]
+/// OtlpExporter sends the collected spans to the collector.
+pub struct OtlpExporter {
+    endpoint: Endpoint,
+    rx: Receiver<SpanData>,
+}
+
+impl OtlpExporter {
+    /// Export the collected spans periodically until the token gets cancelled.
+    pub async fn run(mut self, token: CancellationToken) {
+        let mut interval = time::interval(EXPORT_INTERVAL);
+        let mut batch = vec![];
+        loop {
+            let stop = tokio::select! {
+                _ = token.cancelled() => true,
+                _ = interval.tick() => false,
+                span = self.rx.recv() => match span {
+                    Some(span) => {
+                        batch.push(span);
+                        if batch.len() < BATCH_SIZE {
+                            continue;
+                        }
+                        false
+                    }
+                    None => true,
+                },
+            };
+            if stop {
+                while let Ok(span) = self.rx.try_recv() {
+                    batch.push(span);
+                }
+            }
+            for chunk in batch.chunks(BATCH_SIZE) {
+                if let Err(e) = self.export(chunk).await {
+                    error!("Unable to export {} spans: {:#}", chunk.len(), e);
+                }
+            }
+            batch.clear();
+            if stop {
+                return;
+            }
+        }
+    }
+
+    async fn export(&self, spans: &[SpanData]) -> Result<()> {
+        let spans = spans
+            .iter()
+            .map(SpanData::to_json)
+            .collect::<Vec<_>>()
+            .join(",");
+        let body = format!(
+            r#"{{"resourceSpans":[{{"resource":{{"attributes":[{}]}},"scopeSpans":[{{"scope":{{"name":"conmonrs"}},"spans":[{}]}}]}}]}}"#,
+            attribute("service.name", "conmonrs"),
+            spans
+        );
+        time::timeout(EXPORT_TIMEOUT, self.endpoint.post(&body))
+            .await
+            .context("timed out")?
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// The HTTP endpoint of the collector.
+struct Endpoint {
+    host: String,
+    address: String,
+    path: String,
+}
+
+impl Endpoint {
+    /// Parse an URL like `http://localhost:4318`, which uses the default path of the OTLP
+    /// traces if none is provided.
+    fn parse(url: &str) -> Result<Self> {
+        let rest = match url.strip_prefix("http://") {
+            Some(rest) => rest,
+            None => bail!(
+                "unsupported tracing endpoint {:?}, expected http://host:port",
+                url
+            ),
+        };
+        let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        if host.is_empty() {
+            bail!("no host in tracing endpoint {:?}", url)
+        }
+        let path = match path {
+            "" | "/" => "/v1/traces",
+            path => path,
+        };
+        let has_port = matches!(host.rsplit_once(':'), Some((_, port)) if !port.contains(']'));
+        let address = if has_port {
+            host.into()
+        } else {
+            format!("{}:80", host)
+        };
+        Ok(Self {
+            host: host.into(),
+            address,
+            path: path.into(),
+        })
+    }
+
+    async fn post(&self, body: &str) -> Result<()> {
+        let mut stream = TcpStream::connect(&self.address)
+            .await
+            .with_context(|| format!("connect to {}", self.address))?;
+        let request = format!(
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.path,
+            self.host,
+            body.len()
+        );
+        stream.write_all(request.as_bytes()).await?;
+        stream.write_all(body.as_bytes()).await?;
+
+        let mut response = vec![];
+        stream.read_to_end(&mut response).await?;
+        let response = String::from_utf8_lossy(&response);
+        let status_line = response.lines().next().unwrap_or_default();
+        match status_line.split_whitespace().nth(1) {
+            Some(status) if status.starts_with('2') => Ok(()),
+            _ => bail!("collector responded with {:?}", status_line),
+        }
+    }
+}
+
+/// Encode a string attribute in the OTLP JSON format.
+fn attribute(key: &str, value: &str) -> String {
+    format!(
+        r#"{{"key":{},"value":{{"stringValue":{}}}}}"#,
+        json_string(key),
+        json_string(value)
+    )
+}
+
+fn unix_nanos(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+    use tracing::{debug_span, field};
+    use tracing_subscriber::prelude::*;
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const SPAN_ID: &str = "00f067aa0ba902b7";
+
+    #[test]
+    fn endpoint_parse() -> Result<()> {
+        let sut = Endpoint::parse("http://localhost:4318")?;
+        assert_eq!(sut.address, "localhost:4318");
+        assert_eq!(sut.path, "/v1/traces");
+
+        let sut = Endpoint::parse("http://[::1]/custom")?;
+        assert_eq!(sut.address, "[::1]:80");
+        assert_eq!(sut.host, "[::1]");
+        assert_eq!(sut.path, "/custom");
+
+        assert!(Endpoint::parse("https://localhost:4318").is_err());
+        assert!(Endpoint::parse("http:///v1/traces").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn layer_export() -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let (layer, exporter) = otlp(&format!("http://{}", listener.local_addr()?))?;
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = debug_span!(
+                "request",
+                trace_id = field::Empty,
+                parent_span_id = field::Empty
+            );
+            span.record(TRACE_ID_FIELD, &TRACE_ID);
+            span.record(PARENT_SPAN_ID_FIELD, &SPAN_ID);
+            let _enter = span.enter();
+            debug_span!("child").in_scope(|| {});
+        });
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let export = tokio::spawn(exporter.run(token));
+        let (mut stream, _) = listener.accept().await?;
+        let mut request = vec![];
+        let mut buf = [0; 4096];
+        while !String::from_utf8_lossy(&request).ends_with("]}]}]}") {
+            let n = stream.read(&mut buf).await?;
+            assert!(n > 0);
+            request.extend_from_slice(&buf[..n]);
+        }
+        stream.write_all(b"HTTP/1.1 200 OK\r\n\r\n").await?;
+        drop(stream);
+        export.await?;
+
+        let request = String::from_utf8_lossy(&request);
+        assert!(request.starts_with("POST /v1/traces HTTP/1.1\r\n"));
+        assert_eq!(request.matches(TRACE_ID).count(), 3);
+        assert!(request.contains(&format!(
+            r#""parentSpanId":"{}","name":"request","kind":2"#,
+            SPAN_ID
+        )));
+        assert!(request.contains(r#""name":"child","kind":1"#));
+        Ok(())
+    }
+}
@@ -5,11 +5,16 @@ use crate::{
     container_io::{ContainerIO, Message, Pipe},
     container_log::SharedContainerLog,
     listener,
+    recording::SharedRecording,
 };
 use anyhow::{bail, format_err, Context, Result};
+use conmon_common::conmon_capnp::conmon::terminal_options;
 use getset::{Getters, MutGetters, Setters};
-use libc::{self, winsize, TIOCSWINSZ};
-use nix::sys::termios::{self, OutputFlags, SetArg};
+use libc::{self, winsize, TIOCGWINSZ, TIOCSWINSZ};
+use nix::{
+    sys::termios::{self, LocalFlags, OutputFlags, SetArg},
+    unistd,
+};
 use sendfd::RecvWithFd;
 use std::{
     io::{Error as IOError, ErrorKind},
@@ -22,10 +27,38 @@ use tokio::{
     io::{AsyncWriteExt, Interest},
     net::UnixStream,
     sync::mpsc::{self, Receiver, Sender, UnboundedReceiver, UnboundedSender},
-    task,
+    task::{self, JoinHandle},
 };
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, debug_span, error, trace, Instrument};
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// The initial settings of a terminal, which get applied as soon as it is connected.
+pub struct TerminalOptions {
+    /// The width of the terminal, which keeps the kernel default if zero.
+    pub width: u16,
+
+    /// The height of the terminal, which keeps the kernel default if zero.
+    pub height: u16,
+
+    /// Put the terminal into raw mode.
+    pub raw: bool,
+
+    /// Disable the echo of the terminal input.
+    pub disable_echo: bool,
+}
+
+impl From<terminal_options::Reader<'_>> for TerminalOptions {
+    fn from(reader: terminal_options::Reader) -> Self {
+        Self {
+            width: reader.get_width(),
+            height: reader.get_height(),
+            raw: reader.get_raw(),
+            disable_echo: reader.get_disable_echo(),
+        }
+    }
+}
+
 #[derive(Debug, Getters, MutGetters, Setters)]
 pub struct Terminal {
     #[getset(get = "pub")]
@@ -38,6 +71,14 @@ pub struct Terminal {
 
     #[getset(get, set)]
     tty: Option<RawFd>,
+
+    recording: SharedRecording,
+
+    options: TerminalOptions,
+
+    listener: JoinHandle<()>,
+
+    stdin_token: CancellationToken,
 }
 
 #[derive(Debug, Getters)]
@@ -53,20 +94,37 @@ struct Config {
 
     #[get]
     message_tx: UnboundedSender<Message>,
+
+    #[get]
+    recording: SharedRecording,
+
+    #[get]
+    options: TerminalOptions,
+
+    #[get]
+    stdin_token: CancellationToken,
 }
 
 impl Terminal {
     /// Setup a new terminal instance.
-    pub fn new(logger: SharedContainerLog, attach: SharedContainerAttach) -> Result<Self> {
+    pub fn new(
+        logger: SharedContainerLog,
+        attach: SharedContainerAttach,
+        recording: SharedRecording,
+        options: TerminalOptions,
+    ) -> Result<Self> {
         debug!("Creating new terminal");
         let path = ContainerIO::temp_file_name(None, "conmon-term-", ".sock")?;
         let path_clone = path.clone();
+        let recording_clone = recording.clone();
 
         let (ready_tx, ready_rx) = std::sync::mpsc::channel();
         let (connected_tx, connected_rx) = mpsc::channel(1);
         let (message_tx, message_rx) = mpsc::unbounded_channel();
+        let stdin_token = CancellationToken::new();
+        let stdin_token_clone = stdin_token.clone();
 
-        task::spawn(
+        let listener = task::spawn(
             async move {
                 if let Err(e) = Self::listen(
                     Config {
@@ -74,6 +132,9 @@ impl Terminal {
                         ready_tx,
                         connected_tx,
                         message_tx,
+                        recording: recording_clone,
+                        options,
+                        stdin_token: stdin_token_clone,
                     },
                     logger,
                     attach,
@@ -92,9 +153,19 @@ impl Terminal {
             connected_rx,
             message_rx,
             tty: None,
+            recording,
+            options,
+            listener,
+            stdin_token,
         })
     }
 
+    /// Setup a new terminal with the same settings and recording, because the console socket
+    /// only accepts a single connection. Used when the container gets restarted.
+    pub fn renew(&self, logger: SharedContainerLog, attach: SharedContainerAttach) -> Result<Self> {
+        Self::new(logger, attach, self.recording.clone(), self.options)
+    }
+
     /// Waits for the socket client to be connected.
     pub async fn wait_connected(&mut self) -> Result<()> {
         debug!("Waiting for terminal socket connection");
@@ -109,6 +180,22 @@ impl Terminal {
 
     /// Resize the terminal width and height.
     pub fn resize(&self, width: u16, height: u16) -> Result<()> {
+        Self::resize_fd(self.tty().context("terminal not connected")?, width, height)?;
+        self.recording.resize(width, height);
+        Ok(())
+    }
+
+    /// Retrieve the width and height of the terminal referred by the file descriptor.
+    pub fn size_fd(fd: RawFd) -> Result<(u16, u16)> {
+        let mut ws: winsize = unsafe { std::mem::zeroed() };
+        match unsafe { libc::ioctl(fd, TIOCGWINSZ, &mut ws) } {
+            0 => Ok((ws.ws_col, ws.ws_row)),
+            _ => Err(IOError::last_os_error().into()),
+        }
+    }
+
+    /// Resize the terminal referred by the file descriptor to the width and height.
+    pub fn resize_fd(fd: RawFd, width: u16, height: u16) -> Result<()> {
         debug!("Resizing terminal to width {} and height {}", width, height);
         let ws = winsize {
             ws_row: height,
@@ -116,18 +203,31 @@ impl Terminal {
             ws_xpixel: 0,
             ws_ypixel: 0,
         };
-        match unsafe {
-            libc::ioctl(
-                self.tty().context("terminal not connected")?,
-                TIOCSWINSZ,
-                &ws,
-            )
-        } {
+        match unsafe { libc::ioctl(fd, TIOCSWINSZ, &ws) } {
             0 => Ok(()),
             _ => Err(IOError::last_os_error().into()),
         }
     }
 
+    /// Apply the terminal settings to the terminal referred by the file descriptor.
+    fn configure(fd: RawFd, options: &TerminalOptions) -> Result<()> {
+        debug!("Changing terminal settings: {:?}", options);
+        let mut term = termios::tcgetattr(fd).context("get terminal attributes")?;
+        term.output_flags |= OutputFlags::ONLCR;
+        if options.raw {
+            termios::cfmakeraw(&mut term);
+        }
+        if options.disable_echo {
+            term.local_flags.remove(LocalFlags::ECHO);
+        }
+        termios::tcsetattr(fd, SetArg::TCSANOW, &term).context("set terminal attributes")?;
+
+        if options.width > 0 && options.height > 0 {
+            Self::resize_fd(fd, options.width, options.height).context("set initial size")?;
+        }
+        Ok(())
+    }
+
     async fn listen(
         config: Config,
         logger: SharedContainerLog,
@@ -185,12 +285,14 @@ impl Terminal {
                     debug!("Received terminal file descriptor");
                     let fd = fd_buffer[0];
 
-                    debug!("Changing terminal settings");
-                    let mut term = termios::tcgetattr(fd)?;
-                    term.output_flags |= OutputFlags::ONLCR;
-                    termios::tcsetattr(fd, SetArg::TCSANOW, &term)?;
+                    Self::configure(fd, config.options())?;
+
+                    let (width, height) = Self::size_fd(fd).unwrap_or_default();
+                    config.recording().start(width, height);
 
                     let attach_clone = attach.clone();
+                    let recording = config.recording.clone();
+                    let stdin_token = config.stdin_token.clone();
                     task::spawn(
                         async move {
                             config
@@ -204,24 +306,40 @@ impl Terminal {
                                 logger,
                                 config.message_tx,
                                 attach_clone,
+                                config.recording,
                             )
                             .await
                             {
                                 error!("Stdout read loop failure: {:#}", e)
                             }
+                            // The process exited, so its stdin must not consume the attach
+                            // input any more.
+                            config.stdin_token.cancel();
                             Ok::<_, anyhow::Error>(())
                         }
                         .instrument(debug_span!("read_loop")),
                     );
 
-                    task::spawn(
-                        async move {
-                            if let Err(e) = ContainerIO::read_loop_stdin(fd, attach).await {
-                                error!("Stdin read loop failure: {:#}", e);
+                    if attach.stdin() {
+                        // Use a duplicate to keep the terminal open if stdin gets closed.
+                        let stdin = unistd::dup(fd).context("duplicate terminal fd")?;
+                        task::spawn(
+                            async move {
+                                if let Err(e) = ContainerIO::read_loop_stdin(
+                                    stdin,
+                                    attach,
+                                    true,
+                                    recording,
+                                    stdin_token,
+                                )
+                                .await
+                                {
+                                    error!("Stdin read loop failure: {:#}", e);
+                                }
                             }
-                        }
-                        .instrument(debug_span!("read_loop_stdin")),
-                    );
+                            .instrument(debug_span!("read_loop_stdin")),
+                        );
+                    }
 
                     debug!("Shutting down listener thread");
                     return Ok(());
@@ -241,6 +359,10 @@ impl Terminal {
 
 impl Drop for Terminal {
     fn drop(&mut self) {
+        // Stop waiting for the runtime to connect, for example if the creation got aborted.
+        self.listener.abort();
+        // Stop forwarding the attach input, for example if the terminal got renewed.
+        self.stdin_token.cancel();
         if let Err(e) = std::fs::remove_file(self.path()) {
             trace!(
                 "Unable to remove socket file path {}: {}",
@@ -259,12 +381,19 @@ mod tests {
     use sendfd::SendWithFd;
     use std::os::unix::io::FromRawFd;
 
-    #[tokio::test]
+    // The terminal blocks until the listener is ready, which requires a second worker thread.
+    #[tokio::test(flavor = "multi_thread")]
     async fn new_success() -> Result<()> {
         let logger = ContainerLog::new();
         let attach = SharedContainerAttach::default();
+        let options = TerminalOptions {
+            width: 100,
+            height: 40,
+            raw: false,
+            disable_echo: true,
+        };
 
-        let mut sut = Terminal::new(logger, attach)?;
+        let mut sut = Terminal::new(logger, attach, Default::default(), options)?;
         assert!(sut.path().exists());
 
         let res = pty::openpty(None, None)?;
@@ -284,6 +413,11 @@ mod tests {
         sut.wait_connected().await?;
         assert!(!sut.path().exists());
 
+        assert_eq!(Terminal::size_fd(res.slave)?, (100, 40));
+        let term = termios::tcgetattr(res.master)?;
+        assert!(!term.local_flags.contains(LocalFlags::ECHO));
+        assert!(term.output_flags.contains(OutputFlags::ONLCR));
+
         // Write to the slave
         let mut file = unsafe { fs::File::from_raw_fd(res.slave) };
         file.write_all(b"test").await?;
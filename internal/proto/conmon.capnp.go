@@ -111,6 +111,134 @@ func (c Conmon) SetWindowSizeContainer(ctx context.Context, params func(Conmon_s
 	ans, release := c.Client.SendCall(ctx, s)
 	return Conmon_setWindowSizeContainer_Results_Future{Future: ans.Future()}, release
 }
+func (c Conmon) Shutdown(ctx context.Context, params func(Conmon_shutdown_Params) error) (Conmon_shutdown_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      6,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "shutdown",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_shutdown_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_shutdown_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ListContainers(ctx context.Context, params func(Conmon_listContainers_Params) error) (Conmon_listContainers_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      7,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "listContainers",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_listContainers_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_listContainers_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) KillContainer(ctx context.Context, params func(Conmon_killContainer_Params) error) (Conmon_killContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      8,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "killContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_killContainer_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_killContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) PortForward(ctx context.Context, params func(Conmon_portForward_Params) error) (Conmon_portForward_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      9,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "portForward",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_portForward_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_portForward_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) CreateNamespaces(ctx context.Context, params func(Conmon_createNamespaces_Params) error) (Conmon_createNamespaces_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      10,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "createNamespaces",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_createNamespaces_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_createNamespaces_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) RemoveNamespaces(ctx context.Context, params func(Conmon_removeNamespaces_Params) error) (Conmon_removeNamespaces_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      11,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "removeNamespaces",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_removeNamespaces_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_removeNamespaces_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) RegisterProbe(ctx context.Context, params func(Conmon_registerProbe_Params) error) (Conmon_registerProbe_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      12,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "registerProbe",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_registerProbe_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_registerProbe_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) SetLogLevel(ctx context.Context, params func(Conmon_setLogLevel_Params) error) (Conmon_setLogLevel_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      13,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "setLogLevel",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_setLogLevel_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_setLogLevel_Results_Future{Future: ans.Future()}, release
+}
 
 func (c Conmon) AddRef() Conmon {
 	return Conmon{
@@ -135,6 +263,22 @@ type Conmon_Server interface {
 	ReopenLogContainer(context.Context, Conmon_reopenLogContainer) error
 
 	SetWindowSizeContainer(context.Context, Conmon_setWindowSizeContainer) error
+
+	Shutdown(context.Context, Conmon_shutdown) error
+
+	ListContainers(context.Context, Conmon_listContainers) error
+
+	KillContainer(context.Context, Conmon_killContainer) error
+
+	PortForward(context.Context, Conmon_portForward) error
+
+	CreateNamespaces(context.Context, Conmon_createNamespaces) error
+
+	RemoveNamespaces(context.Context, Conmon_removeNamespaces) error
+
+	RegisterProbe(context.Context, Conmon_registerProbe) error
+
+	SetLogLevel(context.Context, Conmon_setLogLevel) error
 }
 
 // Conmon_NewServer creates a new Server from an implementation of Conmon_Server.
@@ -153,7 +297,7 @@ func Conmon_ServerToClient(s Conmon_Server, policy *server.Policy) Conmon {
 // This can be used to create a more complicated Server.
 func Conmon_Methods(methods []server.Method, s Conmon_Server) []server.Method {
 	if cap(methods) == 0 {
-		methods = make([]server.Method, 0, 6)
+		methods = make([]server.Method, 0, 14)
 	}
 
 	methods = append(methods, server.Method{
@@ -228,6 +372,102 @@ func Conmon_Methods(methods []server.Method, s Conmon_Server) []server.Method {
 		},
 	})
 
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      6,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "shutdown",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.Shutdown(ctx, Conmon_shutdown{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      7,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "listContainers",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ListContainers(ctx, Conmon_listContainers{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      8,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "killContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.KillContainer(ctx, Conmon_killContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      9,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "portForward",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.PortForward(ctx, Conmon_portForward{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      10,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "createNamespaces",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.CreateNamespaces(ctx, Conmon_createNamespaces{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      11,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "removeNamespaces",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.RemoveNamespaces(ctx, Conmon_removeNamespaces{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      12,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "registerProbe",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.RegisterProbe(ctx, Conmon_registerProbe{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      13,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "setLogLevel",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.SetLogLevel(ctx, Conmon_setLogLevel{call})
+		},
+	})
+
 	return methods
 }
 
@@ -333,101 +573,351 @@ func (c Conmon_setWindowSizeContainer) AllocResults() (Conmon_setWindowSizeConta
 	return Conmon_setWindowSizeContainer_Results{Struct: r}, err
 }
 
-type Conmon_VersionResponse struct{ capnp.Struct }
-
-// Conmon_VersionResponse_TypeID is the unique identifier for the type Conmon_VersionResponse.
-const Conmon_VersionResponse_TypeID = 0xf34be5cbac1feed1
-
-func NewConmon_VersionResponse(s *capnp.Segment) (Conmon_VersionResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
-	return Conmon_VersionResponse{st}, err
+// Conmon_shutdown holds the state for a server call to Conmon.shutdown.
+// See server.Call for documentation.
+type Conmon_shutdown struct {
+	*server.Call
 }
 
-func NewRootConmon_VersionResponse(s *capnp.Segment) (Conmon_VersionResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
-	return Conmon_VersionResponse{st}, err
+// Args returns the call's arguments.
+func (c Conmon_shutdown) Args() Conmon_shutdown_Params {
+	return Conmon_shutdown_Params{Struct: c.Call.Args()}
 }
 
-func ReadRootConmon_VersionResponse(msg *capnp.Message) (Conmon_VersionResponse, error) {
-	root, err := msg.Root()
-	return Conmon_VersionResponse{root.Struct()}, err
+// AllocResults allocates the results struct.
+func (c Conmon_shutdown) AllocResults() (Conmon_shutdown_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_shutdown_Results{Struct: r}, err
 }
 
-func (s Conmon_VersionResponse) String() string {
-	str, _ := text.Marshal(0xf34be5cbac1feed1, s.Struct)
-	return str
+// Conmon_listContainers holds the state for a server call to Conmon.listContainers.
+// See server.Call for documentation.
+type Conmon_listContainers struct {
+	*server.Call
 }
 
-func (s Conmon_VersionResponse) Version() (string, error) {
-	p, err := s.Struct.Ptr(0)
-	return p.Text(), err
+// Args returns the call's arguments.
+func (c Conmon_listContainers) Args() Conmon_listContainers_Params {
+	return Conmon_listContainers_Params{Struct: c.Call.Args()}
 }
 
-func (s Conmon_VersionResponse) HasVersion() bool {
-	return s.Struct.HasPtr(0)
+// AllocResults allocates the results struct.
+func (c Conmon_listContainers) AllocResults() (Conmon_listContainers_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Results{Struct: r}, err
 }
 
-func (s Conmon_VersionResponse) VersionBytes() ([]byte, error) {
-	p, err := s.Struct.Ptr(0)
-	return p.TextBytes(), err
+// Conmon_killContainer holds the state for a server call to Conmon.killContainer.
+// See server.Call for documentation.
+type Conmon_killContainer struct {
+	*server.Call
 }
 
-func (s Conmon_VersionResponse) SetVersion(v string) error {
-	return s.Struct.SetText(0, v)
+// Args returns the call's arguments.
+func (c Conmon_killContainer) Args() Conmon_killContainer_Params {
+	return Conmon_killContainer_Params{Struct: c.Call.Args()}
 }
 
-func (s Conmon_VersionResponse) Tag() (string, error) {
-	p, err := s.Struct.Ptr(1)
-	return p.Text(), err
+// AllocResults allocates the results struct.
+func (c Conmon_killContainer) AllocResults() (Conmon_killContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_killContainer_Results{Struct: r}, err
 }
 
-func (s Conmon_VersionResponse) HasTag() bool {
-	return s.Struct.HasPtr(1)
+// Conmon_portForward holds the state for a server call to Conmon.portForward.
+// See server.Call for documentation.
+type Conmon_portForward struct {
+	*server.Call
 }
 
-func (s Conmon_VersionResponse) TagBytes() ([]byte, error) {
-	p, err := s.Struct.Ptr(1)
-	return p.TextBytes(), err
+// Args returns the call's arguments.
+func (c Conmon_portForward) Args() Conmon_portForward_Params {
+	return Conmon_portForward_Params{Struct: c.Call.Args()}
 }
 
-func (s Conmon_VersionResponse) SetTag(v string) error {
-	return s.Struct.SetText(1, v)
+// AllocResults allocates the results struct.
+func (c Conmon_portForward) AllocResults() (Conmon_portForward_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_portForward_Results{Struct: r}, err
 }
 
-func (s Conmon_VersionResponse) Commit() (string, error) {
-	p, err := s.Struct.Ptr(2)
-	return p.Text(), err
+// Conmon_createNamespaces holds the state for a server call to Conmon.createNamespaces.
+// See server.Call for documentation.
+type Conmon_createNamespaces struct {
+	*server.Call
 }
 
-func (s Conmon_VersionResponse) HasCommit() bool {
-	return s.Struct.HasPtr(2)
+// Args returns the call's arguments.
+func (c Conmon_createNamespaces) Args() Conmon_createNamespaces_Params {
+	return Conmon_createNamespaces_Params{Struct: c.Call.Args()}
 }
 
-func (s Conmon_VersionResponse) CommitBytes() ([]byte, error) {
-	p, err := s.Struct.Ptr(2)
-	return p.TextBytes(), err
+// AllocResults allocates the results struct.
+func (c Conmon_createNamespaces) AllocResults() (Conmon_createNamespaces_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createNamespaces_Results{Struct: r}, err
 }
 
-func (s Conmon_VersionResponse) SetCommit(v string) error {
-	return s.Struct.SetText(2, v)
+// Conmon_removeNamespaces holds the state for a server call to Conmon.removeNamespaces.
+// See server.Call for documentation.
+type Conmon_removeNamespaces struct {
+	*server.Call
 }
 
-func (s Conmon_VersionResponse) BuildDate() (string, error) {
-	p, err := s.Struct.Ptr(3)
-	return p.Text(), err
+// Args returns the call's arguments.
+func (c Conmon_removeNamespaces) Args() Conmon_removeNamespaces_Params {
+	return Conmon_removeNamespaces_Params{Struct: c.Call.Args()}
 }
 
-func (s Conmon_VersionResponse) HasBuildDate() bool {
-	return s.Struct.HasPtr(3)
+// AllocResults allocates the results struct.
+func (c Conmon_removeNamespaces) AllocResults() (Conmon_removeNamespaces_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_removeNamespaces_Results{Struct: r}, err
 }
 
-func (s Conmon_VersionResponse) BuildDateBytes() ([]byte, error) {
-	p, err := s.Struct.Ptr(3)
-	return p.TextBytes(), err
+// Conmon_registerProbe holds the state for a server call to Conmon.registerProbe.
+// See server.Call for documentation.
+type Conmon_registerProbe struct {
+	*server.Call
 }
 
-func (s Conmon_VersionResponse) SetBuildDate(v string) error {
-	return s.Struct.SetText(3, v)
+// Args returns the call's arguments.
+func (c Conmon_registerProbe) Args() Conmon_registerProbe_Params {
+	return Conmon_registerProbe_Params{Struct: c.Call.Args()}
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_registerProbe) AllocResults() (Conmon_registerProbe_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_registerProbe_Results{Struct: r}, err
+}
+
+// Conmon_setLogLevel holds the state for a server call to Conmon.setLogLevel.
+// See server.Call for documentation.
+type Conmon_setLogLevel struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_setLogLevel) Args() Conmon_setLogLevel_Params {
+	return Conmon_setLogLevel_Params{Struct: c.Call.Args()}
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_setLogLevel) AllocResults() (Conmon_setLogLevel_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setLogLevel_Results{Struct: r}, err
+}
+
+type Conmon_Metadata struct{ capnp.Struct }
+
+// Conmon_Metadata_TypeID is the unique identifier for the type Conmon_Metadata.
+const Conmon_Metadata_TypeID = 0xa3f97cb4a8fd3ec3
+
+func NewConmon_Metadata(s *capnp.Segment) (Conmon_Metadata, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 4})
+	return Conmon_Metadata{st}, err
+}
+
+func NewRootConmon_Metadata(s *capnp.Segment) (Conmon_Metadata, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 4})
+	return Conmon_Metadata{st}, err
+}
+
+func ReadRootConmon_Metadata(msg *capnp.Message) (Conmon_Metadata, error) {
+	root, err := msg.Root()
+	return Conmon_Metadata{root.Struct()}, err
+}
+
+func (s Conmon_Metadata) String() string {
+	str, _ := text.Marshal(0xa3f97cb4a8fd3ec3, s.Struct)
+	return str
+}
+
+func (s Conmon_Metadata) TraceId() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_Metadata) HasTraceId() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_Metadata) TraceIdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Metadata) SetTraceId(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_Metadata) SpanId() (string, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_Metadata) HasSpanId() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_Metadata) SpanIdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Metadata) SetSpanId(v string) error {
+	return s.Struct.SetText(1, v)
+}
+
+func (s Conmon_Metadata) RequestId() (string, error) {
+	p, err := s.Struct.Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_Metadata) HasRequestId() bool {
+	return s.Struct.HasPtr(2)
+}
+
+func (s Conmon_Metadata) RequestIdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Metadata) SetRequestId(v string) error {
+	return s.Struct.SetText(2, v)
+}
+
+func (s Conmon_Metadata) CallerName() (string, error) {
+	p, err := s.Struct.Ptr(3)
+	return p.Text(), err
+}
+
+func (s Conmon_Metadata) HasCallerName() bool {
+	return s.Struct.HasPtr(3)
+}
+
+func (s Conmon_Metadata) CallerNameBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(3)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Metadata) SetCallerName(v string) error {
+	return s.Struct.SetText(3, v)
+}
+
+// Conmon_Metadata_List is a list of Conmon_Metadata.
+type Conmon_Metadata_List = capnp.StructList[Conmon_Metadata]
+
+// NewConmon_Metadata creates a new list of Conmon_Metadata.
+func NewConmon_Metadata_List(s *capnp.Segment, sz int32) (Conmon_Metadata_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 4}, sz)
+	return capnp.StructList[Conmon_Metadata]{l}, err
+}
+
+// Conmon_Metadata_Future is a wrapper for a Conmon_Metadata promised by a client call.
+type Conmon_Metadata_Future struct{ *capnp.Future }
+
+func (p Conmon_Metadata_Future) Struct() (Conmon_Metadata, error) {
+	s, err := p.Future.Struct()
+	return Conmon_Metadata{s}, err
+}
+
+type Conmon_VersionResponse struct{ capnp.Struct }
+
+// Conmon_VersionResponse_TypeID is the unique identifier for the type Conmon_VersionResponse.
+const Conmon_VersionResponse_TypeID = 0xf34be5cbac1feed1
+
+func NewConmon_VersionResponse(s *capnp.Segment) (Conmon_VersionResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5})
+	return Conmon_VersionResponse{st}, err
+}
+
+func NewRootConmon_VersionResponse(s *capnp.Segment) (Conmon_VersionResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5})
+	return Conmon_VersionResponse{st}, err
+}
+
+func ReadRootConmon_VersionResponse(msg *capnp.Message) (Conmon_VersionResponse, error) {
+	root, err := msg.Root()
+	return Conmon_VersionResponse{root.Struct()}, err
+}
+
+func (s Conmon_VersionResponse) String() string {
+	str, _ := text.Marshal(0xf34be5cbac1feed1, s.Struct)
+	return str
+}
+
+func (s Conmon_VersionResponse) Version() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_VersionResponse) HasVersion() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_VersionResponse) VersionBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_VersionResponse) SetVersion(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_VersionResponse) Tag() (string, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_VersionResponse) HasTag() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_VersionResponse) TagBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_VersionResponse) SetTag(v string) error {
+	return s.Struct.SetText(1, v)
+}
+
+func (s Conmon_VersionResponse) Commit() (string, error) {
+	p, err := s.Struct.Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_VersionResponse) HasCommit() bool {
+	return s.Struct.HasPtr(2)
+}
+
+func (s Conmon_VersionResponse) CommitBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_VersionResponse) SetCommit(v string) error {
+	return s.Struct.SetText(2, v)
+}
+
+func (s Conmon_VersionResponse) BuildDate() (string, error) {
+	p, err := s.Struct.Ptr(3)
+	return p.Text(), err
+}
+
+func (s Conmon_VersionResponse) HasBuildDate() bool {
+	return s.Struct.HasPtr(3)
+}
+
+func (s Conmon_VersionResponse) BuildDateBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(3)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_VersionResponse) SetBuildDate(v string) error {
+	return s.Struct.SetText(3, v)
 }
 
 func (s Conmon_VersionResponse) RustVersion() (string, error) {
@@ -456,12 +946,20 @@ func (s Conmon_VersionResponse) SetProcessId(v uint32) {
 	s.Struct.SetUint32(0, v)
 }
 
+func (s Conmon_VersionResponse) ReapedOrphans() uint64 {
+	return s.Struct.Uint64(8)
+}
+
+func (s Conmon_VersionResponse) SetReapedOrphans(v uint64) {
+	s.Struct.SetUint64(8, v)
+}
+
 // Conmon_VersionResponse_List is a list of Conmon_VersionResponse.
 type Conmon_VersionResponse_List = capnp.StructList[Conmon_VersionResponse]
 
 // NewConmon_VersionResponse creates a new list of Conmon_VersionResponse.
 func NewConmon_VersionResponse_List(s *capnp.Segment, sz int32) (Conmon_VersionResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5}, sz)
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5}, sz)
 	return capnp.StructList[Conmon_VersionResponse]{l}, err
 }
 
@@ -479,12 +977,12 @@ type Conmon_CreateContainerRequest struct{ capnp.Struct }
 const Conmon_CreateContainerRequest_TypeID = 0xba77e3fa3aa9b6ca
 
 func NewConmon_CreateContainerRequest(s *capnp.Segment) (Conmon_CreateContainerRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 9})
 	return Conmon_CreateContainerRequest{st}, err
 }
 
 func NewRootConmon_CreateContainerRequest(s *capnp.Segment) (Conmon_CreateContainerRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 9})
 	return Conmon_CreateContainerRequest{st}, err
 }
 
@@ -614,94 +1112,421 @@ func (s Conmon_CreateContainerRequest) NewLogDrivers(n int32) (Conmon_LogDriver_
 	return l, err
 }
 
-// Conmon_CreateContainerRequest_List is a list of Conmon_CreateContainerRequest.
-type Conmon_CreateContainerRequest_List = capnp.StructList[Conmon_CreateContainerRequest]
+func (s Conmon_CreateContainerRequest) Stdin() bool {
+	return s.Struct.Bit(1)
+}
 
-// NewConmon_CreateContainerRequest creates a new list of Conmon_CreateContainerRequest.
-func NewConmon_CreateContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_CreateContainerRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5}, sz)
-	return capnp.StructList[Conmon_CreateContainerRequest]{l}, err
+func (s Conmon_CreateContainerRequest) SetStdin(v bool) {
+	s.Struct.SetBit(1, v)
 }
 
-// Conmon_CreateContainerRequest_Future is a wrapper for a Conmon_CreateContainerRequest promised by a client call.
-type Conmon_CreateContainerRequest_Future struct{ *capnp.Future }
+func (s Conmon_CreateContainerRequest) StdinOnce() bool {
+	return s.Struct.Bit(2)
+}
 
-func (p Conmon_CreateContainerRequest_Future) Struct() (Conmon_CreateContainerRequest, error) {
-	s, err := p.Future.Struct()
-	return Conmon_CreateContainerRequest{s}, err
+func (s Conmon_CreateContainerRequest) SetStdinOnce(v bool) {
+	s.Struct.SetBit(2, v)
 }
 
-type Conmon_LogDriver struct{ capnp.Struct }
+func (s Conmon_CreateContainerRequest) RecordingPath() (string, error) {
+	p, err := s.Struct.Ptr(5)
+	return p.Text(), err
+}
 
-// Conmon_LogDriver_TypeID is the unique identifier for the type Conmon_LogDriver.
-const Conmon_LogDriver_TypeID = 0xae78ee8eb6b3a134
+func (s Conmon_CreateContainerRequest) HasRecordingPath() bool {
+	return s.Struct.HasPtr(5)
+}
 
-func NewConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
-	return Conmon_LogDriver{st}, err
+func (s Conmon_CreateContainerRequest) RecordingPathBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(5)
+	return p.TextBytes(), err
 }
 
-func NewRootConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
-	return Conmon_LogDriver{st}, err
+func (s Conmon_CreateContainerRequest) SetRecordingPath(v string) error {
+	return s.Struct.SetText(5, v)
 }
 
-func ReadRootConmon_LogDriver(msg *capnp.Message) (Conmon_LogDriver, error) {
-	root, err := msg.Root()
-	return Conmon_LogDriver{root.Struct()}, err
+func (s Conmon_CreateContainerRequest) RecordStdin() bool {
+	return s.Struct.Bit(3)
 }
 
-func (s Conmon_LogDriver) String() string {
-	str, _ := text.Marshal(0xae78ee8eb6b3a134, s.Struct)
-	return str
+func (s Conmon_CreateContainerRequest) SetRecordStdin(v bool) {
+	s.Struct.SetBit(3, v)
 }
 
-func (s Conmon_LogDriver) Type() Conmon_LogDriver_Type {
-	return Conmon_LogDriver_Type(s.Struct.Uint16(0))
+func (s Conmon_CreateContainerRequest) TerminalOptions() (Conmon_TerminalOptions, error) {
+	p, err := s.Struct.Ptr(6)
+	return Conmon_TerminalOptions{Struct: p.Struct()}, err
 }
 
-func (s Conmon_LogDriver) SetType(v Conmon_LogDriver_Type) {
-	s.Struct.SetUint16(0, uint16(v))
+func (s Conmon_CreateContainerRequest) HasTerminalOptions() bool {
+	return s.Struct.HasPtr(6)
 }
 
-func (s Conmon_LogDriver) Path() (string, error) {
-	p, err := s.Struct.Ptr(0)
-	return p.Text(), err
+func (s Conmon_CreateContainerRequest) SetTerminalOptions(v Conmon_TerminalOptions) error {
+	return s.Struct.SetPtr(6, v.Struct.ToPtr())
 }
 
-func (s Conmon_LogDriver) HasPath() bool {
-	return s.Struct.HasPtr(0)
+// NewTerminalOptions sets the terminalOptions field to a newly
+// allocated Conmon_TerminalOptions struct, preferring placement in s's segment.
+func (s Conmon_CreateContainerRequest) NewTerminalOptions() (Conmon_TerminalOptions, error) {
+	ss, err := NewConmon_TerminalOptions(s.Struct.Segment())
+	if err != nil {
+		return Conmon_TerminalOptions{}, err
+	}
+	err = s.Struct.SetPtr(6, ss.Struct.ToPtr())
+	return ss, err
 }
 
-func (s Conmon_LogDriver) PathBytes() ([]byte, error) {
-	p, err := s.Struct.Ptr(0)
-	return p.TextBytes(), err
+func (s Conmon_CreateContainerRequest) RestartPolicy() (Conmon_RestartPolicy, error) {
+	p, err := s.Struct.Ptr(7)
+	return Conmon_RestartPolicy{Struct: p.Struct()}, err
 }
 
-func (s Conmon_LogDriver) SetPath(v string) error {
-	return s.Struct.SetText(0, v)
+func (s Conmon_CreateContainerRequest) HasRestartPolicy() bool {
+	return s.Struct.HasPtr(7)
 }
 
-// Conmon_LogDriver_List is a list of Conmon_LogDriver.
-type Conmon_LogDriver_List = capnp.StructList[Conmon_LogDriver]
+func (s Conmon_CreateContainerRequest) SetRestartPolicy(v Conmon_RestartPolicy) error {
+	return s.Struct.SetPtr(7, v.Struct.ToPtr())
+}
 
-// NewConmon_LogDriver creates a new list of Conmon_LogDriver.
-func NewConmon_LogDriver_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_LogDriver]{l}, err
+// NewRestartPolicy sets the restartPolicy field to a newly
+// allocated Conmon_RestartPolicy struct, preferring placement in s's segment.
+func (s Conmon_CreateContainerRequest) NewRestartPolicy() (Conmon_RestartPolicy, error) {
+	ss, err := NewConmon_RestartPolicy(s.Struct.Segment())
+	if err != nil {
+		return Conmon_RestartPolicy{}, err
+	}
+	err = s.Struct.SetPtr(7, ss.Struct.ToPtr())
+	return ss, err
 }
 
-// Conmon_LogDriver_Future is a wrapper for a Conmon_LogDriver promised by a client call.
-type Conmon_LogDriver_Future struct{ *capnp.Future }
+func (s Conmon_CreateContainerRequest) TimeoutSec() uint64 {
+	return s.Struct.Uint64(8)
+}
 
-func (p Conmon_LogDriver_Future) Struct() (Conmon_LogDriver, error) {
-	s, err := p.Future.Struct()
-	return Conmon_LogDriver{s}, err
+func (s Conmon_CreateContainerRequest) SetTimeoutSec(v uint64) {
+	s.Struct.SetUint64(8, v)
 }
 
-type Conmon_LogDriver_Type uint16
+func (s Conmon_CreateContainerRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(8)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
 
-// Conmon_LogDriver_Type_TypeID is the unique identifier for the type Conmon_LogDriver_Type.
+func (s Conmon_CreateContainerRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(8)
+}
+
+func (s Conmon_CreateContainerRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(8, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_CreateContainerRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(8, ss.Struct.ToPtr())
+	return ss, err
+}
+
+func (s Conmon_CreateContainerRequest) ScrollbackSize() uint64 {
+	return s.Struct.Uint64(16)
+}
+
+func (s Conmon_CreateContainerRequest) SetScrollbackSize(v uint64) {
+	s.Struct.SetUint64(16, v)
+}
+
+// Conmon_CreateContainerRequest_List is a list of Conmon_CreateContainerRequest.
+type Conmon_CreateContainerRequest_List = capnp.StructList[Conmon_CreateContainerRequest]
+
+// NewConmon_CreateContainerRequest creates a new list of Conmon_CreateContainerRequest.
+func NewConmon_CreateContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_CreateContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 24, PointerCount: 9}, sz)
+	return capnp.StructList[Conmon_CreateContainerRequest]{l}, err
+}
+
+// Conmon_CreateContainerRequest_Future is a wrapper for a Conmon_CreateContainerRequest promised by a client call.
+type Conmon_CreateContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_CreateContainerRequest_Future) Struct() (Conmon_CreateContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_CreateContainerRequest{s}, err
+}
+
+func (p Conmon_CreateContainerRequest_Future) TerminalOptions() Conmon_TerminalOptions_Future {
+	return Conmon_TerminalOptions_Future{Future: p.Future.Field(6, nil)}
+}
+
+func (p Conmon_CreateContainerRequest_Future) RestartPolicy() Conmon_RestartPolicy_Future {
+	return Conmon_RestartPolicy_Future{Future: p.Future.Field(7, nil)}
+}
+
+func (p Conmon_CreateContainerRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(8, nil)}
+}
+
+type Conmon_RestartPolicy struct{ capnp.Struct }
+
+// Conmon_RestartPolicy_TypeID is the unique identifier for the type Conmon_RestartPolicy.
+const Conmon_RestartPolicy_TypeID = 0xab8d13e6952bdbff
+
+func NewConmon_RestartPolicy(s *capnp.Segment) (Conmon_RestartPolicy, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_RestartPolicy{st}, err
+}
+
+func NewRootConmon_RestartPolicy(s *capnp.Segment) (Conmon_RestartPolicy, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_RestartPolicy{st}, err
+}
+
+func ReadRootConmon_RestartPolicy(msg *capnp.Message) (Conmon_RestartPolicy, error) {
+	root, err := msg.Root()
+	return Conmon_RestartPolicy{root.Struct()}, err
+}
+
+func (s Conmon_RestartPolicy) String() string {
+	str, _ := text.Marshal(0xab8d13e6952bdbff, s.Struct)
+	return str
+}
+
+func (s Conmon_RestartPolicy) Mode() Conmon_RestartPolicy_Mode {
+	return Conmon_RestartPolicy_Mode(s.Struct.Uint16(0))
+}
+
+func (s Conmon_RestartPolicy) SetMode(v Conmon_RestartPolicy_Mode) {
+	s.Struct.SetUint16(0, uint16(v))
+}
+
+func (s Conmon_RestartPolicy) MaxRetries() uint32 {
+	return s.Struct.Uint32(4)
+}
+
+func (s Conmon_RestartPolicy) SetMaxRetries(v uint32) {
+	s.Struct.SetUint32(4, v)
+}
+
+// Conmon_RestartPolicy_List is a list of Conmon_RestartPolicy.
+type Conmon_RestartPolicy_List = capnp.StructList[Conmon_RestartPolicy]
+
+// NewConmon_RestartPolicy creates a new list of Conmon_RestartPolicy.
+func NewConmon_RestartPolicy_List(s *capnp.Segment, sz int32) (Conmon_RestartPolicy_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_RestartPolicy]{l}, err
+}
+
+// Conmon_RestartPolicy_Future is a wrapper for a Conmon_RestartPolicy promised by a client call.
+type Conmon_RestartPolicy_Future struct{ *capnp.Future }
+
+func (p Conmon_RestartPolicy_Future) Struct() (Conmon_RestartPolicy, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RestartPolicy{s}, err
+}
+
+type Conmon_RestartPolicy_Mode uint16
+
+// Conmon_RestartPolicy_Mode_TypeID is the unique identifier for the type Conmon_RestartPolicy_Mode.
+const Conmon_RestartPolicy_Mode_TypeID = 0xe902d14c78cd31a2
+
+// Values of Conmon_RestartPolicy_Mode.
+const (
+	Conmon_RestartPolicy_Mode_no        Conmon_RestartPolicy_Mode = 0
+	Conmon_RestartPolicy_Mode_onFailure Conmon_RestartPolicy_Mode = 1
+	Conmon_RestartPolicy_Mode_always    Conmon_RestartPolicy_Mode = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_RestartPolicy_Mode) String() string {
+	switch c {
+	case Conmon_RestartPolicy_Mode_no:
+		return "no"
+
+	case Conmon_RestartPolicy_Mode_onFailure:
+		return "onFailure"
+
+	case Conmon_RestartPolicy_Mode_always:
+		return "always"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_RestartPolicy_ModeFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_RestartPolicy_ModeFromString(c string) Conmon_RestartPolicy_Mode {
+	switch c {
+	case "no":
+		return Conmon_RestartPolicy_Mode_no
+
+	case "onFailure":
+		return Conmon_RestartPolicy_Mode_onFailure
+
+	case "always":
+		return Conmon_RestartPolicy_Mode_always
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_RestartPolicy_Mode_List = capnp.EnumList[Conmon_RestartPolicy_Mode]
+
+func NewConmon_RestartPolicy_Mode_List(s *capnp.Segment, sz int32) (Conmon_RestartPolicy_Mode_List, error) {
+	return capnp.NewEnumList[Conmon_RestartPolicy_Mode](s, sz)
+}
+
+type Conmon_TerminalOptions struct{ capnp.Struct }
+
+// Conmon_TerminalOptions_TypeID is the unique identifier for the type Conmon_TerminalOptions.
+const Conmon_TerminalOptions_TypeID = 0xd3fab29e13b163b2
+
+func NewConmon_TerminalOptions(s *capnp.Segment) (Conmon_TerminalOptions, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_TerminalOptions{st}, err
+}
+
+func NewRootConmon_TerminalOptions(s *capnp.Segment) (Conmon_TerminalOptions, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_TerminalOptions{st}, err
+}
+
+func ReadRootConmon_TerminalOptions(msg *capnp.Message) (Conmon_TerminalOptions, error) {
+	root, err := msg.Root()
+	return Conmon_TerminalOptions{root.Struct()}, err
+}
+
+func (s Conmon_TerminalOptions) String() string {
+	str, _ := text.Marshal(0xd3fab29e13b163b2, s.Struct)
+	return str
+}
+
+func (s Conmon_TerminalOptions) Width() uint16 {
+	return s.Struct.Uint16(0)
+}
+
+func (s Conmon_TerminalOptions) SetWidth(v uint16) {
+	s.Struct.SetUint16(0, v)
+}
+
+func (s Conmon_TerminalOptions) Height() uint16 {
+	return s.Struct.Uint16(2)
+}
+
+func (s Conmon_TerminalOptions) SetHeight(v uint16) {
+	s.Struct.SetUint16(2, v)
+}
+
+func (s Conmon_TerminalOptions) Raw() bool {
+	return s.Struct.Bit(32)
+}
+
+func (s Conmon_TerminalOptions) SetRaw(v bool) {
+	s.Struct.SetBit(32, v)
+}
+
+func (s Conmon_TerminalOptions) DisableEcho() bool {
+	return s.Struct.Bit(33)
+}
+
+func (s Conmon_TerminalOptions) SetDisableEcho(v bool) {
+	s.Struct.SetBit(33, v)
+}
+
+// Conmon_TerminalOptions_List is a list of Conmon_TerminalOptions.
+type Conmon_TerminalOptions_List = capnp.StructList[Conmon_TerminalOptions]
+
+// NewConmon_TerminalOptions creates a new list of Conmon_TerminalOptions.
+func NewConmon_TerminalOptions_List(s *capnp.Segment, sz int32) (Conmon_TerminalOptions_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_TerminalOptions]{l}, err
+}
+
+// Conmon_TerminalOptions_Future is a wrapper for a Conmon_TerminalOptions promised by a client call.
+type Conmon_TerminalOptions_Future struct{ *capnp.Future }
+
+func (p Conmon_TerminalOptions_Future) Struct() (Conmon_TerminalOptions, error) {
+	s, err := p.Future.Struct()
+	return Conmon_TerminalOptions{s}, err
+}
+
+type Conmon_LogDriver struct{ capnp.Struct }
+
+// Conmon_LogDriver_TypeID is the unique identifier for the type Conmon_LogDriver.
+const Conmon_LogDriver_TypeID = 0xae78ee8eb6b3a134
+
+func NewConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_LogDriver{st}, err
+}
+
+func NewRootConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_LogDriver{st}, err
+}
+
+func ReadRootConmon_LogDriver(msg *capnp.Message) (Conmon_LogDriver, error) {
+	root, err := msg.Root()
+	return Conmon_LogDriver{root.Struct()}, err
+}
+
+func (s Conmon_LogDriver) String() string {
+	str, _ := text.Marshal(0xae78ee8eb6b3a134, s.Struct)
+	return str
+}
+
+func (s Conmon_LogDriver) Type() Conmon_LogDriver_Type {
+	return Conmon_LogDriver_Type(s.Struct.Uint16(0))
+}
+
+func (s Conmon_LogDriver) SetType(v Conmon_LogDriver_Type) {
+	s.Struct.SetUint16(0, uint16(v))
+}
+
+func (s Conmon_LogDriver) Path() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_LogDriver) HasPath() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_LogDriver) PathBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_LogDriver) SetPath(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+// Conmon_LogDriver_List is a list of Conmon_LogDriver.
+type Conmon_LogDriver_List = capnp.StructList[Conmon_LogDriver]
+
+// NewConmon_LogDriver creates a new list of Conmon_LogDriver.
+func NewConmon_LogDriver_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_LogDriver]{l}, err
+}
+
+// Conmon_LogDriver_Future is a wrapper for a Conmon_LogDriver promised by a client call.
+type Conmon_LogDriver_Future struct{ *capnp.Future }
+
+func (p Conmon_LogDriver_Future) Struct() (Conmon_LogDriver, error) {
+	s, err := p.Future.Struct()
+	return Conmon_LogDriver{s}, err
+}
+
+type Conmon_LogDriver_Type uint16
+
+// Conmon_LogDriver_Type_TypeID is the unique identifier for the type Conmon_LogDriver_Type.
 const Conmon_LogDriver_Type_TypeID = 0xf026e3d750335bc1
 
 // Values of Conmon_LogDriver_Type.
@@ -794,12 +1619,12 @@ type Conmon_ExecSyncContainerRequest struct{ capnp.Struct }
 const Conmon_ExecSyncContainerRequest_TypeID = 0xf41122f890a371a6
 
 func NewConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 2})
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5})
 	return Conmon_ExecSyncContainerRequest{st}, err
 }
 
 func NewRootConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 2})
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5})
 	return Conmon_ExecSyncContainerRequest{st}, err
 }
 
@@ -871,12 +1696,78 @@ func (s Conmon_ExecSyncContainerRequest) SetTerminal(v bool) {
 	s.Struct.SetBit(64, v)
 }
 
+func (s Conmon_ExecSyncContainerRequest) RecordingPath() (string, error) {
+	p, err := s.Struct.Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasRecordingPath() bool {
+	return s.Struct.HasPtr(2)
+}
+
+func (s Conmon_ExecSyncContainerRequest) RecordingPathBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetRecordingPath(v string) error {
+	return s.Struct.SetText(2, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) TerminalOptions() (Conmon_TerminalOptions, error) {
+	p, err := s.Struct.Ptr(3)
+	return Conmon_TerminalOptions{Struct: p.Struct()}, err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasTerminalOptions() bool {
+	return s.Struct.HasPtr(3)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetTerminalOptions(v Conmon_TerminalOptions) error {
+	return s.Struct.SetPtr(3, v.Struct.ToPtr())
+}
+
+// NewTerminalOptions sets the terminalOptions field to a newly
+// allocated Conmon_TerminalOptions struct, preferring placement in s's segment.
+func (s Conmon_ExecSyncContainerRequest) NewTerminalOptions() (Conmon_TerminalOptions, error) {
+	ss, err := NewConmon_TerminalOptions(s.Struct.Segment())
+	if err != nil {
+		return Conmon_TerminalOptions{}, err
+	}
+	err = s.Struct.SetPtr(3, ss.Struct.ToPtr())
+	return ss, err
+}
+
+func (s Conmon_ExecSyncContainerRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(4)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(4)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(4, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_ExecSyncContainerRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(4, ss.Struct.ToPtr())
+	return ss, err
+}
+
 // Conmon_ExecSyncContainerRequest_List is a list of Conmon_ExecSyncContainerRequest.
 type Conmon_ExecSyncContainerRequest_List = capnp.StructList[Conmon_ExecSyncContainerRequest]
 
 // NewConmon_ExecSyncContainerRequest creates a new list of Conmon_ExecSyncContainerRequest.
 func NewConmon_ExecSyncContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_ExecSyncContainerRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 2}, sz)
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5}, sz)
 	return capnp.StructList[Conmon_ExecSyncContainerRequest]{l}, err
 }
 
@@ -888,6 +1779,14 @@ func (p Conmon_ExecSyncContainerRequest_Future) Struct() (Conmon_ExecSyncContain
 	return Conmon_ExecSyncContainerRequest{s}, err
 }
 
+func (p Conmon_ExecSyncContainerRequest_Future) TerminalOptions() Conmon_TerminalOptions_Future {
+	return Conmon_TerminalOptions_Future{Future: p.Future.Field(3, nil)}
+}
+
+func (p Conmon_ExecSyncContainerRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(4, nil)}
+}
+
 type Conmon_ExecSyncContainerResponse struct{ capnp.Struct }
 
 // Conmon_ExecSyncContainerResponse_TypeID is the unique identifier for the type Conmon_ExecSyncContainerResponse.
@@ -978,12 +1877,12 @@ type Conmon_AttachRequest struct{ capnp.Struct }
 const Conmon_AttachRequest_TypeID = 0xdf703ca0befc3afc
 
 func NewConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 6})
 	return Conmon_AttachRequest{st}, err
 }
 
 func NewRootConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 6})
 	return Conmon_AttachRequest{st}, err
 }
 
@@ -1051,12 +1950,88 @@ func (s Conmon_AttachRequest) SetExecSessionId(v string) error {
 	return s.Struct.SetText(2, v)
 }
 
-// Conmon_AttachRequest_List is a list of Conmon_AttachRequest.
+func (s Conmon_AttachRequest) ScrollbackSize() uint64 {
+	return s.Struct.Uint64(0)
+}
+
+func (s Conmon_AttachRequest) SetScrollbackSize(v uint64) {
+	s.Struct.SetUint64(0, v)
+}
+
+func (s Conmon_AttachRequest) DetachKeys() (string, error) {
+	p, err := s.Struct.Ptr(3)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachRequest) HasDetachKeys() bool {
+	return s.Struct.HasPtr(3)
+}
+
+func (s Conmon_AttachRequest) DetachKeysBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(3)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachRequest) SetDetachKeys(v string) error {
+	return s.Struct.SetText(3, v)
+}
+
+func (s Conmon_AttachRequest) SlowClientPolicy() Conmon_AttachRequest_SlowClientPolicy {
+	return Conmon_AttachRequest_SlowClientPolicy(s.Struct.Uint16(8))
+}
+
+func (s Conmon_AttachRequest) SetSlowClientPolicy(v Conmon_AttachRequest_SlowClientPolicy) {
+	s.Struct.SetUint16(8, uint16(v))
+}
+
+func (s Conmon_AttachRequest) OutputSink() Conmon_StreamSink {
+	p, _ := s.Struct.Ptr(4)
+	return Conmon_StreamSink{Client: p.Interface().Client()}
+}
+
+func (s Conmon_AttachRequest) HasOutputSink() bool {
+	return s.Struct.HasPtr(4)
+}
+
+func (s Conmon_AttachRequest) SetOutputSink(v Conmon_StreamSink) error {
+	if !v.Client.IsValid() {
+		return s.Struct.SetPtr(4, capnp.Ptr{})
+	}
+	seg := s.Segment()
+	in := capnp.NewInterface(seg, seg.Message().AddCap(v.Client))
+	return s.Struct.SetPtr(4, in.ToPtr())
+}
+
+func (s Conmon_AttachRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(5)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_AttachRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(5)
+}
+
+func (s Conmon_AttachRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(5, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_AttachRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(5, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_AttachRequest_List is a list of Conmon_AttachRequest.
 type Conmon_AttachRequest_List = capnp.StructList[Conmon_AttachRequest]
 
 // NewConmon_AttachRequest creates a new list of Conmon_AttachRequest.
 func NewConmon_AttachRequest_List(s *capnp.Segment, sz int32) (Conmon_AttachRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3}, sz)
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 6}, sz)
 	return capnp.StructList[Conmon_AttachRequest]{l}, err
 }
 
@@ -1068,18 +2043,79 @@ func (p Conmon_AttachRequest_Future) Struct() (Conmon_AttachRequest, error) {
 	return Conmon_AttachRequest{s}, err
 }
 
+func (p Conmon_AttachRequest_Future) OutputSink() Conmon_StreamSink {
+	return Conmon_StreamSink{Client: p.Future.Field(4, nil).Client()}
+}
+
+func (p Conmon_AttachRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(5, nil)}
+}
+
+type Conmon_AttachRequest_SlowClientPolicy uint16
+
+// Conmon_AttachRequest_SlowClientPolicy_TypeID is the unique identifier for the type Conmon_AttachRequest_SlowClientPolicy.
+const Conmon_AttachRequest_SlowClientPolicy_TypeID = 0x8114bbd158364c30
+
+// Values of Conmon_AttachRequest_SlowClientPolicy.
+const (
+	Conmon_AttachRequest_SlowClientPolicy_drop       Conmon_AttachRequest_SlowClientPolicy = 0
+	Conmon_AttachRequest_SlowClientPolicy_block      Conmon_AttachRequest_SlowClientPolicy = 1
+	Conmon_AttachRequest_SlowClientPolicy_disconnect Conmon_AttachRequest_SlowClientPolicy = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_AttachRequest_SlowClientPolicy) String() string {
+	switch c {
+	case Conmon_AttachRequest_SlowClientPolicy_drop:
+		return "drop"
+
+	case Conmon_AttachRequest_SlowClientPolicy_block:
+		return "block"
+
+	case Conmon_AttachRequest_SlowClientPolicy_disconnect:
+		return "disconnect"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_AttachRequest_SlowClientPolicyFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_AttachRequest_SlowClientPolicyFromString(c string) Conmon_AttachRequest_SlowClientPolicy {
+	switch c {
+	case "drop":
+		return Conmon_AttachRequest_SlowClientPolicy_drop
+
+	case "block":
+		return Conmon_AttachRequest_SlowClientPolicy_block
+
+	case "disconnect":
+		return Conmon_AttachRequest_SlowClientPolicy_disconnect
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_AttachRequest_SlowClientPolicy_List = capnp.EnumList[Conmon_AttachRequest_SlowClientPolicy]
+
+func NewConmon_AttachRequest_SlowClientPolicy_List(s *capnp.Segment, sz int32) (Conmon_AttachRequest_SlowClientPolicy_List, error) {
+	return capnp.NewEnumList[Conmon_AttachRequest_SlowClientPolicy](s, sz)
+}
+
 type Conmon_AttachResponse struct{ capnp.Struct }
 
 // Conmon_AttachResponse_TypeID is the unique identifier for the type Conmon_AttachResponse.
 const Conmon_AttachResponse_TypeID = 0xace5517aafc86077
 
 func NewConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
 	return Conmon_AttachResponse{st}, err
 }
 
 func NewRootConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
 	return Conmon_AttachResponse{st}, err
 }
 
@@ -1093,12 +2129,30 @@ func (s Conmon_AttachResponse) String() string {
 	return str
 }
 
+func (s Conmon_AttachResponse) StdinSink() Conmon_StreamSink {
+	p, _ := s.Struct.Ptr(0)
+	return Conmon_StreamSink{Client: p.Interface().Client()}
+}
+
+func (s Conmon_AttachResponse) HasStdinSink() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_AttachResponse) SetStdinSink(v Conmon_StreamSink) error {
+	if !v.Client.IsValid() {
+		return s.Struct.SetPtr(0, capnp.Ptr{})
+	}
+	seg := s.Segment()
+	in := capnp.NewInterface(seg, seg.Message().AddCap(v.Client))
+	return s.Struct.SetPtr(0, in.ToPtr())
+}
+
 // Conmon_AttachResponse_List is a list of Conmon_AttachResponse.
 type Conmon_AttachResponse_List = capnp.StructList[Conmon_AttachResponse]
 
 // NewConmon_AttachResponse creates a new list of Conmon_AttachResponse.
 func NewConmon_AttachResponse_List(s *capnp.Segment, sz int32) (Conmon_AttachResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
 	return capnp.StructList[Conmon_AttachResponse]{l}, err
 }
 
@@ -1110,1189 +2164,4937 @@ func (p Conmon_AttachResponse_Future) Struct() (Conmon_AttachResponse, error) {
 	return Conmon_AttachResponse{s}, err
 }
 
-type Conmon_ReopenLogRequest struct{ capnp.Struct }
+func (p Conmon_AttachResponse_Future) StdinSink() Conmon_StreamSink {
+	return Conmon_StreamSink{Client: p.Future.Field(0, nil).Client()}
+}
 
-// Conmon_ReopenLogRequest_TypeID is the unique identifier for the type Conmon_ReopenLogRequest.
-const Conmon_ReopenLogRequest_TypeID = 0xd0476e0f34d1411a
+type Conmon_StreamSink struct{ Client *capnp.Client }
 
-func NewConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_ReopenLogRequest{st}, err
+// Conmon_StreamSink_TypeID is the unique identifier for the type Conmon_StreamSink.
+const Conmon_StreamSink_TypeID = 0xdd3efd1b219eb35a
+
+func (c Conmon_StreamSink) Write(ctx context.Context, params func(Conmon_StreamSink_write_Params) error) (Conmon_StreamSink_write_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xdd3efd1b219eb35a,
+			MethodID:      0,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon.StreamSink",
+			MethodName:    "write",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 8, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_StreamSink_write_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_StreamSink_write_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon_StreamSink) Close(ctx context.Context, params func(Conmon_StreamSink_close_Params) error) (Conmon_StreamSink_close_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xdd3efd1b219eb35a,
+			MethodID:      1,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon.StreamSink",
+			MethodName:    "close",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 0}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_StreamSink_close_Params{Struct: s}) }
+	}
+	ans, release := c.Client.SendCall(ctx, s)
+	return Conmon_StreamSink_close_Results_Future{Future: ans.Future()}, release
 }
 
-func NewRootConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_ReopenLogRequest{st}, err
+func (c Conmon_StreamSink) AddRef() Conmon_StreamSink {
+	return Conmon_StreamSink{
+		Client: c.Client.AddRef(),
+	}
 }
 
-func ReadRootConmon_ReopenLogRequest(msg *capnp.Message) (Conmon_ReopenLogRequest, error) {
+func (c Conmon_StreamSink) Release() {
+	c.Client.Release()
+}
+
+// A Conmon_StreamSink_Server is a Conmon_StreamSink with a local implementation.
+type Conmon_StreamSink_Server interface {
+	Write(context.Context, Conmon_StreamSink_write) error
+
+	Close(context.Context, Conmon_StreamSink_close) error
+}
+
+// Conmon_StreamSink_NewServer creates a new Server from an implementation of Conmon_StreamSink_Server.
+func Conmon_StreamSink_NewServer(s Conmon_StreamSink_Server, policy *server.Policy) *server.Server {
+	c, _ := s.(server.Shutdowner)
+	return server.New(Conmon_StreamSink_Methods(nil, s), s, c, policy)
+}
+
+// Conmon_StreamSink_ServerToClient creates a new Client from an implementation of Conmon_StreamSink_Server.
+// The caller is responsible for calling Release on the returned Client.
+func Conmon_StreamSink_ServerToClient(s Conmon_StreamSink_Server, policy *server.Policy) Conmon_StreamSink {
+	return Conmon_StreamSink{Client: capnp.NewClient(Conmon_StreamSink_NewServer(s, policy))}
+}
+
+// Conmon_StreamSink_Methods appends Methods to a slice that invoke the methods on s.
+// This can be used to create a more complicated Server.
+func Conmon_StreamSink_Methods(methods []server.Method, s Conmon_StreamSink_Server) []server.Method {
+	if cap(methods) == 0 {
+		methods = make([]server.Method, 0, 2)
+	}
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xdd3efd1b219eb35a,
+			MethodID:      0,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon.StreamSink",
+			MethodName:    "write",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.Write(ctx, Conmon_StreamSink_write{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xdd3efd1b219eb35a,
+			MethodID:      1,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon.StreamSink",
+			MethodName:    "close",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.Close(ctx, Conmon_StreamSink_close{call})
+		},
+	})
+
+	return methods
+}
+
+// Conmon_StreamSink_write holds the state for a server call to Conmon_StreamSink.write.
+// See server.Call for documentation.
+type Conmon_StreamSink_write struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_StreamSink_write) Args() Conmon_StreamSink_write_Params {
+	return Conmon_StreamSink_write_Params{Struct: c.Call.Args()}
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_StreamSink_write) AllocResults() (Conmon_StreamSink_write_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_StreamSink_write_Results{Struct: r}, err
+}
+
+// Conmon_StreamSink_close holds the state for a server call to Conmon_StreamSink.close.
+// See server.Call for documentation.
+type Conmon_StreamSink_close struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_StreamSink_close) Args() Conmon_StreamSink_close_Params {
+	return Conmon_StreamSink_close_Params{Struct: c.Call.Args()}
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_StreamSink_close) AllocResults() (Conmon_StreamSink_close_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_StreamSink_close_Results{Struct: r}, err
+}
+
+type Conmon_StreamSink_Pipe uint16
+
+// Conmon_StreamSink_Pipe_TypeID is the unique identifier for the type Conmon_StreamSink_Pipe.
+const Conmon_StreamSink_Pipe_TypeID = 0xa8e923c2fa0576b2
+
+// Values of Conmon_StreamSink_Pipe.
+const (
+	Conmon_StreamSink_Pipe_stdin  Conmon_StreamSink_Pipe = 0
+	Conmon_StreamSink_Pipe_stdout Conmon_StreamSink_Pipe = 1
+	Conmon_StreamSink_Pipe_stderr Conmon_StreamSink_Pipe = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_StreamSink_Pipe) String() string {
+	switch c {
+	case Conmon_StreamSink_Pipe_stdin:
+		return "stdin"
+
+	case Conmon_StreamSink_Pipe_stdout:
+		return "stdout"
+
+	case Conmon_StreamSink_Pipe_stderr:
+		return "stderr"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_StreamSink_PipeFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_StreamSink_PipeFromString(c string) Conmon_StreamSink_Pipe {
+	switch c {
+	case "stdin":
+		return Conmon_StreamSink_Pipe_stdin
+
+	case "stdout":
+		return Conmon_StreamSink_Pipe_stdout
+
+	case "stderr":
+		return Conmon_StreamSink_Pipe_stderr
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_StreamSink_Pipe_List = capnp.EnumList[Conmon_StreamSink_Pipe]
+
+func NewConmon_StreamSink_Pipe_List(s *capnp.Segment, sz int32) (Conmon_StreamSink_Pipe_List, error) {
+	return capnp.NewEnumList[Conmon_StreamSink_Pipe](s, sz)
+}
+
+type Conmon_StreamSink_write_Params struct{ capnp.Struct }
+
+// Conmon_StreamSink_write_Params_TypeID is the unique identifier for the type Conmon_StreamSink_write_Params.
+const Conmon_StreamSink_write_Params_TypeID = 0xce5d492153adfc07
+
+func NewConmon_StreamSink_write_Params(s *capnp.Segment) (Conmon_StreamSink_write_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_StreamSink_write_Params{st}, err
+}
+
+func NewRootConmon_StreamSink_write_Params(s *capnp.Segment) (Conmon_StreamSink_write_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_StreamSink_write_Params{st}, err
+}
+
+func ReadRootConmon_StreamSink_write_Params(msg *capnp.Message) (Conmon_StreamSink_write_Params, error) {
 	root, err := msg.Root()
-	return Conmon_ReopenLogRequest{root.Struct()}, err
+	return Conmon_StreamSink_write_Params{root.Struct()}, err
 }
 
-func (s Conmon_ReopenLogRequest) String() string {
-	str, _ := text.Marshal(0xd0476e0f34d1411a, s.Struct)
+func (s Conmon_StreamSink_write_Params) String() string {
+	str, _ := text.Marshal(0xce5d492153adfc07, s.Struct)
 	return str
 }
 
-func (s Conmon_ReopenLogRequest) Id() (string, error) {
-	p, err := s.Struct.Ptr(0)
-	return p.Text(), err
+func (s Conmon_StreamSink_write_Params) Pipe() Conmon_StreamSink_Pipe {
+	return Conmon_StreamSink_Pipe(s.Struct.Uint16(0))
 }
 
-func (s Conmon_ReopenLogRequest) HasId() bool {
-	return s.Struct.HasPtr(0)
+func (s Conmon_StreamSink_write_Params) SetPipe(v Conmon_StreamSink_Pipe) {
+	s.Struct.SetUint16(0, uint16(v))
 }
 
-func (s Conmon_ReopenLogRequest) IdBytes() ([]byte, error) {
+func (s Conmon_StreamSink_write_Params) Data() ([]byte, error) {
 	p, err := s.Struct.Ptr(0)
-	return p.TextBytes(), err
+	return []byte(p.Data()), err
 }
 
-func (s Conmon_ReopenLogRequest) SetId(v string) error {
-	return s.Struct.SetText(0, v)
+func (s Conmon_StreamSink_write_Params) HasData() bool {
+	return s.Struct.HasPtr(0)
 }
 
-// Conmon_ReopenLogRequest_List is a list of Conmon_ReopenLogRequest.
-type Conmon_ReopenLogRequest_List = capnp.StructList[Conmon_ReopenLogRequest]
+func (s Conmon_StreamSink_write_Params) SetData(v []byte) error {
+	return s.Struct.SetData(0, v)
+}
 
-// NewConmon_ReopenLogRequest creates a new list of Conmon_ReopenLogRequest.
-func NewConmon_ReopenLogRequest_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_ReopenLogRequest]{l}, err
+// Conmon_StreamSink_write_Params_List is a list of Conmon_StreamSink_write_Params.
+type Conmon_StreamSink_write_Params_List = capnp.StructList[Conmon_StreamSink_write_Params]
+
+// NewConmon_StreamSink_write_Params creates a new list of Conmon_StreamSink_write_Params.
+func NewConmon_StreamSink_write_Params_List(s *capnp.Segment, sz int32) (Conmon_StreamSink_write_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_StreamSink_write_Params]{l}, err
 }
 
-// Conmon_ReopenLogRequest_Future is a wrapper for a Conmon_ReopenLogRequest promised by a client call.
-type Conmon_ReopenLogRequest_Future struct{ *capnp.Future }
+// Conmon_StreamSink_write_Params_Future is a wrapper for a Conmon_StreamSink_write_Params promised by a client call.
+type Conmon_StreamSink_write_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_ReopenLogRequest_Future) Struct() (Conmon_ReopenLogRequest, error) {
+func (p Conmon_StreamSink_write_Params_Future) Struct() (Conmon_StreamSink_write_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_ReopenLogRequest{s}, err
+	return Conmon_StreamSink_write_Params{s}, err
 }
 
-type Conmon_ReopenLogResponse struct{ capnp.Struct }
+type Conmon_StreamSink_write_Results struct{ capnp.Struct }
 
-// Conmon_ReopenLogResponse_TypeID is the unique identifier for the type Conmon_ReopenLogResponse.
-const Conmon_ReopenLogResponse_TypeID = 0xa20f49456be85b99
+// Conmon_StreamSink_write_Results_TypeID is the unique identifier for the type Conmon_StreamSink_write_Results.
+const Conmon_StreamSink_write_Results_TypeID = 0xda7ceb31aea59293
 
-func NewConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
+func NewConmon_StreamSink_write_Results(s *capnp.Segment) (Conmon_StreamSink_write_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_ReopenLogResponse{st}, err
+	return Conmon_StreamSink_write_Results{st}, err
+}
+
+func NewRootConmon_StreamSink_write_Results(s *capnp.Segment) (Conmon_StreamSink_write_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_StreamSink_write_Results{st}, err
+}
+
+func ReadRootConmon_StreamSink_write_Results(msg *capnp.Message) (Conmon_StreamSink_write_Results, error) {
+	root, err := msg.Root()
+	return Conmon_StreamSink_write_Results{root.Struct()}, err
+}
+
+func (s Conmon_StreamSink_write_Results) String() string {
+	str, _ := text.Marshal(0xda7ceb31aea59293, s.Struct)
+	return str
+}
+
+// Conmon_StreamSink_write_Results_List is a list of Conmon_StreamSink_write_Results.
+type Conmon_StreamSink_write_Results_List = capnp.StructList[Conmon_StreamSink_write_Results]
+
+// NewConmon_StreamSink_write_Results creates a new list of Conmon_StreamSink_write_Results.
+func NewConmon_StreamSink_write_Results_List(s *capnp.Segment, sz int32) (Conmon_StreamSink_write_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_StreamSink_write_Results]{l}, err
+}
+
+// Conmon_StreamSink_write_Results_Future is a wrapper for a Conmon_StreamSink_write_Results promised by a client call.
+type Conmon_StreamSink_write_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_StreamSink_write_Results_Future) Struct() (Conmon_StreamSink_write_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_StreamSink_write_Results{s}, err
+}
+
+type Conmon_StreamSink_close_Params struct{ capnp.Struct }
+
+// Conmon_StreamSink_close_Params_TypeID is the unique identifier for the type Conmon_StreamSink_close_Params.
+const Conmon_StreamSink_close_Params_TypeID = 0xce1d80a5a8c514d6
+
+func NewConmon_StreamSink_close_Params(s *capnp.Segment) (Conmon_StreamSink_close_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_StreamSink_close_Params{st}, err
+}
+
+func NewRootConmon_StreamSink_close_Params(s *capnp.Segment) (Conmon_StreamSink_close_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_StreamSink_close_Params{st}, err
+}
+
+func ReadRootConmon_StreamSink_close_Params(msg *capnp.Message) (Conmon_StreamSink_close_Params, error) {
+	root, err := msg.Root()
+	return Conmon_StreamSink_close_Params{root.Struct()}, err
+}
+
+func (s Conmon_StreamSink_close_Params) String() string {
+	str, _ := text.Marshal(0xce1d80a5a8c514d6, s.Struct)
+	return str
+}
+
+// Conmon_StreamSink_close_Params_List is a list of Conmon_StreamSink_close_Params.
+type Conmon_StreamSink_close_Params_List = capnp.StructList[Conmon_StreamSink_close_Params]
+
+// NewConmon_StreamSink_close_Params creates a new list of Conmon_StreamSink_close_Params.
+func NewConmon_StreamSink_close_Params_List(s *capnp.Segment, sz int32) (Conmon_StreamSink_close_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_StreamSink_close_Params]{l}, err
+}
+
+// Conmon_StreamSink_close_Params_Future is a wrapper for a Conmon_StreamSink_close_Params promised by a client call.
+type Conmon_StreamSink_close_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_StreamSink_close_Params_Future) Struct() (Conmon_StreamSink_close_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_StreamSink_close_Params{s}, err
+}
+
+type Conmon_StreamSink_close_Results struct{ capnp.Struct }
+
+// Conmon_StreamSink_close_Results_TypeID is the unique identifier for the type Conmon_StreamSink_close_Results.
+const Conmon_StreamSink_close_Results_TypeID = 0xe1f2dc9c7f38d49a
+
+func NewConmon_StreamSink_close_Results(s *capnp.Segment) (Conmon_StreamSink_close_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_StreamSink_close_Results{st}, err
+}
+
+func NewRootConmon_StreamSink_close_Results(s *capnp.Segment) (Conmon_StreamSink_close_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_StreamSink_close_Results{st}, err
+}
+
+func ReadRootConmon_StreamSink_close_Results(msg *capnp.Message) (Conmon_StreamSink_close_Results, error) {
+	root, err := msg.Root()
+	return Conmon_StreamSink_close_Results{root.Struct()}, err
+}
+
+func (s Conmon_StreamSink_close_Results) String() string {
+	str, _ := text.Marshal(0xe1f2dc9c7f38d49a, s.Struct)
+	return str
+}
+
+// Conmon_StreamSink_close_Results_List is a list of Conmon_StreamSink_close_Results.
+type Conmon_StreamSink_close_Results_List = capnp.StructList[Conmon_StreamSink_close_Results]
+
+// NewConmon_StreamSink_close_Results creates a new list of Conmon_StreamSink_close_Results.
+func NewConmon_StreamSink_close_Results_List(s *capnp.Segment, sz int32) (Conmon_StreamSink_close_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_StreamSink_close_Results]{l}, err
+}
+
+// Conmon_StreamSink_close_Results_Future is a wrapper for a Conmon_StreamSink_close_Results promised by a client call.
+type Conmon_StreamSink_close_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_StreamSink_close_Results_Future) Struct() (Conmon_StreamSink_close_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_StreamSink_close_Results{s}, err
+}
+
+type Conmon_ReopenLogRequest struct{ capnp.Struct }
+
+// Conmon_ReopenLogRequest_TypeID is the unique identifier for the type Conmon_ReopenLogRequest.
+const Conmon_ReopenLogRequest_TypeID = 0xd0476e0f34d1411a
+
+func NewConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ReopenLogRequest{st}, err
+}
+
+func NewRootConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ReopenLogRequest{st}, err
+}
+
+func ReadRootConmon_ReopenLogRequest(msg *capnp.Message) (Conmon_ReopenLogRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ReopenLogRequest{root.Struct()}, err
+}
+
+func (s Conmon_ReopenLogRequest) String() string {
+	str, _ := text.Marshal(0xd0476e0f34d1411a, s.Struct)
+	return str
+}
+
+func (s Conmon_ReopenLogRequest) Id() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ReopenLogRequest) HasId() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_ReopenLogRequest) IdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReopenLogRequest) SetId(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_ReopenLogRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(1)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_ReopenLogRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_ReopenLogRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(1, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_ReopenLogRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(1, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_ReopenLogRequest_List is a list of Conmon_ReopenLogRequest.
+type Conmon_ReopenLogRequest_List = capnp.StructList[Conmon_ReopenLogRequest]
+
+// NewConmon_ReopenLogRequest creates a new list of Conmon_ReopenLogRequest.
+func NewConmon_ReopenLogRequest_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_ReopenLogRequest]{l}, err
+}
+
+// Conmon_ReopenLogRequest_Future is a wrapper for a Conmon_ReopenLogRequest promised by a client call.
+type Conmon_ReopenLogRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ReopenLogRequest_Future) Struct() (Conmon_ReopenLogRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ReopenLogRequest{s}, err
+}
+
+func (p Conmon_ReopenLogRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(1, nil)}
+}
+
+type Conmon_ReopenLogResponse struct{ capnp.Struct }
+
+// Conmon_ReopenLogResponse_TypeID is the unique identifier for the type Conmon_ReopenLogResponse.
+const Conmon_ReopenLogResponse_TypeID = 0xa20f49456be85b99
+
+func NewConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ReopenLogResponse{st}, err
+}
+
+func NewRootConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ReopenLogResponse{st}, err
+}
+
+func ReadRootConmon_ReopenLogResponse(msg *capnp.Message) (Conmon_ReopenLogResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ReopenLogResponse{root.Struct()}, err
+}
+
+func (s Conmon_ReopenLogResponse) String() string {
+	str, _ := text.Marshal(0xa20f49456be85b99, s.Struct)
+	return str
+}
+
+// Conmon_ReopenLogResponse_List is a list of Conmon_ReopenLogResponse.
+type Conmon_ReopenLogResponse_List = capnp.StructList[Conmon_ReopenLogResponse]
+
+// NewConmon_ReopenLogResponse creates a new list of Conmon_ReopenLogResponse.
+func NewConmon_ReopenLogResponse_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ReopenLogResponse]{l}, err
+}
+
+// Conmon_ReopenLogResponse_Future is a wrapper for a Conmon_ReopenLogResponse promised by a client call.
+type Conmon_ReopenLogResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ReopenLogResponse_Future) Struct() (Conmon_ReopenLogResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ReopenLogResponse{s}, err
+}
+
+type Conmon_SetWindowSizeRequest struct{ capnp.Struct }
+
+// Conmon_SetWindowSizeRequest_TypeID is the unique identifier for the type Conmon_SetWindowSizeRequest.
+const Conmon_SetWindowSizeRequest_TypeID = 0xb5418b8ea8ead17b
+
+func NewConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_SetWindowSizeRequest{st}, err
+}
+
+func NewRootConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_SetWindowSizeRequest{st}, err
+}
+
+func ReadRootConmon_SetWindowSizeRequest(msg *capnp.Message) (Conmon_SetWindowSizeRequest, error) {
+	root, err := msg.Root()
+	return Conmon_SetWindowSizeRequest{root.Struct()}, err
+}
+
+func (s Conmon_SetWindowSizeRequest) String() string {
+	str, _ := text.Marshal(0xb5418b8ea8ead17b, s.Struct)
+	return str
+}
+
+func (s Conmon_SetWindowSizeRequest) Id() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_SetWindowSizeRequest) HasId() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_SetWindowSizeRequest) IdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_SetWindowSizeRequest) SetId(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_SetWindowSizeRequest) Width() uint16 {
+	return s.Struct.Uint16(0)
+}
+
+func (s Conmon_SetWindowSizeRequest) SetWidth(v uint16) {
+	s.Struct.SetUint16(0, v)
+}
+
+func (s Conmon_SetWindowSizeRequest) Height() uint16 {
+	return s.Struct.Uint16(2)
+}
+
+func (s Conmon_SetWindowSizeRequest) SetHeight(v uint16) {
+	s.Struct.SetUint16(2, v)
+}
+
+func (s Conmon_SetWindowSizeRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(1)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_SetWindowSizeRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_SetWindowSizeRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(1, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_SetWindowSizeRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(1, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_SetWindowSizeRequest_List is a list of Conmon_SetWindowSizeRequest.
+type Conmon_SetWindowSizeRequest_List = capnp.StructList[Conmon_SetWindowSizeRequest]
+
+// NewConmon_SetWindowSizeRequest creates a new list of Conmon_SetWindowSizeRequest.
+func NewConmon_SetWindowSizeRequest_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_SetWindowSizeRequest]{l}, err
+}
+
+// Conmon_SetWindowSizeRequest_Future is a wrapper for a Conmon_SetWindowSizeRequest promised by a client call.
+type Conmon_SetWindowSizeRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_SetWindowSizeRequest_Future) Struct() (Conmon_SetWindowSizeRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetWindowSizeRequest{s}, err
+}
+
+func (p Conmon_SetWindowSizeRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(1, nil)}
+}
+
+type Conmon_SetWindowSizeResponse struct{ capnp.Struct }
+
+// Conmon_SetWindowSizeResponse_TypeID is the unique identifier for the type Conmon_SetWindowSizeResponse.
+const Conmon_SetWindowSizeResponse_TypeID = 0xf9b3cd8033aba1f8
+
+func NewConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetWindowSizeResponse{st}, err
+}
+
+func NewRootConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetWindowSizeResponse{st}, err
+}
+
+func ReadRootConmon_SetWindowSizeResponse(msg *capnp.Message) (Conmon_SetWindowSizeResponse, error) {
+	root, err := msg.Root()
+	return Conmon_SetWindowSizeResponse{root.Struct()}, err
+}
+
+func (s Conmon_SetWindowSizeResponse) String() string {
+	str, _ := text.Marshal(0xf9b3cd8033aba1f8, s.Struct)
+	return str
+}
+
+// Conmon_SetWindowSizeResponse_List is a list of Conmon_SetWindowSizeResponse.
+type Conmon_SetWindowSizeResponse_List = capnp.StructList[Conmon_SetWindowSizeResponse]
+
+// NewConmon_SetWindowSizeResponse creates a new list of Conmon_SetWindowSizeResponse.
+func NewConmon_SetWindowSizeResponse_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_SetWindowSizeResponse]{l}, err
+}
+
+// Conmon_SetWindowSizeResponse_Future is a wrapper for a Conmon_SetWindowSizeResponse promised by a client call.
+type Conmon_SetWindowSizeResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_SetWindowSizeResponse_Future) Struct() (Conmon_SetWindowSizeResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetWindowSizeResponse{s}, err
+}
+
+type Conmon_ShutdownRequest struct{ capnp.Struct }
+
+// Conmon_ShutdownRequest_TypeID is the unique identifier for the type Conmon_ShutdownRequest.
+const Conmon_ShutdownRequest_TypeID = 0xbcc8964d9d37437b
+
+func NewConmon_ShutdownRequest(s *capnp.Segment) (Conmon_ShutdownRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	return Conmon_ShutdownRequest{st}, err
+}
+
+func NewRootConmon_ShutdownRequest(s *capnp.Segment) (Conmon_ShutdownRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	return Conmon_ShutdownRequest{st}, err
+}
+
+func ReadRootConmon_ShutdownRequest(msg *capnp.Message) (Conmon_ShutdownRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ShutdownRequest{root.Struct()}, err
+}
+
+func (s Conmon_ShutdownRequest) String() string {
+	str, _ := text.Marshal(0xbcc8964d9d37437b, s.Struct)
+	return str
+}
+
+func (s Conmon_ShutdownRequest) Mode() Conmon_ShutdownRequest_Mode {
+	return Conmon_ShutdownRequest_Mode(s.Struct.Uint16(0))
+}
+
+func (s Conmon_ShutdownRequest) SetMode(v Conmon_ShutdownRequest_Mode) {
+	s.Struct.SetUint16(0, uint16(v))
+}
+
+func (s Conmon_ShutdownRequest) GracePeriodSec() uint64 {
+	return s.Struct.Uint64(8)
+}
+
+func (s Conmon_ShutdownRequest) SetGracePeriodSec(v uint64) {
+	s.Struct.SetUint64(8, v)
+}
+
+func (s Conmon_ShutdownRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_ShutdownRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_ShutdownRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_ShutdownRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_ShutdownRequest_List is a list of Conmon_ShutdownRequest.
+type Conmon_ShutdownRequest_List = capnp.StructList[Conmon_ShutdownRequest]
+
+// NewConmon_ShutdownRequest creates a new list of Conmon_ShutdownRequest.
+func NewConmon_ShutdownRequest_List(s *capnp.Segment, sz int32) (Conmon_ShutdownRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ShutdownRequest]{l}, err
+}
+
+// Conmon_ShutdownRequest_Future is a wrapper for a Conmon_ShutdownRequest promised by a client call.
+type Conmon_ShutdownRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ShutdownRequest_Future) Struct() (Conmon_ShutdownRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ShutdownRequest{s}, err
+}
+
+func (p Conmon_ShutdownRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_ShutdownRequest_Mode uint16
+
+// Conmon_ShutdownRequest_Mode_TypeID is the unique identifier for the type Conmon_ShutdownRequest_Mode.
+const Conmon_ShutdownRequest_Mode_TypeID = 0xad51c7e1283a3d0d
+
+// Values of Conmon_ShutdownRequest_Mode.
+const (
+	Conmon_ShutdownRequest_Mode_killAll     Conmon_ShutdownRequest_Mode = 0
+	Conmon_ShutdownRequest_Mode_detach      Conmon_ShutdownRequest_Mode = 1
+	Conmon_ShutdownRequest_Mode_waitForExit Conmon_ShutdownRequest_Mode = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_ShutdownRequest_Mode) String() string {
+	switch c {
+	case Conmon_ShutdownRequest_Mode_killAll:
+		return "killAll"
+
+	case Conmon_ShutdownRequest_Mode_detach:
+		return "detach"
+
+	case Conmon_ShutdownRequest_Mode_waitForExit:
+		return "waitForExit"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_ShutdownRequest_ModeFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_ShutdownRequest_ModeFromString(c string) Conmon_ShutdownRequest_Mode {
+	switch c {
+	case "killAll":
+		return Conmon_ShutdownRequest_Mode_killAll
+
+	case "detach":
+		return Conmon_ShutdownRequest_Mode_detach
+
+	case "waitForExit":
+		return Conmon_ShutdownRequest_Mode_waitForExit
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_ShutdownRequest_Mode_List = capnp.EnumList[Conmon_ShutdownRequest_Mode]
+
+func NewConmon_ShutdownRequest_Mode_List(s *capnp.Segment, sz int32) (Conmon_ShutdownRequest_Mode_List, error) {
+	return capnp.NewEnumList[Conmon_ShutdownRequest_Mode](s, sz)
+}
+
+type Conmon_ShutdownResponse struct{ capnp.Struct }
+
+// Conmon_ShutdownResponse_TypeID is the unique identifier for the type Conmon_ShutdownResponse.
+const Conmon_ShutdownResponse_TypeID = 0x826958b3c0d0f6e3
+
+func NewConmon_ShutdownResponse(s *capnp.Segment) (Conmon_ShutdownResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ShutdownResponse{st}, err
+}
+
+func NewRootConmon_ShutdownResponse(s *capnp.Segment) (Conmon_ShutdownResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ShutdownResponse{st}, err
+}
+
+func ReadRootConmon_ShutdownResponse(msg *capnp.Message) (Conmon_ShutdownResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ShutdownResponse{root.Struct()}, err
+}
+
+func (s Conmon_ShutdownResponse) String() string {
+	str, _ := text.Marshal(0x826958b3c0d0f6e3, s.Struct)
+	return str
+}
+
+// Conmon_ShutdownResponse_List is a list of Conmon_ShutdownResponse.
+type Conmon_ShutdownResponse_List = capnp.StructList[Conmon_ShutdownResponse]
+
+// NewConmon_ShutdownResponse creates a new list of Conmon_ShutdownResponse.
+func NewConmon_ShutdownResponse_List(s *capnp.Segment, sz int32) (Conmon_ShutdownResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ShutdownResponse]{l}, err
+}
+
+// Conmon_ShutdownResponse_Future is a wrapper for a Conmon_ShutdownResponse promised by a client call.
+type Conmon_ShutdownResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ShutdownResponse_Future) Struct() (Conmon_ShutdownResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ShutdownResponse{s}, err
+}
+
+type Conmon_ListContainersRequest struct{ capnp.Struct }
+
+// Conmon_ListContainersRequest_TypeID is the unique identifier for the type Conmon_ListContainersRequest.
+const Conmon_ListContainersRequest_TypeID = 0xbc1bca51fe8ba645
+
+func NewConmon_ListContainersRequest(s *capnp.Segment) (Conmon_ListContainersRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListContainersRequest{st}, err
+}
+
+func NewRootConmon_ListContainersRequest(s *capnp.Segment) (Conmon_ListContainersRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListContainersRequest{st}, err
+}
+
+func ReadRootConmon_ListContainersRequest(msg *capnp.Message) (Conmon_ListContainersRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ListContainersRequest{root.Struct()}, err
+}
+
+func (s Conmon_ListContainersRequest) String() string {
+	str, _ := text.Marshal(0xbc1bca51fe8ba645, s.Struct)
+	return str
+}
+
+func (s Conmon_ListContainersRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_ListContainersRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_ListContainersRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_ListContainersRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_ListContainersRequest_List is a list of Conmon_ListContainersRequest.
+type Conmon_ListContainersRequest_List = capnp.StructList[Conmon_ListContainersRequest]
+
+// NewConmon_ListContainersRequest creates a new list of Conmon_ListContainersRequest.
+func NewConmon_ListContainersRequest_List(s *capnp.Segment, sz int32) (Conmon_ListContainersRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ListContainersRequest]{l}, err
+}
+
+// Conmon_ListContainersRequest_Future is a wrapper for a Conmon_ListContainersRequest promised by a client call.
+type Conmon_ListContainersRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ListContainersRequest_Future) Struct() (Conmon_ListContainersRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ListContainersRequest{s}, err
+}
+
+func (p Conmon_ListContainersRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_ListContainersResponse struct{ capnp.Struct }
+
+// Conmon_ListContainersResponse_TypeID is the unique identifier for the type Conmon_ListContainersResponse.
+const Conmon_ListContainersResponse_TypeID = 0xfdae861fa8890aa3
+
+func NewConmon_ListContainersResponse(s *capnp.Segment) (Conmon_ListContainersResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListContainersResponse{st}, err
+}
+
+func NewRootConmon_ListContainersResponse(s *capnp.Segment) (Conmon_ListContainersResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListContainersResponse{st}, err
+}
+
+func ReadRootConmon_ListContainersResponse(msg *capnp.Message) (Conmon_ListContainersResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ListContainersResponse{root.Struct()}, err
+}
+
+func (s Conmon_ListContainersResponse) String() string {
+	str, _ := text.Marshal(0xfdae861fa8890aa3, s.Struct)
+	return str
+}
+
+func (s Conmon_ListContainersResponse) Containers() (Conmon_Container_List, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_Container_List{List: p.List()}, err
+}
+
+func (s Conmon_ListContainersResponse) HasContainers() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_ListContainersResponse) SetContainers(v Conmon_Container_List) error {
+	return s.Struct.SetPtr(0, v.List.ToPtr())
+}
+
+// NewContainers sets the containers field to a newly
+// allocated Conmon_Container_List, preferring placement in s's segment.
+func (s Conmon_ListContainersResponse) NewContainers(n int32) (Conmon_Container_List, error) {
+	l, err := NewConmon_Container_List(s.Struct.Segment(), n)
+	if err != nil {
+		return Conmon_Container_List{}, err
+	}
+	err = s.Struct.SetPtr(0, l.List.ToPtr())
+	return l, err
+}
+
+// Conmon_ListContainersResponse_List is a list of Conmon_ListContainersResponse.
+type Conmon_ListContainersResponse_List = capnp.StructList[Conmon_ListContainersResponse]
+
+// NewConmon_ListContainersResponse creates a new list of Conmon_ListContainersResponse.
+func NewConmon_ListContainersResponse_List(s *capnp.Segment, sz int32) (Conmon_ListContainersResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ListContainersResponse]{l}, err
+}
+
+// Conmon_ListContainersResponse_Future is a wrapper for a Conmon_ListContainersResponse promised by a client call.
+type Conmon_ListContainersResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ListContainersResponse_Future) Struct() (Conmon_ListContainersResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ListContainersResponse{s}, err
+}
+
+type Conmon_Container struct{ capnp.Struct }
+
+// Conmon_Container_TypeID is the unique identifier for the type Conmon_Container.
+const Conmon_Container_TypeID = 0xa3e6c413c9e0821d
+
+func NewConmon_Container(s *capnp.Segment) (Conmon_Container, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 3})
+	return Conmon_Container{st}, err
+}
+
+func NewRootConmon_Container(s *capnp.Segment) (Conmon_Container, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 3})
+	return Conmon_Container{st}, err
+}
+
+func ReadRootConmon_Container(msg *capnp.Message) (Conmon_Container, error) {
+	root, err := msg.Root()
+	return Conmon_Container{root.Struct()}, err
+}
+
+func (s Conmon_Container) String() string {
+	str, _ := text.Marshal(0xa3e6c413c9e0821d, s.Struct)
+	return str
+}
+
+func (s Conmon_Container) Id() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_Container) HasId() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_Container) IdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Container) SetId(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_Container) Pid() uint32 {
+	return s.Struct.Uint32(0)
+}
+
+func (s Conmon_Container) SetPid(v uint32) {
+	s.Struct.SetUint32(0, v)
+}
+
+func (s Conmon_Container) LogDrivers() (Conmon_LogDriver_List, error) {
+	p, err := s.Struct.Ptr(1)
+	return Conmon_LogDriver_List{List: p.List()}, err
+}
+
+func (s Conmon_Container) HasLogDrivers() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_Container) SetLogDrivers(v Conmon_LogDriver_List) error {
+	return s.Struct.SetPtr(1, v.List.ToPtr())
+}
+
+// NewLogDrivers sets the logDrivers field to a newly
+// allocated Conmon_LogDriver_List, preferring placement in s's segment.
+func (s Conmon_Container) NewLogDrivers(n int32) (Conmon_LogDriver_List, error) {
+	l, err := NewConmon_LogDriver_List(s.Struct.Segment(), n)
+	if err != nil {
+		return Conmon_LogDriver_List{}, err
+	}
+	err = s.Struct.SetPtr(1, l.List.ToPtr())
+	return l, err
+}
+
+func (s Conmon_Container) Probes() (Conmon_ProbeStatus_List, error) {
+	p, err := s.Struct.Ptr(2)
+	return Conmon_ProbeStatus_List{List: p.List()}, err
+}
+
+func (s Conmon_Container) HasProbes() bool {
+	return s.Struct.HasPtr(2)
+}
+
+func (s Conmon_Container) SetProbes(v Conmon_ProbeStatus_List) error {
+	return s.Struct.SetPtr(2, v.List.ToPtr())
+}
+
+// NewProbes sets the probes field to a newly
+// allocated Conmon_ProbeStatus_List, preferring placement in s's segment.
+func (s Conmon_Container) NewProbes(n int32) (Conmon_ProbeStatus_List, error) {
+	l, err := NewConmon_ProbeStatus_List(s.Struct.Segment(), n)
+	if err != nil {
+		return Conmon_ProbeStatus_List{}, err
+	}
+	err = s.Struct.SetPtr(2, l.List.ToPtr())
+	return l, err
+}
+
+func (s Conmon_Container) RestartCount() uint32 {
+	return s.Struct.Uint32(4)
+}
+
+func (s Conmon_Container) SetRestartCount(v uint32) {
+	s.Struct.SetUint32(4, v)
+}
+
+func (s Conmon_Container) LastExitCode() int32 {
+	return int32(s.Struct.Uint32(8))
+}
+
+func (s Conmon_Container) SetLastExitCode(v int32) {
+	s.Struct.SetUint32(8, uint32(v))
+}
+
+// Conmon_Container_List is a list of Conmon_Container.
+type Conmon_Container_List = capnp.StructList[Conmon_Container]
+
+// NewConmon_Container creates a new list of Conmon_Container.
+func NewConmon_Container_List(s *capnp.Segment, sz int32) (Conmon_Container_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_Container]{l}, err
+}
+
+// Conmon_Container_Future is a wrapper for a Conmon_Container promised by a client call.
+type Conmon_Container_Future struct{ *capnp.Future }
+
+func (p Conmon_Container_Future) Struct() (Conmon_Container, error) {
+	s, err := p.Future.Struct()
+	return Conmon_Container{s}, err
+}
+
+type Conmon_ProbeStatus struct{ capnp.Struct }
+
+// Conmon_ProbeStatus_TypeID is the unique identifier for the type Conmon_ProbeStatus.
+const Conmon_ProbeStatus_TypeID = 0xa16b58808ac7e538
+
+func NewConmon_ProbeStatus(s *capnp.Segment) (Conmon_ProbeStatus, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_ProbeStatus{st}, err
+}
+
+func NewRootConmon_ProbeStatus(s *capnp.Segment) (Conmon_ProbeStatus, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_ProbeStatus{st}, err
+}
+
+func ReadRootConmon_ProbeStatus(msg *capnp.Message) (Conmon_ProbeStatus, error) {
+	root, err := msg.Root()
+	return Conmon_ProbeStatus{root.Struct()}, err
+}
+
+func (s Conmon_ProbeStatus) String() string {
+	str, _ := text.Marshal(0xa16b58808ac7e538, s.Struct)
+	return str
+}
+
+func (s Conmon_ProbeStatus) Name() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ProbeStatus) HasName() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_ProbeStatus) NameBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ProbeStatus) SetName(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_ProbeStatus) Result() Conmon_ProbeResult {
+	return Conmon_ProbeResult(s.Struct.Uint16(0))
+}
+
+func (s Conmon_ProbeStatus) SetResult(v Conmon_ProbeResult) {
+	s.Struct.SetUint16(0, uint16(v))
+}
+
+func (s Conmon_ProbeStatus) Message() (string, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_ProbeStatus) HasMessage() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_ProbeStatus) MessageBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ProbeStatus) SetMessage(v string) error {
+	return s.Struct.SetText(1, v)
+}
+
+// Conmon_ProbeStatus_List is a list of Conmon_ProbeStatus.
+type Conmon_ProbeStatus_List = capnp.StructList[Conmon_ProbeStatus]
+
+// NewConmon_ProbeStatus creates a new list of Conmon_ProbeStatus.
+func NewConmon_ProbeStatus_List(s *capnp.Segment, sz int32) (Conmon_ProbeStatus_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_ProbeStatus]{l}, err
+}
+
+// Conmon_ProbeStatus_Future is a wrapper for a Conmon_ProbeStatus promised by a client call.
+type Conmon_ProbeStatus_Future struct{ *capnp.Future }
+
+func (p Conmon_ProbeStatus_Future) Struct() (Conmon_ProbeStatus, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ProbeStatus{s}, err
+}
+
+type Conmon_ProbeResult uint16
+
+// Conmon_ProbeResult_TypeID is the unique identifier for the type Conmon_ProbeResult.
+const Conmon_ProbeResult_TypeID = 0xe3fb0b5eb795495d
+
+// Values of Conmon_ProbeResult.
+const (
+	Conmon_ProbeResult_unknown Conmon_ProbeResult = 0
+	Conmon_ProbeResult_success Conmon_ProbeResult = 1
+	Conmon_ProbeResult_failure Conmon_ProbeResult = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_ProbeResult) String() string {
+	switch c {
+	case Conmon_ProbeResult_unknown:
+		return "unknown"
+
+	case Conmon_ProbeResult_success:
+		return "success"
+
+	case Conmon_ProbeResult_failure:
+		return "failure"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_ProbeResultFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_ProbeResultFromString(c string) Conmon_ProbeResult {
+	switch c {
+	case "unknown":
+		return Conmon_ProbeResult_unknown
+
+	case "success":
+		return Conmon_ProbeResult_success
+
+	case "failure":
+		return Conmon_ProbeResult_failure
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_ProbeResult_List = capnp.EnumList[Conmon_ProbeResult]
+
+func NewConmon_ProbeResult_List(s *capnp.Segment, sz int32) (Conmon_ProbeResult_List, error) {
+	return capnp.NewEnumList[Conmon_ProbeResult](s, sz)
+}
+
+type Conmon_KillContainerRequest struct{ capnp.Struct }
+
+// Conmon_KillContainerRequest_TypeID is the unique identifier for the type Conmon_KillContainerRequest.
+const Conmon_KillContainerRequest_TypeID = 0xd285ab9e532f8e8f
+
+func NewConmon_KillContainerRequest(s *capnp.Segment) (Conmon_KillContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_KillContainerRequest{st}, err
+}
+
+func NewRootConmon_KillContainerRequest(s *capnp.Segment) (Conmon_KillContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_KillContainerRequest{st}, err
+}
+
+func ReadRootConmon_KillContainerRequest(msg *capnp.Message) (Conmon_KillContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_KillContainerRequest{root.Struct()}, err
+}
+
+func (s Conmon_KillContainerRequest) String() string {
+	str, _ := text.Marshal(0xd285ab9e532f8e8f, s.Struct)
+	return str
+}
+
+func (s Conmon_KillContainerRequest) Id() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_KillContainerRequest) HasId() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_KillContainerRequest) IdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_KillContainerRequest) SetId(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_KillContainerRequest) Signal() uint32 {
+	return s.Struct.Uint32(0)
+}
+
+func (s Conmon_KillContainerRequest) SetSignal(v uint32) {
+	s.Struct.SetUint32(0, v)
+}
+
+func (s Conmon_KillContainerRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(1)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_KillContainerRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_KillContainerRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(1, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_KillContainerRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(1, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_KillContainerRequest_List is a list of Conmon_KillContainerRequest.
+type Conmon_KillContainerRequest_List = capnp.StructList[Conmon_KillContainerRequest]
+
+// NewConmon_KillContainerRequest creates a new list of Conmon_KillContainerRequest.
+func NewConmon_KillContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_KillContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_KillContainerRequest]{l}, err
+}
+
+// Conmon_KillContainerRequest_Future is a wrapper for a Conmon_KillContainerRequest promised by a client call.
+type Conmon_KillContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_KillContainerRequest_Future) Struct() (Conmon_KillContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_KillContainerRequest{s}, err
+}
+
+func (p Conmon_KillContainerRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(1, nil)}
+}
+
+type Conmon_KillContainerResponse struct{ capnp.Struct }
+
+// Conmon_KillContainerResponse_TypeID is the unique identifier for the type Conmon_KillContainerResponse.
+const Conmon_KillContainerResponse_TypeID = 0xadb66abea677f8fc
+
+func NewConmon_KillContainerResponse(s *capnp.Segment) (Conmon_KillContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_KillContainerResponse{st}, err
+}
+
+func NewRootConmon_KillContainerResponse(s *capnp.Segment) (Conmon_KillContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_KillContainerResponse{st}, err
+}
+
+func ReadRootConmon_KillContainerResponse(msg *capnp.Message) (Conmon_KillContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_KillContainerResponse{root.Struct()}, err
+}
+
+func (s Conmon_KillContainerResponse) String() string {
+	str, _ := text.Marshal(0xadb66abea677f8fc, s.Struct)
+	return str
+}
+
+// Conmon_KillContainerResponse_List is a list of Conmon_KillContainerResponse.
+type Conmon_KillContainerResponse_List = capnp.StructList[Conmon_KillContainerResponse]
+
+// NewConmon_KillContainerResponse creates a new list of Conmon_KillContainerResponse.
+func NewConmon_KillContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_KillContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_KillContainerResponse]{l}, err
+}
+
+// Conmon_KillContainerResponse_Future is a wrapper for a Conmon_KillContainerResponse promised by a client call.
+type Conmon_KillContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_KillContainerResponse_Future) Struct() (Conmon_KillContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_KillContainerResponse{s}, err
+}
+
+type Conmon_PortForwardRequest struct{ capnp.Struct }
+
+// Conmon_PortForwardRequest_TypeID is the unique identifier for the type Conmon_PortForwardRequest.
+const Conmon_PortForwardRequest_TypeID = 0xb78b75a9a91a9748
+
+func NewConmon_PortForwardRequest(s *capnp.Segment) (Conmon_PortForwardRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_PortForwardRequest{st}, err
+}
+
+func NewRootConmon_PortForwardRequest(s *capnp.Segment) (Conmon_PortForwardRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_PortForwardRequest{st}, err
+}
+
+func ReadRootConmon_PortForwardRequest(msg *capnp.Message) (Conmon_PortForwardRequest, error) {
+	root, err := msg.Root()
+	return Conmon_PortForwardRequest{root.Struct()}, err
+}
+
+func (s Conmon_PortForwardRequest) String() string {
+	str, _ := text.Marshal(0xb78b75a9a91a9748, s.Struct)
+	return str
+}
+
+func (s Conmon_PortForwardRequest) Id() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_PortForwardRequest) HasId() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_PortForwardRequest) IdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_PortForwardRequest) SetId(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_PortForwardRequest) Port() uint16 {
+	return s.Struct.Uint16(0)
+}
+
+func (s Conmon_PortForwardRequest) SetPort(v uint16) {
+	s.Struct.SetUint16(0, v)
+}
+
+func (s Conmon_PortForwardRequest) OutputSink() Conmon_StreamSink {
+	p, _ := s.Struct.Ptr(1)
+	return Conmon_StreamSink{Client: p.Interface().Client()}
+}
+
+func (s Conmon_PortForwardRequest) HasOutputSink() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_PortForwardRequest) SetOutputSink(v Conmon_StreamSink) error {
+	if !v.Client.IsValid() {
+		return s.Struct.SetPtr(1, capnp.Ptr{})
+	}
+	seg := s.Segment()
+	in := capnp.NewInterface(seg, seg.Message().AddCap(v.Client))
+	return s.Struct.SetPtr(1, in.ToPtr())
+}
+
+func (s Conmon_PortForwardRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(2)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_PortForwardRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(2)
+}
+
+func (s Conmon_PortForwardRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(2, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_PortForwardRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(2, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_PortForwardRequest_List is a list of Conmon_PortForwardRequest.
+type Conmon_PortForwardRequest_List = capnp.StructList[Conmon_PortForwardRequest]
+
+// NewConmon_PortForwardRequest creates a new list of Conmon_PortForwardRequest.
+func NewConmon_PortForwardRequest_List(s *capnp.Segment, sz int32) (Conmon_PortForwardRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_PortForwardRequest]{l}, err
+}
+
+// Conmon_PortForwardRequest_Future is a wrapper for a Conmon_PortForwardRequest promised by a client call.
+type Conmon_PortForwardRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_PortForwardRequest_Future) Struct() (Conmon_PortForwardRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_PortForwardRequest{s}, err
+}
+
+func (p Conmon_PortForwardRequest_Future) OutputSink() Conmon_StreamSink {
+	return Conmon_StreamSink{Client: p.Future.Field(1, nil).Client()}
+}
+
+func (p Conmon_PortForwardRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(2, nil)}
+}
+
+type Conmon_PortForwardResponse struct{ capnp.Struct }
+
+// Conmon_PortForwardResponse_TypeID is the unique identifier for the type Conmon_PortForwardResponse.
+const Conmon_PortForwardResponse_TypeID = 0xfa066186bb70bb83
+
+func NewConmon_PortForwardResponse(s *capnp.Segment) (Conmon_PortForwardResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_PortForwardResponse{st}, err
+}
+
+func NewRootConmon_PortForwardResponse(s *capnp.Segment) (Conmon_PortForwardResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_PortForwardResponse{st}, err
+}
+
+func ReadRootConmon_PortForwardResponse(msg *capnp.Message) (Conmon_PortForwardResponse, error) {
+	root, err := msg.Root()
+	return Conmon_PortForwardResponse{root.Struct()}, err
+}
+
+func (s Conmon_PortForwardResponse) String() string {
+	str, _ := text.Marshal(0xfa066186bb70bb83, s.Struct)
+	return str
+}
+
+func (s Conmon_PortForwardResponse) InputSink() Conmon_StreamSink {
+	p, _ := s.Struct.Ptr(0)
+	return Conmon_StreamSink{Client: p.Interface().Client()}
+}
+
+func (s Conmon_PortForwardResponse) HasInputSink() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_PortForwardResponse) SetInputSink(v Conmon_StreamSink) error {
+	if !v.Client.IsValid() {
+		return s.Struct.SetPtr(0, capnp.Ptr{})
+	}
+	seg := s.Segment()
+	in := capnp.NewInterface(seg, seg.Message().AddCap(v.Client))
+	return s.Struct.SetPtr(0, in.ToPtr())
+}
+
+// Conmon_PortForwardResponse_List is a list of Conmon_PortForwardResponse.
+type Conmon_PortForwardResponse_List = capnp.StructList[Conmon_PortForwardResponse]
+
+// NewConmon_PortForwardResponse creates a new list of Conmon_PortForwardResponse.
+func NewConmon_PortForwardResponse_List(s *capnp.Segment, sz int32) (Conmon_PortForwardResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_PortForwardResponse]{l}, err
+}
+
+// Conmon_PortForwardResponse_Future is a wrapper for a Conmon_PortForwardResponse promised by a client call.
+type Conmon_PortForwardResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_PortForwardResponse_Future) Struct() (Conmon_PortForwardResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_PortForwardResponse{s}, err
+}
+
+func (p Conmon_PortForwardResponse_Future) InputSink() Conmon_StreamSink {
+	return Conmon_StreamSink{Client: p.Future.Field(0, nil).Client()}
+}
+
+type Conmon_CreateNamespacesRequest struct{ capnp.Struct }
+
+// Conmon_CreateNamespacesRequest_TypeID is the unique identifier for the type Conmon_CreateNamespacesRequest.
+const Conmon_CreateNamespacesRequest_TypeID = 0x8b5b1693940f607e
+
+func NewConmon_CreateNamespacesRequest(s *capnp.Segment) (Conmon_CreateNamespacesRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 7})
+	return Conmon_CreateNamespacesRequest{st}, err
+}
+
+func NewRootConmon_CreateNamespacesRequest(s *capnp.Segment) (Conmon_CreateNamespacesRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 7})
+	return Conmon_CreateNamespacesRequest{st}, err
+}
+
+func ReadRootConmon_CreateNamespacesRequest(msg *capnp.Message) (Conmon_CreateNamespacesRequest, error) {
+	root, err := msg.Root()
+	return Conmon_CreateNamespacesRequest{root.Struct()}, err
+}
+
+func (s Conmon_CreateNamespacesRequest) String() string {
+	str, _ := text.Marshal(0x8b5b1693940f607e, s.Struct)
+	return str
+}
+
+func (s Conmon_CreateNamespacesRequest) BasePath() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasBasePath() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_CreateNamespacesRequest) BasePathBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateNamespacesRequest) SetBasePath(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_CreateNamespacesRequest) PodId() (string, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasPodId() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_CreateNamespacesRequest) PodIdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateNamespacesRequest) SetPodId(v string) error {
+	return s.Struct.SetText(1, v)
+}
+
+func (s Conmon_CreateNamespacesRequest) Namespaces() (Conmon_Namespace_List, error) {
+	p, err := s.Struct.Ptr(2)
+	return Conmon_Namespace_List{List: p.List()}, err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasNamespaces() bool {
+	return s.Struct.HasPtr(2)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetNamespaces(v Conmon_Namespace_List) error {
+	return s.Struct.SetPtr(2, v.List.ToPtr())
+}
+
+// NewNamespaces sets the namespaces field to a newly
+// allocated Conmon_Namespace_List, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesRequest) NewNamespaces(n int32) (Conmon_Namespace_List, error) {
+	l, err := NewConmon_Namespace_List(s.Struct.Segment(), n)
+	if err != nil {
+		return Conmon_Namespace_List{}, err
+	}
+	err = s.Struct.SetPtr(2, l.List.ToPtr())
+	return l, err
+}
+
+func (s Conmon_CreateNamespacesRequest) UidMappings() (capnp.TextList, error) {
+	p, err := s.Struct.Ptr(3)
+	return capnp.TextList{List: p.List()}, err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasUidMappings() bool {
+	return s.Struct.HasPtr(3)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetUidMappings(v capnp.TextList) error {
+	return s.Struct.SetPtr(3, v.List.ToPtr())
+}
+
+// NewUidMappings sets the uidMappings field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesRequest) NewUidMappings(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(s.Struct.Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = s.Struct.SetPtr(3, l.List.ToPtr())
+	return l, err
+}
+
+func (s Conmon_CreateNamespacesRequest) GidMappings() (capnp.TextList, error) {
+	p, err := s.Struct.Ptr(4)
+	return capnp.TextList{List: p.List()}, err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasGidMappings() bool {
+	return s.Struct.HasPtr(4)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetGidMappings(v capnp.TextList) error {
+	return s.Struct.SetPtr(4, v.List.ToPtr())
+}
+
+// NewGidMappings sets the gidMappings field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesRequest) NewGidMappings(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(s.Struct.Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = s.Struct.SetPtr(4, l.List.ToPtr())
+	return l, err
+}
+
+func (s Conmon_CreateNamespacesRequest) Sysctls() (Conmon_Sysctl_List, error) {
+	p, err := s.Struct.Ptr(5)
+	return Conmon_Sysctl_List{List: p.List()}, err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasSysctls() bool {
+	return s.Struct.HasPtr(5)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetSysctls(v Conmon_Sysctl_List) error {
+	return s.Struct.SetPtr(5, v.List.ToPtr())
+}
+
+// NewSysctls sets the sysctls field to a newly
+// allocated Conmon_Sysctl_List, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesRequest) NewSysctls(n int32) (Conmon_Sysctl_List, error) {
+	l, err := NewConmon_Sysctl_List(s.Struct.Segment(), n)
+	if err != nil {
+		return Conmon_Sysctl_List{}, err
+	}
+	err = s.Struct.SetPtr(5, l.List.ToPtr())
+	return l, err
+}
+
+func (s Conmon_CreateNamespacesRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(6)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(6)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(6, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(6, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_CreateNamespacesRequest_List is a list of Conmon_CreateNamespacesRequest.
+type Conmon_CreateNamespacesRequest_List = capnp.StructList[Conmon_CreateNamespacesRequest]
+
+// NewConmon_CreateNamespacesRequest creates a new list of Conmon_CreateNamespacesRequest.
+func NewConmon_CreateNamespacesRequest_List(s *capnp.Segment, sz int32) (Conmon_CreateNamespacesRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 7}, sz)
+	return capnp.StructList[Conmon_CreateNamespacesRequest]{l}, err
+}
+
+// Conmon_CreateNamespacesRequest_Future is a wrapper for a Conmon_CreateNamespacesRequest promised by a client call.
+type Conmon_CreateNamespacesRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_CreateNamespacesRequest_Future) Struct() (Conmon_CreateNamespacesRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_CreateNamespacesRequest{s}, err
+}
+
+func (p Conmon_CreateNamespacesRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(6, nil)}
+}
+
+type Conmon_Namespace uint16
+
+// Conmon_Namespace_TypeID is the unique identifier for the type Conmon_Namespace.
+const Conmon_Namespace_TypeID = 0xd61491b560a8f3a3
+
+// Values of Conmon_Namespace.
+const (
+	Conmon_Namespace_ipc    Conmon_Namespace = 0
+	Conmon_Namespace_net    Conmon_Namespace = 1
+	Conmon_Namespace_uts    Conmon_Namespace = 2
+	Conmon_Namespace_user   Conmon_Namespace = 3
+	Conmon_Namespace_cgroup Conmon_Namespace = 4
+)
+
+// String returns the enum's constant name.
+func (c Conmon_Namespace) String() string {
+	switch c {
+	case Conmon_Namespace_ipc:
+		return "ipc"
+
+	case Conmon_Namespace_net:
+		return "net"
+
+	case Conmon_Namespace_uts:
+		return "uts"
+
+	case Conmon_Namespace_user:
+		return "user"
+
+	case Conmon_Namespace_cgroup:
+		return "cgroup"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_NamespaceFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_NamespaceFromString(c string) Conmon_Namespace {
+	switch c {
+	case "ipc":
+		return Conmon_Namespace_ipc
+
+	case "net":
+		return Conmon_Namespace_net
+
+	case "uts":
+		return Conmon_Namespace_uts
+
+	case "user":
+		return Conmon_Namespace_user
+
+	case "cgroup":
+		return Conmon_Namespace_cgroup
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_Namespace_List = capnp.EnumList[Conmon_Namespace]
+
+func NewConmon_Namespace_List(s *capnp.Segment, sz int32) (Conmon_Namespace_List, error) {
+	return capnp.NewEnumList[Conmon_Namespace](s, sz)
+}
+
+type Conmon_Sysctl struct{ capnp.Struct }
+
+// Conmon_Sysctl_TypeID is the unique identifier for the type Conmon_Sysctl.
+const Conmon_Sysctl_TypeID = 0xf798b7a4fe56d11d
+
+func NewConmon_Sysctl(s *capnp.Segment) (Conmon_Sysctl, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_Sysctl{st}, err
+}
+
+func NewRootConmon_Sysctl(s *capnp.Segment) (Conmon_Sysctl, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_Sysctl{st}, err
+}
+
+func ReadRootConmon_Sysctl(msg *capnp.Message) (Conmon_Sysctl, error) {
+	root, err := msg.Root()
+	return Conmon_Sysctl{root.Struct()}, err
+}
+
+func (s Conmon_Sysctl) String() string {
+	str, _ := text.Marshal(0xf798b7a4fe56d11d, s.Struct)
+	return str
+}
+
+func (s Conmon_Sysctl) Key() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_Sysctl) HasKey() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_Sysctl) KeyBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Sysctl) SetKey(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_Sysctl) Value() (string, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_Sysctl) HasValue() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_Sysctl) ValueBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Sysctl) SetValue(v string) error {
+	return s.Struct.SetText(1, v)
+}
+
+// Conmon_Sysctl_List is a list of Conmon_Sysctl.
+type Conmon_Sysctl_List = capnp.StructList[Conmon_Sysctl]
+
+// NewConmon_Sysctl creates a new list of Conmon_Sysctl.
+func NewConmon_Sysctl_List(s *capnp.Segment, sz int32) (Conmon_Sysctl_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_Sysctl]{l}, err
+}
+
+// Conmon_Sysctl_Future is a wrapper for a Conmon_Sysctl promised by a client call.
+type Conmon_Sysctl_Future struct{ *capnp.Future }
+
+func (p Conmon_Sysctl_Future) Struct() (Conmon_Sysctl, error) {
+	s, err := p.Future.Struct()
+	return Conmon_Sysctl{s}, err
+}
+
+type Conmon_CreateNamespacesResponse struct{ capnp.Struct }
+
+// Conmon_CreateNamespacesResponse_TypeID is the unique identifier for the type Conmon_CreateNamespacesResponse.
+const Conmon_CreateNamespacesResponse_TypeID = 0x9887a60f577a1ecb
+
+func NewConmon_CreateNamespacesResponse(s *capnp.Segment) (Conmon_CreateNamespacesResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_CreateNamespacesResponse{st}, err
+}
+
+func NewRootConmon_CreateNamespacesResponse(s *capnp.Segment) (Conmon_CreateNamespacesResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_CreateNamespacesResponse{st}, err
+}
+
+func ReadRootConmon_CreateNamespacesResponse(msg *capnp.Message) (Conmon_CreateNamespacesResponse, error) {
+	root, err := msg.Root()
+	return Conmon_CreateNamespacesResponse{root.Struct()}, err
+}
+
+func (s Conmon_CreateNamespacesResponse) String() string {
+	str, _ := text.Marshal(0x9887a60f577a1ecb, s.Struct)
+	return str
+}
+
+func (s Conmon_CreateNamespacesResponse) Namespaces() (Conmon_NamespaceInfo_List, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_NamespaceInfo_List{List: p.List()}, err
+}
+
+func (s Conmon_CreateNamespacesResponse) HasNamespaces() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_CreateNamespacesResponse) SetNamespaces(v Conmon_NamespaceInfo_List) error {
+	return s.Struct.SetPtr(0, v.List.ToPtr())
+}
+
+// NewNamespaces sets the namespaces field to a newly
+// allocated Conmon_NamespaceInfo_List, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesResponse) NewNamespaces(n int32) (Conmon_NamespaceInfo_List, error) {
+	l, err := NewConmon_NamespaceInfo_List(s.Struct.Segment(), n)
+	if err != nil {
+		return Conmon_NamespaceInfo_List{}, err
+	}
+	err = s.Struct.SetPtr(0, l.List.ToPtr())
+	return l, err
+}
+
+// Conmon_CreateNamespacesResponse_List is a list of Conmon_CreateNamespacesResponse.
+type Conmon_CreateNamespacesResponse_List = capnp.StructList[Conmon_CreateNamespacesResponse]
+
+// NewConmon_CreateNamespacesResponse creates a new list of Conmon_CreateNamespacesResponse.
+func NewConmon_CreateNamespacesResponse_List(s *capnp.Segment, sz int32) (Conmon_CreateNamespacesResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_CreateNamespacesResponse]{l}, err
+}
+
+// Conmon_CreateNamespacesResponse_Future is a wrapper for a Conmon_CreateNamespacesResponse promised by a client call.
+type Conmon_CreateNamespacesResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_CreateNamespacesResponse_Future) Struct() (Conmon_CreateNamespacesResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_CreateNamespacesResponse{s}, err
+}
+
+type Conmon_NamespaceInfo struct{ capnp.Struct }
+
+// Conmon_NamespaceInfo_TypeID is the unique identifier for the type Conmon_NamespaceInfo.
+const Conmon_NamespaceInfo_TypeID = 0xeaea0070cb69c1e8
+
+func NewConmon_NamespaceInfo(s *capnp.Segment) (Conmon_NamespaceInfo, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_NamespaceInfo{st}, err
+}
+
+func NewRootConmon_NamespaceInfo(s *capnp.Segment) (Conmon_NamespaceInfo, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_NamespaceInfo{st}, err
+}
+
+func ReadRootConmon_NamespaceInfo(msg *capnp.Message) (Conmon_NamespaceInfo, error) {
+	root, err := msg.Root()
+	return Conmon_NamespaceInfo{root.Struct()}, err
+}
+
+func (s Conmon_NamespaceInfo) String() string {
+	str, _ := text.Marshal(0xeaea0070cb69c1e8, s.Struct)
+	return str
+}
+
+func (s Conmon_NamespaceInfo) Type() Conmon_Namespace {
+	return Conmon_Namespace(s.Struct.Uint16(0))
+}
+
+func (s Conmon_NamespaceInfo) SetType(v Conmon_Namespace) {
+	s.Struct.SetUint16(0, uint16(v))
+}
+
+func (s Conmon_NamespaceInfo) Path() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_NamespaceInfo) HasPath() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_NamespaceInfo) PathBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_NamespaceInfo) SetPath(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+// Conmon_NamespaceInfo_List is a list of Conmon_NamespaceInfo.
+type Conmon_NamespaceInfo_List = capnp.StructList[Conmon_NamespaceInfo]
+
+// NewConmon_NamespaceInfo creates a new list of Conmon_NamespaceInfo.
+func NewConmon_NamespaceInfo_List(s *capnp.Segment, sz int32) (Conmon_NamespaceInfo_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_NamespaceInfo]{l}, err
+}
+
+// Conmon_NamespaceInfo_Future is a wrapper for a Conmon_NamespaceInfo promised by a client call.
+type Conmon_NamespaceInfo_Future struct{ *capnp.Future }
+
+func (p Conmon_NamespaceInfo_Future) Struct() (Conmon_NamespaceInfo, error) {
+	s, err := p.Future.Struct()
+	return Conmon_NamespaceInfo{s}, err
+}
+
+type Conmon_RemoveNamespacesRequest struct{ capnp.Struct }
+
+// Conmon_RemoveNamespacesRequest_TypeID is the unique identifier for the type Conmon_RemoveNamespacesRequest.
+const Conmon_RemoveNamespacesRequest_TypeID = 0x976bce7564c27fce
+
+func NewConmon_RemoveNamespacesRequest(s *capnp.Segment) (Conmon_RemoveNamespacesRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_RemoveNamespacesRequest{st}, err
+}
+
+func NewRootConmon_RemoveNamespacesRequest(s *capnp.Segment) (Conmon_RemoveNamespacesRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_RemoveNamespacesRequest{st}, err
+}
+
+func ReadRootConmon_RemoveNamespacesRequest(msg *capnp.Message) (Conmon_RemoveNamespacesRequest, error) {
+	root, err := msg.Root()
+	return Conmon_RemoveNamespacesRequest{root.Struct()}, err
+}
+
+func (s Conmon_RemoveNamespacesRequest) String() string {
+	str, _ := text.Marshal(0x976bce7564c27fce, s.Struct)
+	return str
+}
+
+func (s Conmon_RemoveNamespacesRequest) BasePath() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_RemoveNamespacesRequest) HasBasePath() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_RemoveNamespacesRequest) BasePathBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_RemoveNamespacesRequest) SetBasePath(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_RemoveNamespacesRequest) PodId() (string, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_RemoveNamespacesRequest) HasPodId() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_RemoveNamespacesRequest) PodIdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_RemoveNamespacesRequest) SetPodId(v string) error {
+	return s.Struct.SetText(1, v)
+}
+
+func (s Conmon_RemoveNamespacesRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(2)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_RemoveNamespacesRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(2)
+}
+
+func (s Conmon_RemoveNamespacesRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(2, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_RemoveNamespacesRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(2, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_RemoveNamespacesRequest_List is a list of Conmon_RemoveNamespacesRequest.
+type Conmon_RemoveNamespacesRequest_List = capnp.StructList[Conmon_RemoveNamespacesRequest]
+
+// NewConmon_RemoveNamespacesRequest creates a new list of Conmon_RemoveNamespacesRequest.
+func NewConmon_RemoveNamespacesRequest_List(s *capnp.Segment, sz int32) (Conmon_RemoveNamespacesRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_RemoveNamespacesRequest]{l}, err
+}
+
+// Conmon_RemoveNamespacesRequest_Future is a wrapper for a Conmon_RemoveNamespacesRequest promised by a client call.
+type Conmon_RemoveNamespacesRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_RemoveNamespacesRequest_Future) Struct() (Conmon_RemoveNamespacesRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RemoveNamespacesRequest{s}, err
+}
+
+func (p Conmon_RemoveNamespacesRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(2, nil)}
+}
+
+type Conmon_RemoveNamespacesResponse struct{ capnp.Struct }
+
+// Conmon_RemoveNamespacesResponse_TypeID is the unique identifier for the type Conmon_RemoveNamespacesResponse.
+const Conmon_RemoveNamespacesResponse_TypeID = 0x94fba2078b461596
+
+func NewConmon_RemoveNamespacesResponse(s *capnp.Segment) (Conmon_RemoveNamespacesResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_RemoveNamespacesResponse{st}, err
+}
+
+func NewRootConmon_RemoveNamespacesResponse(s *capnp.Segment) (Conmon_RemoveNamespacesResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_RemoveNamespacesResponse{st}, err
+}
+
+func ReadRootConmon_RemoveNamespacesResponse(msg *capnp.Message) (Conmon_RemoveNamespacesResponse, error) {
+	root, err := msg.Root()
+	return Conmon_RemoveNamespacesResponse{root.Struct()}, err
+}
+
+func (s Conmon_RemoveNamespacesResponse) String() string {
+	str, _ := text.Marshal(0x94fba2078b461596, s.Struct)
+	return str
+}
+
+// Conmon_RemoveNamespacesResponse_List is a list of Conmon_RemoveNamespacesResponse.
+type Conmon_RemoveNamespacesResponse_List = capnp.StructList[Conmon_RemoveNamespacesResponse]
+
+// NewConmon_RemoveNamespacesResponse creates a new list of Conmon_RemoveNamespacesResponse.
+func NewConmon_RemoveNamespacesResponse_List(s *capnp.Segment, sz int32) (Conmon_RemoveNamespacesResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_RemoveNamespacesResponse]{l}, err
+}
+
+// Conmon_RemoveNamespacesResponse_Future is a wrapper for a Conmon_RemoveNamespacesResponse promised by a client call.
+type Conmon_RemoveNamespacesResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_RemoveNamespacesResponse_Future) Struct() (Conmon_RemoveNamespacesResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RemoveNamespacesResponse{s}, err
+}
+
+type Conmon_RegisterProbeRequest struct{ capnp.Struct }
+
+// Conmon_RegisterProbeRequest_TypeID is the unique identifier for the type Conmon_RegisterProbeRequest.
+const Conmon_RegisterProbeRequest_TypeID = 0x90f68440d914a14c
+
+func NewConmon_RegisterProbeRequest(s *capnp.Segment) (Conmon_RegisterProbeRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 4})
+	return Conmon_RegisterProbeRequest{st}, err
+}
+
+func NewRootConmon_RegisterProbeRequest(s *capnp.Segment) (Conmon_RegisterProbeRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 4})
+	return Conmon_RegisterProbeRequest{st}, err
+}
+
+func ReadRootConmon_RegisterProbeRequest(msg *capnp.Message) (Conmon_RegisterProbeRequest, error) {
+	root, err := msg.Root()
+	return Conmon_RegisterProbeRequest{root.Struct()}, err
+}
+
+func (s Conmon_RegisterProbeRequest) String() string {
+	str, _ := text.Marshal(0x90f68440d914a14c, s.Struct)
+	return str
+}
+
+func (s Conmon_RegisterProbeRequest) Id() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_RegisterProbeRequest) HasId() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_RegisterProbeRequest) IdBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_RegisterProbeRequest) SetId(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_RegisterProbeRequest) Name() (string, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_RegisterProbeRequest) HasName() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_RegisterProbeRequest) NameBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_RegisterProbeRequest) SetName(v string) error {
+	return s.Struct.SetText(1, v)
+}
+
+func (s Conmon_RegisterProbeRequest) Command() (capnp.TextList, error) {
+	p, err := s.Struct.Ptr(2)
+	return capnp.TextList{List: p.List()}, err
+}
+
+func (s Conmon_RegisterProbeRequest) HasCommand() bool {
+	return s.Struct.HasPtr(2)
+}
+
+func (s Conmon_RegisterProbeRequest) SetCommand(v capnp.TextList) error {
+	return s.Struct.SetPtr(2, v.List.ToPtr())
+}
+
+// NewCommand sets the command field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_RegisterProbeRequest) NewCommand(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(s.Struct.Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = s.Struct.SetPtr(2, l.List.ToPtr())
+	return l, err
+}
+
+func (s Conmon_RegisterProbeRequest) TcpPort() uint16 {
+	return s.Struct.Uint16(0)
+}
+
+func (s Conmon_RegisterProbeRequest) SetTcpPort(v uint16) {
+	s.Struct.SetUint16(0, v)
+}
+
+func (s Conmon_RegisterProbeRequest) InitialDelaySec() uint32 {
+	return s.Struct.Uint32(4)
+}
+
+func (s Conmon_RegisterProbeRequest) SetInitialDelaySec(v uint32) {
+	s.Struct.SetUint32(4, v)
+}
+
+func (s Conmon_RegisterProbeRequest) PeriodSec() uint32 {
+	return s.Struct.Uint32(8)
+}
+
+func (s Conmon_RegisterProbeRequest) SetPeriodSec(v uint32) {
+	s.Struct.SetUint32(8, v)
+}
+
+func (s Conmon_RegisterProbeRequest) TimeoutSec() uint32 {
+	return s.Struct.Uint32(12)
+}
+
+func (s Conmon_RegisterProbeRequest) SetTimeoutSec(v uint32) {
+	s.Struct.SetUint32(12, v)
+}
+
+func (s Conmon_RegisterProbeRequest) SuccessThreshold() uint32 {
+	return s.Struct.Uint32(16)
+}
+
+func (s Conmon_RegisterProbeRequest) SetSuccessThreshold(v uint32) {
+	s.Struct.SetUint32(16, v)
+}
+
+func (s Conmon_RegisterProbeRequest) FailureThreshold() uint32 {
+	return s.Struct.Uint32(20)
+}
+
+func (s Conmon_RegisterProbeRequest) SetFailureThreshold(v uint32) {
+	s.Struct.SetUint32(20, v)
+}
+
+func (s Conmon_RegisterProbeRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(3)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_RegisterProbeRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(3)
+}
+
+func (s Conmon_RegisterProbeRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(3, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_RegisterProbeRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(3, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_RegisterProbeRequest_List is a list of Conmon_RegisterProbeRequest.
+type Conmon_RegisterProbeRequest_List = capnp.StructList[Conmon_RegisterProbeRequest]
+
+// NewConmon_RegisterProbeRequest creates a new list of Conmon_RegisterProbeRequest.
+func NewConmon_RegisterProbeRequest_List(s *capnp.Segment, sz int32) (Conmon_RegisterProbeRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 24, PointerCount: 4}, sz)
+	return capnp.StructList[Conmon_RegisterProbeRequest]{l}, err
+}
+
+// Conmon_RegisterProbeRequest_Future is a wrapper for a Conmon_RegisterProbeRequest promised by a client call.
+type Conmon_RegisterProbeRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_RegisterProbeRequest_Future) Struct() (Conmon_RegisterProbeRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RegisterProbeRequest{s}, err
+}
+
+func (p Conmon_RegisterProbeRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(3, nil)}
+}
+
+type Conmon_RegisterProbeResponse struct{ capnp.Struct }
+
+// Conmon_RegisterProbeResponse_TypeID is the unique identifier for the type Conmon_RegisterProbeResponse.
+const Conmon_RegisterProbeResponse_TypeID = 0xd345304c72ce2f15
+
+func NewConmon_RegisterProbeResponse(s *capnp.Segment) (Conmon_RegisterProbeResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_RegisterProbeResponse{st}, err
+}
+
+func NewRootConmon_RegisterProbeResponse(s *capnp.Segment) (Conmon_RegisterProbeResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_RegisterProbeResponse{st}, err
+}
+
+func ReadRootConmon_RegisterProbeResponse(msg *capnp.Message) (Conmon_RegisterProbeResponse, error) {
+	root, err := msg.Root()
+	return Conmon_RegisterProbeResponse{root.Struct()}, err
+}
+
+func (s Conmon_RegisterProbeResponse) String() string {
+	str, _ := text.Marshal(0xd345304c72ce2f15, s.Struct)
+	return str
+}
+
+// Conmon_RegisterProbeResponse_List is a list of Conmon_RegisterProbeResponse.
+type Conmon_RegisterProbeResponse_List = capnp.StructList[Conmon_RegisterProbeResponse]
+
+// NewConmon_RegisterProbeResponse creates a new list of Conmon_RegisterProbeResponse.
+func NewConmon_RegisterProbeResponse_List(s *capnp.Segment, sz int32) (Conmon_RegisterProbeResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_RegisterProbeResponse]{l}, err
+}
+
+// Conmon_RegisterProbeResponse_Future is a wrapper for a Conmon_RegisterProbeResponse promised by a client call.
+type Conmon_RegisterProbeResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_RegisterProbeResponse_Future) Struct() (Conmon_RegisterProbeResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RegisterProbeResponse{s}, err
+}
+
+type Conmon_SetLogLevelRequest struct{ capnp.Struct }
+
+// Conmon_SetLogLevelRequest_TypeID is the unique identifier for the type Conmon_SetLogLevelRequest.
+const Conmon_SetLogLevelRequest_TypeID = 0xc437ab0d294724d9
+
+func NewConmon_SetLogLevelRequest(s *capnp.Segment) (Conmon_SetLogLevelRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_SetLogLevelRequest{st}, err
+}
+
+func NewRootConmon_SetLogLevelRequest(s *capnp.Segment) (Conmon_SetLogLevelRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_SetLogLevelRequest{st}, err
+}
+
+func ReadRootConmon_SetLogLevelRequest(msg *capnp.Message) (Conmon_SetLogLevelRequest, error) {
+	root, err := msg.Root()
+	return Conmon_SetLogLevelRequest{root.Struct()}, err
+}
+
+func (s Conmon_SetLogLevelRequest) String() string {
+	str, _ := text.Marshal(0xc437ab0d294724d9, s.Struct)
+	return str
+}
+
+func (s Conmon_SetLogLevelRequest) Filter() (string, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_SetLogLevelRequest) HasFilter() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_SetLogLevelRequest) FilterBytes() ([]byte, error) {
+	p, err := s.Struct.Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_SetLogLevelRequest) SetFilter(v string) error {
+	return s.Struct.SetText(0, v)
+}
+
+func (s Conmon_SetLogLevelRequest) TimeoutSec() uint64 {
+	return s.Struct.Uint64(0)
+}
+
+func (s Conmon_SetLogLevelRequest) SetTimeoutSec(v uint64) {
+	s.Struct.SetUint64(0, v)
+}
+
+func (s Conmon_SetLogLevelRequest) Metadata() (Conmon_Metadata, error) {
+	p, err := s.Struct.Ptr(1)
+	return Conmon_Metadata{Struct: p.Struct()}, err
+}
+
+func (s Conmon_SetLogLevelRequest) HasMetadata() bool {
+	return s.Struct.HasPtr(1)
+}
+
+func (s Conmon_SetLogLevelRequest) SetMetadata(v Conmon_Metadata) error {
+	return s.Struct.SetPtr(1, v.Struct.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_Metadata struct, preferring placement in s's segment.
+func (s Conmon_SetLogLevelRequest) NewMetadata() (Conmon_Metadata, error) {
+	ss, err := NewConmon_Metadata(s.Struct.Segment())
+	if err != nil {
+		return Conmon_Metadata{}, err
+	}
+	err = s.Struct.SetPtr(1, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_SetLogLevelRequest_List is a list of Conmon_SetLogLevelRequest.
+type Conmon_SetLogLevelRequest_List = capnp.StructList[Conmon_SetLogLevelRequest]
+
+// NewConmon_SetLogLevelRequest creates a new list of Conmon_SetLogLevelRequest.
+func NewConmon_SetLogLevelRequest_List(s *capnp.Segment, sz int32) (Conmon_SetLogLevelRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_SetLogLevelRequest]{l}, err
+}
+
+// Conmon_SetLogLevelRequest_Future is a wrapper for a Conmon_SetLogLevelRequest promised by a client call.
+type Conmon_SetLogLevelRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_SetLogLevelRequest_Future) Struct() (Conmon_SetLogLevelRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetLogLevelRequest{s}, err
+}
+
+func (p Conmon_SetLogLevelRequest_Future) Metadata() Conmon_Metadata_Future {
+	return Conmon_Metadata_Future{Future: p.Future.Field(1, nil)}
+}
+
+type Conmon_SetLogLevelResponse struct{ capnp.Struct }
+
+// Conmon_SetLogLevelResponse_TypeID is the unique identifier for the type Conmon_SetLogLevelResponse.
+const Conmon_SetLogLevelResponse_TypeID = 0xc8865f0ec25b4ef8
+
+func NewConmon_SetLogLevelResponse(s *capnp.Segment) (Conmon_SetLogLevelResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetLogLevelResponse{st}, err
+}
+
+func NewRootConmon_SetLogLevelResponse(s *capnp.Segment) (Conmon_SetLogLevelResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetLogLevelResponse{st}, err
+}
+
+func ReadRootConmon_SetLogLevelResponse(msg *capnp.Message) (Conmon_SetLogLevelResponse, error) {
+	root, err := msg.Root()
+	return Conmon_SetLogLevelResponse{root.Struct()}, err
+}
+
+func (s Conmon_SetLogLevelResponse) String() string {
+	str, _ := text.Marshal(0xc8865f0ec25b4ef8, s.Struct)
+	return str
+}
+
+// Conmon_SetLogLevelResponse_List is a list of Conmon_SetLogLevelResponse.
+type Conmon_SetLogLevelResponse_List = capnp.StructList[Conmon_SetLogLevelResponse]
+
+// NewConmon_SetLogLevelResponse creates a new list of Conmon_SetLogLevelResponse.
+func NewConmon_SetLogLevelResponse_List(s *capnp.Segment, sz int32) (Conmon_SetLogLevelResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_SetLogLevelResponse]{l}, err
+}
+
+// Conmon_SetLogLevelResponse_Future is a wrapper for a Conmon_SetLogLevelResponse promised by a client call.
+type Conmon_SetLogLevelResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_SetLogLevelResponse_Future) Struct() (Conmon_SetLogLevelResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetLogLevelResponse{s}, err
+}
+
+type Conmon_version_Params struct{ capnp.Struct }
+
+// Conmon_version_Params_TypeID is the unique identifier for the type Conmon_version_Params.
+const Conmon_version_Params_TypeID = 0xcc2f70676afee4e7
+
+func NewConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_version_Params{st}, err
+}
+
+func NewRootConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_version_Params{st}, err
+}
+
+func ReadRootConmon_version_Params(msg *capnp.Message) (Conmon_version_Params, error) {
+	root, err := msg.Root()
+	return Conmon_version_Params{root.Struct()}, err
+}
+
+func (s Conmon_version_Params) String() string {
+	str, _ := text.Marshal(0xcc2f70676afee4e7, s.Struct)
+	return str
+}
+
+// Conmon_version_Params_List is a list of Conmon_version_Params.
+type Conmon_version_Params_List = capnp.StructList[Conmon_version_Params]
+
+// NewConmon_version_Params creates a new list of Conmon_version_Params.
+func NewConmon_version_Params_List(s *capnp.Segment, sz int32) (Conmon_version_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_version_Params]{l}, err
+}
+
+// Conmon_version_Params_Future is a wrapper for a Conmon_version_Params promised by a client call.
+type Conmon_version_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_version_Params_Future) Struct() (Conmon_version_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_version_Params{s}, err
+}
+
+type Conmon_version_Results struct{ capnp.Struct }
+
+// Conmon_version_Results_TypeID is the unique identifier for the type Conmon_version_Results.
+const Conmon_version_Results_TypeID = 0xe313695ea9477b30
+
+func NewConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_version_Results{st}, err
+}
+
+func NewRootConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_version_Results{st}, err
+}
+
+func ReadRootConmon_version_Results(msg *capnp.Message) (Conmon_version_Results, error) {
+	root, err := msg.Root()
+	return Conmon_version_Results{root.Struct()}, err
+}
+
+func (s Conmon_version_Results) String() string {
+	str, _ := text.Marshal(0xe313695ea9477b30, s.Struct)
+	return str
+}
+
+func (s Conmon_version_Results) Response() (Conmon_VersionResponse, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_VersionResponse{Struct: p.Struct()}, err
+}
+
+func (s Conmon_version_Results) HasResponse() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_version_Results) SetResponse(v Conmon_VersionResponse) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_VersionResponse struct, preferring placement in s's segment.
+func (s Conmon_version_Results) NewResponse() (Conmon_VersionResponse, error) {
+	ss, err := NewConmon_VersionResponse(s.Struct.Segment())
+	if err != nil {
+		return Conmon_VersionResponse{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_version_Results_List is a list of Conmon_version_Results.
+type Conmon_version_Results_List = capnp.StructList[Conmon_version_Results]
+
+// NewConmon_version_Results creates a new list of Conmon_version_Results.
+func NewConmon_version_Results_List(s *capnp.Segment, sz int32) (Conmon_version_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_version_Results]{l}, err
+}
+
+// Conmon_version_Results_Future is a wrapper for a Conmon_version_Results promised by a client call.
+type Conmon_version_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_version_Results_Future) Struct() (Conmon_version_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_version_Results{s}, err
+}
+
+func (p Conmon_version_Results_Future) Response() Conmon_VersionResponse_Future {
+	return Conmon_VersionResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_createContainer_Params struct{ capnp.Struct }
+
+// Conmon_createContainer_Params_TypeID is the unique identifier for the type Conmon_createContainer_Params.
+const Conmon_createContainer_Params_TypeID = 0xf44732c48f949ab8
+
+func NewConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Params{st}, err
+}
+
+func NewRootConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Params{st}, err
+}
+
+func ReadRootConmon_createContainer_Params(msg *capnp.Message) (Conmon_createContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_createContainer_Params{root.Struct()}, err
+}
+
+func (s Conmon_createContainer_Params) String() string {
+	str, _ := text.Marshal(0xf44732c48f949ab8, s.Struct)
+	return str
+}
+
+func (s Conmon_createContainer_Params) Request() (Conmon_CreateContainerRequest, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_CreateContainerRequest{Struct: p.Struct()}, err
+}
+
+func (s Conmon_createContainer_Params) HasRequest() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_createContainer_Params) SetRequest(v Conmon_CreateContainerRequest) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_CreateContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_createContainer_Params) NewRequest() (Conmon_CreateContainerRequest, error) {
+	ss, err := NewConmon_CreateContainerRequest(s.Struct.Segment())
+	if err != nil {
+		return Conmon_CreateContainerRequest{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_createContainer_Params_List is a list of Conmon_createContainer_Params.
+type Conmon_createContainer_Params_List = capnp.StructList[Conmon_createContainer_Params]
+
+// NewConmon_createContainer_Params creates a new list of Conmon_createContainer_Params.
+func NewConmon_createContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_createContainer_Params]{l}, err
+}
+
+// Conmon_createContainer_Params_Future is a wrapper for a Conmon_createContainer_Params promised by a client call.
+type Conmon_createContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_createContainer_Params_Future) Struct() (Conmon_createContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_createContainer_Params{s}, err
+}
+
+func (p Conmon_createContainer_Params_Future) Request() Conmon_CreateContainerRequest_Future {
+	return Conmon_CreateContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_createContainer_Results struct{ capnp.Struct }
+
+// Conmon_createContainer_Results_TypeID is the unique identifier for the type Conmon_createContainer_Results.
+const Conmon_createContainer_Results_TypeID = 0xceba3c1a97be15f8
+
+func NewConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Results{st}, err
+}
+
+func NewRootConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Results{st}, err
+}
+
+func ReadRootConmon_createContainer_Results(msg *capnp.Message) (Conmon_createContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_createContainer_Results{root.Struct()}, err
+}
+
+func (s Conmon_createContainer_Results) String() string {
+	str, _ := text.Marshal(0xceba3c1a97be15f8, s.Struct)
+	return str
+}
+
+func (s Conmon_createContainer_Results) Response() (Conmon_CreateContainerResponse, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_CreateContainerResponse{Struct: p.Struct()}, err
+}
+
+func (s Conmon_createContainer_Results) HasResponse() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_createContainer_Results) SetResponse(v Conmon_CreateContainerResponse) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_CreateContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_createContainer_Results) NewResponse() (Conmon_CreateContainerResponse, error) {
+	ss, err := NewConmon_CreateContainerResponse(s.Struct.Segment())
+	if err != nil {
+		return Conmon_CreateContainerResponse{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_createContainer_Results_List is a list of Conmon_createContainer_Results.
+type Conmon_createContainer_Results_List = capnp.StructList[Conmon_createContainer_Results]
+
+// NewConmon_createContainer_Results creates a new list of Conmon_createContainer_Results.
+func NewConmon_createContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_createContainer_Results]{l}, err
+}
+
+// Conmon_createContainer_Results_Future is a wrapper for a Conmon_createContainer_Results promised by a client call.
+type Conmon_createContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_createContainer_Results_Future) Struct() (Conmon_createContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_createContainer_Results{s}, err
+}
+
+func (p Conmon_createContainer_Results_Future) Response() Conmon_CreateContainerResponse_Future {
+	return Conmon_CreateContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_execSyncContainer_Params struct{ capnp.Struct }
+
+// Conmon_execSyncContainer_Params_TypeID is the unique identifier for the type Conmon_execSyncContainer_Params.
+const Conmon_execSyncContainer_Params_TypeID = 0x83479da67279e173
+
+func NewConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Params{st}, err
+}
+
+func NewRootConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Params{st}, err
+}
+
+func ReadRootConmon_execSyncContainer_Params(msg *capnp.Message) (Conmon_execSyncContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_execSyncContainer_Params{root.Struct()}, err
+}
+
+func (s Conmon_execSyncContainer_Params) String() string {
+	str, _ := text.Marshal(0x83479da67279e173, s.Struct)
+	return str
+}
+
+func (s Conmon_execSyncContainer_Params) Request() (Conmon_ExecSyncContainerRequest, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_ExecSyncContainerRequest{Struct: p.Struct()}, err
+}
+
+func (s Conmon_execSyncContainer_Params) HasRequest() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_execSyncContainer_Params) SetRequest(v Conmon_ExecSyncContainerRequest) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ExecSyncContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_execSyncContainer_Params) NewRequest() (Conmon_ExecSyncContainerRequest, error) {
+	ss, err := NewConmon_ExecSyncContainerRequest(s.Struct.Segment())
+	if err != nil {
+		return Conmon_ExecSyncContainerRequest{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_execSyncContainer_Params_List is a list of Conmon_execSyncContainer_Params.
+type Conmon_execSyncContainer_Params_List = capnp.StructList[Conmon_execSyncContainer_Params]
+
+// NewConmon_execSyncContainer_Params creates a new list of Conmon_execSyncContainer_Params.
+func NewConmon_execSyncContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_execSyncContainer_Params]{l}, err
+}
+
+// Conmon_execSyncContainer_Params_Future is a wrapper for a Conmon_execSyncContainer_Params promised by a client call.
+type Conmon_execSyncContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_execSyncContainer_Params_Future) Struct() (Conmon_execSyncContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_execSyncContainer_Params{s}, err
+}
+
+func (p Conmon_execSyncContainer_Params_Future) Request() Conmon_ExecSyncContainerRequest_Future {
+	return Conmon_ExecSyncContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_execSyncContainer_Results struct{ capnp.Struct }
+
+// Conmon_execSyncContainer_Results_TypeID is the unique identifier for the type Conmon_execSyncContainer_Results.
+const Conmon_execSyncContainer_Results_TypeID = 0xf8e86a5c0baa01bc
+
+func NewConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Results{st}, err
+}
+
+func NewRootConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Results{st}, err
+}
+
+func ReadRootConmon_execSyncContainer_Results(msg *capnp.Message) (Conmon_execSyncContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_execSyncContainer_Results{root.Struct()}, err
+}
+
+func (s Conmon_execSyncContainer_Results) String() string {
+	str, _ := text.Marshal(0xf8e86a5c0baa01bc, s.Struct)
+	return str
+}
+
+func (s Conmon_execSyncContainer_Results) Response() (Conmon_ExecSyncContainerResponse, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_ExecSyncContainerResponse{Struct: p.Struct()}, err
+}
+
+func (s Conmon_execSyncContainer_Results) HasResponse() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_execSyncContainer_Results) SetResponse(v Conmon_ExecSyncContainerResponse) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ExecSyncContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_execSyncContainer_Results) NewResponse() (Conmon_ExecSyncContainerResponse, error) {
+	ss, err := NewConmon_ExecSyncContainerResponse(s.Struct.Segment())
+	if err != nil {
+		return Conmon_ExecSyncContainerResponse{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_execSyncContainer_Results_List is a list of Conmon_execSyncContainer_Results.
+type Conmon_execSyncContainer_Results_List = capnp.StructList[Conmon_execSyncContainer_Results]
+
+// NewConmon_execSyncContainer_Results creates a new list of Conmon_execSyncContainer_Results.
+func NewConmon_execSyncContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_execSyncContainer_Results]{l}, err
+}
+
+// Conmon_execSyncContainer_Results_Future is a wrapper for a Conmon_execSyncContainer_Results promised by a client call.
+type Conmon_execSyncContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_execSyncContainer_Results_Future) Struct() (Conmon_execSyncContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_execSyncContainer_Results{s}, err
+}
+
+func (p Conmon_execSyncContainer_Results_Future) Response() Conmon_ExecSyncContainerResponse_Future {
+	return Conmon_ExecSyncContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_attachContainer_Params struct{ capnp.Struct }
+
+// Conmon_attachContainer_Params_TypeID is the unique identifier for the type Conmon_attachContainer_Params.
+const Conmon_attachContainer_Params_TypeID = 0xaa2f3c8ad1c3af24
+
+func NewConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Params{st}, err
+}
+
+func NewRootConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Params{st}, err
+}
+
+func ReadRootConmon_attachContainer_Params(msg *capnp.Message) (Conmon_attachContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_attachContainer_Params{root.Struct()}, err
+}
+
+func (s Conmon_attachContainer_Params) String() string {
+	str, _ := text.Marshal(0xaa2f3c8ad1c3af24, s.Struct)
+	return str
+}
+
+func (s Conmon_attachContainer_Params) Request() (Conmon_AttachRequest, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_AttachRequest{Struct: p.Struct()}, err
+}
+
+func (s Conmon_attachContainer_Params) HasRequest() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_attachContainer_Params) SetRequest(v Conmon_AttachRequest) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_AttachRequest struct, preferring placement in s's segment.
+func (s Conmon_attachContainer_Params) NewRequest() (Conmon_AttachRequest, error) {
+	ss, err := NewConmon_AttachRequest(s.Struct.Segment())
+	if err != nil {
+		return Conmon_AttachRequest{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_attachContainer_Params_List is a list of Conmon_attachContainer_Params.
+type Conmon_attachContainer_Params_List = capnp.StructList[Conmon_attachContainer_Params]
+
+// NewConmon_attachContainer_Params creates a new list of Conmon_attachContainer_Params.
+func NewConmon_attachContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_attachContainer_Params]{l}, err
+}
+
+// Conmon_attachContainer_Params_Future is a wrapper for a Conmon_attachContainer_Params promised by a client call.
+type Conmon_attachContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_attachContainer_Params_Future) Struct() (Conmon_attachContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_attachContainer_Params{s}, err
+}
+
+func (p Conmon_attachContainer_Params_Future) Request() Conmon_AttachRequest_Future {
+	return Conmon_AttachRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_attachContainer_Results struct{ capnp.Struct }
+
+// Conmon_attachContainer_Results_TypeID is the unique identifier for the type Conmon_attachContainer_Results.
+const Conmon_attachContainer_Results_TypeID = 0xc5e65eec3dcf5b10
+
+func NewConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Results{st}, err
+}
+
+func NewRootConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Results{st}, err
+}
+
+func ReadRootConmon_attachContainer_Results(msg *capnp.Message) (Conmon_attachContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_attachContainer_Results{root.Struct()}, err
+}
+
+func (s Conmon_attachContainer_Results) String() string {
+	str, _ := text.Marshal(0xc5e65eec3dcf5b10, s.Struct)
+	return str
+}
+
+func (s Conmon_attachContainer_Results) Response() (Conmon_AttachResponse, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_AttachResponse{Struct: p.Struct()}, err
+}
+
+func (s Conmon_attachContainer_Results) HasResponse() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_attachContainer_Results) SetResponse(v Conmon_AttachResponse) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_AttachResponse struct, preferring placement in s's segment.
+func (s Conmon_attachContainer_Results) NewResponse() (Conmon_AttachResponse, error) {
+	ss, err := NewConmon_AttachResponse(s.Struct.Segment())
+	if err != nil {
+		return Conmon_AttachResponse{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_attachContainer_Results_List is a list of Conmon_attachContainer_Results.
+type Conmon_attachContainer_Results_List = capnp.StructList[Conmon_attachContainer_Results]
+
+// NewConmon_attachContainer_Results creates a new list of Conmon_attachContainer_Results.
+func NewConmon_attachContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_attachContainer_Results]{l}, err
+}
+
+// Conmon_attachContainer_Results_Future is a wrapper for a Conmon_attachContainer_Results promised by a client call.
+type Conmon_attachContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_attachContainer_Results_Future) Struct() (Conmon_attachContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_attachContainer_Results{s}, err
+}
+
+func (p Conmon_attachContainer_Results_Future) Response() Conmon_AttachResponse_Future {
+	return Conmon_AttachResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_reopenLogContainer_Params struct{ capnp.Struct }
+
+// Conmon_reopenLogContainer_Params_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Params.
+const Conmon_reopenLogContainer_Params_TypeID = 0xe5ea916eb0c31336
+
+func NewConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Params{st}, err
+}
+
+func NewRootConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Params{st}, err
+}
+
+func ReadRootConmon_reopenLogContainer_Params(msg *capnp.Message) (Conmon_reopenLogContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_reopenLogContainer_Params{root.Struct()}, err
+}
+
+func (s Conmon_reopenLogContainer_Params) String() string {
+	str, _ := text.Marshal(0xe5ea916eb0c31336, s.Struct)
+	return str
+}
+
+func (s Conmon_reopenLogContainer_Params) Request() (Conmon_ReopenLogRequest, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_ReopenLogRequest{Struct: p.Struct()}, err
+}
+
+func (s Conmon_reopenLogContainer_Params) HasRequest() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_reopenLogContainer_Params) SetRequest(v Conmon_ReopenLogRequest) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ReopenLogRequest struct, preferring placement in s's segment.
+func (s Conmon_reopenLogContainer_Params) NewRequest() (Conmon_ReopenLogRequest, error) {
+	ss, err := NewConmon_ReopenLogRequest(s.Struct.Segment())
+	if err != nil {
+		return Conmon_ReopenLogRequest{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_reopenLogContainer_Params_List is a list of Conmon_reopenLogContainer_Params.
+type Conmon_reopenLogContainer_Params_List = capnp.StructList[Conmon_reopenLogContainer_Params]
+
+// NewConmon_reopenLogContainer_Params creates a new list of Conmon_reopenLogContainer_Params.
+func NewConmon_reopenLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_reopenLogContainer_Params]{l}, err
+}
+
+// Conmon_reopenLogContainer_Params_Future is a wrapper for a Conmon_reopenLogContainer_Params promised by a client call.
+type Conmon_reopenLogContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_reopenLogContainer_Params_Future) Struct() (Conmon_reopenLogContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_reopenLogContainer_Params{s}, err
+}
+
+func (p Conmon_reopenLogContainer_Params_Future) Request() Conmon_ReopenLogRequest_Future {
+	return Conmon_ReopenLogRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_reopenLogContainer_Results struct{ capnp.Struct }
+
+// Conmon_reopenLogContainer_Results_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Results.
+const Conmon_reopenLogContainer_Results_TypeID = 0xa0ef8355b64ee985
+
+func NewConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Results{st}, err
+}
+
+func NewRootConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Results{st}, err
+}
+
+func ReadRootConmon_reopenLogContainer_Results(msg *capnp.Message) (Conmon_reopenLogContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_reopenLogContainer_Results{root.Struct()}, err
+}
+
+func (s Conmon_reopenLogContainer_Results) String() string {
+	str, _ := text.Marshal(0xa0ef8355b64ee985, s.Struct)
+	return str
+}
+
+func (s Conmon_reopenLogContainer_Results) Response() (Conmon_ReopenLogResponse, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_ReopenLogResponse{Struct: p.Struct()}, err
+}
+
+func (s Conmon_reopenLogContainer_Results) HasResponse() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_reopenLogContainer_Results) SetResponse(v Conmon_ReopenLogResponse) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ReopenLogResponse struct, preferring placement in s's segment.
+func (s Conmon_reopenLogContainer_Results) NewResponse() (Conmon_ReopenLogResponse, error) {
+	ss, err := NewConmon_ReopenLogResponse(s.Struct.Segment())
+	if err != nil {
+		return Conmon_ReopenLogResponse{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_reopenLogContainer_Results_List is a list of Conmon_reopenLogContainer_Results.
+type Conmon_reopenLogContainer_Results_List = capnp.StructList[Conmon_reopenLogContainer_Results]
+
+// NewConmon_reopenLogContainer_Results creates a new list of Conmon_reopenLogContainer_Results.
+func NewConmon_reopenLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_reopenLogContainer_Results]{l}, err
+}
+
+// Conmon_reopenLogContainer_Results_Future is a wrapper for a Conmon_reopenLogContainer_Results promised by a client call.
+type Conmon_reopenLogContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_reopenLogContainer_Results_Future) Struct() (Conmon_reopenLogContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_reopenLogContainer_Results{s}, err
+}
+
+func (p Conmon_reopenLogContainer_Results_Future) Response() Conmon_ReopenLogResponse_Future {
+	return Conmon_ReopenLogResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_setWindowSizeContainer_Params struct{ capnp.Struct }
+
+// Conmon_setWindowSizeContainer_Params_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Params.
+const Conmon_setWindowSizeContainer_Params_TypeID = 0xc76ccd4502bb61e7
+
+func NewConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Params{st}, err
+}
+
+func NewRootConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Params{st}, err
+}
+
+func ReadRootConmon_setWindowSizeContainer_Params(msg *capnp.Message) (Conmon_setWindowSizeContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_setWindowSizeContainer_Params{root.Struct()}, err
+}
+
+func (s Conmon_setWindowSizeContainer_Params) String() string {
+	str, _ := text.Marshal(0xc76ccd4502bb61e7, s.Struct)
+	return str
+}
+
+func (s Conmon_setWindowSizeContainer_Params) Request() (Conmon_SetWindowSizeRequest, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_SetWindowSizeRequest{Struct: p.Struct()}, err
+}
+
+func (s Conmon_setWindowSizeContainer_Params) HasRequest() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_setWindowSizeContainer_Params) SetRequest(v Conmon_SetWindowSizeRequest) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_SetWindowSizeRequest struct, preferring placement in s's segment.
+func (s Conmon_setWindowSizeContainer_Params) NewRequest() (Conmon_SetWindowSizeRequest, error) {
+	ss, err := NewConmon_SetWindowSizeRequest(s.Struct.Segment())
+	if err != nil {
+		return Conmon_SetWindowSizeRequest{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_setWindowSizeContainer_Params_List is a list of Conmon_setWindowSizeContainer_Params.
+type Conmon_setWindowSizeContainer_Params_List = capnp.StructList[Conmon_setWindowSizeContainer_Params]
+
+// NewConmon_setWindowSizeContainer_Params creates a new list of Conmon_setWindowSizeContainer_Params.
+func NewConmon_setWindowSizeContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_setWindowSizeContainer_Params]{l}, err
+}
+
+// Conmon_setWindowSizeContainer_Params_Future is a wrapper for a Conmon_setWindowSizeContainer_Params promised by a client call.
+type Conmon_setWindowSizeContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_setWindowSizeContainer_Params_Future) Struct() (Conmon_setWindowSizeContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_setWindowSizeContainer_Params{s}, err
+}
+
+func (p Conmon_setWindowSizeContainer_Params_Future) Request() Conmon_SetWindowSizeRequest_Future {
+	return Conmon_SetWindowSizeRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_setWindowSizeContainer_Results struct{ capnp.Struct }
+
+// Conmon_setWindowSizeContainer_Results_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Results.
+const Conmon_setWindowSizeContainer_Results_TypeID = 0xe00e522611477055
+
+func NewConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Results{st}, err
+}
+
+func NewRootConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Results{st}, err
+}
+
+func ReadRootConmon_setWindowSizeContainer_Results(msg *capnp.Message) (Conmon_setWindowSizeContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_setWindowSizeContainer_Results{root.Struct()}, err
+}
+
+func (s Conmon_setWindowSizeContainer_Results) String() string {
+	str, _ := text.Marshal(0xe00e522611477055, s.Struct)
+	return str
+}
+
+func (s Conmon_setWindowSizeContainer_Results) Response() (Conmon_SetWindowSizeResponse, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_SetWindowSizeResponse{Struct: p.Struct()}, err
+}
+
+func (s Conmon_setWindowSizeContainer_Results) HasResponse() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_setWindowSizeContainer_Results) SetResponse(v Conmon_SetWindowSizeResponse) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_SetWindowSizeResponse struct, preferring placement in s's segment.
+func (s Conmon_setWindowSizeContainer_Results) NewResponse() (Conmon_SetWindowSizeResponse, error) {
+	ss, err := NewConmon_SetWindowSizeResponse(s.Struct.Segment())
+	if err != nil {
+		return Conmon_SetWindowSizeResponse{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_setWindowSizeContainer_Results_List is a list of Conmon_setWindowSizeContainer_Results.
+type Conmon_setWindowSizeContainer_Results_List = capnp.StructList[Conmon_setWindowSizeContainer_Results]
+
+// NewConmon_setWindowSizeContainer_Results creates a new list of Conmon_setWindowSizeContainer_Results.
+func NewConmon_setWindowSizeContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_setWindowSizeContainer_Results]{l}, err
+}
+
+// Conmon_setWindowSizeContainer_Results_Future is a wrapper for a Conmon_setWindowSizeContainer_Results promised by a client call.
+type Conmon_setWindowSizeContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_setWindowSizeContainer_Results_Future) Struct() (Conmon_setWindowSizeContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_setWindowSizeContainer_Results{s}, err
+}
+
+func (p Conmon_setWindowSizeContainer_Results_Future) Response() Conmon_SetWindowSizeResponse_Future {
+	return Conmon_SetWindowSizeResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_shutdown_Params struct{ capnp.Struct }
+
+// Conmon_shutdown_Params_TypeID is the unique identifier for the type Conmon_shutdown_Params.
+const Conmon_shutdown_Params_TypeID = 0x8b4c03a0662a38dc
+
+func NewConmon_shutdown_Params(s *capnp.Segment) (Conmon_shutdown_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_shutdown_Params{st}, err
+}
+
+func NewRootConmon_shutdown_Params(s *capnp.Segment) (Conmon_shutdown_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_shutdown_Params{st}, err
+}
+
+func ReadRootConmon_shutdown_Params(msg *capnp.Message) (Conmon_shutdown_Params, error) {
+	root, err := msg.Root()
+	return Conmon_shutdown_Params{root.Struct()}, err
+}
+
+func (s Conmon_shutdown_Params) String() string {
+	str, _ := text.Marshal(0x8b4c03a0662a38dc, s.Struct)
+	return str
+}
+
+func (s Conmon_shutdown_Params) Request() (Conmon_ShutdownRequest, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_ShutdownRequest{Struct: p.Struct()}, err
+}
+
+func (s Conmon_shutdown_Params) HasRequest() bool {
+	return s.Struct.HasPtr(0)
+}
+
+func (s Conmon_shutdown_Params) SetRequest(v Conmon_ShutdownRequest) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ShutdownRequest struct, preferring placement in s's segment.
+func (s Conmon_shutdown_Params) NewRequest() (Conmon_ShutdownRequest, error) {
+	ss, err := NewConmon_ShutdownRequest(s.Struct.Segment())
+	if err != nil {
+		return Conmon_ShutdownRequest{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_shutdown_Params_List is a list of Conmon_shutdown_Params.
+type Conmon_shutdown_Params_List = capnp.StructList[Conmon_shutdown_Params]
+
+// NewConmon_shutdown_Params creates a new list of Conmon_shutdown_Params.
+func NewConmon_shutdown_Params_List(s *capnp.Segment, sz int32) (Conmon_shutdown_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_shutdown_Params]{l}, err
+}
+
+// Conmon_shutdown_Params_Future is a wrapper for a Conmon_shutdown_Params promised by a client call.
+type Conmon_shutdown_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_shutdown_Params_Future) Struct() (Conmon_shutdown_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_shutdown_Params{s}, err
+}
+
+func (p Conmon_shutdown_Params_Future) Request() Conmon_ShutdownRequest_Future {
+	return Conmon_ShutdownRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_shutdown_Results struct{ capnp.Struct }
+
+// Conmon_shutdown_Results_TypeID is the unique identifier for the type Conmon_shutdown_Results.
+const Conmon_shutdown_Results_TypeID = 0x8aef91973dc8a4f5
+
+func NewConmon_shutdown_Results(s *capnp.Segment) (Conmon_shutdown_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_shutdown_Results{st}, err
 }
 
-func NewRootConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_ReopenLogResponse{st}, err
+func NewRootConmon_shutdown_Results(s *capnp.Segment) (Conmon_shutdown_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_shutdown_Results{st}, err
 }
 
-func ReadRootConmon_ReopenLogResponse(msg *capnp.Message) (Conmon_ReopenLogResponse, error) {
+func ReadRootConmon_shutdown_Results(msg *capnp.Message) (Conmon_shutdown_Results, error) {
 	root, err := msg.Root()
-	return Conmon_ReopenLogResponse{root.Struct()}, err
+	return Conmon_shutdown_Results{root.Struct()}, err
 }
 
-func (s Conmon_ReopenLogResponse) String() string {
-	str, _ := text.Marshal(0xa20f49456be85b99, s.Struct)
+func (s Conmon_shutdown_Results) String() string {
+	str, _ := text.Marshal(0x8aef91973dc8a4f5, s.Struct)
 	return str
 }
 
-// Conmon_ReopenLogResponse_List is a list of Conmon_ReopenLogResponse.
-type Conmon_ReopenLogResponse_List = capnp.StructList[Conmon_ReopenLogResponse]
+func (s Conmon_shutdown_Results) Response() (Conmon_ShutdownResponse, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_ShutdownResponse{Struct: p.Struct()}, err
+}
 
-// NewConmon_ReopenLogResponse creates a new list of Conmon_ReopenLogResponse.
-func NewConmon_ReopenLogResponse_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_ReopenLogResponse]{l}, err
+func (s Conmon_shutdown_Results) HasResponse() bool {
+	return s.Struct.HasPtr(0)
 }
 
-// Conmon_ReopenLogResponse_Future is a wrapper for a Conmon_ReopenLogResponse promised by a client call.
-type Conmon_ReopenLogResponse_Future struct{ *capnp.Future }
+func (s Conmon_shutdown_Results) SetResponse(v Conmon_ShutdownResponse) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
 
-func (p Conmon_ReopenLogResponse_Future) Struct() (Conmon_ReopenLogResponse, error) {
+// NewResponse sets the response field to a newly
+// allocated Conmon_ShutdownResponse struct, preferring placement in s's segment.
+func (s Conmon_shutdown_Results) NewResponse() (Conmon_ShutdownResponse, error) {
+	ss, err := NewConmon_ShutdownResponse(s.Struct.Segment())
+	if err != nil {
+		return Conmon_ShutdownResponse{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_shutdown_Results_List is a list of Conmon_shutdown_Results.
+type Conmon_shutdown_Results_List = capnp.StructList[Conmon_shutdown_Results]
+
+// NewConmon_shutdown_Results creates a new list of Conmon_shutdown_Results.
+func NewConmon_shutdown_Results_List(s *capnp.Segment, sz int32) (Conmon_shutdown_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_shutdown_Results]{l}, err
+}
+
+// Conmon_shutdown_Results_Future is a wrapper for a Conmon_shutdown_Results promised by a client call.
+type Conmon_shutdown_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_shutdown_Results_Future) Struct() (Conmon_shutdown_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_ReopenLogResponse{s}, err
+	return Conmon_shutdown_Results{s}, err
 }
 
-type Conmon_SetWindowSizeRequest struct{ capnp.Struct }
+func (p Conmon_shutdown_Results_Future) Response() Conmon_ShutdownResponse_Future {
+	return Conmon_ShutdownResponse_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_SetWindowSizeRequest_TypeID is the unique identifier for the type Conmon_SetWindowSizeRequest.
-const Conmon_SetWindowSizeRequest_TypeID = 0xb5418b8ea8ead17b
+type Conmon_listContainers_Params struct{ capnp.Struct }
 
-func NewConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
-	return Conmon_SetWindowSizeRequest{st}, err
+// Conmon_listContainers_Params_TypeID is the unique identifier for the type Conmon_listContainers_Params.
+const Conmon_listContainers_Params_TypeID = 0xce733f0914c80b6b
+
+func NewConmon_listContainers_Params(s *capnp.Segment) (Conmon_listContainers_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Params{st}, err
 }
 
-func NewRootConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
-	return Conmon_SetWindowSizeRequest{st}, err
+func NewRootConmon_listContainers_Params(s *capnp.Segment) (Conmon_listContainers_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Params{st}, err
 }
 
-func ReadRootConmon_SetWindowSizeRequest(msg *capnp.Message) (Conmon_SetWindowSizeRequest, error) {
+func ReadRootConmon_listContainers_Params(msg *capnp.Message) (Conmon_listContainers_Params, error) {
 	root, err := msg.Root()
-	return Conmon_SetWindowSizeRequest{root.Struct()}, err
+	return Conmon_listContainers_Params{root.Struct()}, err
 }
 
-func (s Conmon_SetWindowSizeRequest) String() string {
-	str, _ := text.Marshal(0xb5418b8ea8ead17b, s.Struct)
+func (s Conmon_listContainers_Params) String() string {
+	str, _ := text.Marshal(0xce733f0914c80b6b, s.Struct)
 	return str
 }
 
-func (s Conmon_SetWindowSizeRequest) Id() (string, error) {
+func (s Conmon_listContainers_Params) Request() (Conmon_ListContainersRequest, error) {
 	p, err := s.Struct.Ptr(0)
-	return p.Text(), err
+	return Conmon_ListContainersRequest{Struct: p.Struct()}, err
 }
 
-func (s Conmon_SetWindowSizeRequest) HasId() bool {
+func (s Conmon_listContainers_Params) HasRequest() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_SetWindowSizeRequest) IdBytes() ([]byte, error) {
-	p, err := s.Struct.Ptr(0)
-	return p.TextBytes(), err
+func (s Conmon_listContainers_Params) SetRequest(v Conmon_ListContainersRequest) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
-func (s Conmon_SetWindowSizeRequest) SetId(v string) error {
-	return s.Struct.SetText(0, v)
+// NewRequest sets the request field to a newly
+// allocated Conmon_ListContainersRequest struct, preferring placement in s's segment.
+func (s Conmon_listContainers_Params) NewRequest() (Conmon_ListContainersRequest, error) {
+	ss, err := NewConmon_ListContainersRequest(s.Struct.Segment())
+	if err != nil {
+		return Conmon_ListContainersRequest{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
 }
 
-func (s Conmon_SetWindowSizeRequest) Width() uint16 {
-	return s.Struct.Uint16(0)
-}
+// Conmon_listContainers_Params_List is a list of Conmon_listContainers_Params.
+type Conmon_listContainers_Params_List = capnp.StructList[Conmon_listContainers_Params]
 
-func (s Conmon_SetWindowSizeRequest) SetWidth(v uint16) {
-	s.Struct.SetUint16(0, v)
+// NewConmon_listContainers_Params creates a new list of Conmon_listContainers_Params.
+func NewConmon_listContainers_Params_List(s *capnp.Segment, sz int32) (Conmon_listContainers_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_listContainers_Params]{l}, err
 }
 
-func (s Conmon_SetWindowSizeRequest) Height() uint16 {
-	return s.Struct.Uint16(2)
+// Conmon_listContainers_Params_Future is a wrapper for a Conmon_listContainers_Params promised by a client call.
+type Conmon_listContainers_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_listContainers_Params_Future) Struct() (Conmon_listContainers_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_listContainers_Params{s}, err
 }
 
-func (s Conmon_SetWindowSizeRequest) SetHeight(v uint16) {
-	s.Struct.SetUint16(2, v)
+func (p Conmon_listContainers_Params_Future) Request() Conmon_ListContainersRequest_Future {
+	return Conmon_ListContainersRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-// Conmon_SetWindowSizeRequest_List is a list of Conmon_SetWindowSizeRequest.
-type Conmon_SetWindowSizeRequest_List = capnp.StructList[Conmon_SetWindowSizeRequest]
+type Conmon_listContainers_Results struct{ capnp.Struct }
 
-// NewConmon_SetWindowSizeRequest creates a new list of Conmon_SetWindowSizeRequest.
-func NewConmon_SetWindowSizeRequest_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_SetWindowSizeRequest]{l}, err
-}
+// Conmon_listContainers_Results_TypeID is the unique identifier for the type Conmon_listContainers_Results.
+const Conmon_listContainers_Results_TypeID = 0xf4e3e92ae0815f15
 
-// Conmon_SetWindowSizeRequest_Future is a wrapper for a Conmon_SetWindowSizeRequest promised by a client call.
-type Conmon_SetWindowSizeRequest_Future struct{ *capnp.Future }
+func NewConmon_listContainers_Results(s *capnp.Segment) (Conmon_listContainers_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Results{st}, err
+}
 
-func (p Conmon_SetWindowSizeRequest_Future) Struct() (Conmon_SetWindowSizeRequest, error) {
-	s, err := p.Future.Struct()
-	return Conmon_SetWindowSizeRequest{s}, err
+func NewRootConmon_listContainers_Results(s *capnp.Segment) (Conmon_listContainers_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Results{st}, err
 }
 
-type Conmon_SetWindowSizeResponse struct{ capnp.Struct }
+func ReadRootConmon_listContainers_Results(msg *capnp.Message) (Conmon_listContainers_Results, error) {
+	root, err := msg.Root()
+	return Conmon_listContainers_Results{root.Struct()}, err
+}
 
-// Conmon_SetWindowSizeResponse_TypeID is the unique identifier for the type Conmon_SetWindowSizeResponse.
-const Conmon_SetWindowSizeResponse_TypeID = 0xf9b3cd8033aba1f8
+func (s Conmon_listContainers_Results) String() string {
+	str, _ := text.Marshal(0xf4e3e92ae0815f15, s.Struct)
+	return str
+}
 
-func NewConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_SetWindowSizeResponse{st}, err
+func (s Conmon_listContainers_Results) Response() (Conmon_ListContainersResponse, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_ListContainersResponse{Struct: p.Struct()}, err
 }
 
-func NewRootConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_SetWindowSizeResponse{st}, err
+func (s Conmon_listContainers_Results) HasResponse() bool {
+	return s.Struct.HasPtr(0)
 }
 
-func ReadRootConmon_SetWindowSizeResponse(msg *capnp.Message) (Conmon_SetWindowSizeResponse, error) {
-	root, err := msg.Root()
-	return Conmon_SetWindowSizeResponse{root.Struct()}, err
+func (s Conmon_listContainers_Results) SetResponse(v Conmon_ListContainersResponse) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
-func (s Conmon_SetWindowSizeResponse) String() string {
-	str, _ := text.Marshal(0xf9b3cd8033aba1f8, s.Struct)
-	return str
+// NewResponse sets the response field to a newly
+// allocated Conmon_ListContainersResponse struct, preferring placement in s's segment.
+func (s Conmon_listContainers_Results) NewResponse() (Conmon_ListContainersResponse, error) {
+	ss, err := NewConmon_ListContainersResponse(s.Struct.Segment())
+	if err != nil {
+		return Conmon_ListContainersResponse{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
 }
 
-// Conmon_SetWindowSizeResponse_List is a list of Conmon_SetWindowSizeResponse.
-type Conmon_SetWindowSizeResponse_List = capnp.StructList[Conmon_SetWindowSizeResponse]
+// Conmon_listContainers_Results_List is a list of Conmon_listContainers_Results.
+type Conmon_listContainers_Results_List = capnp.StructList[Conmon_listContainers_Results]
 
-// NewConmon_SetWindowSizeResponse creates a new list of Conmon_SetWindowSizeResponse.
-func NewConmon_SetWindowSizeResponse_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_SetWindowSizeResponse]{l}, err
+// NewConmon_listContainers_Results creates a new list of Conmon_listContainers_Results.
+func NewConmon_listContainers_Results_List(s *capnp.Segment, sz int32) (Conmon_listContainers_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_listContainers_Results]{l}, err
 }
 
-// Conmon_SetWindowSizeResponse_Future is a wrapper for a Conmon_SetWindowSizeResponse promised by a client call.
-type Conmon_SetWindowSizeResponse_Future struct{ *capnp.Future }
+// Conmon_listContainers_Results_Future is a wrapper for a Conmon_listContainers_Results promised by a client call.
+type Conmon_listContainers_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_SetWindowSizeResponse_Future) Struct() (Conmon_SetWindowSizeResponse, error) {
+func (p Conmon_listContainers_Results_Future) Struct() (Conmon_listContainers_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_SetWindowSizeResponse{s}, err
+	return Conmon_listContainers_Results{s}, err
 }
 
-type Conmon_version_Params struct{ capnp.Struct }
+func (p Conmon_listContainers_Results_Future) Response() Conmon_ListContainersResponse_Future {
+	return Conmon_ListContainersResponse_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_version_Params_TypeID is the unique identifier for the type Conmon_version_Params.
-const Conmon_version_Params_TypeID = 0xcc2f70676afee4e7
+type Conmon_killContainer_Params struct{ capnp.Struct }
 
-func NewConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_version_Params{st}, err
+// Conmon_killContainer_Params_TypeID is the unique identifier for the type Conmon_killContainer_Params.
+const Conmon_killContainer_Params_TypeID = 0x90a3950a51412b8b
+
+func NewConmon_killContainer_Params(s *capnp.Segment) (Conmon_killContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_killContainer_Params{st}, err
 }
 
-func NewRootConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_version_Params{st}, err
+func NewRootConmon_killContainer_Params(s *capnp.Segment) (Conmon_killContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_killContainer_Params{st}, err
 }
 
-func ReadRootConmon_version_Params(msg *capnp.Message) (Conmon_version_Params, error) {
+func ReadRootConmon_killContainer_Params(msg *capnp.Message) (Conmon_killContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_version_Params{root.Struct()}, err
+	return Conmon_killContainer_Params{root.Struct()}, err
 }
 
-func (s Conmon_version_Params) String() string {
-	str, _ := text.Marshal(0xcc2f70676afee4e7, s.Struct)
+func (s Conmon_killContainer_Params) String() string {
+	str, _ := text.Marshal(0x90a3950a51412b8b, s.Struct)
 	return str
 }
 
-// Conmon_version_Params_List is a list of Conmon_version_Params.
-type Conmon_version_Params_List = capnp.StructList[Conmon_version_Params]
+func (s Conmon_killContainer_Params) Request() (Conmon_KillContainerRequest, error) {
+	p, err := s.Struct.Ptr(0)
+	return Conmon_KillContainerRequest{Struct: p.Struct()}, err
+}
 
-// NewConmon_version_Params creates a new list of Conmon_version_Params.
-func NewConmon_version_Params_List(s *capnp.Segment, sz int32) (Conmon_version_Params_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_version_Params]{l}, err
+func (s Conmon_killContainer_Params) HasRequest() bool {
+	return s.Struct.HasPtr(0)
 }
 
-// Conmon_version_Params_Future is a wrapper for a Conmon_version_Params promised by a client call.
-type Conmon_version_Params_Future struct{ *capnp.Future }
+func (s Conmon_killContainer_Params) SetRequest(v Conmon_KillContainerRequest) error {
+	return s.Struct.SetPtr(0, v.Struct.ToPtr())
+}
 
-func (p Conmon_version_Params_Future) Struct() (Conmon_version_Params, error) {
+// NewRequest sets the request field to a newly
+// allocated Conmon_KillContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_killContainer_Params) NewRequest() (Conmon_KillContainerRequest, error) {
+	ss, err := NewConmon_KillContainerRequest(s.Struct.Segment())
+	if err != nil {
+		return Conmon_KillContainerRequest{}, err
+	}
+	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
+	return ss, err
+}
+
+// Conmon_killContainer_Params_List is a list of Conmon_killContainer_Params.
+type Conmon_killContainer_Params_List = capnp.StructList[Conmon_killContainer_Params]
+
+// NewConmon_killContainer_Params creates a new list of Conmon_killContainer_Params.
+func NewConmon_killContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_killContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_killContainer_Params]{l}, err
+}
+
+// Conmon_killContainer_Params_Future is a wrapper for a Conmon_killContainer_Params promised by a client call.
+type Conmon_killContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_killContainer_Params_Future) Struct() (Conmon_killContainer_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_version_Params{s}, err
+	return Conmon_killContainer_Params{s}, err
 }
 
-type Conmon_version_Results struct{ capnp.Struct }
+func (p Conmon_killContainer_Params_Future) Request() Conmon_KillContainerRequest_Future {
+	return Conmon_KillContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_version_Results_TypeID is the unique identifier for the type Conmon_version_Results.
-const Conmon_version_Results_TypeID = 0xe313695ea9477b30
+type Conmon_killContainer_Results struct{ capnp.Struct }
 
-func NewConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+// Conmon_killContainer_Results_TypeID is the unique identifier for the type Conmon_killContainer_Results.
+const Conmon_killContainer_Results_TypeID = 0xdebaeed2a782ac80
+
+func NewConmon_killContainer_Results(s *capnp.Segment) (Conmon_killContainer_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_version_Results{st}, err
+	return Conmon_killContainer_Results{st}, err
 }
 
-func NewRootConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+func NewRootConmon_killContainer_Results(s *capnp.Segment) (Conmon_killContainer_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_version_Results{st}, err
+	return Conmon_killContainer_Results{st}, err
 }
 
-func ReadRootConmon_version_Results(msg *capnp.Message) (Conmon_version_Results, error) {
+func ReadRootConmon_killContainer_Results(msg *capnp.Message) (Conmon_killContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_version_Results{root.Struct()}, err
+	return Conmon_killContainer_Results{root.Struct()}, err
 }
 
-func (s Conmon_version_Results) String() string {
-	str, _ := text.Marshal(0xe313695ea9477b30, s.Struct)
+func (s Conmon_killContainer_Results) String() string {
+	str, _ := text.Marshal(0xdebaeed2a782ac80, s.Struct)
 	return str
 }
 
-func (s Conmon_version_Results) Response() (Conmon_VersionResponse, error) {
+func (s Conmon_killContainer_Results) Response() (Conmon_KillContainerResponse, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_VersionResponse{Struct: p.Struct()}, err
+	return Conmon_KillContainerResponse{Struct: p.Struct()}, err
 }
 
-func (s Conmon_version_Results) HasResponse() bool {
+func (s Conmon_killContainer_Results) HasResponse() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_version_Results) SetResponse(v Conmon_VersionResponse) error {
+func (s Conmon_killContainer_Results) SetResponse(v Conmon_KillContainerResponse) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_VersionResponse struct, preferring placement in s's segment.
-func (s Conmon_version_Results) NewResponse() (Conmon_VersionResponse, error) {
-	ss, err := NewConmon_VersionResponse(s.Struct.Segment())
+// allocated Conmon_KillContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_killContainer_Results) NewResponse() (Conmon_KillContainerResponse, error) {
+	ss, err := NewConmon_KillContainerResponse(s.Struct.Segment())
 	if err != nil {
-		return Conmon_VersionResponse{}, err
+		return Conmon_KillContainerResponse{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_version_Results_List is a list of Conmon_version_Results.
-type Conmon_version_Results_List = capnp.StructList[Conmon_version_Results]
+// Conmon_killContainer_Results_List is a list of Conmon_killContainer_Results.
+type Conmon_killContainer_Results_List = capnp.StructList[Conmon_killContainer_Results]
 
-// NewConmon_version_Results creates a new list of Conmon_version_Results.
-func NewConmon_version_Results_List(s *capnp.Segment, sz int32) (Conmon_version_Results_List, error) {
+// NewConmon_killContainer_Results creates a new list of Conmon_killContainer_Results.
+func NewConmon_killContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_killContainer_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_version_Results]{l}, err
+	return capnp.StructList[Conmon_killContainer_Results]{l}, err
 }
 
-// Conmon_version_Results_Future is a wrapper for a Conmon_version_Results promised by a client call.
-type Conmon_version_Results_Future struct{ *capnp.Future }
+// Conmon_killContainer_Results_Future is a wrapper for a Conmon_killContainer_Results promised by a client call.
+type Conmon_killContainer_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_version_Results_Future) Struct() (Conmon_version_Results, error) {
+func (p Conmon_killContainer_Results_Future) Struct() (Conmon_killContainer_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_version_Results{s}, err
+	return Conmon_killContainer_Results{s}, err
 }
 
-func (p Conmon_version_Results_Future) Response() Conmon_VersionResponse_Future {
-	return Conmon_VersionResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_killContainer_Results_Future) Response() Conmon_KillContainerResponse_Future {
+	return Conmon_KillContainerResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_createContainer_Params struct{ capnp.Struct }
+type Conmon_portForward_Params struct{ capnp.Struct }
 
-// Conmon_createContainer_Params_TypeID is the unique identifier for the type Conmon_createContainer_Params.
-const Conmon_createContainer_Params_TypeID = 0xf44732c48f949ab8
+// Conmon_portForward_Params_TypeID is the unique identifier for the type Conmon_portForward_Params.
+const Conmon_portForward_Params_TypeID = 0xa3cb406c522dcab1
 
-func NewConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+func NewConmon_portForward_Params(s *capnp.Segment) (Conmon_portForward_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Params{st}, err
+	return Conmon_portForward_Params{st}, err
 }
 
-func NewRootConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+func NewRootConmon_portForward_Params(s *capnp.Segment) (Conmon_portForward_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Params{st}, err
+	return Conmon_portForward_Params{st}, err
 }
 
-func ReadRootConmon_createContainer_Params(msg *capnp.Message) (Conmon_createContainer_Params, error) {
+func ReadRootConmon_portForward_Params(msg *capnp.Message) (Conmon_portForward_Params, error) {
 	root, err := msg.Root()
-	return Conmon_createContainer_Params{root.Struct()}, err
+	return Conmon_portForward_Params{root.Struct()}, err
 }
 
-func (s Conmon_createContainer_Params) String() string {
-	str, _ := text.Marshal(0xf44732c48f949ab8, s.Struct)
+func (s Conmon_portForward_Params) String() string {
+	str, _ := text.Marshal(0xa3cb406c522dcab1, s.Struct)
 	return str
 }
 
-func (s Conmon_createContainer_Params) Request() (Conmon_CreateContainerRequest, error) {
+func (s Conmon_portForward_Params) Request() (Conmon_PortForwardRequest, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_CreateContainerRequest{Struct: p.Struct()}, err
+	return Conmon_PortForwardRequest{Struct: p.Struct()}, err
 }
 
-func (s Conmon_createContainer_Params) HasRequest() bool {
+func (s Conmon_portForward_Params) HasRequest() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_createContainer_Params) SetRequest(v Conmon_CreateContainerRequest) error {
+func (s Conmon_portForward_Params) SetRequest(v Conmon_PortForwardRequest) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_CreateContainerRequest struct, preferring placement in s's segment.
-func (s Conmon_createContainer_Params) NewRequest() (Conmon_CreateContainerRequest, error) {
-	ss, err := NewConmon_CreateContainerRequest(s.Struct.Segment())
+// allocated Conmon_PortForwardRequest struct, preferring placement in s's segment.
+func (s Conmon_portForward_Params) NewRequest() (Conmon_PortForwardRequest, error) {
+	ss, err := NewConmon_PortForwardRequest(s.Struct.Segment())
 	if err != nil {
-		return Conmon_CreateContainerRequest{}, err
+		return Conmon_PortForwardRequest{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_createContainer_Params_List is a list of Conmon_createContainer_Params.
-type Conmon_createContainer_Params_List = capnp.StructList[Conmon_createContainer_Params]
+// Conmon_portForward_Params_List is a list of Conmon_portForward_Params.
+type Conmon_portForward_Params_List = capnp.StructList[Conmon_portForward_Params]
 
-// NewConmon_createContainer_Params creates a new list of Conmon_createContainer_Params.
-func NewConmon_createContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Params_List, error) {
+// NewConmon_portForward_Params creates a new list of Conmon_portForward_Params.
+func NewConmon_portForward_Params_List(s *capnp.Segment, sz int32) (Conmon_portForward_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_createContainer_Params]{l}, err
+	return capnp.StructList[Conmon_portForward_Params]{l}, err
 }
 
-// Conmon_createContainer_Params_Future is a wrapper for a Conmon_createContainer_Params promised by a client call.
-type Conmon_createContainer_Params_Future struct{ *capnp.Future }
+// Conmon_portForward_Params_Future is a wrapper for a Conmon_portForward_Params promised by a client call.
+type Conmon_portForward_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_createContainer_Params_Future) Struct() (Conmon_createContainer_Params, error) {
+func (p Conmon_portForward_Params_Future) Struct() (Conmon_portForward_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_createContainer_Params{s}, err
+	return Conmon_portForward_Params{s}, err
 }
 
-func (p Conmon_createContainer_Params_Future) Request() Conmon_CreateContainerRequest_Future {
-	return Conmon_CreateContainerRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_portForward_Params_Future) Request() Conmon_PortForwardRequest_Future {
+	return Conmon_PortForwardRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_createContainer_Results struct{ capnp.Struct }
+type Conmon_portForward_Results struct{ capnp.Struct }
 
-// Conmon_createContainer_Results_TypeID is the unique identifier for the type Conmon_createContainer_Results.
-const Conmon_createContainer_Results_TypeID = 0xceba3c1a97be15f8
+// Conmon_portForward_Results_TypeID is the unique identifier for the type Conmon_portForward_Results.
+const Conmon_portForward_Results_TypeID = 0xedd2e5b018f17bbb
 
-func NewConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+func NewConmon_portForward_Results(s *capnp.Segment) (Conmon_portForward_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Results{st}, err
+	return Conmon_portForward_Results{st}, err
 }
 
-func NewRootConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+func NewRootConmon_portForward_Results(s *capnp.Segment) (Conmon_portForward_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Results{st}, err
+	return Conmon_portForward_Results{st}, err
 }
 
-func ReadRootConmon_createContainer_Results(msg *capnp.Message) (Conmon_createContainer_Results, error) {
+func ReadRootConmon_portForward_Results(msg *capnp.Message) (Conmon_portForward_Results, error) {
 	root, err := msg.Root()
-	return Conmon_createContainer_Results{root.Struct()}, err
+	return Conmon_portForward_Results{root.Struct()}, err
 }
 
-func (s Conmon_createContainer_Results) String() string {
-	str, _ := text.Marshal(0xceba3c1a97be15f8, s.Struct)
+func (s Conmon_portForward_Results) String() string {
+	str, _ := text.Marshal(0xedd2e5b018f17bbb, s.Struct)
 	return str
 }
 
-func (s Conmon_createContainer_Results) Response() (Conmon_CreateContainerResponse, error) {
+func (s Conmon_portForward_Results) Response() (Conmon_PortForwardResponse, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_CreateContainerResponse{Struct: p.Struct()}, err
+	return Conmon_PortForwardResponse{Struct: p.Struct()}, err
 }
 
-func (s Conmon_createContainer_Results) HasResponse() bool {
+func (s Conmon_portForward_Results) HasResponse() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_createContainer_Results) SetResponse(v Conmon_CreateContainerResponse) error {
+func (s Conmon_portForward_Results) SetResponse(v Conmon_PortForwardResponse) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_CreateContainerResponse struct, preferring placement in s's segment.
-func (s Conmon_createContainer_Results) NewResponse() (Conmon_CreateContainerResponse, error) {
-	ss, err := NewConmon_CreateContainerResponse(s.Struct.Segment())
+// allocated Conmon_PortForwardResponse struct, preferring placement in s's segment.
+func (s Conmon_portForward_Results) NewResponse() (Conmon_PortForwardResponse, error) {
+	ss, err := NewConmon_PortForwardResponse(s.Struct.Segment())
 	if err != nil {
-		return Conmon_CreateContainerResponse{}, err
+		return Conmon_PortForwardResponse{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_createContainer_Results_List is a list of Conmon_createContainer_Results.
-type Conmon_createContainer_Results_List = capnp.StructList[Conmon_createContainer_Results]
+// Conmon_portForward_Results_List is a list of Conmon_portForward_Results.
+type Conmon_portForward_Results_List = capnp.StructList[Conmon_portForward_Results]
 
-// NewConmon_createContainer_Results creates a new list of Conmon_createContainer_Results.
-func NewConmon_createContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Results_List, error) {
+// NewConmon_portForward_Results creates a new list of Conmon_portForward_Results.
+func NewConmon_portForward_Results_List(s *capnp.Segment, sz int32) (Conmon_portForward_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_createContainer_Results]{l}, err
+	return capnp.StructList[Conmon_portForward_Results]{l}, err
 }
 
-// Conmon_createContainer_Results_Future is a wrapper for a Conmon_createContainer_Results promised by a client call.
-type Conmon_createContainer_Results_Future struct{ *capnp.Future }
+// Conmon_portForward_Results_Future is a wrapper for a Conmon_portForward_Results promised by a client call.
+type Conmon_portForward_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_createContainer_Results_Future) Struct() (Conmon_createContainer_Results, error) {
+func (p Conmon_portForward_Results_Future) Struct() (Conmon_portForward_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_createContainer_Results{s}, err
+	return Conmon_portForward_Results{s}, err
 }
 
-func (p Conmon_createContainer_Results_Future) Response() Conmon_CreateContainerResponse_Future {
-	return Conmon_CreateContainerResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_portForward_Results_Future) Response() Conmon_PortForwardResponse_Future {
+	return Conmon_PortForwardResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_execSyncContainer_Params struct{ capnp.Struct }
+type Conmon_createNamespaces_Params struct{ capnp.Struct }
 
-// Conmon_execSyncContainer_Params_TypeID is the unique identifier for the type Conmon_execSyncContainer_Params.
-const Conmon_execSyncContainer_Params_TypeID = 0x83479da67279e173
+// Conmon_createNamespaces_Params_TypeID is the unique identifier for the type Conmon_createNamespaces_Params.
+const Conmon_createNamespaces_Params_TypeID = 0x9d82529754851252
 
-func NewConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+func NewConmon_createNamespaces_Params(s *capnp.Segment) (Conmon_createNamespaces_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Params{st}, err
+	return Conmon_createNamespaces_Params{st}, err
 }
 
-func NewRootConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+func NewRootConmon_createNamespaces_Params(s *capnp.Segment) (Conmon_createNamespaces_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Params{st}, err
+	return Conmon_createNamespaces_Params{st}, err
 }
 
-func ReadRootConmon_execSyncContainer_Params(msg *capnp.Message) (Conmon_execSyncContainer_Params, error) {
+func ReadRootConmon_createNamespaces_Params(msg *capnp.Message) (Conmon_createNamespaces_Params, error) {
 	root, err := msg.Root()
-	return Conmon_execSyncContainer_Params{root.Struct()}, err
+	return Conmon_createNamespaces_Params{root.Struct()}, err
 }
 
-func (s Conmon_execSyncContainer_Params) String() string {
-	str, _ := text.Marshal(0x83479da67279e173, s.Struct)
+func (s Conmon_createNamespaces_Params) String() string {
+	str, _ := text.Marshal(0x9d82529754851252, s.Struct)
 	return str
 }
 
-func (s Conmon_execSyncContainer_Params) Request() (Conmon_ExecSyncContainerRequest, error) {
+func (s Conmon_createNamespaces_Params) Request() (Conmon_CreateNamespacesRequest, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_ExecSyncContainerRequest{Struct: p.Struct()}, err
+	return Conmon_CreateNamespacesRequest{Struct: p.Struct()}, err
 }
 
-func (s Conmon_execSyncContainer_Params) HasRequest() bool {
+func (s Conmon_createNamespaces_Params) HasRequest() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_execSyncContainer_Params) SetRequest(v Conmon_ExecSyncContainerRequest) error {
+func (s Conmon_createNamespaces_Params) SetRequest(v Conmon_CreateNamespacesRequest) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_ExecSyncContainerRequest struct, preferring placement in s's segment.
-func (s Conmon_execSyncContainer_Params) NewRequest() (Conmon_ExecSyncContainerRequest, error) {
-	ss, err := NewConmon_ExecSyncContainerRequest(s.Struct.Segment())
+// allocated Conmon_CreateNamespacesRequest struct, preferring placement in s's segment.
+func (s Conmon_createNamespaces_Params) NewRequest() (Conmon_CreateNamespacesRequest, error) {
+	ss, err := NewConmon_CreateNamespacesRequest(s.Struct.Segment())
 	if err != nil {
-		return Conmon_ExecSyncContainerRequest{}, err
+		return Conmon_CreateNamespacesRequest{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_execSyncContainer_Params_List is a list of Conmon_execSyncContainer_Params.
-type Conmon_execSyncContainer_Params_List = capnp.StructList[Conmon_execSyncContainer_Params]
+// Conmon_createNamespaces_Params_List is a list of Conmon_createNamespaces_Params.
+type Conmon_createNamespaces_Params_List = capnp.StructList[Conmon_createNamespaces_Params]
 
-// NewConmon_execSyncContainer_Params creates a new list of Conmon_execSyncContainer_Params.
-func NewConmon_execSyncContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Params_List, error) {
+// NewConmon_createNamespaces_Params creates a new list of Conmon_createNamespaces_Params.
+func NewConmon_createNamespaces_Params_List(s *capnp.Segment, sz int32) (Conmon_createNamespaces_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_execSyncContainer_Params]{l}, err
+	return capnp.StructList[Conmon_createNamespaces_Params]{l}, err
 }
 
-// Conmon_execSyncContainer_Params_Future is a wrapper for a Conmon_execSyncContainer_Params promised by a client call.
-type Conmon_execSyncContainer_Params_Future struct{ *capnp.Future }
+// Conmon_createNamespaces_Params_Future is a wrapper for a Conmon_createNamespaces_Params promised by a client call.
+type Conmon_createNamespaces_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_execSyncContainer_Params_Future) Struct() (Conmon_execSyncContainer_Params, error) {
+func (p Conmon_createNamespaces_Params_Future) Struct() (Conmon_createNamespaces_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_execSyncContainer_Params{s}, err
+	return Conmon_createNamespaces_Params{s}, err
 }
 
-func (p Conmon_execSyncContainer_Params_Future) Request() Conmon_ExecSyncContainerRequest_Future {
-	return Conmon_ExecSyncContainerRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_createNamespaces_Params_Future) Request() Conmon_CreateNamespacesRequest_Future {
+	return Conmon_CreateNamespacesRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_execSyncContainer_Results struct{ capnp.Struct }
+type Conmon_createNamespaces_Results struct{ capnp.Struct }
 
-// Conmon_execSyncContainer_Results_TypeID is the unique identifier for the type Conmon_execSyncContainer_Results.
-const Conmon_execSyncContainer_Results_TypeID = 0xf8e86a5c0baa01bc
+// Conmon_createNamespaces_Results_TypeID is the unique identifier for the type Conmon_createNamespaces_Results.
+const Conmon_createNamespaces_Results_TypeID = 0xae5e0ae5001ebdfe
 
-func NewConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+func NewConmon_createNamespaces_Results(s *capnp.Segment) (Conmon_createNamespaces_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Results{st}, err
+	return Conmon_createNamespaces_Results{st}, err
 }
 
-func NewRootConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+func NewRootConmon_createNamespaces_Results(s *capnp.Segment) (Conmon_createNamespaces_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Results{st}, err
+	return Conmon_createNamespaces_Results{st}, err
 }
 
-func ReadRootConmon_execSyncContainer_Results(msg *capnp.Message) (Conmon_execSyncContainer_Results, error) {
+func ReadRootConmon_createNamespaces_Results(msg *capnp.Message) (Conmon_createNamespaces_Results, error) {
 	root, err := msg.Root()
-	return Conmon_execSyncContainer_Results{root.Struct()}, err
+	return Conmon_createNamespaces_Results{root.Struct()}, err
 }
 
-func (s Conmon_execSyncContainer_Results) String() string {
-	str, _ := text.Marshal(0xf8e86a5c0baa01bc, s.Struct)
+func (s Conmon_createNamespaces_Results) String() string {
+	str, _ := text.Marshal(0xae5e0ae5001ebdfe, s.Struct)
 	return str
 }
 
-func (s Conmon_execSyncContainer_Results) Response() (Conmon_ExecSyncContainerResponse, error) {
+func (s Conmon_createNamespaces_Results) Response() (Conmon_CreateNamespacesResponse, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_ExecSyncContainerResponse{Struct: p.Struct()}, err
+	return Conmon_CreateNamespacesResponse{Struct: p.Struct()}, err
 }
 
-func (s Conmon_execSyncContainer_Results) HasResponse() bool {
+func (s Conmon_createNamespaces_Results) HasResponse() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_execSyncContainer_Results) SetResponse(v Conmon_ExecSyncContainerResponse) error {
+func (s Conmon_createNamespaces_Results) SetResponse(v Conmon_CreateNamespacesResponse) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_ExecSyncContainerResponse struct, preferring placement in s's segment.
-func (s Conmon_execSyncContainer_Results) NewResponse() (Conmon_ExecSyncContainerResponse, error) {
-	ss, err := NewConmon_ExecSyncContainerResponse(s.Struct.Segment())
+// allocated Conmon_CreateNamespacesResponse struct, preferring placement in s's segment.
+func (s Conmon_createNamespaces_Results) NewResponse() (Conmon_CreateNamespacesResponse, error) {
+	ss, err := NewConmon_CreateNamespacesResponse(s.Struct.Segment())
 	if err != nil {
-		return Conmon_ExecSyncContainerResponse{}, err
+		return Conmon_CreateNamespacesResponse{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_execSyncContainer_Results_List is a list of Conmon_execSyncContainer_Results.
-type Conmon_execSyncContainer_Results_List = capnp.StructList[Conmon_execSyncContainer_Results]
+// Conmon_createNamespaces_Results_List is a list of Conmon_createNamespaces_Results.
+type Conmon_createNamespaces_Results_List = capnp.StructList[Conmon_createNamespaces_Results]
 
-// NewConmon_execSyncContainer_Results creates a new list of Conmon_execSyncContainer_Results.
-func NewConmon_execSyncContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Results_List, error) {
+// NewConmon_createNamespaces_Results creates a new list of Conmon_createNamespaces_Results.
+func NewConmon_createNamespaces_Results_List(s *capnp.Segment, sz int32) (Conmon_createNamespaces_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_execSyncContainer_Results]{l}, err
+	return capnp.StructList[Conmon_createNamespaces_Results]{l}, err
 }
 
-// Conmon_execSyncContainer_Results_Future is a wrapper for a Conmon_execSyncContainer_Results promised by a client call.
-type Conmon_execSyncContainer_Results_Future struct{ *capnp.Future }
+// Conmon_createNamespaces_Results_Future is a wrapper for a Conmon_createNamespaces_Results promised by a client call.
+type Conmon_createNamespaces_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_execSyncContainer_Results_Future) Struct() (Conmon_execSyncContainer_Results, error) {
+func (p Conmon_createNamespaces_Results_Future) Struct() (Conmon_createNamespaces_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_execSyncContainer_Results{s}, err
+	return Conmon_createNamespaces_Results{s}, err
 }
 
-func (p Conmon_execSyncContainer_Results_Future) Response() Conmon_ExecSyncContainerResponse_Future {
-	return Conmon_ExecSyncContainerResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_createNamespaces_Results_Future) Response() Conmon_CreateNamespacesResponse_Future {
+	return Conmon_CreateNamespacesResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_attachContainer_Params struct{ capnp.Struct }
+type Conmon_removeNamespaces_Params struct{ capnp.Struct }
 
-// Conmon_attachContainer_Params_TypeID is the unique identifier for the type Conmon_attachContainer_Params.
-const Conmon_attachContainer_Params_TypeID = 0xaa2f3c8ad1c3af24
+// Conmon_removeNamespaces_Params_TypeID is the unique identifier for the type Conmon_removeNamespaces_Params.
+const Conmon_removeNamespaces_Params_TypeID = 0xa6d76ce69f13a816
 
-func NewConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+func NewConmon_removeNamespaces_Params(s *capnp.Segment) (Conmon_removeNamespaces_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Params{st}, err
+	return Conmon_removeNamespaces_Params{st}, err
 }
 
-func NewRootConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+func NewRootConmon_removeNamespaces_Params(s *capnp.Segment) (Conmon_removeNamespaces_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Params{st}, err
+	return Conmon_removeNamespaces_Params{st}, err
 }
 
-func ReadRootConmon_attachContainer_Params(msg *capnp.Message) (Conmon_attachContainer_Params, error) {
+func ReadRootConmon_removeNamespaces_Params(msg *capnp.Message) (Conmon_removeNamespaces_Params, error) {
 	root, err := msg.Root()
-	return Conmon_attachContainer_Params{root.Struct()}, err
+	return Conmon_removeNamespaces_Params{root.Struct()}, err
 }
 
-func (s Conmon_attachContainer_Params) String() string {
-	str, _ := text.Marshal(0xaa2f3c8ad1c3af24, s.Struct)
+func (s Conmon_removeNamespaces_Params) String() string {
+	str, _ := text.Marshal(0xa6d76ce69f13a816, s.Struct)
 	return str
 }
 
-func (s Conmon_attachContainer_Params) Request() (Conmon_AttachRequest, error) {
+func (s Conmon_removeNamespaces_Params) Request() (Conmon_RemoveNamespacesRequest, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_AttachRequest{Struct: p.Struct()}, err
+	return Conmon_RemoveNamespacesRequest{Struct: p.Struct()}, err
 }
 
-func (s Conmon_attachContainer_Params) HasRequest() bool {
+func (s Conmon_removeNamespaces_Params) HasRequest() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_attachContainer_Params) SetRequest(v Conmon_AttachRequest) error {
+func (s Conmon_removeNamespaces_Params) SetRequest(v Conmon_RemoveNamespacesRequest) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_AttachRequest struct, preferring placement in s's segment.
-func (s Conmon_attachContainer_Params) NewRequest() (Conmon_AttachRequest, error) {
-	ss, err := NewConmon_AttachRequest(s.Struct.Segment())
+// allocated Conmon_RemoveNamespacesRequest struct, preferring placement in s's segment.
+func (s Conmon_removeNamespaces_Params) NewRequest() (Conmon_RemoveNamespacesRequest, error) {
+	ss, err := NewConmon_RemoveNamespacesRequest(s.Struct.Segment())
 	if err != nil {
-		return Conmon_AttachRequest{}, err
+		return Conmon_RemoveNamespacesRequest{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_attachContainer_Params_List is a list of Conmon_attachContainer_Params.
-type Conmon_attachContainer_Params_List = capnp.StructList[Conmon_attachContainer_Params]
+// Conmon_removeNamespaces_Params_List is a list of Conmon_removeNamespaces_Params.
+type Conmon_removeNamespaces_Params_List = capnp.StructList[Conmon_removeNamespaces_Params]
 
-// NewConmon_attachContainer_Params creates a new list of Conmon_attachContainer_Params.
-func NewConmon_attachContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Params_List, error) {
+// NewConmon_removeNamespaces_Params creates a new list of Conmon_removeNamespaces_Params.
+func NewConmon_removeNamespaces_Params_List(s *capnp.Segment, sz int32) (Conmon_removeNamespaces_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_attachContainer_Params]{l}, err
+	return capnp.StructList[Conmon_removeNamespaces_Params]{l}, err
 }
 
-// Conmon_attachContainer_Params_Future is a wrapper for a Conmon_attachContainer_Params promised by a client call.
-type Conmon_attachContainer_Params_Future struct{ *capnp.Future }
+// Conmon_removeNamespaces_Params_Future is a wrapper for a Conmon_removeNamespaces_Params promised by a client call.
+type Conmon_removeNamespaces_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_attachContainer_Params_Future) Struct() (Conmon_attachContainer_Params, error) {
+func (p Conmon_removeNamespaces_Params_Future) Struct() (Conmon_removeNamespaces_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_attachContainer_Params{s}, err
+	return Conmon_removeNamespaces_Params{s}, err
 }
 
-func (p Conmon_attachContainer_Params_Future) Request() Conmon_AttachRequest_Future {
-	return Conmon_AttachRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_removeNamespaces_Params_Future) Request() Conmon_RemoveNamespacesRequest_Future {
+	return Conmon_RemoveNamespacesRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_attachContainer_Results struct{ capnp.Struct }
+type Conmon_removeNamespaces_Results struct{ capnp.Struct }
 
-// Conmon_attachContainer_Results_TypeID is the unique identifier for the type Conmon_attachContainer_Results.
-const Conmon_attachContainer_Results_TypeID = 0xc5e65eec3dcf5b10
+// Conmon_removeNamespaces_Results_TypeID is the unique identifier for the type Conmon_removeNamespaces_Results.
+const Conmon_removeNamespaces_Results_TypeID = 0xaaa69aebe451afba
 
-func NewConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+func NewConmon_removeNamespaces_Results(s *capnp.Segment) (Conmon_removeNamespaces_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Results{st}, err
+	return Conmon_removeNamespaces_Results{st}, err
 }
 
-func NewRootConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+func NewRootConmon_removeNamespaces_Results(s *capnp.Segment) (Conmon_removeNamespaces_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Results{st}, err
+	return Conmon_removeNamespaces_Results{st}, err
 }
 
-func ReadRootConmon_attachContainer_Results(msg *capnp.Message) (Conmon_attachContainer_Results, error) {
+func ReadRootConmon_removeNamespaces_Results(msg *capnp.Message) (Conmon_removeNamespaces_Results, error) {
 	root, err := msg.Root()
-	return Conmon_attachContainer_Results{root.Struct()}, err
+	return Conmon_removeNamespaces_Results{root.Struct()}, err
 }
 
-func (s Conmon_attachContainer_Results) String() string {
-	str, _ := text.Marshal(0xc5e65eec3dcf5b10, s.Struct)
+func (s Conmon_removeNamespaces_Results) String() string {
+	str, _ := text.Marshal(0xaaa69aebe451afba, s.Struct)
 	return str
 }
 
-func (s Conmon_attachContainer_Results) Response() (Conmon_AttachResponse, error) {
+func (s Conmon_removeNamespaces_Results) Response() (Conmon_RemoveNamespacesResponse, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_AttachResponse{Struct: p.Struct()}, err
+	return Conmon_RemoveNamespacesResponse{Struct: p.Struct()}, err
 }
 
-func (s Conmon_attachContainer_Results) HasResponse() bool {
+func (s Conmon_removeNamespaces_Results) HasResponse() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_attachContainer_Results) SetResponse(v Conmon_AttachResponse) error {
+func (s Conmon_removeNamespaces_Results) SetResponse(v Conmon_RemoveNamespacesResponse) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_AttachResponse struct, preferring placement in s's segment.
-func (s Conmon_attachContainer_Results) NewResponse() (Conmon_AttachResponse, error) {
-	ss, err := NewConmon_AttachResponse(s.Struct.Segment())
+// allocated Conmon_RemoveNamespacesResponse struct, preferring placement in s's segment.
+func (s Conmon_removeNamespaces_Results) NewResponse() (Conmon_RemoveNamespacesResponse, error) {
+	ss, err := NewConmon_RemoveNamespacesResponse(s.Struct.Segment())
 	if err != nil {
-		return Conmon_AttachResponse{}, err
+		return Conmon_RemoveNamespacesResponse{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_attachContainer_Results_List is a list of Conmon_attachContainer_Results.
-type Conmon_attachContainer_Results_List = capnp.StructList[Conmon_attachContainer_Results]
+// Conmon_removeNamespaces_Results_List is a list of Conmon_removeNamespaces_Results.
+type Conmon_removeNamespaces_Results_List = capnp.StructList[Conmon_removeNamespaces_Results]
 
-// NewConmon_attachContainer_Results creates a new list of Conmon_attachContainer_Results.
-func NewConmon_attachContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Results_List, error) {
+// NewConmon_removeNamespaces_Results creates a new list of Conmon_removeNamespaces_Results.
+func NewConmon_removeNamespaces_Results_List(s *capnp.Segment, sz int32) (Conmon_removeNamespaces_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_attachContainer_Results]{l}, err
+	return capnp.StructList[Conmon_removeNamespaces_Results]{l}, err
 }
 
-// Conmon_attachContainer_Results_Future is a wrapper for a Conmon_attachContainer_Results promised by a client call.
-type Conmon_attachContainer_Results_Future struct{ *capnp.Future }
+// Conmon_removeNamespaces_Results_Future is a wrapper for a Conmon_removeNamespaces_Results promised by a client call.
+type Conmon_removeNamespaces_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_attachContainer_Results_Future) Struct() (Conmon_attachContainer_Results, error) {
+func (p Conmon_removeNamespaces_Results_Future) Struct() (Conmon_removeNamespaces_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_attachContainer_Results{s}, err
+	return Conmon_removeNamespaces_Results{s}, err
 }
 
-func (p Conmon_attachContainer_Results_Future) Response() Conmon_AttachResponse_Future {
-	return Conmon_AttachResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_removeNamespaces_Results_Future) Response() Conmon_RemoveNamespacesResponse_Future {
+	return Conmon_RemoveNamespacesResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_reopenLogContainer_Params struct{ capnp.Struct }
+type Conmon_registerProbe_Params struct{ capnp.Struct }
 
-// Conmon_reopenLogContainer_Params_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Params.
-const Conmon_reopenLogContainer_Params_TypeID = 0xe5ea916eb0c31336
+// Conmon_registerProbe_Params_TypeID is the unique identifier for the type Conmon_registerProbe_Params.
+const Conmon_registerProbe_Params_TypeID = 0xe989fde14d6e82dd
 
-func NewConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+func NewConmon_registerProbe_Params(s *capnp.Segment) (Conmon_registerProbe_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Params{st}, err
+	return Conmon_registerProbe_Params{st}, err
 }
 
-func NewRootConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+func NewRootConmon_registerProbe_Params(s *capnp.Segment) (Conmon_registerProbe_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Params{st}, err
+	return Conmon_registerProbe_Params{st}, err
 }
 
-func ReadRootConmon_reopenLogContainer_Params(msg *capnp.Message) (Conmon_reopenLogContainer_Params, error) {
+func ReadRootConmon_registerProbe_Params(msg *capnp.Message) (Conmon_registerProbe_Params, error) {
 	root, err := msg.Root()
-	return Conmon_reopenLogContainer_Params{root.Struct()}, err
+	return Conmon_registerProbe_Params{root.Struct()}, err
 }
 
-func (s Conmon_reopenLogContainer_Params) String() string {
-	str, _ := text.Marshal(0xe5ea916eb0c31336, s.Struct)
+func (s Conmon_registerProbe_Params) String() string {
+	str, _ := text.Marshal(0xe989fde14d6e82dd, s.Struct)
 	return str
 }
 
-func (s Conmon_reopenLogContainer_Params) Request() (Conmon_ReopenLogRequest, error) {
+func (s Conmon_registerProbe_Params) Request() (Conmon_RegisterProbeRequest, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_ReopenLogRequest{Struct: p.Struct()}, err
+	return Conmon_RegisterProbeRequest{Struct: p.Struct()}, err
 }
 
-func (s Conmon_reopenLogContainer_Params) HasRequest() bool {
+func (s Conmon_registerProbe_Params) HasRequest() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_reopenLogContainer_Params) SetRequest(v Conmon_ReopenLogRequest) error {
+func (s Conmon_registerProbe_Params) SetRequest(v Conmon_RegisterProbeRequest) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_ReopenLogRequest struct, preferring placement in s's segment.
-func (s Conmon_reopenLogContainer_Params) NewRequest() (Conmon_ReopenLogRequest, error) {
-	ss, err := NewConmon_ReopenLogRequest(s.Struct.Segment())
+// allocated Conmon_RegisterProbeRequest struct, preferring placement in s's segment.
+func (s Conmon_registerProbe_Params) NewRequest() (Conmon_RegisterProbeRequest, error) {
+	ss, err := NewConmon_RegisterProbeRequest(s.Struct.Segment())
 	if err != nil {
-		return Conmon_ReopenLogRequest{}, err
+		return Conmon_RegisterProbeRequest{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_reopenLogContainer_Params_List is a list of Conmon_reopenLogContainer_Params.
-type Conmon_reopenLogContainer_Params_List = capnp.StructList[Conmon_reopenLogContainer_Params]
+// Conmon_registerProbe_Params_List is a list of Conmon_registerProbe_Params.
+type Conmon_registerProbe_Params_List = capnp.StructList[Conmon_registerProbe_Params]
 
-// NewConmon_reopenLogContainer_Params creates a new list of Conmon_reopenLogContainer_Params.
-func NewConmon_reopenLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Params_List, error) {
+// NewConmon_registerProbe_Params creates a new list of Conmon_registerProbe_Params.
+func NewConmon_registerProbe_Params_List(s *capnp.Segment, sz int32) (Conmon_registerProbe_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_reopenLogContainer_Params]{l}, err
+	return capnp.StructList[Conmon_registerProbe_Params]{l}, err
 }
 
-// Conmon_reopenLogContainer_Params_Future is a wrapper for a Conmon_reopenLogContainer_Params promised by a client call.
-type Conmon_reopenLogContainer_Params_Future struct{ *capnp.Future }
+// Conmon_registerProbe_Params_Future is a wrapper for a Conmon_registerProbe_Params promised by a client call.
+type Conmon_registerProbe_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_reopenLogContainer_Params_Future) Struct() (Conmon_reopenLogContainer_Params, error) {
+func (p Conmon_registerProbe_Params_Future) Struct() (Conmon_registerProbe_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_reopenLogContainer_Params{s}, err
+	return Conmon_registerProbe_Params{s}, err
 }
 
-func (p Conmon_reopenLogContainer_Params_Future) Request() Conmon_ReopenLogRequest_Future {
-	return Conmon_ReopenLogRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_registerProbe_Params_Future) Request() Conmon_RegisterProbeRequest_Future {
+	return Conmon_RegisterProbeRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_reopenLogContainer_Results struct{ capnp.Struct }
+type Conmon_registerProbe_Results struct{ capnp.Struct }
 
-// Conmon_reopenLogContainer_Results_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Results.
-const Conmon_reopenLogContainer_Results_TypeID = 0xa0ef8355b64ee985
+// Conmon_registerProbe_Results_TypeID is the unique identifier for the type Conmon_registerProbe_Results.
+const Conmon_registerProbe_Results_TypeID = 0x9488d71c49c86c29
 
-func NewConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+func NewConmon_registerProbe_Results(s *capnp.Segment) (Conmon_registerProbe_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Results{st}, err
+	return Conmon_registerProbe_Results{st}, err
 }
 
-func NewRootConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+func NewRootConmon_registerProbe_Results(s *capnp.Segment) (Conmon_registerProbe_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Results{st}, err
+	return Conmon_registerProbe_Results{st}, err
 }
 
-func ReadRootConmon_reopenLogContainer_Results(msg *capnp.Message) (Conmon_reopenLogContainer_Results, error) {
+func ReadRootConmon_registerProbe_Results(msg *capnp.Message) (Conmon_registerProbe_Results, error) {
 	root, err := msg.Root()
-	return Conmon_reopenLogContainer_Results{root.Struct()}, err
+	return Conmon_registerProbe_Results{root.Struct()}, err
 }
 
-func (s Conmon_reopenLogContainer_Results) String() string {
-	str, _ := text.Marshal(0xa0ef8355b64ee985, s.Struct)
+func (s Conmon_registerProbe_Results) String() string {
+	str, _ := text.Marshal(0x9488d71c49c86c29, s.Struct)
 	return str
 }
 
-func (s Conmon_reopenLogContainer_Results) Response() (Conmon_ReopenLogResponse, error) {
+func (s Conmon_registerProbe_Results) Response() (Conmon_RegisterProbeResponse, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_ReopenLogResponse{Struct: p.Struct()}, err
+	return Conmon_RegisterProbeResponse{Struct: p.Struct()}, err
 }
 
-func (s Conmon_reopenLogContainer_Results) HasResponse() bool {
+func (s Conmon_registerProbe_Results) HasResponse() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_reopenLogContainer_Results) SetResponse(v Conmon_ReopenLogResponse) error {
+func (s Conmon_registerProbe_Results) SetResponse(v Conmon_RegisterProbeResponse) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_ReopenLogResponse struct, preferring placement in s's segment.
-func (s Conmon_reopenLogContainer_Results) NewResponse() (Conmon_ReopenLogResponse, error) {
-	ss, err := NewConmon_ReopenLogResponse(s.Struct.Segment())
+// allocated Conmon_RegisterProbeResponse struct, preferring placement in s's segment.
+func (s Conmon_registerProbe_Results) NewResponse() (Conmon_RegisterProbeResponse, error) {
+	ss, err := NewConmon_RegisterProbeResponse(s.Struct.Segment())
 	if err != nil {
-		return Conmon_ReopenLogResponse{}, err
+		return Conmon_RegisterProbeResponse{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_reopenLogContainer_Results_List is a list of Conmon_reopenLogContainer_Results.
-type Conmon_reopenLogContainer_Results_List = capnp.StructList[Conmon_reopenLogContainer_Results]
+// Conmon_registerProbe_Results_List is a list of Conmon_registerProbe_Results.
+type Conmon_registerProbe_Results_List = capnp.StructList[Conmon_registerProbe_Results]
 
-// NewConmon_reopenLogContainer_Results creates a new list of Conmon_reopenLogContainer_Results.
-func NewConmon_reopenLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Results_List, error) {
+// NewConmon_registerProbe_Results creates a new list of Conmon_registerProbe_Results.
+func NewConmon_registerProbe_Results_List(s *capnp.Segment, sz int32) (Conmon_registerProbe_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_reopenLogContainer_Results]{l}, err
+	return capnp.StructList[Conmon_registerProbe_Results]{l}, err
 }
 
-// Conmon_reopenLogContainer_Results_Future is a wrapper for a Conmon_reopenLogContainer_Results promised by a client call.
-type Conmon_reopenLogContainer_Results_Future struct{ *capnp.Future }
+// Conmon_registerProbe_Results_Future is a wrapper for a Conmon_registerProbe_Results promised by a client call.
+type Conmon_registerProbe_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_reopenLogContainer_Results_Future) Struct() (Conmon_reopenLogContainer_Results, error) {
+func (p Conmon_registerProbe_Results_Future) Struct() (Conmon_registerProbe_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_reopenLogContainer_Results{s}, err
+	return Conmon_registerProbe_Results{s}, err
 }
 
-func (p Conmon_reopenLogContainer_Results_Future) Response() Conmon_ReopenLogResponse_Future {
-	return Conmon_ReopenLogResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_registerProbe_Results_Future) Response() Conmon_RegisterProbeResponse_Future {
+	return Conmon_RegisterProbeResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_setWindowSizeContainer_Params struct{ capnp.Struct }
+type Conmon_setLogLevel_Params struct{ capnp.Struct }
 
-// Conmon_setWindowSizeContainer_Params_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Params.
-const Conmon_setWindowSizeContainer_Params_TypeID = 0xc76ccd4502bb61e7
+// Conmon_setLogLevel_Params_TypeID is the unique identifier for the type Conmon_setLogLevel_Params.
+const Conmon_setLogLevel_Params_TypeID = 0xad5e6e3b177fdffd
 
-func NewConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+func NewConmon_setLogLevel_Params(s *capnp.Segment) (Conmon_setLogLevel_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Params{st}, err
+	return Conmon_setLogLevel_Params{st}, err
 }
 
-func NewRootConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+func NewRootConmon_setLogLevel_Params(s *capnp.Segment) (Conmon_setLogLevel_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Params{st}, err
+	return Conmon_setLogLevel_Params{st}, err
 }
 
-func ReadRootConmon_setWindowSizeContainer_Params(msg *capnp.Message) (Conmon_setWindowSizeContainer_Params, error) {
+func ReadRootConmon_setLogLevel_Params(msg *capnp.Message) (Conmon_setLogLevel_Params, error) {
 	root, err := msg.Root()
-	return Conmon_setWindowSizeContainer_Params{root.Struct()}, err
+	return Conmon_setLogLevel_Params{root.Struct()}, err
 }
 
-func (s Conmon_setWindowSizeContainer_Params) String() string {
-	str, _ := text.Marshal(0xc76ccd4502bb61e7, s.Struct)
+func (s Conmon_setLogLevel_Params) String() string {
+	str, _ := text.Marshal(0xad5e6e3b177fdffd, s.Struct)
 	return str
 }
 
-func (s Conmon_setWindowSizeContainer_Params) Request() (Conmon_SetWindowSizeRequest, error) {
+func (s Conmon_setLogLevel_Params) Request() (Conmon_SetLogLevelRequest, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_SetWindowSizeRequest{Struct: p.Struct()}, err
+	return Conmon_SetLogLevelRequest{Struct: p.Struct()}, err
 }
 
-func (s Conmon_setWindowSizeContainer_Params) HasRequest() bool {
+func (s Conmon_setLogLevel_Params) HasRequest() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_setWindowSizeContainer_Params) SetRequest(v Conmon_SetWindowSizeRequest) error {
+func (s Conmon_setLogLevel_Params) SetRequest(v Conmon_SetLogLevelRequest) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_SetWindowSizeRequest struct, preferring placement in s's segment.
-func (s Conmon_setWindowSizeContainer_Params) NewRequest() (Conmon_SetWindowSizeRequest, error) {
-	ss, err := NewConmon_SetWindowSizeRequest(s.Struct.Segment())
+// allocated Conmon_SetLogLevelRequest struct, preferring placement in s's segment.
+func (s Conmon_setLogLevel_Params) NewRequest() (Conmon_SetLogLevelRequest, error) {
+	ss, err := NewConmon_SetLogLevelRequest(s.Struct.Segment())
 	if err != nil {
-		return Conmon_SetWindowSizeRequest{}, err
+		return Conmon_SetLogLevelRequest{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_setWindowSizeContainer_Params_List is a list of Conmon_setWindowSizeContainer_Params.
-type Conmon_setWindowSizeContainer_Params_List = capnp.StructList[Conmon_setWindowSizeContainer_Params]
+// Conmon_setLogLevel_Params_List is a list of Conmon_setLogLevel_Params.
+type Conmon_setLogLevel_Params_List = capnp.StructList[Conmon_setLogLevel_Params]
 
-// NewConmon_setWindowSizeContainer_Params creates a new list of Conmon_setWindowSizeContainer_Params.
-func NewConmon_setWindowSizeContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Params_List, error) {
+// NewConmon_setLogLevel_Params creates a new list of Conmon_setLogLevel_Params.
+func NewConmon_setLogLevel_Params_List(s *capnp.Segment, sz int32) (Conmon_setLogLevel_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_setWindowSizeContainer_Params]{l}, err
+	return capnp.StructList[Conmon_setLogLevel_Params]{l}, err
 }
 
-// Conmon_setWindowSizeContainer_Params_Future is a wrapper for a Conmon_setWindowSizeContainer_Params promised by a client call.
-type Conmon_setWindowSizeContainer_Params_Future struct{ *capnp.Future }
+// Conmon_setLogLevel_Params_Future is a wrapper for a Conmon_setLogLevel_Params promised by a client call.
+type Conmon_setLogLevel_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_setWindowSizeContainer_Params_Future) Struct() (Conmon_setWindowSizeContainer_Params, error) {
+func (p Conmon_setLogLevel_Params_Future) Struct() (Conmon_setLogLevel_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_setWindowSizeContainer_Params{s}, err
+	return Conmon_setLogLevel_Params{s}, err
 }
 
-func (p Conmon_setWindowSizeContainer_Params_Future) Request() Conmon_SetWindowSizeRequest_Future {
-	return Conmon_SetWindowSizeRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_setLogLevel_Params_Future) Request() Conmon_SetLogLevelRequest_Future {
+	return Conmon_SetLogLevelRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_setWindowSizeContainer_Results struct{ capnp.Struct }
+type Conmon_setLogLevel_Results struct{ capnp.Struct }
 
-// Conmon_setWindowSizeContainer_Results_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Results.
-const Conmon_setWindowSizeContainer_Results_TypeID = 0xe00e522611477055
+// Conmon_setLogLevel_Results_TypeID is the unique identifier for the type Conmon_setLogLevel_Results.
+const Conmon_setLogLevel_Results_TypeID = 0xc9701dd28ecc4dec
 
-func NewConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+func NewConmon_setLogLevel_Results(s *capnp.Segment) (Conmon_setLogLevel_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Results{st}, err
+	return Conmon_setLogLevel_Results{st}, err
 }
 
-func NewRootConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+func NewRootConmon_setLogLevel_Results(s *capnp.Segment) (Conmon_setLogLevel_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Results{st}, err
+	return Conmon_setLogLevel_Results{st}, err
 }
 
-func ReadRootConmon_setWindowSizeContainer_Results(msg *capnp.Message) (Conmon_setWindowSizeContainer_Results, error) {
+func ReadRootConmon_setLogLevel_Results(msg *capnp.Message) (Conmon_setLogLevel_Results, error) {
 	root, err := msg.Root()
-	return Conmon_setWindowSizeContainer_Results{root.Struct()}, err
+	return Conmon_setLogLevel_Results{root.Struct()}, err
 }
 
-func (s Conmon_setWindowSizeContainer_Results) String() string {
-	str, _ := text.Marshal(0xe00e522611477055, s.Struct)
+func (s Conmon_setLogLevel_Results) String() string {
+	str, _ := text.Marshal(0xc9701dd28ecc4dec, s.Struct)
 	return str
 }
 
-func (s Conmon_setWindowSizeContainer_Results) Response() (Conmon_SetWindowSizeResponse, error) {
+func (s Conmon_setLogLevel_Results) Response() (Conmon_SetLogLevelResponse, error) {
 	p, err := s.Struct.Ptr(0)
-	return Conmon_SetWindowSizeResponse{Struct: p.Struct()}, err
+	return Conmon_SetLogLevelResponse{Struct: p.Struct()}, err
 }
 
-func (s Conmon_setWindowSizeContainer_Results) HasResponse() bool {
+func (s Conmon_setLogLevel_Results) HasResponse() bool {
 	return s.Struct.HasPtr(0)
 }
 
-func (s Conmon_setWindowSizeContainer_Results) SetResponse(v Conmon_SetWindowSizeResponse) error {
+func (s Conmon_setLogLevel_Results) SetResponse(v Conmon_SetLogLevelResponse) error {
 	return s.Struct.SetPtr(0, v.Struct.ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_SetWindowSizeResponse struct, preferring placement in s's segment.
-func (s Conmon_setWindowSizeContainer_Results) NewResponse() (Conmon_SetWindowSizeResponse, error) {
-	ss, err := NewConmon_SetWindowSizeResponse(s.Struct.Segment())
+// allocated Conmon_SetLogLevelResponse struct, preferring placement in s's segment.
+func (s Conmon_setLogLevel_Results) NewResponse() (Conmon_SetLogLevelResponse, error) {
+	ss, err := NewConmon_SetLogLevelResponse(s.Struct.Segment())
 	if err != nil {
-		return Conmon_SetWindowSizeResponse{}, err
+		return Conmon_SetLogLevelResponse{}, err
 	}
 	err = s.Struct.SetPtr(0, ss.Struct.ToPtr())
 	return ss, err
 }
 
-// Conmon_setWindowSizeContainer_Results_List is a list of Conmon_setWindowSizeContainer_Results.
-type Conmon_setWindowSizeContainer_Results_List = capnp.StructList[Conmon_setWindowSizeContainer_Results]
+// Conmon_setLogLevel_Results_List is a list of Conmon_setLogLevel_Results.
+type Conmon_setLogLevel_Results_List = capnp.StructList[Conmon_setLogLevel_Results]
 
-// NewConmon_setWindowSizeContainer_Results creates a new list of Conmon_setWindowSizeContainer_Results.
-func NewConmon_setWindowSizeContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Results_List, error) {
+// NewConmon_setLogLevel_Results creates a new list of Conmon_setLogLevel_Results.
+func NewConmon_setLogLevel_Results_List(s *capnp.Segment, sz int32) (Conmon_setLogLevel_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_setWindowSizeContainer_Results]{l}, err
+	return capnp.StructList[Conmon_setLogLevel_Results]{l}, err
 }
 
-// Conmon_setWindowSizeContainer_Results_Future is a wrapper for a Conmon_setWindowSizeContainer_Results promised by a client call.
-type Conmon_setWindowSizeContainer_Results_Future struct{ *capnp.Future }
+// Conmon_setLogLevel_Results_Future is a wrapper for a Conmon_setLogLevel_Results promised by a client call.
+type Conmon_setLogLevel_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_setWindowSizeContainer_Results_Future) Struct() (Conmon_setWindowSizeContainer_Results, error) {
+func (p Conmon_setLogLevel_Results_Future) Struct() (Conmon_setLogLevel_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_setWindowSizeContainer_Results{s}, err
-}
-
-func (p Conmon_setWindowSizeContainer_Results_Future) Response() Conmon_SetWindowSizeResponse_Future {
-	return Conmon_SetWindowSizeResponse_Future{Future: p.Future.Field(0, nil)}
-}
-
-const schema_ffaaf7385bc4adad = "x\xda\xacW}l\x1cG\x15\x7fo\xe6\xd6\xe7\xda>" +
-	"\xdfM\xd7\x88b)2\x8aBE\x12\xb5\x0eq\xca\x87" +
-	"\x15d\xe7\xc32.\x09\xdc\\\x12\xa2\xe6K\xdd\xdcM" +
-	"\xedM}\xbb\xe7\xdd\xbd8N\x89\x92\xb6XH\x85\x16" +
-	"Z\x15\x81#\"%\xd0T\x8d\x89IKI\xa8\x02T" +
-	"@\x13\xb5\x04\x02\xd8\x12\xa0V\x14\x1aBh\x1a\xd1\x02" +
-	"\xa2\x08\x07U,\x9a\xdd\xdb\x8f\xbb\x18\xd5\x1f\xfcw\xb7" +
-	"\xfb\xdb7o\xde\xbc\xdf\xef\xf7fE\xbf\xd2\x9d\xf8P" +
-	"jk\x03\x10\xbeO\xa9s\xedK#\xd6\x93Gz\x1f" +
-	"\x04\xb6\x1c\x01\x14L\x02t0e1\x01T\x97*]" +
-	"\x80\xee\xe8\x1b\x9f:\xb3\xe5\xc1\xbf\x1e\x8d\x03\xfa\x94\x95" +
-	"\x12\xa0y\x80\xb1\xedW\xef\xed\xe9K\x7fC\x02\xdc\xbf" +
-	"w\xdc\xf3\xea\xd8\xd5\x8f|\x0f\x12\x127\xaa\\C\xf5" +
-	"\x98\x92\x04\xea.9\xf5\xc2\xe4C\xab\xdb\xc7\xe3a\x0e" +
-	"(7\xcb0_\xf1\xc2\x0c\xdf\xfd\xd2\xa9\xfd\xfc\xca\xc9" +
-	"\x19\xc2\x9cV\xa6P\x9d\xf4\xc2\xac:\xf6\xec\x99G\xde" +
-	"\xda\xf7m\xe0\xcb1\x06\xf3\xe3M(\xe3\xa8\x9eW\xde" +
-	"\x0b\xa0^T\x86\x01\xdd\xfb&\xaf=\xf5\xc8\x17\xd6\x9c" +
-	"\x9e\x11\xbd\xb4\x8e\x10\xb5\xa7.\x09\xa0\xae\xa9;\x05\xb1" +
-	"\xf7l\x09u'&\xcem\xff\xe8\xbf\xc6]\x00\xecx" +
-	"\xb9n\x1b\xaao\xd6\xbd\x08\xd01\x99\xfc<\xaa\x97\xea" +
-	"\x93\x00\xee\x853':\xff}y\xf8lmpE\x06" +
-	"\xffi\xfd\xcdD}C\xe2:\xae\xd4oE@7\xb3" +
-	"\xfd\x97\x1f\xff\xcb\xae?\x9f\x8f\x17\xa0\xdc\xd0*\x0b\xf0" +
-	"p\x83,\xc0\xeb\xda\xf7I\xcf\xc5\xc1\x17\xe3\x80g\x1a" +
-	"\xee\x94\x80\x8b>\xe0O\xff\xd9\xd3_j\xff\xb9\x0f\xf0" +
-	"*\xf3f\xc3\x14B\xc2\x9d~\xcf\xf3_m]}\xf6" +
-	"\x17\xf1O/\xf9\xb1\xaf{\x9f\xb6\xae\x99\\\x956z" +
-	"\x7fU]\\\x1f\xb8\xa8\xf1\x8f\xa8~\xacQ\xd6\xe1\x8e" +
-	"F\x09~gt\xf5\xa1E\x8b~\xf3r\xed\xc6\x88D" +
-	"oi\\F\xd4\xb2\x87\x1ej|\x1d\xd0=\xbc|\xb8" +
-	"\xb4kw\xe7\xefk\xd0^z\x1b\x9bZ\x89Zl\x92" +
-	"`\xbd\xc9\x0b\xdd\xf9\xce\xf3GW\x97\xfeP\x93\x07\x95" +
-	"\xe0\x87\x9b.\xa0z\xc2\x03\x1fo\x92\xe7\xb1\xa5\xd4\xcb" +
-	"n\xcd5\xbf\x16\xdfUO*\xe7u^JF[q" +
-	"_\xef\x89]\xbaz9\x0e\x18M\xbd\x82\x80\xea\x98\x07" +
-	"\xf8\xb0\xfa\xc2\xd3\xc6\xa3\xd7\xae\xc4\x01?L-\x93\x11" +
-	"~\xed\x01~\xbc\xbd#\xfb\xdb\xcb\xb7\xfe\x0d\xd8\x1d$" +
-	"\xea-\xc0\x8e\x7f\xa6\xa6Pe\xcd2\x99Ts\x1b\xa0" +
-	";\xf9V\xdb\xc9\x9f]\xf9\xe4?f<\xedT\xf3+" +
-	"\xa8.\x95\xe8\x8e\x0f4{\xa7\xfd\xe4\xd07\xbf<\xbd" +
-	"\x98\xbd-\xe1\xa4\xb6\x86\x07\xd2\x8b\x89z$-\x83\x8f" +
-	"\xa5e\x0d\x9f;\xfc\xf8\x97\xce\xad\xec}\xbbj#\x19" +
-	"\x8f\x1cG22\xcf\x1f\xe0x\xe3\x8e=W\xa7\xab6" +
-	"\x92\xf17\xe2\x01\xa6\x8f}\xab\xe3\xd0\xc5g\xaf\xcf\xc0" +
-	"\x9e\xeb\x99\x06\xa2\xbe\x8f%\xa1\xdd\xcd\x9bF\xd14n" +
-	"\xb3\x92v{\xde,\x16M\xa3\xbdd\x99\x8e\xd9\xee?" +
-	"\xbf=\xaf\x95\x8cR\xe7:\xff\x8f\xd8'\xf2\x9bF\x8c" +
-	"\xfc:\xd3p4\xdd\x10\xd6\x92\xacf%\xb5\xa2\xcd\x13" +
-	"4\x01\x90@\x00\x96Z\x0b\xc0\xeb)\xf2\x16\x82\x07-" +
-	"1T\x16\xb6\x83\x99h\xf3\x80\x98\x01\x9c\xd3\xb2\x960" +
-	"K\xc2\xd8`\xf6G\xeb\xe6D\x9b]\x1et\xaa\x16\xbe" +
-	"\x13\x807Q\xe4\xb7\x10t-a\x97L\xc3\x16\x00\x80" +
-	"\x99H\x8ej\x16\xaf\x9b\xc5\xe2\xb9`\xf1\x9c\xb0Ki" +
-	"\x193\x8bsK_s\x1c-?PU3\xad\x88\xb3" +
-	"\xa8Y\xc8\x8cy\xa4\xbd\xc6[4\xe7\x97\x01\xabrV" +
-	"f\xf1\xf9\x06\xb3\x7f\xbd\x95\xd6\xf7\x0a\x8b'0\xce\x09" +
-	"\\\x96\xde<R\x12\xbc>L~\xe92\x00\xbe\x84\"" +
-	"_A\x10\xb1\x05\xe5\xb3\xdb\xe4\xb3\x0fR\xe4\xab\x08\xa6" +
-	"\x9d\x91\x92\xc0t\x14\x03\x10\xd3\x80\xe9\x92\xe6\x0c`\x13" +
-	"\x10l\x9ac;l\x12\xceV\xdd(\x98\xc3\x9b\xf4\xfd" +
-	"\"\xe7W\x0b\xb2\x88\xbc)\xcc\xa9\xa7\x15\x80wS\xe4" +
-	"\x1b\xa2\x9c\xfaV\x02\xf0\xf5\x14y\x96 #\xd8\x82\x04" +
-	"\x80m\xec\x04\xe0\x9f\xa0\xc87\x13\xa4z!\xc8\xa7m" +
-	"X/8\x03\x98\x04\x82I\xc0\xae\x01\xa1\xf7\x0f8\xc1" +
-	"\xdf0\xd9\xc4\xbb%KM\x83\xaf\xc0\x98P\xb0\xbb\x1e" +
-	"\x88<\x82\xddu6\xd2\x17\xb63\x17\xc9&\xdb\xf9\x93" +
-	"\x880L\xbb\x10\xc9/\xd3\xa7b\x829d\xc5,r" +
-	"h\x7fL\xd2\x87\x1e\x8ayp\xf9\xb1\xc8\xf5\xd8\xc8x" +
-	"L\x18\x0e|\xc7\xfd\x8c\xb0l\xdd4r4`\xcc:" +
-	"Kh\x8e\x08\xdb5\xd7\xe5W\xd8\xf5ZB\xdf+\x00" +
-	"-7\xc0(\x01(\xf8\xb8\xa7V!\x82\xf3\x017x" +
-	"Eb\xef*\xdd\xe9\x06\xdd\x0am\xfeZ\xe1\xff.?" +
-	"\xae\x1b\xb0\x10\xfb\xa3\x80\xf1gA\xa0\xa070h\x8e" +
-	"\xb4\x17\xaf\xf6\xb1\xdd\xe6\x85\xe5\xb7P\x05 4Q\x0c" +
-	"\xcc\x83=\xb3\x16\x08;\x9e\xc4H\x861\xf0S6\xf6" +
-	"\x00\x10\xf6h\x12I8'a \xc5l\xf41 \xec" +
-	"\xfe$F\xb3\x0d\x06\x1e\xcf\xca\xf2\xbbb\x12\x13\xa1\x05" +
-	"a0G1\xed0\x10\xb63\x89J\xe8\xf8\x188\x1d" +
-	"\xe3g\x81\xb0\x8d\xc9\x83{\xfd\x83\xeaF7_\xa9>" +
-	"V\xea\x08\xdd\xe8\x06\xd2\x8cAu\xd1\xeaF7\x90\x9e" +
-	"8\xd2\x0a\xcbV\x81R!\xa1vU\x89\xd6\x99F\x97" +
-	"\xffI7\xceU\xefj\x1b\xc8;0t$Co\x09" +
-	"\x19:&\x19\xfa8E~\x94 \x0b(zd\x1b\x00" +
-	"\xff:E\xfe\x14A$>C\x8fKY\x7f\x82\"\x7f" +
-	"\x9a \xa3\xa4\x05)\x00\x9b\xc8\x01\xf0\x93\x14\xf9K\x04" +
-	"Y\x82\xb6`\x02\x80\x9d\xdf\x03\xc0\xcfQ\xe4\xaf\x11d" +
-	"J\xa2\x05\x15\x00\xf6\xaa\x0c\xf9;\x8a|\xba\x8a\xe0\xee" +
-	"\xee\xb2Q\x18\x14Y\x0dhL\x85\x1ca\x15uC\x1b" +
-	"\x94\xae\x81@P\xba\xb6\xd8\xa7;Y\xcd\x19\x00\xb4\xb1" +
-	"\x190K\xd1\x837\x03\xba\xa6Y\xec\x91o!\xad9" +
-	"\x037\xbc\x1d\x0c\xf8B\xad\xf0]&>Px\xa8\x85" +
-	"\x18IN\xd8\xe5A:[\x13\x0c\x95\xa2\xc6M\xeag" +
-	"\xb1\xb2\x1d\x97\xdc\x1a'\xb3\x01\xde\xdd\xcaB\xfd\x99\x87" +
-	"\x95U\x1a?\xf0\xcd9\x95,_\xdd\x8bs,Y(" +
-	"\xc8\x0b\x9b\x1b\x86\xcaIa{\xfd\x1f[\xb55\xaaS" +
-	"\xbc/\xe7\xb2\xb9\x19\xc46\x1aQx&\\K\x93;" +
-	"\xbc\x9b\"\x1f\x8c\xdcP\x97\xc6W\xa0\xc8K17," +
-	"\xca\x87\x03\x14\xb9#\xb9\xf6~\x9fkC\xf2\xeb\x12E" +
-	"\xfeY\xe2\xb3a\x9dY\xf0\xea\x93\x00\x82\x09\xc0.\xdb" +
-	")\x98e\x07S@0\xe5\xff\x15\x96\x15\xfcu\x1d\xbd" +
-	"(\x0a\x9f.;qN-HM\xe4\x01Q\x7f\x8b\xb1" +
-	"r\xee\x89\x1db\xbe\x02\x86\xb4\x95\xd5\x0bX\x0f\x04\xeb" +
-	"\xe79>U\xbc\xe6\x7fO\x17\xa1v\xf5m\x8b&\x09" +
-	"F*\xe2\xc5-\x00\x9e\xa5\xc8wT\x9f\xb2m\xe6\xef" +
-	"\x15N\x8d\xfax\x12.l\x1b\xdat\xd3\xe8\xbb\xb1%" +
-	"\x16@T\xaf\xed\x1d\x9ce\xdb\x87\xf3\xc1\x02\xc8:7" +
-	"\xa2\x85\x03\xd2\xff\xe7v\x90\xd5\xd2\xd6\xacn%\xe1\xc0" +
-	"4\x8f\x9d\x06\xf3\x90u\xfb\xe6\x91\x12\xfa\xfd\xe8\x1d\xba" +
-	"2\x05\x10\xf6 \xb1reCr\xa0\xcfp\x84u\x8f" +
-	"\x96G1\xa7U\x82\xf1,\xde\xf61\x17];\x93\x8b" +
-	".\x06\xe0_\xa3\xc8\x9f\x88u\xe2\xb1\xce\xc8Z\x19\xa5" +
-	">\xb5\x8f\xe7b\xde\x9aH\xf86:\xb1\xbb\xe2\xad\xcf" +
-	"\x11D\xc5w\xd1\xd3\x12\xf8]\x8a\xfcG\x04\x83A$" +
-	"\xe8\xcf\xa4\xa3\xf5\x07\xbf\xbb\xe4~t'\xe6\xb2\xfa`" +
-	"a\xbd\xe6\x00\x8a\xf0\x99U\xb6\x1d\xb9+H\xc6\x82\xb8" +
-	"%\xcb\xcc\x0b\xdb\xee\x03\xbc\x91\xb0\xf3\xd4\xc2Hvc" +
-	"R(\xa9\xbb\x83\"\x1f\x88\xa4Pl\x9bI\x0a\xd7V" +
-	"\xa4\xf0s\xb2^\xdd~\xbd\xee\x97\x1d|\x88\"\xffb" +
-	"5\x9d\xe5\xf9\x9aeg\x13P\x91\xc7\x9b\x80\xe0M\x80" +
-	"\x07e\xca\x9aQ\xa8\x1d\x0bf\x9a1\x16\xe2h\xb3\xbe" +
-	"M\x86\xf7\x8eyp\xec\xc6\x8b\x7fN\xd8\xe9\xd9_\xc0" +
-	"\xc3\xfb\xcb<\xd6\xae\xb9\xee\x05a\xb3\x88\xff\x0d\x00\x00" +
-	"\xff\xff\x1a==\xe6"
+	return Conmon_setLogLevel_Results{s}, err
+}
+
+func (p Conmon_setLogLevel_Results_Future) Response() Conmon_SetLogLevelResponse_Future {
+	return Conmon_SetLogLevelResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+const schema_ffaaf7385bc4adad = "x\xda\xb5[\x0bx\x14\xe5\xb9\x9e\x7f6a\xb9\x85\xcd" +
+	"v\x12 \x81\xb8\x82XH\x80\x84$\"\x81B\x13\x84" +
+	"\x88`\xa2\xd9]\xa8\\\x84\xb2\xd9\x1d\xc2\x90\xcd\xeef" +
+	"v\x96\x10\xd4\x13\xc0\xa2\x05\x0e\xde*Ux\xd4#\"" +
+	"\x1c@QA\xd1\x8a\x97GT\x1e\x01\x85\xd3\xd0\xa3\x96" +
+	"\x1e\xa9r0V\xacXmk\x05K\xdc\xf3}\xff\xcc" +
+	"\xfc3{\x81\xb3\xbb\xd0\xe7aH\xe6\xdb7\xdf\x7f\xfb" +
+	"\xfe\xef\xbec\x1e\xb6\xd7d\x95\xe7\xdc=\x88\xe3\xdd5" +
+	"$\xbbGtL\xdd\xb5\xb3;_\xc9[\xc9\xd9\xc7\xf3" +
+	"\xd1\xf3\x13\xce\xbf\xfe\xf8\xc4\xd0\xc7\x1cG*w\xe4\xb8" +
+	"x\xe1H\x8e\x95\xe3\x84C97s$z\xea\x1f\xbf" +
+	"}\xe3\xf9\xd9\xd2*\xce>\x92D\xbf\xa9\\tb\xe3" +
+	"\xe7\xe3^\xe2\xb2\x00Py2\xe7\x7f\x89\xd0M\xb1\xe7" +
+	"\xe0\x7f\x12\x0d\x9fl\x97\xb7=:\xedN\xc4r\\6" +
+	"A\xd0\x89\x9c\xa1<\x03U\x03\xe8\xdb'\x0fNz\xe8" +
+	"\xfe\xbf\xac5\x83\x8a\xfa\x01\xa7\xf1\xfd\x104\xb6\x1f\x82" +
+	">\xaa*Y\xf4\xb8\xa5n\x9d\x194\xbf\xdf\x1f\x88\xd0" +
+	"NA\x11\x0a\xfa\xb7\x85\xb6\x07\x7f\xd5\x7f\xde\xba\xd8\xa9" +
+	"e[\x11\xbc\xb1_!/\xbc\x8c\xe0\xca\xbd\xfd\xee%" +
+	"\x80^7r\xb2\xb3\xf7\x86-\xf7\x99Yn\xcd\xe5y" +
+	"a\x7f.\xb2|-\x17Y\xd6m\xce;^\xf3\x8b\x7f" +
+	"\xdc\xc79G\x12\x8b\x89\xa7\xba^Dw#\xba\xf2\\" +
+	"\xee8\x1e\xe0\xc5\xfe\x83\xd3\x07\x7f\xf8\xcb\x07\xcd<[" +
+	"\x85\xde\xbcp\xbf\x80<\xd7\x0b\xc8\xf3\xd7\xf9\xd7\xaf\xb3" +
+	">\xf1\xcf\x07\x93\xec\xe0^\x016\xe7}\x8a\xed\x14p" +
+	"\x07\x8fv\xbc\xe9\x8b\x1cm~(nI\x16\x15\\h" +
+	"\x80\x9f\x05\xf0\xbbW,\xbf\xc5\xb6\xed\xee\x87\xe3\xc0t" +
+	"\x16w\xe4\x01\xe7G\xf3\x10\xbc1\x0fg\xe1\xfa\xd1\xea" +
+	"\x99\x0f\xb9V=j\x9ejg\x1ep\xfc\x86\x82\xceP" +
+	"\xd0\xea\xd37\xbd8\xeb\xce\xbf<n\x06\xd9\xf3+x" +
+	"\xa1<\x1fA\xa3\xf3\x11T\xd5\xf5\xce\xda\x15\xb3\x9b7" +
+	"\xe3\x1e\x99\xc7\xe5\x11=+\x7f\x1f\x11Z(Z\xca\xc7" +
+	"In\x9c\xf7ys\xedt\xdb\x13IV\x9f\xd3\xff\x0b" +
+	"\"\x14\xf7G\xec\xd5\xfdq\xf5\xbb\x0f\x8fv\xf9k\xde" +
+	"\xddb\x1e\xbeW\xff\xbf\x12\xfa1'\x0c\xe9\x8f\xc3\x17" +
+	"\xad\xfa\xe4\x90\xf0\xf6g[px>~\x8fj\xfb\xef" +
+	"$\xc2|DW\xce\xe9\x7f\x0b\x1e\xfb[?\xed\xde\xfe" +
+	"\xc2\xed\xe7\xb6\xc4m\x12\x9d\xc0\x89\x01O\x10\xe1\xdb\x01" +
+	"\xc8\xfb\x9b\x01\x7f\x02p\xff\xed\xc2\x7f|\xe6\xffp[" +
+	"\x8c\x00\x0f\x84M\xea\x1eH\x05x N`\xcf\xd2\xec" +
+	"\xef\xdf\xbc\xea\xf4v\xce~-\x1f\x9d\xfb\xfccC\x06" +
+	"u\xff\xf4\x04\xde\x9d\xa2\x02\x10\xd0\xf1\x05T\x8a\x0b\xf0" +
+	"\xee\x0c{\xf6\xad\xce\xb5\x13\xcbv\x9a\xd9M/\xf8\x11" +
+	"/H\x14$\x16 \xbb}\xcf:?\xfd\xf3\xa6m1" +
+	"\xa05\x05pz;(h+\x05E\xffg\xe4\x86\xcf" +
+	"\x84\xf5O\xc5\xed9]\xc5\x91\x82\xc3D8]0\x00" +
+	"WQ\xd0\x06\xe0\xb6\x85\x07\x9f]\xee\xecz:\x99\\" +
+	"\xcc*<F\x84\xd6B\xe4\xdcR\x88\x9cs&M\x18" +
+	"q\xf2\x1d\xe7.\xce\xfe\x13>z\xdb\x94q\x8f\xd6\xff" +
+	"\xfa\xe0\xab\xb8\x9a\x0d\x85 \xed\xbb)rW!\xae\xa6" +
+	"\xfb\xe3\x8e\x01?\x09,\xd8e\x9e\xe8\x81B8\x9d\x93" +
+	"\x14t\x82\xb2;\x7f\xb6m\xdb\xebK^\xdc\x95\xe4\xb8" +
+	"\xc9 \xb8\x18C\x06!\xb6\x08\xfe'\xdf\xfd\xf0\xda\x15" +
+	"]\xbd\x17<cbG\x06\x0d5 \xc8\xee\x9a\xcd\xcf" +
+	"\xbfx\xcfW\xcb\x9e\x89\x975\x8a\xae\x1d\x84\x87=\x08" +
+	"\x17.\x0e\xc2\x85\xdf\xd6\xf9\xc5\xf6{\xd6M\xde\x9bT" +
+	"2\x0f\x0d\x82\xf5tQ\xde'\x07\xe1a\xb3\xcf\xed\xc3" +
+	",\xd1]\xbb\xde\x9eW\xf5\xdd\xce(\xae|\xff\xe0\xb9" +
+	"\xa4\xf2\xfd\xc1M<\xea\x11\xc7\xdd\x16a\xe5P\xf8\xab" +
+	"\xe8\x0d\x0f\x15\xee\xd8\x11Y\xf7R<w*x\xd2P" +
+	"\xd8\x88\xd5\x88\x034r?\xfc\xe2\x8e\x09\xdf\x9fj\xdb" +
+	"\x17\xafIzQ\xf4U \x04k\xae\xc2_W_u" +
+	"\xb3\x05\xe0\xb5\xdb\xd6\xfd\xe0<<\xe8\xd5dg\xd62" +
+	"\x1c6n\xfdp\xe4\xbdf8\xee\x0a;\xa6\xb8+@" +
+	"\xd1\xbb\x87\x83\x14\x1e\x19\x8e\xbb\xf2\xfep\xbc\x81\xc7\x87" +
+	"M+\xceyj\xdc\xdbIw\xe5\x8e\x110\xef\x8d#" +
+	"\x90\xf7\x86\x11\x88\xce\x9d\xf7_\x93\xbe\\\xf0\xd9\x01\xf3" +
+	")O*\x86+0\xbf\x18As\x8aq\x02\x7f\xf2\xbc" +
+	"\xc2\xd7\x1e\xf1\xbfc\x06\xad,\x9e\xc1\x0b[)h3" +
+	"\x05\x9d\xbdi\xde\x9b\xfd~~\xd7\xc1$\xa2p\xa0\xf8" +
+	"{\x10\x1b\x8a=Q\x8c7\xff\xcb\xfa\xf7\xee9V\x14" +
+	":df\xb8\x1fA'(\xe8\xb8:\xea\xa7?,i" +
+	"\x0a\x95\xbd\xa7\x82T\x99*\x01q.*ALA\x09" +
+	"2\xfa \xef\xc0\xf6\xad+\x8a\x8e\xc2\xe5\xd41\xdd8" +
+	"{3\xc6z~\x97{\xc8\xf4\xf9G9\xe7\xb5\x84\x8d" +
+	"\x16\x83Baj\xees0\xafWu\xf8\xa8yJ\xed" +
+	"%p\x12\x1b)hC\x09]c\xfe\xeb\x0f\x15N\xdc" +
+	"\x17\x03z\xb9\x048\x1d\xa7\xa0\xf7)\xa8pr\xe75" +
+	"\xb6\xc0\xb4\xdf\xc6\x9d-\xdd\xff\xee\x12\xb0|\x05#\x11" +
+	"\x9c?\x12\x87\xbd\xf7\x9e2\xf7cO\xad>\x96\xfc\xb4" +
+	"F\x82\x0co\xa4\xe8\x0d#\xf1\xb4\xf2\xcb\x8e\xcauc" +
+	"j\x7f\x97d\x8f\xc7\x8f\x82\xb9\xce\x19\x85\xd8Y\xa3p" +
+	"\xd9{\xbc\xbb\x85\xc7\xf6|\xff\xbbd:d\xfc(\x10" +
+	"\x9aY\x14\xec\x1c\x85\xe2\xbb\xe5o\xdb\x17\xee\xbd?\xef" +
+	"\x03`l\x920\xb8\x1c\xc5\xa3\xe1\xd2M\x1e\x8d\xc8I" +
+	"\xa3;\xf0\xc6\xaf\x9e\xb8\xa2\xa8\xe8\x83\xe3I'\xdc:" +
+	"\xba\x04\x8c!E\xaf\x1f\x8d|\x7f\xf5\xc0\xd6g\xca\xff" +
+	"|\xfb\x1fL\xe7#\x95\xc2\xa5_S\x8a\x98\xd5\xa58" +
+	"Q\xa6S\xed#-1cK\xa5{\x88\xb0\xb2\x14E" +
+	"{M\xe9\xdd\xc2i\xfc\x9b\xe8\xa6\x91m\xa1\x05\x8d\x13" +
+	"\xfe\x98lY\x9d\xa5h\xe0(\xeb3\xa5x\x14+\x9e" +
+	"^\xf5\x9f\xc7\xbe\xda\xf7\xc7\x18\x03W\x06\x1bU^F" +
+	"\x0d\\\x19\xd5a\xba?\x14w\xbdzP\x05Z\x06\xda" +
+	"\xb6\xb5l\x00J|\xd9;haf\x85\xa6\xd9\x7f\xec" +
+	"\xea\xf7I\xcce(\x077jk9\xbd\x0c\xe5\xc8s" +
+	"\xd3\x7fWu<\xf2\xd1_O\x9a\xd6}\xa8\x1c\xd6}" +
+	"\x9ab\xba\xcaq\xddcn\x9b\xb6c\x81$\x9c23" +
+	":T\x0e\x07\xd3EA')\xa3\xf9\xd37\xbc\xb4\xa0" +
+	"\xcf?O%\x1cL\xaf\x0a\xb0\xbcC*\xa8\xee\xac@" +
+	"}}\xad\xf0\xd6s\x81\xfb\xbf\xe82\xb3+\xaf\x80\xf3" +
+	"pRP}\x05\xb2{\xa2\xfc\xc8\xb2\xbaN\xfe4u" +
+	"\x04\x99\x95\x01v\xad\x15\xa0\x18\xd6S\xe4\x1a\xca\xee\xc4" +
+	"\xaa@\xfd\xc9\xee5\xa7\xcd\xec6W\x80<\xbeFA" +
+	"/#\xbb\x8f?\xdf/\xbd\x1b\xfa\xe2\x8bd\xda\xfaD" +
+	"\x05l\xdc9\x8a\xfd\xb6\x02%\xfd\x95\xdb\xbe\x19\xf8\\" +
+	"\xd7\xb13f\x86s*\xe1\xceG*\x11\xd4Z\x89\xf3" +
+	"\xdb?\xaf\xb2\xe1\xc3S?\xfe\x9a\xb3\x8f\xe5\x0dk\x00" +
+	"\xf3\xdbX\x097\x7f/E\xee\xaet\x00\xb2\xf3+\xc7" +
+	"\xd3\xefv\xdd\xf8\xb7\xf8S\xcb\xa6J\xb1\x12\x95b%" +
+	"\xdd\xd1J\xea\x0enk\xddr\xdf\xd9\xa1\xf6\xbf'\x85" +
+	"G\xc6\xc2\xd1l\x18\x8b\xbf\xde?\x96\xc2\x7f\xb3\xe9\xc1" +
+	"{\xdf\xae\x98\xf6w\xf3d\xeb\xc7\x81\x16o\x19G}" +
+	"\x9dq8\xd9\xfc\x9f\xaf\xfc\xa4\xe4\xf4\xa9\x18\xd0z\x04" +
+	"\xed\xa2\xa0\x1d\x14T\xd4\xf9\xb3\x1f\x9e|\xe9\xe1\xef\x92" +
+	"i\x83\xceq\x0f\x80)\xa7\xe0\xaeq\xb8G\xaf\x92\x9d" +
+	"}n]\xf2\xf9\xd9\x18\x0f\xa2\x0a\xceP\xaa\xa2\x1eD" +
+	"\x15UB\x9b\x9f\xaa\\q\xe4\xf9sI\x94\xc0\xfa*" +
+	"\x90\xed]\x14\xbb\xa3\x0ae\xec\xceWB\xaf\xdc\xe5\xe9" +
+	"\xf1}2;\xb3\xbe\x0a6\x7f\x07\x05o\xa5\x8c\xb7\xf4" +
+	"^\xb3\xddq\xd73\xdd\xc9\xc0G\xaa`]g(\xf8" +
+	"4\x80GG\xbd\xc1@K00Z\xee\x19.\xf3\x06" +
+	"[\xe0\xd7\xb2\x90\x1cT\x82e*\xbd\xd4\xeb\x09\x05B" +
+	"\x13\xa6\xa8/\x93\x15\xc5\xe3]\xec\x12[#bX)" +
+	"u\xfb\x83mS\xfc\x92\x18P\x1a\x82~\xc9K\xda\x1b" +
+	"\x08q\xf6%`~\xedE%p\xd6\x04\\P\xf8\xc1" +
+	"\xdbs\xe6r\x9c\xcd'\x07C\x8eF\x7f\xd0\xdb\x1c\xf5" +
+	"Ia\xe0\x1e\x109\x8bWa\xe3\xf7Ha|\xf7\xe2" +
+	"\x88\xe2\x0b\xb6\x05\\b8\x14\xb4\x06\xc2\"\x8c\xd8`" +
+	"\xc9b<\xac)\xf0\x10\x97\x89^w{\xc0\x0b\xaf\x8a" +
+	"G\x0a\x88\xf2\xb0\x06\x8fl\xf5\xb4\x84q\xf6Y\x96," +
+	"\xb8\xe5pd\xf6\x9c\xeb8\xce\xd9\xd3B\x9c\x03y\xd2" +
+	"!\xab+&\xb9\x86\xf8q\\\x0d\xc0H.lw:" +
+	"+\x08k+\x18\x06K\x88X\xfdJ\xfc\xa83`\xd4" +
+	"\xbe0\xea`\x9eDe\\%,\x92\xc3q\x8c\x80\xee" +
+	"RG\x86\xe5z,).\xd7p,\x13\x07Me\xb3" +
+	"\xa7\xc8\xa2G\x11o\xf2\xb4\xc0R<^1LE\xc7" +
+	"\x12Vp\xf0\xc1l\xf0\xbd\xb8\xea\x17`\xf07xb" +
+	"'$\x0f\xad\xbb}?\xc8\x8e\xf3U \x1e\x04\"\xcf" +
+	"\xe7Q\xb9:\x04\x92\xe4<\x08\xc4\xcf\x81h\xb1\xe4\x11" +
+	"\x0b\x10\xbb\x1a\x81\xf8\xa9\x85\xb8\x08\x10\xb3\xb2\xf2\x08\xf0" +
+	"\xb5w#\xf1\xbc\x85\xb8\x07\"5;;\x8fd\xa3\xad" +
+	"&\xb0Nw.\x01\xfa\x18\xa4\xf7\xe8\x91Gz\xa0\x01" +
+	"!0\x05\xf7(\xa4O\x04z\xb4\xd1\x03\xb2\xe5Q\x16" +
+	"\xe3\xd6\xf7\xe5xx8;\xe9\xcdq\x8eP\xd07\xdd" +
+	"\x17C\x8a\x06\xb4\xd5q\x161L\xfaq \x90\x84\xd8" +
+	"\x0cC\xac\xed\x1c|\x10\x8dH\xbezO($q\xd6" +
+	"@\x13\x83\"/\xfc\xb0\xe9\"\x1fv\x84\xdb\xc3^\xc5" +
+	"\xcf\xc8\xb9\x8662\xb1o\x11\x15\x8f\xcf\xa3xTy" +
+	"a\x01T\x86G\xd7,\xf9\xfd1w\x04\xae\x08\x97\x92" +
+	"\xd00W(\xc3\x91]b\x93\x14VD\xb9A\x0e6" +
+	"\x8a\x9a\xb2\xa1#\x8f\xd0G\x16z\x91B8\xaf,<" +
+	"\xaf\\b\x08\x8d`'\xa0x\xdc}\x89~\xee\x9a\xdc" +
+	"\x08\x05\xf4\xdc\xf3\xf4s'\xaa\xe4\xc0\xb1#y\x04\x92" +
+	"\xaf\xa1\xc2C\xa8\xf0\x08\xe5\x04\xae\x19 5q\xb0g" +
+	"\xf3\xaa\xf8\x8c'.\xa0W!}*\x15\x1f\x8b*>" +
+	"\x93\x09\xc8\xa5\xbb\x06\xe9uH\xb7\x82\x10\xa2\x86\x9dN" +
+	"\xd6\x02\xbd\x0e\xe9\xb3\x91\xde\x13\xc4\xb0':v\x94>" +
+	"\x1b\xe9>\xa4\xf7\x02>\xbd\x80\xee\xa1b\xb8\x10\xe9!" +
+	"\xa0[\xa4XQ\xb3\xa1\xa8\xc5P:p3=\x01_" +
+	"\xbc\xbc(\xdePCPV`\x12<A\xf3!\x05$" +
+	"E\xf2\xf8\xa7\x12\xd1\xefiw\x8b^\x0e\xa6\xc1\xc3C" +
+	"\xa2!Q\x96\x82>\xb7\xc8\x11/\xa3)R\x8b\x18\x8c" +
+	"(n\x90h\x83\x18\x8ex\xe1\x02\x87g\x92\xc5\xa0\x97" +
+	"\x16\x07\xfd>\xce`\xb2\xc8#\xf9#\xb2\x98\xf4\xb3\xcb" +
+	"+\x95\xb2Y6\xa8\x12\x05\x1d\xcaq)kQ\xe6x" +
+	"g,\x9b-\xc1\xa51\x0a-sK\x94\xc8\xcbP\x8e" +
+	"}\xd9bjq1Sa1\x0d&\xe5\xe8D\xe5X" +
+	"\x07\xc4\xd9&\xe58\x07\x91\xb3\x81\xb88M\x0dvy" +
+	"\x8f(Q\xe7\x1b[d>\xa4\xb9\xda!\x8d\x82\xd9&" +
+	"\xd3\xa1\xb9\xbaWjRq\xe9\xcc\xc3\x1b7\x8f\xb4\x0c" +
+	"\x1f\xcbQf,\xa7\xc1\x90\x18\xa8\x0b6\x19*\xd4%" +
+	":\xa8\xb4\xa6,\xab,\x05\x97d\x0e\xd9)\xcc\x81\xde" +
+	"\x91j\xb7\xe2Q\"\xe18\x99\x02-\xe9\xac\x81A\xeb" +
+	"@\x17j\"U?\x01h7\xe8\"ET\x91\x9a\x85" +
+	"\xbb\x03\x87\xe1\xbc\x95'\x89\xda\xa7Z\xa6\xf7\x0f\xac\x1d" +
+	"\x8bn\xb4\xa9\xda@\x07\xc1\xbe\x87=M\xb1\x7f\x91\x96" +
+	"\xc7\xe2\xd2\xf7\x10%\xc8\x16L\xbcd\xa90\x09\x81\x1a" +
+	"\xbc>(\xb7yd\xdf\xb0\x06\x07\xb5b)\x09\x00\xcb" +
+	"\x1ae\xb8\xf9\xf4\xdcmx\xf08\xdc@6\xdcF0" +
+	"\\\xce\x07a\xb8\xc7\x8d\xad\xdf<\x14h\x8f\x00m\xbb" +
+	"i\xeb\xb7\xe2\xfdx\x12\x88o\xa3\xab\xc3\xab\xae\xce\xfe" +
+	"\x09\x9aS\xf4\x91a\xad\xec\xc7\x97\x00\xf1\xf7@\xfc\xd4" +
+	"0U\xf6\x93H\xfc\x04\x88_&\xda\x11k\x08\x08\xba" +
+	"~\xf6\x07\x9b\xa6\xca\xd2Rp\xc6e\x93\x7fa\x84l" +
+	"\xec\xf2U\x87P\x9eL\x18\x96P6]P\x90\x08\xc5" +
+	"#+S8[0\x12P\x8cA<a\xa5v\x99\xa4" +
+	"p\xb6)A\x9f\x08\xb3\xe6\xe1IoC\xebQIY" +
+	"AK\xe1~\xe6\xb2\xfd\xf4\xe0\xf1\xdd\xaa*=\xa6\x1e" +
+	"%\xdc&\x1f\x10C&\xf5\xd8\x0a\xb6\xdb\x19\x02\xe2\xed" +
+	"&\xdf\xf1\x0e\xdc\xe5\xdb\x81\xf8K8}E\x06=\x11" +
+	"\xa7\x1c\xabAy\x04\xe2\x15\xa6&&\xd39\x12\xf7\x81" +
+	"\xd7\xe3\xf7\x8b\xf2M\x1e\xce\xd2r\x01\xc1OMy\xc4" +
+	"\x1a\x86\xb44\x17+Ed\x18'\xb8\x15\xd0\x9b-n" +
+	")\xd0\\\xda YB\xa2)\xa8\xabP\x83\xba\x09j" +
+	"P\x07?\x1ca\xc5'\x05\xaa\xe1\x7fp\x17\xf0\x87(" +
+	"\xcbi\xad\xd4C\x03\xca87\x93\xa4\xb6P\xa3\xfc\x95" +
+	"\xa9\x8a\x8e\xdb\xe5\xb4C2V!\xcap\xab]\xeae" +
+	"\xa1\xd1s;\x8c\x92E\xcc\x89\x1dRb\xab\x87\xcb\x02" +
+	"+\xd7\xa7R\x8cj{\x18L\xe5\x1a\x93\xac\x97\xa3\x04" +
+	"\x8f\x01\xe2DP\xd1-x\xbbl\x06\x13C\x1dG[" +
+	"<\xcb\\\xa2\"K\xd4\xc4\xea\xf72\x9d\xd9\xea\xc1?" +
+	"\xdd\x01\x12o\xcd]\xa6]\xa2R\x01\x12\xc4\x91fb" +
+	"7\x95Z\xd4\xc9\xd8\xd3<'#\xe8W\xd3\x0e\xb8)" +
+	"\x9cI*\xaf\x8b\x91J\x08\xfb:0t\x99\xec\xf7W" +
+	"\xfbD\x9cq\xb4\xcd#\xa1\x09\xa8\xe5\xac\xa0\x81\xd2\x8b" +
+	"\x99E\x05\xccO\x9d\xb8T\xf4\xa7e<X\xea>C" +
+	"\xd1\xbc\xd1\x1c{\xb9\x98\xcce\xe0c&8Ai\x0b" +
+	"9\xabVfh\x08\xeb\xd0\xbe\xd8\xc0\xc0\xc8T\xbe\x8d" +
+	"\xc4 \xc8\xf7\xcc\xf6\xd0\x85\xe4\x9b\x897\xd2F\x01\xad" +
+	"\x0a\xc4[\x01<\x887\xe3a\x88\xb7-\x04\xben\xe6" +
+	"\x1a\xd7-*\xb7H\x01\x902\xb7\xb4<&\xe44\x19" +
+	"\x9aB\xc3\xd00;Sa\xb63\x9a\xe1nA\xe3\xb3" +
+	"\x18\x88\x0a\xda\x19\xa2\xda\x99\xd6\x19\x9a\xf1Y\x91h\x8e" +
+	"\x1dm\x92\x0f&\xaf\xc5j\xd5\x8bE\xa9i\xb1\x11\xba" +
+	"\xa5\xee\x9cg\xfd\x7f\x0b\xb5\x04\x03\xce\x87\x89\xa9\xb6*" +
+	"\x14\x93\x19F\x02\x16\xdeV\x19\xf5/x\xdbgd\x96" +
+	"!T\x96\x8dJ\x04\xbc\xad2\\\x04xs\x19\xe9|" +
+	"x{\xd3H\x93A(}\xd8(5@\x00}\xcc\xd0" +
+	"\xdb\x106\xcbF\xd1\x13\xde\x96\x1b\xba\x02Cj\xa3\x00" +
+	"\x03ok\x0dwX\xa8%\x0f\x185C\x08\xb2w\x1a" +
+	"\xa9T\xa1\x9e\xec1\xb2V\x82\x13\xe6\xc9\x12g\xf0\xb6" +
+	"\xd6(\xd8A\x10\xbe\xc7\xc8\x94\x0as`\xb5\xacD-" +
+	"\xcc\x87\x151\xf7\x06\xde\x1a\x0d\x0f\x97\xbe\xb1\x14\x07\xbc" +
+	"\xed4\x8a\xa7\x10\xc0\xef1<GA$\x9b\x8c\xc4\xad" +
+	" \x91'\x8c\xb0Bh\x81]b9\"\xa1\x15\xc6c" +
+	")\x1dx\x9b`\\;@\x1e\xd6#!!\x02;\xc6" +
+	"\x0c<\xbc\xbdi\xd8 \xa1\x1dv\x9a\xf5A\x08+a" +
+	"^,\xd8\x15V\xc3\xbc\x98R\x12\xd6\xc0\xbcX\x95O" +
+	"X\x0f\xf3\xaa7\x84,\xfa3Q\x0eK\xc1\x80\xcb\xa2" +
+	"\xab\x015\xa03\xf4Q\xb5z?\xa2\xba\xf9\xe2\x1c\xd4" +
+	"\x80Eg\x8ar\x8b\x14\xf0\xf8\xf9\x9bC\x0a0\x08s" +
+	"u\x9ak\x09\x93\xd6yd\xc7+\xb5\xda\xf8t\xac~" +
+	"\xfb\xb8\xa8\xfe\x11\x1f\xaf\x0a\x89\x18\xd5\x8d\x11\xe7P\xe7" +
+	"\xc2\xde\xabU\xbeQ\xdd\x8d\xe1,\x81\xe6\xa8\x1eI\x90" +
+	"&\x83\xbb\x99\xa6s\xd5\xd5\x00\xd1\xf5\x80\x8d2\x8f'" +
+	"\x87\x1d\xda\x18\x9ae\xb2\xe8\\c\xf2\xd3T\x83F\xeb" +
+	"\xa4\xb0\x82\xf3't\x01\x10\x0ck\x13N\xa0\xeb\x13\xd7" +
+	"\x17\xcb\xc1\xae\xd1\x08\x0e\x028\xce\x0a!\\T\xcb\x87" +
+	"\x859+\x04]Q\xddF\x10}\xd7\xd4\xb9\xc6\x93\xf5" +
+	"\xb96hq\x10\x81@HO4\xc4\x11\xb50=\xaa" +
+	"\x07\xf0\x96\x98\xc4\x04,O7#\x04\xa2J\x9a\x90d" +
+	"P>&\xd8WW\xae\xa39\x878=\xb0(\x18\xd5" +
+	"\xd3\x1d\xd9\xf1l\x93\xe6TT\x1ez*\x90\xe8\xb9@" +
+	"\x9b&y\xb1dv \x9a\xbd&`\xb0\xd9\"c\x89" +
+	"\xda\"\x9dU\x16\x88\x92Xi\x9a\xe85<a7\xa6" +
+	"\x04\xa7<M\x08>\xc2.b%F\x11\x89\xe8ec" +
+	"a3&\x08\xa7<\x0e(x\xe0\xcdJx\xd6EE" +
+	"\xf4\xe2\x8f\xb0\x81<\x00(\xd0\xb8\xf8\x08\x1b\x01ea" +
+	"\xbd%D/\xd8\xc3\xfdC^\xff\x0e(x\xe0\xcdJ" +
+	"\xb2X\x0d\x90\xe8\x9d=p\x9f7\x01\xea\x17\x80\x82\x07" +
+	"\xee\xb3\x95d\xb3r>\xd1K\x99\xa0\x03\xf6\x01\xeav" +
+	"@\xc1#\xdc\x01\xa8\x1e\xac'\x8b\xe8\x1d\\\xa0_\xc0" +
+	"\x1aM\x09\x01\x0a\x1ex\xb3\x12++\x9b\x13\xbd\x18\x06" +
+	"\xdak9\xa0|\x80\x82\x07\xde\xac\xa4'k\xc6\"z" +
+	"A\x16\xf4\xa6\x0c\xa8\xd9\x80\x82\x07\xde\xac\xa4\x17\xeb\x07" +
+	"\"z\xa9\x10\xb42\xf8eS\xea\x00\x05\x0f\xbcYI" +
+	"o\xd6\xd9D~x\xed\x0a\x0e\x1bJP\xcb\x03j*" +
+	"\xa0\xe0\x01-o%}Xk\x0f\xd1\xfbm\xc0\x86 " +
+	"j\"\xa0\xe0\x11&\x01\xaa/+r\x12\xbd\xb3\x0b\xec" +
+	"\x0e\xcek\x0c\xa0\xe0\x817+\xc9a\x9d0Do[" +
+	"\x10\xae\xa6\xf3\x1a\x06(x\xe0\xcd\xda\xb1T\xd5~\x1c" +
+	"\xa9\x01\xc3\xaa)-\xa2\xa9\x1f\x8eR\xf5\x02\x12\xd1o" +
+	"*\x8c\x84t=\x96\x89E\xcbL\xcdhp\x8b\xa8\xc2" +
+	"\xc31J\x05>\xac\xd6\xfe\x8c~\xa8)\x12\x8e\xe3\xe1" +
+	"\xcd\xaf\xe9\x0a\xaeZU\x16\x14\xa2\xe7\xe79\x87\xf1g" +
+	"z\xb2\x83\xb3\xc2\x856-\xe1&\x0f\xd1/\x17e\xa8" +
+	"\x87>\xf1T\xf5Vq\x0ez\xad\xf4Y\xd2\xcb\xc3Y" +
+	"\xe1\xf6 \x05\x9c\xa2\xb4\\\xe8\x06#\xff\xa2\xeb\xbe\x14" +
+	"\xfc\xaa\x92d~\x15\x06:~=~\xd7\x12\"\xed\xe8" +
+	"W-S\xe3\xf7\x84ty\xc8\x9c\x02\x87\x085\x14Q" +
+	"T\x83p\x81\xa8\xe4_\x91\x07\x8d\xb3k\x84\xae~\"" +
+	"+dl\xa0\x85\x8c\xfb0\xe3\xff\x88\xb9\x90\xf1(\xad" +
+	"(<\x82\xf4\xedX\xb0\xd0\xea\x18;h\x81`;\x92" +
+	"_ \xc66\x80\xd6\xc2\xc2\xc4sH\x7f\x8f\x162," +
+	"j!\xe3\x10Y\x02\xf4\x83H\xff\x94\x162\xb2\xd4B" +
+	"\xc6I\xca\xfe\x13\xa0\xbbx\xacc\x10\xb5\x8e\xd1M\xc0" +
+	"\xa1u\x9fEx\x16\xd2\xad\xbcZ\xc7 <\xb0w\xf1" +
+	"@\xee\xcb\x9b\xca\x18\xbdx\x10rwO\xa4\xe7\xf1\xa6" +
+	"2F>\xdf\x88\xd5\x16\xa4_\x89\xf4\xde=\xf2\xf0<" +
+	"\x84\"\x1e\xcb*\x83\x91>\x0a\xe9}\xacy\xa4\x0f\xd0" +
+	"\x8b)\x9f\x11H\xafBz_\x98N_\xec\xc6\xe3q" +
+	"\x96\xd7 \xbd\x06\xe99=\xf3H\x0e6\xb0\xf0\xb8\x0b" +
+	"\x13\x91^\x87\xf4~0\xcd~Xn\xe1Ac\xb9o" +
+	"@\xfaL>Q\x1e\xa2\x8d\x91\x80\xcf/6x8K" +
+	"|\xac\xa0h\xae\x0b\x9e<\x81\x0f\xb0u@\x84h\x91" +
+	"f\xd0IB\xad.\x18l\xc1lV\x03g\x83\xcf\x13" +
+	">M9\xa3\xa6\xa6O\xd8\x80\xf4\xed\xe6\x80\x97#\"" +
+	"\xa3\xc9\xa27(\x03\x99s45$D8\xea\x87n" +
+	"\x85\xb3\x9a\xd9\xe8k!\xba\x1b\x06\x833\xcf\xdd$\xcb" +
+	"r\xac\xfb\x06(\xa3\x8f\xc4@\xc5\xd4\x82z\xc1\x10\xbd" +
+	"R\xba(a\xaf\x1c\xf4\xfb\x1b=\\\xb5\xb7\x19\xf5\x1b" +
+	"\xfb\xd3tn\x90\xee#\xe9.\x92\xe6\xba]$tM" +
+	"\xfd\x06\xf7H7\xf3\x00\xae\x04\x0d`\x8d\xc6K=A" +
+	"\x93$\xafn\xae\xd5\xd4/7j5\xda=\xb6\xcf\xc2" +
+	"I\xcf\x04\x9a\xcfH\xda0\xc6\xa6\xa4M\x13f$\x1b" +
+	"@%W\xd3\"]:'\x90\xd6B\x8d<\x87YI" +
+	"\x9b\x166!I\xc1`\xae\xb9\x06E\x12\x17V\xbdH" +
+	"\xf2\xa3\x93\x16{\xd32\x93\xa7KI*\xd2*\xa1%" +
+	"\x8d\x94\x07\x8bI\x93L\xa0gji##\x9b\x90P" +
+	"CO\xad\x88\xce\xe2\xdb\xcbq\xa4Zl\x91A\xf9\xc4" +
+	"\x9c\x01\x83\xc0/\xbd\x02\x16\x8b13\\\x83\xe6\x8b\x99" +
+	"\xb2\xc2\xe9\xa6\xbfL\xc9l\xaf?\x18\x16M\x99\xf4K" +
+	"`\xd5&K\x8a\x99U:)\xac\x90DSX\xac\x19" +
+	"\xdd\x94\xc2\xc2\x1b\x00\xf6\x8dG\x1bg\x87\x80\"\x9d\xd9" +
+	"\xf9cTez\xa2\xc6R\"\x19\xde7o\xac\xa3\x93" +
+	"\xfe}c\xb9\xa3\x8c\xf3\xe8\xacP\xd8\x1a\xb1j\xca\xcb" +
+	"t&\x85\xda\x99\x8c1\xa7\xcdghi\xf3\x9a$\x8e" +
+	"\xc2\xe5\xd5Gq\xd9\\#\xb9h\xd2\xaf\x85\x17)\xc8" +
+	"\xce4\xe9W\xe7\x0c\xad \xbb0q\xda\xd5a\xa9\x09" +
+	"\xed\xfe\xbf\xa6\xf3\"\xae+\xe7\x029\xe9T\x8eK\xcf" +
+	"\x13Q\xff\xc4\x12\x08\xc7\xc5\x03\x15I\x0az\"\xee\xc5" +
+	"B \xfaq/\xaeT\xf7B\x1aj\x8a\x12,C\xd4" +
+	"\x80\xa0\xa5Q\x8b\x12\x96\xf1\xe4\xa2\x89U\xab\xecic" +
+	">\x93O\x0a{\x1a\xfdb-g\xf5.\x0e2j:" +
+	"in\x9a\xbe\xb0a0\x85\xcbQ'8v(-M" +
+	"\x8c\x1eJK\x13W\xe3\x0f\x8b\xda\x1a\x99E\xeb\x15V" +
+	")\xe4\xb5\x06D\xc5\x1aQ\xc2\xb6HX\x94\xab\xbdM" +
+	"r0\x12J\xeb\\\x92$\xd0\x8cj\xb9y_g\x18" +
+	"[\x98\xbcN\x9a4\x7f}e\\\xfe\x1a\x82/\xea\x19" +
+	"c\x1d\x17\x05K+\xe5j5@\x93\xfa\xd2\xca\x81f" +
+	"\x85\x86\xb6\xdfwsD1{\xd9\x97\xa4\x82M\xc5\x0c" +
+	"\xb3\x10f\xa7\xca\xcbA\x99Q\xb7\xce\xf8v\x10\xb8u" +
+	"\x0d\x12\xadK`\x86H\xff\xd2\x01\xd1;\xe0\xed\xe5 " +
+	"\xa0\x93G\x11\xf8g\x1f\x8d\xd9!\xfd\xab\x0bD\xef\x15" +
+	"\xb7\x0fA\xc4`\x02\xff\xecEV\x07\x9d*\x86\xce\x0e" +
+	"j\x82\xe2\x83\xe8\x8cBI\xbc}\x96\x84\x8e\x9a%\x9a" +
+	"\x86\x05\xf5\x8e\xec\xd54\xa2Mn0U\xfb3\xef\x00" +
+	"L\xbf\xd7\x8a\xa5\xc53T\xeb1\xdd\xc6Zy\xd4\xf8" +
+	"\x02$Y\x1b\xd5\x1b\x90I\xc0\xa8\xa1^\xc9f\xd6\x89" +
+	":\xf5=\x98\xd9\xefM\x8a\xe4\xf8\\S\xaf\x84\xde\x19" +
+	"\xd0%\xab\x0d\xa4\xce\xafYk\xa0\xfd\x1b\xf4\xda\xbf\x06" +
+	"\xday#\x9c\xb6\x9f\xc3\xbf>\x8b\xd1\xb19\x98\xce\xa6" +
+	"\xdd|=Y\xd3a\x8f,5\x9a\xce\xa7A6m:" +
+	"\x1cF\xbb\x02\xb3\xd5hz\x08\x0d\xe2\xaf\xd4\x9b\x11\x13" +
+	"\xacO8\xe8m\x16\x95da*M=\x89\xe10\xe7" +
+	"\x00\xb7(\xbe)\xe1\x82!\x97Z\x06\xbd\x11\xe2\xd1\xf6" +
+	"p\xdc\x9f$n \xf8&l\x8fM\x81\xc8e\xcd\x9e" +
+	"\\\x82\x0f\xadJ!I\xc3\x07\xd5+C\x19\x9a\xbd\x04" +
+	"\x0f\xf2\x02:'\x1d\x7f6m\x07\x89\x95\xe8.\xa9\x13" +
+	"L\xdd\xbb\xc4\xca\xf9uj\xe5\x1c~tD\x02\xcd\x01" +
+	"\x08x;\xb4f\xcf\x0e\xad\xb1\xf3R[\xdf\x1a<\xb6" +
+	"\x94k\xe7\xac\xe8w9\x9a*\x1c\xb4[\xc0\xb4\xe4B" +
+	"u\xc9.\xbad;X:K \x18\x0d\x06\xae\xa7\xeb" +
+	"\xe4\x88X\xed\xf1\xb7y\xda\xc3\x97\xd0\x93\x9aN\xa74" +
+	"+\xd7e\xb8VV3\xc1\x02\x8b\xaa\x99\xd3\xaf\xa6\xc7" +
+	"w\xaa_\xbc\x9a\x9en\xcf]\xdaA#+\x98f\xb8" +
+	"'z\xd1Q.\x9d\xd9\x1e\xd2\xdaT\xe8\xd9g\x1fS" +
+	"\x97Ae\x92\x97]\x91\x00\xfa#\xd3\x03pr\x8b\xb0" +
+	"\xa6\x95\xd6(z\x91\xd4l\x89M_h\xc0\xe3~\x0e" +
+	"\x96\xf7\xaa\xc9\xf4\xbc\x86\xee\xeao\xb4\x86>\xdd\xf4\x1c" +
+	"@g\xeb\x0d \xbegjJ;\xe22Y.\xfd\x0b" +
+	"\x0d\xc7\x1b\x0d\xcbE\xd4\xaf3\xd8\xbb\\\x86\xe1\xd2\x93" +
+	"\xb8\xf63h\xcd\xbe\x04\xe2Y\x905M\xe5\xc4\xb6\xff" +
+	")\x9e\xa6\xd8\xc0\x01\x97+)\xf1\xc9R\xc9\xef\x9b\xea" +
+	"Q0\x1b\x19\x9bu\x8c\x84\x15\\>\xb8\xafq\x8c\xa3" +
+	"\xb0_\xa86h_\x9c\xeeq\x80\xfa\x0c\x81\xd7's" +
+	"\x8e\xd0bO \x9cQ\"0i\x91X\x0f\xf4L\xdb" +
+	"^hl;\xdb\xf5\xb9\xe6\xaf\x91h.\xee\x81\xeb\xb4" +
+	"]\xc76JK\x8d\xba\xeb\xc7g\x98\\\x83,^\xdd" +
+	"\xf5\x93\xb2\xd1Fi\xcf\xb6\xa8\xdb~f\x95\xb6\xc3\xe7" +
+	"\x0dko?7C\xf3\x0dz&\xb3\xe9\xc9\xd2^\x17" +
+	"h\xdeO\x9a\x8c\xbeh\x1e8\xd5\x8c\xef\xe5\x0d\x08\xe3" +
+	"\xc3\xfetz\xf7X\x97I\x86c\xc7\xa5;\xd2\xf6\x0a" +
+	"X\xdfG\x86\x06\x95\x96\xbf\x89?N\xe1\x0eM\x96g" +
+	"\xa804\xae\xb5Yl\x8f\xed\xfcY\xea\xf1G.\xa1" +
+	"Q4\xf1\xbbl\xb0\x13\xb6\xb4\xd4-\xeb\xcd\xc9\xd4?" +
+	"\x8a\xed\x9c\xba\x84\xb4@L\xa5\xd0\xc8X^\xa8\xc9P" +
+	"\x0aP\x87\xf425\x19\xc6\x97\x1a\x9279\x9a\xbf\xb2" +
+	"\xc0\x02\xac\x98*\x0fk!2\xaa<\xff\x07\x15e\xef" +
+	"%"
 
 func init() {
 	schemas.Register(schema_ffaaf7385bc4adad,
+		0x8114bbd158364c30,
+		0x826958b3c0d0f6e3,
 		0x83479da67279e173,
+		0x8aef91973dc8a4f5,
+		0x8b4c03a0662a38dc,
+		0x8b5b1693940f607e,
+		0x90a3950a51412b8b,
+		0x90f68440d914a14c,
+		0x9488d71c49c86c29,
+		0x94fba2078b461596,
+		0x976bce7564c27fce,
+		0x9887a60f577a1ecb,
+		0x9d82529754851252,
 		0xa0ef8355b64ee985,
+		0xa16b58808ac7e538,
 		0xa20f49456be85b99,
+		0xa3cb406c522dcab1,
+		0xa3e6c413c9e0821d,
+		0xa3f97cb4a8fd3ec3,
+		0xa6d76ce69f13a816,
+		0xa8e923c2fa0576b2,
 		0xaa2f3c8ad1c3af24,
+		0xaaa69aebe451afba,
+		0xab8d13e6952bdbff,
 		0xace5517aafc86077,
+		0xad51c7e1283a3d0d,
+		0xad5e6e3b177fdffd,
+		0xadb66abea677f8fc,
+		0xae5e0ae5001ebdfe,
 		0xae78ee8eb6b3a134,
 		0xb5418b8ea8ead17b,
 		0xb737e899dd6633f1,
+		0xb78b75a9a91a9748,
 		0xba77e3fa3aa9b6ca,
+		0xbc1bca51fe8ba645,
+		0xbcc8964d9d37437b,
+		0xc437ab0d294724d9,
 		0xc5e65eec3dcf5b10,
 		0xc76ccd4502bb61e7,
+		0xc8865f0ec25b4ef8,
+		0xc9701dd28ecc4dec,
 		0xcc2f70676afee4e7,
+		0xce1d80a5a8c514d6,
+		0xce5d492153adfc07,
+		0xce733f0914c80b6b,
 		0xceba3c1a97be15f8,
 		0xd0476e0f34d1411a,
+		0xd285ab9e532f8e8f,
+		0xd345304c72ce2f15,
+		0xd3fab29e13b163b2,
+		0xd61491b560a8f3a3,
 		0xd9d61d1d803c85fc,
+		0xda7ceb31aea59293,
+		0xdd3efd1b219eb35a,
 		0xde3a625e70772b9a,
+		0xdebaeed2a782ac80,
 		0xdf703ca0befc3afc,
 		0xe00e522611477055,
+		0xe1f2dc9c7f38d49a,
 		0xe313695ea9477b30,
+		0xe3fb0b5eb795495d,
 		0xe5ea916eb0c31336,
+		0xe902d14c78cd31a2,
+		0xe989fde14d6e82dd,
+		0xeaea0070cb69c1e8,
+		0xedd2e5b018f17bbb,
 		0xf026e3d750335bc1,
 		0xf34be5cbac1feed1,
 		0xf41122f890a371a6,
 		0xf44732c48f949ab8,
+		0xf4e3e92ae0815f15,
+		0xf798b7a4fe56d11d,
 		0xf8e86a5c0baa01bc,
-		0xf9b3cd8033aba1f8)
+		0xf9b3cd8033aba1f8,
+		0xfa066186bb70bb83,
+		0xfdae861fa8890aa3)
 }
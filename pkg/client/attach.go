@@ -6,6 +6,7 @@ import (
 	"fmt"
 	"io"
 	"net"
+	"sync"
 
 	"github.com/containers/conmon-rs/internal/proto"
 	"github.com/containers/podman/v4/libpod/define"
@@ -86,8 +87,37 @@ type AttachConfig struct {
 
 	// The keys that indicate the attach session should be detached.
 	DetachKeys []byte
+
+	// Number of recent output bytes replayed when attaching, at most 65536.
+	// Only the output kept according to the ScrollbackSize of the container
+	// is replayed.
+	ScrollbackSize uint64
+
+	// Comma separated key sequence like "ctrl-p,ctrl-q" which makes the
+	// server detach the session. In contrast to DetachKeys, the sequence is
+	// detected by the server and not passed to the container.
+	ServerDetachKeys string
+
+	// The behavior if the client does not keep up with the container output.
+	SlowClientPolicy SlowClientPolicy
 }
 
+// SlowClientPolicy specifies the behavior if an attach client does not keep
+// up with the container output.
+type SlowClientPolicy int
+
+const (
+	// SlowClientPolicyDrop drops the output for the client.
+	SlowClientPolicyDrop SlowClientPolicy = iota
+
+	// SlowClientPolicyBlock blocks the container output until the client
+	// caught up.
+	SlowClientPolicyBlock
+
+	// SlowClientPolicyDisconnect disconnects the client.
+	SlowClientPolicyDisconnect
+)
+
 // AttachContainer can be used to attach to a running container.
 func (c *ConmonClient) AttachContainer(ctx context.Context, cfg *AttachConfig) error {
 	conn, err := c.newRPCConn()
@@ -102,21 +132,9 @@ func (c *ConmonClient) AttachContainer(ctx context.Context, cfg *AttachConfig) e
 
 	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
 	future, free := client.AttachContainer(ctx, func(p proto.Conmon_attachContainer_Params) error {
-		req, err := p.NewRequest()
-		if err != nil {
-			return fmt.Errorf("create request: %w", err)
-		}
-
-		if err := req.SetId(cfg.ID); err != nil {
-			return fmt.Errorf("set ID: %w", err)
-		}
+		_, err := c.initAttachRequest(p, cfg)
 
-		if err := req.SetSocketPath(cfg.SocketPath); err != nil {
-			return fmt.Errorf("set socket path: %w", err)
-		}
-
-		// TODO: add exec session
-		return nil
+		return err
 	})
 	defer free()
 
@@ -136,6 +154,47 @@ func (c *ConmonClient) AttachContainer(ctx context.Context, cfg *AttachConfig) e
 	return nil
 }
 
+func (c *ConmonClient) initAttachRequest(
+	p proto.Conmon_attachContainer_Params, cfg *AttachConfig,
+) (proto.Conmon_AttachRequest, error) {
+	req, err := p.NewRequest()
+	if err != nil {
+		return req, fmt.Errorf("create request: %w", err)
+	}
+
+	if err := c.initMetadata(req.NewMetadata); err != nil {
+		return req, fmt.Errorf("init metadata: %w", err)
+	}
+
+	if err := req.SetId(cfg.ID); err != nil {
+		return req, fmt.Errorf("set ID: %w", err)
+	}
+
+	if err := req.SetSocketPath(cfg.SocketPath); err != nil {
+		return req, fmt.Errorf("set socket path: %w", err)
+	}
+
+	req.SetScrollbackSize(cfg.ScrollbackSize)
+
+	if err := req.SetDetachKeys(cfg.ServerDetachKeys); err != nil {
+		return req, fmt.Errorf("set detach keys: %w", err)
+	}
+
+	switch cfg.SlowClientPolicy {
+	case SlowClientPolicyDrop:
+		req.SetSlowClientPolicy(proto.Conmon_AttachRequest_SlowClientPolicy_drop)
+	case SlowClientPolicyBlock:
+		req.SetSlowClientPolicy(proto.Conmon_AttachRequest_SlowClientPolicy_block)
+	case SlowClientPolicyDisconnect:
+		req.SetSlowClientPolicy(proto.Conmon_AttachRequest_SlowClientPolicy_disconnect)
+	default:
+		return req, fmt.Errorf("%w: slow client policy %d", errInvalidValue, cfg.SlowClientPolicy)
+	}
+
+	// TODO: add exec session
+	return req, nil
+}
+
 func (c *ConmonClient) attach(ctx context.Context, cfg *AttachConfig) (err error) {
 	var conn *net.UnixConn
 	if !cfg.Passthrough {
@@ -304,6 +363,255 @@ func (c *ConmonClient) readStdio(
 	return nil
 }
 
+// AttachContainerStream can be used to attach to a running container by
+// streaming the standard input and output via the RPC connection instead of
+// the attach socket. The SocketPath and Passthrough fields of the
+// configuration are not used. The method blocks until the container output
+// ends, the session got detached or the context is done.
+func (c *ConmonClient) AttachContainerStream(ctx context.Context, cfg *AttachConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+
+	sink := newOutputSink(cfg.Streams)
+	sinkClient := proto.Conmon_StreamSink_ServerToClient(sink, nil)
+	defer sinkClient.Release()
+
+	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
+	future, free := client.AttachContainer(ctx, func(p proto.Conmon_attachContainer_Params) error {
+		req, err := c.initAttachRequest(p, cfg)
+		if err != nil {
+			return err
+		}
+
+		if err := req.SetOutputSink(sinkClient.AddRef()); err != nil {
+			return fmt.Errorf("set output sink: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	response, err := result.Response()
+	if err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	if cfg.PreAttachFunc != nil {
+		if err := cfg.PreAttachFunc(); err != nil {
+			return fmt.Errorf("run pre attach func: %w", err)
+		}
+	}
+
+	var inputDone <-chan error
+	if cfg.Streams.Stdin != nil {
+		inputDone = forwardInput(ctx, response.StdinSink(), cfg.Streams.Stdin, cfg.DetachKeys)
+	}
+
+	if cfg.PostAttachFunc != nil {
+		if err := cfg.PostAttachFunc(); err != nil {
+			return fmt.Errorf("run post attach func: %w", err)
+		}
+	}
+
+	return sink.wait(ctx, inputDone, cfg.StopAfterStdinEOF)
+}
+
+// outputSink receives the container output of an AttachContainerStream
+// session or the port output of a PortForward session.
+type outputSink struct {
+	streams   AttachStreams
+	done      chan struct{}
+	closeOnce sync.Once
+}
+
+func newOutputSink(streams AttachStreams) *outputSink {
+	return &outputSink{streams: streams, done: make(chan struct{})}
+}
+
+func (s *outputSink) Write(_ context.Context, call proto.Conmon_StreamSink_write) error {
+	args := call.Args()
+
+	var dst *Out
+	switch args.Pipe() {
+	case proto.Conmon_StreamSink_Pipe_stdout:
+		dst = s.streams.Stdout
+	case proto.Conmon_StreamSink_Pipe_stderr:
+		dst = s.streams.Stderr
+	case proto.Conmon_StreamSink_Pipe_stdin:
+		return fmt.Errorf("%w: output pipe %s", errInvalidValue, args.Pipe())
+	}
+	if dst == nil {
+		return nil
+	}
+
+	data, err := args.Data()
+	if err != nil {
+		return fmt.Errorf("get data: %w", err)
+	}
+
+	if _, err := dst.Write(data); err != nil {
+		return fmt.Errorf("write %s: %w", args.Pipe(), err)
+	}
+
+	return nil
+}
+
+func (s *outputSink) Close(context.Context, proto.Conmon_StreamSink_close) error {
+	s.closeOnce.Do(func() { close(s.done) })
+
+	return nil
+}
+
+// wait blocks until the output ended, the input failed or the context is
+// done. It also returns as soon as the input ended if stopAfterInputEOF is
+// set.
+func (s *outputSink) wait(ctx context.Context, inputDone <-chan error, stopAfterInputEOF bool) error {
+	for {
+		select {
+		case <-s.done:
+			return nil
+
+		case err := <-inputDone:
+			if err != nil {
+				return err
+			}
+			if stopAfterInputEOF {
+				return nil
+			}
+			// Wait for the output to end.
+			inputDone = nil
+
+		case <-ctx.Done():
+			return fmt.Errorf("wait for output: %w", ctx.Err())
+		}
+	}
+}
+
+// forwardInput copies src to the sink in the background until EOF and closes
+// the sink afterwards. The result is sent to the returned channel.
+func forwardInput(ctx context.Context, sink proto.Conmon_StreamSink, src io.Reader, detachKeys []byte) <-chan error {
+	done := make(chan error, 1)
+	go func() {
+		done <- copyToStreamSink(ctx, sink, src, detachKeys)
+	}()
+
+	return done
+}
+
+func copyToStreamSink(ctx context.Context, sink proto.Conmon_StreamSink, src io.Reader, detachKeys []byte) error {
+	dst := streamSinkWriter(func(data []byte) (int, error) {
+		future, free := sink.Write(ctx, func(p proto.Conmon_StreamSink_write_Params) error {
+			p.SetPipe(proto.Conmon_StreamSink_Pipe_stdin)
+
+			return p.SetData(data)
+		})
+		defer free()
+
+		if _, err := future.Struct(); err != nil {
+			return 0, fmt.Errorf("write input: %w", err)
+		}
+
+		return len(data), nil
+	})
+	if _, err := utils.CopyDetachable(dst, src, detachKeys); err != nil {
+		return fmt.Errorf("copy input: %w", err)
+	}
+
+	future, free := sink.Close(ctx, nil)
+	defer free()
+
+	if _, err := future.Struct(); err != nil {
+		return fmt.Errorf("close input: %w", err)
+	}
+
+	return nil
+}
+
+// streamSinkWriter is an io.Writer which writes to a stream sink.
+type streamSinkWriter func(data []byte) (int, error)
+
+func (w streamSinkWriter) Write(data []byte) (int, error) {
+	return w(data)
+}
+
+// PortForwardConfig is the configuration for calling the PortForward method.
+type PortForwardConfig struct {
+	// ID is the container identifier.
+	ID string
+
+	// Port is the TCP port within the network namespace of the container.
+	Port uint16
+
+	// Stream is forwarded to the port. It does not get closed by the
+	// PortForward method.
+	Stream io.ReadWriteCloser
+}
+
+// PortForward can be used to forward a stream to a port in the network
+// namespace of a running container. The method blocks until the connection
+// got closed by the container side, the stream returned an error or the
+// context is done.
+func (c *ConmonClient) PortForward(ctx context.Context, cfg *PortForwardConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer func() {
+		if err := conn.Close(); err != nil {
+			c.logger.Errorf("Unable to close connection: %v", err)
+		}
+	}()
+
+	sink := newOutputSink(AttachStreams{Stdout: &Out{cfg.Stream}})
+	sinkClient := proto.Conmon_StreamSink_ServerToClient(sink, nil)
+	defer sinkClient.Release()
+
+	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
+	future, free := client.PortForward(ctx, func(p proto.Conmon_portForward_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+		req.SetPort(cfg.Port)
+
+		if err := req.SetOutputSink(sinkClient.AddRef()); err != nil {
+			return fmt.Errorf("set output sink: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	response, err := result.Response()
+	if err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	inputDone := forwardInput(ctx, response.InputSink(), cfg.Stream, nil)
+
+	return sink.wait(ctx, inputDone, false)
+}
+
 // SetWindowSizeContainerConfig is the configuration for calling the SetWindowSizeContainer method.
 type SetWindowSizeContainerConfig struct {
 	// ID specifies the container ID.
@@ -331,6 +639,9 @@ func (c *ConmonClient) SetWindowSizeContainer(ctx context.Context, cfg *SetWindo
 		if err != nil {
 			return fmt.Errorf("create request: %w", err)
 		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
 
 		if err := req.SetId(cfg.ID); err != nil {
 			return fmt.Errorf("set ID: %w", err)
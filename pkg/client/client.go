@@ -31,6 +31,7 @@ var (
 	errRunDirUnspecified  = errors.New("RunDir must be specified")
 	errInvalidValue       = errors.New("invalid value")
 	errRunDirNotCreated   = errors.New("could not create RunDir")
+	errLogFileUnspecified = errors.New("LogFile must be specified for the file log driver")
 )
 
 // ConmonClient is the main client structure of this package.
@@ -38,6 +39,25 @@ type ConmonClient struct {
 	serverPID uint32
 	runDir    string
 	logger    *logrus.Logger
+	metadata  *RequestMetadata
+}
+
+// RequestMetadata is the optional metadata of the caller, which gets attached
+// to the tracing span of a request.
+type RequestMetadata struct {
+	// TraceID is the W3C trace ID of the caller as 32 hex characters.
+	TraceID string
+
+	// SpanID is the W3C span ID of the caller as 16 hex characters, which
+	// becomes the parent span.
+	SpanID string
+
+	// RequestID is the identifier of the request, which replaces the random
+	// one of the span if set.
+	RequestID string
+
+	// CallerName is the name of the calling application.
+	CallerName string
 }
 
 // ConmonServerConfig is the configuration for the conmon server instance.
@@ -54,9 +74,21 @@ type ConmonServerConfig struct {
 	LogLevel string
 
 	// LogDriver is the possible server logging driver.
-	// Can be "stdout" or "systemd".
+	// Can be "stdout", "systemd", "json" or "file".
 	LogDriver string
 
+	// LogFile is the path of the log file used by the "file" log driver.
+	LogFile string
+
+	// LogFileMaxSize is the size in bytes after which the log file gets
+	// rotated, where zero disables the rotation. The server default is used
+	// if nil.
+	LogFileMaxSize *uint64
+
+	// LogFileMaxBackups is the number of rotated log files to keep. The
+	// server default is used if nil.
+	LogFileMaxBackups *uint32
+
 	// Runtime is the binary path of the OCI runtime to use to operate on the
 	// containers.
 	Runtime string
@@ -70,12 +102,51 @@ type ConmonServerConfig struct {
 	ServerRunDir string
 
 	// Stdout is the standard output stream of the server when the log driver
-	// "stdout" is being used (can be nil).
+	// "stdout" or "json" is being used (can be nil).
 	Stdout io.WriteCloser
 
 	// Stderr is the standard error stream of the server when the log driver
-	// "stdout" is being used (can be nil).
+	// "stdout" or "json" is being used (can be nil).
 	Stderr io.WriteCloser
+
+	// AllowedUIDs are the user IDs of clients which are allowed to connect.
+	// All users are allowed if neither UIDs nor GIDs are specified.
+	AllowedUIDs []uint32
+
+	// AllowedGIDs are the group IDs of clients which are allowed to connect.
+	// All groups are allowed if neither UIDs nor GIDs are specified.
+	AllowedGIDs []uint32
+
+	// AllowedExecutables are the executable paths of clients which are
+	// allowed to connect. All executables are allowed if not specified.
+	AllowedExecutables []string
+
+	// TracingEndpoint is the OTLP/HTTP collector endpoint to export the
+	// tracing spans to, like "http://localhost:4318". Spans are not exported
+	// if empty.
+	TracingEndpoint string
+
+	// OOMScoreAdj is the OOM score adjustment of the server, between -1000
+	// and 1000. The server default of -1000 is used if nil.
+	OOMScoreAdj *int32
+
+	// RlimitNofile is the soft and hard limit of open file descriptors of the
+	// server. Inherited if nil.
+	RlimitNofile *uint64
+
+	// RlimitCore is the soft and hard limit of the core dump size of the
+	// server. Inherited if nil.
+	RlimitCore *uint64
+
+	// Cgroup is the cgroup v2 path relative to the cgroup mount to move the
+	// server into, or the name of the systemd scope like "conmonrs.scope"
+	// when using the systemd cgroup manager. The server stays in the cgroup
+	// of its parent if empty.
+	Cgroup string
+
+	// CgroupManager is the cgroup manager used to move the server into its
+	// cgroup. Can be "cgroupfs" or "systemd".
+	CgroupManager string
 }
 
 // NewConmonServerConfig creates a new ConmonServerConfig instance for the
@@ -166,7 +237,7 @@ func (c *ConmonClient) startServer(config *ConmonServerConfig) error {
 		Setpgid: true,
 	}
 
-	if config.LogDriver == LogDriverStdout {
+	if config.LogDriver == LogDriverStdout || config.LogDriver == LogDriverJSON {
 		cmd.Stdout = os.Stdout
 		cmd.Stderr = os.Stderr
 		if config.Stdout != nil {
@@ -217,16 +288,98 @@ func (c *ConmonClient) toArgs(config *ConmonServerConfig) (entrypoint string, ar
 		args = append(args, "--log-level", config.LogLevel)
 	}
 
-	if config.LogDriver != "" {
-		if err := validateLogDriver(config.LogDriver); err != nil {
-			return "", args, fmt.Errorf("validate log driver: %w", err)
-		}
-		args = append(args, "--log-driver", config.LogDriver)
+	logArgs, err := config.logArgs()
+	if err != nil {
+		return "", args, err
+	}
+	args = append(args, logArgs...)
+	args = append(args, config.accessArgs()...)
+
+	if config.TracingEndpoint != "" {
+		args = append(args, "--tracing-endpoint", config.TracingEndpoint)
 	}
 
+	resourceArgs, err := config.resourceArgs()
+	if err != nil {
+		return "", args, err
+	}
+	args = append(args, resourceArgs...)
+
 	return entrypoint, args, nil
 }
 
+const formatBase = 10
+
+func (c *ConmonServerConfig) logArgs() (args []string, err error) {
+	if c.LogDriver != "" {
+		if err := validateLogDriver(c.LogDriver); err != nil {
+			return nil, fmt.Errorf("validate log driver: %w", err)
+		}
+		args = append(args, "--log-driver", c.LogDriver)
+	}
+
+	if c.LogDriver == LogDriverFile && c.LogFile == "" {
+		return nil, errLogFileUnspecified
+	}
+
+	if c.LogFile != "" {
+		args = append(args, "--log-file", c.LogFile)
+	}
+
+	if c.LogFileMaxSize != nil {
+		args = append(args, "--log-file-max-size", strconv.FormatUint(*c.LogFileMaxSize, formatBase))
+	}
+
+	if c.LogFileMaxBackups != nil {
+		args = append(args, "--log-file-max-backups", strconv.FormatUint(uint64(*c.LogFileMaxBackups), formatBase))
+	}
+
+	return args, nil
+}
+
+func (c *ConmonServerConfig) accessArgs() (args []string) {
+	for _, uid := range c.AllowedUIDs {
+		args = append(args, "--allowed-uid", strconv.FormatUint(uint64(uid), formatBase))
+	}
+
+	for _, gid := range c.AllowedGIDs {
+		args = append(args, "--allowed-gid", strconv.FormatUint(uint64(gid), formatBase))
+	}
+
+	for _, executable := range c.AllowedExecutables {
+		args = append(args, "--allowed-executable", executable)
+	}
+
+	return args
+}
+
+func (c *ConmonServerConfig) resourceArgs() (args []string, err error) {
+	if c.OOMScoreAdj != nil {
+		args = append(args, "--oom-score-adj", strconv.FormatInt(int64(*c.OOMScoreAdj), formatBase))
+	}
+
+	if c.RlimitNofile != nil {
+		args = append(args, "--rlimit-nofile", strconv.FormatUint(*c.RlimitNofile, formatBase))
+	}
+
+	if c.RlimitCore != nil {
+		args = append(args, "--rlimit-core", strconv.FormatUint(*c.RlimitCore, formatBase))
+	}
+
+	if c.Cgroup != "" {
+		args = append(args, "--cgroup", c.Cgroup)
+	}
+
+	if c.CgroupManager != "" {
+		if err := validateCgroupManager(c.CgroupManager); err != nil {
+			return nil, fmt.Errorf("validate cgroup manager: %w", err)
+		}
+		args = append(args, "--cgroup-manager", c.CgroupManager)
+	}
+
+	return args, nil
+}
+
 func validateLogLevel(level string) error {
 	return validateStringSlice(
 		"log level",
@@ -239,7 +392,15 @@ func validateLogDriver(driver string) error {
 	return validateStringSlice(
 		"log driver",
 		driver,
-		LogDriverStdout, LogDriverSystemd,
+		LogDriverStdout, LogDriverSystemd, LogDriverJSON, LogDriverFile,
+	)
+}
+
+func validateCgroupManager(manager string) error {
+	return validateStringSlice(
+		"cgroup manager",
+		manager,
+		CgroupManagerCgroupfs, CgroupManagerSystemd,
 	)
 }
 
@@ -349,6 +510,10 @@ type VersionResponse struct {
 
 	// ProcessID is the PID of the server.
 	ProcessID uint32
+
+	// ReapedOrphans is the number of orphaned processes reaped by the server
+	// so far.
+	ReapedOrphans uint64
 }
 
 // Version can be used to retrieve all available version information.
@@ -399,12 +564,13 @@ func (c *ConmonClient) Version(ctx context.Context) (*VersionResponse, error) {
 	}
 
 	return &VersionResponse{
-		Version:     version,
-		Tag:         tag,
-		Commit:      commit,
-		BuildDate:   buildDate,
-		RustVersion: rustVersion,
-		ProcessID:   response.ProcessId(),
+		Version:       version,
+		Tag:           tag,
+		Commit:        commit,
+		BuildDate:     buildDate,
+		RustVersion:   rustVersion,
+		ProcessID:     response.ProcessId(),
+		ReapedOrphans: response.ReapedOrphans(),
 	}, nil
 }
 
@@ -428,6 +594,79 @@ type CreateContainerConfig struct {
 
 	// LogDrivers is a slice of selected log drivers.
 	LogDrivers []LogDriver
+
+	// Stdin indicates if the standard input of the container should be
+	// available for attach clients.
+	Stdin bool
+
+	// StdinOnce indicates if the standard input should be closed after the
+	// first attach client disconnected.
+	StdinOnce bool
+
+	// ScrollbackSize is the number of recent output bytes kept for replaying
+	// them to newly connected attach clients, at most 65536. Nothing is kept
+	// if zero.
+	ScrollbackSize uint64
+
+	// RecordingPath is the path to record the terminal session in the
+	// asciicast v2 format, if not empty. Requires Terminal to be set.
+	RecordingPath string
+
+	// RecordStdin indicates if the standard input should be included in the
+	// terminal session recording.
+	RecordStdin bool
+
+	// TerminalOptions are the initial settings of the terminal, if Terminal
+	// is true. Defaults are used if nil.
+	TerminalOptions *TerminalOptions
+
+	// RestartPolicy specifies if the container should be restarted on exit.
+	// The container is never restarted if nil.
+	RestartPolicy *RestartPolicy
+
+	// Timeout is the maximum time in seconds the runtime can take to create
+	// the container, disabled if zero.
+	Timeout uint64
+}
+
+// RestartPolicy specifies if a container should be restarted on exit.
+type RestartPolicy struct {
+	// Mode of the restart policy.
+	Mode RestartPolicyMode
+
+	// MaxRetries is the maximum number of restarts for the
+	// RestartPolicyModeOnFailure, unlimited if zero.
+	MaxRetries uint32
+}
+
+// RestartPolicyMode specifies available restart policy modes.
+type RestartPolicyMode int
+
+const (
+	// RestartPolicyModeNo never restarts the container.
+	RestartPolicyModeNo RestartPolicyMode = iota
+
+	// RestartPolicyModeOnFailure restarts the container on a non-zero exit
+	// code.
+	RestartPolicyModeOnFailure
+
+	// RestartPolicyModeAlways always restarts the container.
+	RestartPolicyModeAlways
+)
+
+// TerminalOptions are the initial settings of a terminal.
+type TerminalOptions struct {
+	// Width of the terminal, which is kept at the kernel default if zero.
+	Width uint16
+
+	// Height of the terminal, which is kept at the kernel default if zero.
+	Height uint16
+
+	// Raw puts the terminal into raw mode.
+	Raw bool
+
+	// DisableEcho disables the echo of the terminal input.
+	DisableEcho bool
 }
 
 // LogDriver specifies a selected logging mechanism.
@@ -470,27 +709,13 @@ func (c *ConmonClient) CreateContainer(
 		if err != nil {
 			return fmt.Errorf("create request: %w", err)
 		}
-		if err := req.SetId(cfg.ID); err != nil {
-			return fmt.Errorf("set ID: %w", err)
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
 		}
-		if err := req.SetBundlePath(cfg.BundlePath); err != nil {
-			return fmt.Errorf("set bundle path: %w", err)
-		}
-		req.SetTerminal(cfg.Terminal)
-		if err := stringSliceToTextList(cfg.ExitPaths, req.NewExitPaths); err != nil {
-			return fmt.Errorf("convert exit paths string slice to text list: %w", err)
-		}
-		if err := stringSliceToTextList(cfg.OOMExitPaths, req.NewOomExitPaths); err != nil {
-			return fmt.Errorf("convert oom exit paths string slice to text list: %w", err)
-		}
-		if err := stringSliceToTextList(cfg.OOMExitPaths, req.NewOomExitPaths); err != nil {
+		if err := c.initCreateContainerRequest(&req, cfg); err != nil {
 			return err
 		}
 
-		if err := c.initLogDrivers(&req, cfg.LogDrivers); err != nil {
-			return fmt.Errorf("init log drivers: %w", err)
-		}
-
 		if err := p.SetRequest(req); err != nil {
 			return fmt.Errorf("set request: %w", err)
 		}
@@ -528,6 +753,14 @@ type ExecSyncConfig struct {
 
 	// Terminal specifies if a tty should be used.
 	Terminal bool
+
+	// RecordingPath is the path to record the terminal session in the
+	// asciicast v2 format, if not empty. Requires Terminal to be set.
+	RecordingPath string
+
+	// TerminalOptions are the initial settings of the terminal, if Terminal
+	// is true. Defaults are used if nil.
+	TerminalOptions *TerminalOptions
 }
 
 // ExecContainerResult is the result for calling the ExecSyncContainer method.
@@ -560,14 +793,12 @@ func (c *ConmonClient) ExecSyncContainer(ctx context.Context, cfg *ExecSyncConfi
 		if err != nil {
 			return fmt.Errorf("create request: %w", err)
 		}
-		if err := req.SetId(cfg.ID); err != nil {
-			return fmt.Errorf("set ID: %w", err)
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
 		}
-		req.SetTimeoutSec(cfg.Timeout)
-		if err := stringSliceToTextList(cfg.Command, req.NewCommand); err != nil {
+		if err := initExecSyncRequest(&req, cfg); err != nil {
 			return err
 		}
-		req.SetTerminal(cfg.Terminal)
 		if err := p.SetRequest(req); err != nil {
 			return fmt.Errorf("set request: %w", err)
 		}
@@ -606,6 +837,66 @@ func (c *ConmonClient) ExecSyncContainer(ctx context.Context, cfg *ExecSyncConfi
 	return execContainerResult, nil
 }
 
+func (c *ConmonClient) initCreateContainerRequest(
+	req *proto.Conmon_CreateContainerRequest, cfg *CreateContainerConfig,
+) error {
+	if err := req.SetId(cfg.ID); err != nil {
+		return fmt.Errorf("set ID: %w", err)
+	}
+	if err := req.SetBundlePath(cfg.BundlePath); err != nil {
+		return fmt.Errorf("set bundle path: %w", err)
+	}
+	req.SetTerminal(cfg.Terminal)
+	req.SetStdin(cfg.Stdin)
+	req.SetStdinOnce(cfg.StdinOnce)
+	req.SetScrollbackSize(cfg.ScrollbackSize)
+	req.SetTimeoutSec(cfg.Timeout)
+	if err := req.SetRecordingPath(cfg.RecordingPath); err != nil {
+		return fmt.Errorf("set recording path: %w", err)
+	}
+	req.SetRecordStdin(cfg.RecordStdin)
+	if err := initTerminalOptions(cfg.TerminalOptions, req.NewTerminalOptions); err != nil {
+		return fmt.Errorf("init terminal options: %w", err)
+	}
+	if err := initRestartPolicy(cfg.RestartPolicy, req); err != nil {
+		return fmt.Errorf("init restart policy: %w", err)
+	}
+	if err := stringSliceToTextList(cfg.ExitPaths, req.NewExitPaths); err != nil {
+		return fmt.Errorf("convert exit paths string slice to text list: %w", err)
+	}
+	if err := stringSliceToTextList(cfg.OOMExitPaths, req.NewOomExitPaths); err != nil {
+		return fmt.Errorf("convert oom exit paths string slice to text list: %w", err)
+	}
+	if err := stringSliceToTextList(cfg.OOMExitPaths, req.NewOomExitPaths); err != nil {
+		return err
+	}
+
+	if err := c.initLogDrivers(req, cfg.LogDrivers); err != nil {
+		return fmt.Errorf("init log drivers: %w", err)
+	}
+
+	return nil
+}
+
+func initExecSyncRequest(req *proto.Conmon_ExecSyncContainerRequest, cfg *ExecSyncConfig) error {
+	if err := req.SetId(cfg.ID); err != nil {
+		return fmt.Errorf("set ID: %w", err)
+	}
+	req.SetTimeoutSec(cfg.Timeout)
+	if err := stringSliceToTextList(cfg.Command, req.NewCommand); err != nil {
+		return err
+	}
+	req.SetTerminal(cfg.Terminal)
+	if err := req.SetRecordingPath(cfg.RecordingPath); err != nil {
+		return fmt.Errorf("set recording path: %w", err)
+	}
+	if err := initTerminalOptions(cfg.TerminalOptions, req.NewTerminalOptions); err != nil {
+		return fmt.Errorf("init terminal options: %w", err)
+	}
+
+	return nil
+}
+
 func stringSliceToTextList(src []string, newFunc func(int32) (capnp.TextList, error)) error {
 	l := int32(len(src))
 	if l == 0 {
@@ -624,6 +915,45 @@ func stringSliceToTextList(src []string, newFunc func(int32) (capnp.TextList, er
 	return nil
 }
 
+func initTerminalOptions(src *TerminalOptions, newFunc func() (proto.Conmon_TerminalOptions, error)) error {
+	if src == nil {
+		return nil
+	}
+	opts, err := newFunc()
+	if err != nil {
+		return err
+	}
+	opts.SetWidth(src.Width)
+	opts.SetHeight(src.Height)
+	opts.SetRaw(src.Raw)
+	opts.SetDisableEcho(src.DisableEcho)
+
+	return nil
+}
+
+func initRestartPolicy(src *RestartPolicy, req *proto.Conmon_CreateContainerRequest) error {
+	if src == nil {
+		return nil
+	}
+	policy, err := req.NewRestartPolicy()
+	if err != nil {
+		return fmt.Errorf("create restart policy: %w", err)
+	}
+	switch src.Mode {
+	case RestartPolicyModeNo:
+		policy.SetMode(proto.Conmon_RestartPolicy_Mode_no)
+	case RestartPolicyModeOnFailure:
+		policy.SetMode(proto.Conmon_RestartPolicy_Mode_onFailure)
+	case RestartPolicyModeAlways:
+		policy.SetMode(proto.Conmon_RestartPolicy_Mode_always)
+	default:
+		return fmt.Errorf("%w: restart policy mode %d", errInvalidValue, src.Mode)
+	}
+	policy.SetMaxRetries(src.MaxRetries)
+
+	return nil
+}
+
 func (c *ConmonClient) initLogDrivers(req *proto.Conmon_CreateContainerRequest, logDrivers []LogDriver) error {
 	newLogDrivers, err := req.NewLogDrivers(int32(len(logDrivers)))
 	if err != nil {
@@ -642,6 +972,40 @@ func (c *ConmonClient) initLogDrivers(req *proto.Conmon_CreateContainerRequest,
 	return nil
 }
 
+// WithMetadata creates a copy of the client, which attaches the provided
+// metadata to every request. This allows the server to correlate its tracing
+// spans with the ones of the caller.
+func (c *ConmonClient) WithMetadata(metadata *RequestMetadata) *ConmonClient {
+	client := *c
+	client.metadata = metadata
+
+	return &client
+}
+
+func (c *ConmonClient) initMetadata(newFunc func() (proto.Conmon_Metadata, error)) error {
+	if c.metadata == nil {
+		return nil
+	}
+	metadata, err := newFunc()
+	if err != nil {
+		return err
+	}
+	if err := metadata.SetTraceId(c.metadata.TraceID); err != nil {
+		return fmt.Errorf("set trace ID: %w", err)
+	}
+	if err := metadata.SetSpanId(c.metadata.SpanID); err != nil {
+		return fmt.Errorf("set span ID: %w", err)
+	}
+	if err := metadata.SetRequestId(c.metadata.RequestID); err != nil {
+		return fmt.Errorf("set request ID: %w", err)
+	}
+	if err := metadata.SetCallerName(c.metadata.CallerName); err != nil {
+		return fmt.Errorf("set caller name: %w", err)
+	}
+
+	return nil
+}
+
 // PID returns the server process ID.
 func (c *ConmonClient) PID() uint32 {
 	return c.serverPID
@@ -656,6 +1020,312 @@ func (c *ConmonClient) Shutdown() error {
 	return nil
 }
 
+// ContainerInfo describes a container or exec session tracked by the server.
+type ContainerInfo struct {
+	// ID is the container identifier.
+	ID string
+
+	// PID is the process identifier of the container, which is zero while the
+	// container waits to be restarted.
+	PID uint32
+
+	// LogDrivers is a slice of the configured log drivers.
+	LogDrivers []LogDriver
+
+	// Probes is a slice of the registered probe statuses.
+	Probes []ProbeStatus
+
+	// RestartCount is the number of restarts of the container.
+	RestartCount uint32
+
+	// LastExitCode is the exit code before the last restart, if RestartCount
+	// is not zero.
+	LastExitCode int32
+}
+
+// ListContainers can be used to retrieve all containers and exec sessions
+// tracked by the server.
+func (c *ConmonClient) ListContainers(ctx context.Context) ([]ContainerInfo, error) {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return nil, fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
+
+	future, free := client.ListContainers(ctx, func(p proto.Conmon_listContainers_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return nil, fmt.Errorf("create result: %w", err)
+	}
+
+	response, err := result.Response()
+	if err != nil {
+		return nil, fmt.Errorf("set response: %w", err)
+	}
+
+	containers, err := response.Containers()
+	if err != nil {
+		return nil, fmt.Errorf("get containers: %w", err)
+	}
+
+	res := make([]ContainerInfo, 0, containers.Len())
+	for i := 0; i < containers.Len(); i++ {
+		info, err := containerInfoFromProto(containers.At(i))
+		if err != nil {
+			return nil, err
+		}
+		res = append(res, info)
+	}
+
+	return res, nil
+}
+
+func containerInfoFromProto(container proto.Conmon_Container) (ContainerInfo, error) {
+	var info ContainerInfo
+
+	id, err := container.Id()
+	if err != nil {
+		return info, fmt.Errorf("get ID: %w", err)
+	}
+
+	logDrivers, err := container.LogDrivers()
+	if err != nil {
+		return info, fmt.Errorf("get log drivers: %w", err)
+	}
+
+	info = ContainerInfo{
+		ID:           id,
+		PID:          container.Pid(),
+		LogDrivers:   make([]LogDriver, 0, logDrivers.Len()),
+		RestartCount: container.RestartCount(),
+		LastExitCode: container.LastExitCode(),
+	}
+	for i := 0; i < logDrivers.Len(); i++ {
+		path, err := logDrivers.At(i).Path()
+		if err != nil {
+			return info, fmt.Errorf("get log driver path: %w", err)
+		}
+		info.LogDrivers = append(info.LogDrivers, LogDriver{
+			Type: LogDriverTypeContainerRuntimeInterface,
+			Path: path,
+		})
+	}
+
+	probes, err := container.Probes()
+	if err != nil {
+		return info, fmt.Errorf("get probes: %w", err)
+	}
+
+	info.Probes, err = probeStatusesFromProto(probes)
+	if err != nil {
+		return info, fmt.Errorf("convert probes: %w", err)
+	}
+
+	return info, nil
+}
+
+// KillContainerConfig is the configuration for calling the KillContainer
+// method.
+type KillContainerConfig struct {
+	// ID is the container identifier.
+	ID string
+
+	// Signal is the signal to be sent to the container process.
+	Signal syscall.Signal
+}
+
+// KillContainer can be used to send a signal to a running container.
+func (c *ConmonClient) KillContainer(ctx context.Context, cfg *KillContainerConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
+
+	future, free := client.KillContainer(ctx, func(p proto.Conmon_killContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+		req.SetSignal(uint32(cfg.Signal))
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	return nil
+}
+
+// SetLogLevelConfig is the configuration for calling the SetLogLevel method.
+type SetLogLevelConfig struct {
+	// Filter is the log level or comma separated filter directives like
+	// "conmon::attach=trace,info". Every directive is either a "level" or a
+	// "target=level", where targets match by module path prefix. Span and
+	// field directives are not supported.
+	Filter string
+
+	// Timeout after which the previous filter gets restored, where zero
+	// keeps the new filter.
+	Timeout time.Duration
+}
+
+// SetLogLevel can be used to change the log level of the server at runtime.
+func (c *ConmonClient) SetLogLevel(ctx context.Context, cfg *SetLogLevelConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
+
+	future, free := client.SetLogLevel(ctx, func(p proto.Conmon_setLogLevel_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
+
+		if err := req.SetFilter(cfg.Filter); err != nil {
+			return fmt.Errorf("set filter: %w", err)
+		}
+		req.SetTimeoutSec(uint64(cfg.Timeout.Seconds()))
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	return nil
+}
+
+// ShutdownServerConfig is the configuration for calling the ShutdownServer
+// method.
+type ShutdownServerConfig struct {
+	// Mode specifies how running containers should be handled.
+	Mode ShutdownMode
+
+	// GracePeriod is the time to wait for the containers to exit before
+	// killing them. Only applies to ShutdownModeKillAll.
+	GracePeriod time.Duration
+}
+
+// ShutdownMode specifies available server shutdown modes.
+type ShutdownMode int
+
+const (
+	// ShutdownModeKillAll terminates all containers and kills them after the
+	// grace period.
+	ShutdownModeKillAll ShutdownMode = iota
+
+	// ShutdownModeDetach leaves all containers running.
+	ShutdownModeDetach
+
+	// ShutdownModeWaitForExit waits until all containers have exited.
+	ShutdownModeWaitForExit
+)
+
+// ShutdownServer can be used to gracefully shut down the server via RPC.
+func (c *ConmonClient) ShutdownServer(ctx context.Context, cfg *ShutdownServerConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
+
+	future, free := client.Shutdown(ctx, func(p proto.Conmon_shutdown_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
+
+		switch cfg.Mode {
+		case ShutdownModeKillAll:
+			req.SetMode(proto.Conmon_ShutdownRequest_Mode_killAll)
+		case ShutdownModeDetach:
+			req.SetMode(proto.Conmon_ShutdownRequest_Mode_detach)
+		case ShutdownModeWaitForExit:
+			req.SetMode(proto.Conmon_ShutdownRequest_Mode_waitForExit)
+		default:
+			return fmt.Errorf("%w: shutdown mode %d", errInvalidValue, cfg.Mode)
+		}
+		req.SetGracePeriodSec(uint64(cfg.GracePeriod.Seconds()))
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	return nil
+}
+
 func (c *ConmonClient) pidFile() string {
 	return filepath.Join(c.runDir, pidFileName)
 }
@@ -686,6 +1356,9 @@ func (c *ConmonClient) ReopenLogContainer(ctx context.Context, cfg *ReopenLogCon
 		if err != nil {
 			return fmt.Errorf("create request: %w", err)
 		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
 
 		if err := req.SetId(cfg.ID); err != nil {
 			return fmt.Errorf("set ID: %w", err)
@@ -1,12 +1,15 @@
 package client_test
 
 import (
+	"bufio"
 	"context"
 	"fmt"
 	"io"
+	"net"
 	"os"
 	"path/filepath"
 	"sync"
+	"syscall"
 	"time"
 
 	"github.com/containers/conmon-rs/pkg/client"
@@ -49,6 +52,162 @@ var _ = Describe("ConmonClient", func() {
 		})
 	})
 
+	Describe("ShutdownServer", func() {
+		It("should kill all containers and stop the server", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(false, []string{"/busybox", "sleep", "10"}, nil)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, false)
+			tr.startContainer(sut)
+
+			Expect(sut.ShutdownServer(context.Background(), &client.ShutdownServerConfig{
+				Mode:        client.ShutdownModeKillAll,
+				GracePeriod: time.Second,
+			})).To(BeNil())
+			Eventually(func() error {
+				_, err := sut.Version(context.Background())
+
+				return err
+			}, time.Second*5).ShouldNot(BeNil())
+			Expect(tr.rr.RunCommandCheckOutput("stopped", "list")).To(BeNil())
+
+			// The server is already gone.
+			sut = nil
+		})
+	})
+
+	Describe("ResourceLimits", func() {
+		It("should apply the OOM score and rlimits to the server", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			cfg := client.NewConmonServerConfig(runtimePath, tr.rr.runtimeRoot, tr.tmpDir)
+			cfg.ConmonServerPath = conmonPath
+			oomScoreAdj := int32(500)
+			cfg.OOMScoreAdj = &oomScoreAdj
+			rlimitNofile := uint64(4096)
+			cfg.RlimitNofile = &rlimitNofile
+
+			var err error
+			sut, err = client.New(cfg)
+			Expect(err).To(BeNil())
+
+			procPath := filepath.Join("/proc", fmt.Sprint(sut.PID()))
+			Expect(fileContents(filepath.Join(procPath, "oom_score_adj"))).To(Equal("500\n"))
+			Expect(fileContents(filepath.Join(procPath, "limits"))).To(
+				MatchRegexp(`Max open files\s+4096\s+4096`),
+			)
+		})
+
+		It("should fail on invalid cgroup manager", func() {
+			sut = nil
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			cfg := client.NewConmonServerConfig(runtimePath, tr.rr.runtimeRoot, tr.tmpDir)
+			cfg.ConmonServerPath = conmonPath
+			cfg.CgroupManager = "invalid"
+
+			_, err := client.New(cfg)
+			Expect(err).NotTo(BeNil())
+		})
+	})
+
+	Describe("LogDriver", func() {
+		It("should write the server logs to the log file", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			cfg := client.NewConmonServerConfig(runtimePath, tr.rr.runtimeRoot, tr.tmpDir)
+			cfg.ConmonServerPath = conmonPath
+			cfg.LogDriver = client.LogDriverFile
+			cfg.LogFile = filepath.Join(tr.tmpDir, "conmonrs.log")
+
+			var err error
+			sut, err = client.New(cfg)
+			Expect(err).To(BeNil())
+			Eventually(func() string {
+				return fileContents(cfg.LogFile)
+			}, time.Second*5).Should(HavePrefix("{"))
+		})
+
+		It("should fail without log file for the file log driver", func() {
+			sut = nil
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			cfg := client.NewConmonServerConfig(runtimePath, tr.rr.runtimeRoot, tr.tmpDir)
+			cfg.ConmonServerPath = conmonPath
+			cfg.LogDriver = client.LogDriverFile
+
+			_, err := client.New(cfg)
+			Expect(err).NotTo(BeNil())
+		})
+	})
+
+	Describe("AllowedUIDs", func() {
+		It("should allow clients of the configured users", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			cfg := client.NewConmonServerConfig(runtimePath, tr.rr.runtimeRoot, tr.tmpDir)
+			cfg.ConmonServerPath = conmonPath
+			cfg.AllowedUIDs = []uint32{uint32(os.Getuid())}
+
+			var err error
+			sut, err = client.New(cfg)
+			Expect(err).To(BeNil())
+		})
+
+		It("should reject clients of other users", func() {
+			sut = nil
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			cfg := client.NewConmonServerConfig(runtimePath, tr.rr.runtimeRoot, tr.tmpDir)
+			cfg.ConmonServerPath = conmonPath
+			cfg.AllowedUIDs = []uint32{uint32(os.Getuid()) + 1}
+
+			_, err := client.New(cfg)
+			Expect(err).NotTo(BeNil())
+		})
+	})
+
+	Describe("WithMetadata", func() {
+		It("should attach the metadata to requests", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = tr.configGivenEnv()
+
+			withMetadata := sut.WithMetadata(&client.RequestMetadata{
+				TraceID:    "4bf92f3577b34da6a3ce929d0e0e4736",
+				SpanID:     "00f067aa0ba902b7",
+				RequestID:  "request",
+				CallerName: "test",
+			})
+			Expect(withMetadata.PID()).To(Equal(sut.PID()))
+			_, err := withMetadata.ListContainers(context.Background())
+			Expect(err).To(BeNil())
+		})
+	})
+
+	Describe("SetLogLevel", func() {
+		It("should change the log level", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = tr.configGivenEnv()
+
+			Expect(sut.SetLogLevel(context.Background(), &client.SetLogLevelConfig{
+				Filter:  "conmon::attach=trace,info",
+				Timeout: time.Minute,
+			})).To(BeNil())
+		})
+
+		It("should fail on invalid filters", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = tr.configGivenEnv()
+
+			Expect(sut.SetLogLevel(context.Background(), &client.SetLogLevelConfig{
+				Filter: "conmon[rpc]=debug",
+			})).NotTo(BeNil())
+		})
+	})
+
 	Describe("CreateContainer", func() {
 		for _, terminal := range []bool{true, false} {
 			terminal := terminal
@@ -161,6 +320,262 @@ var _ = Describe("ConmonClient", func() {
 		}
 	})
 
+	Describe("ListContainers", func() {
+		It("should list created containers", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, false)
+
+			containers, err := sut.ListContainers(context.Background())
+			Expect(err).To(BeNil())
+			Expect(containers).To(HaveLen(1))
+			Expect(containers[0].ID).To(Equal(tr.ctrID))
+			Expect(containers[0].PID).NotTo(BeZero())
+			Expect(containers[0].LogDrivers).To(HaveLen(1))
+			Expect(containers[0].LogDrivers[0].Path).To(Equal(tr.logPath()))
+		})
+	})
+
+	Describe("RestartPolicy", func() {
+		It("should restart a failing container", func() {
+			tr = newTestRunner()
+			// Fail on the first run only, to keep the restarted container running.
+			tr.createRuntimeConfigWithProcessArgs(false, []string{
+				"/busybox", "sh", "-c", "/busybox test -e /marker || { /busybox touch /marker; exit 1; }; /busybox sleep 10",
+			}, nil)
+			sut = tr.configGivenEnv()
+			tr.createContainerWithConfig(sut, &client.CreateContainerConfig{
+				RestartPolicy: &client.RestartPolicy{
+					Mode:       client.RestartPolicyModeOnFailure,
+					MaxRetries: 1,
+				},
+			})
+			tr.startContainer(sut)
+
+			Eventually(func() []client.ContainerInfo {
+				containers, err := sut.ListContainers(context.Background())
+				Expect(err).To(BeNil())
+
+				return containers
+			}, time.Second*10).Should(ContainElement(SatisfyAll(
+				HaveField("RestartCount", BeEquivalentTo(1)),
+				HaveField("LastExitCode", BeEquivalentTo(1)),
+			)))
+		})
+	})
+
+	Describe("KillContainer", func() {
+		It("should kill a running container", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(false, []string{"/busybox", "sleep", "10"}, nil)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, false)
+			tr.startContainer(sut)
+
+			Expect(sut.KillContainer(context.Background(), &client.KillContainerConfig{
+				ID:     tr.ctrID,
+				Signal: syscall.SIGKILL,
+			})).To(BeNil())
+			Eventually(func() error {
+				return tr.rr.RunCommandCheckOutput("stopped", "list")
+			}, time.Second*5).Should(BeNil())
+		})
+
+		It("should fail for unknown containers", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = tr.configGivenEnv()
+
+			Expect(sut.KillContainer(context.Background(), &client.KillContainerConfig{
+				ID:     "unknown",
+				Signal: syscall.SIGTERM,
+			})).NotTo(BeNil())
+		})
+	})
+
+	Describe("RegisterProbe", func() {
+		It("should report a succeeding TCP probe", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(
+				false, []string{"/busybox", "httpd", "-f", "-p", "8080", "-h", "/"}, nil,
+			)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, false)
+			tr.startContainer(sut)
+
+			Expect(sut.RegisterProbe(context.Background(), &client.RegisterProbeConfig{
+				ID:      tr.ctrID,
+				Name:    "readiness",
+				TCPPort: 8080,
+				Period:  time.Second,
+			})).To(BeNil())
+
+			Eventually(func() []client.ProbeStatus {
+				containers, err := sut.ListContainers(context.Background())
+				Expect(err).To(BeNil())
+				Expect(containers).To(HaveLen(1))
+
+				return containers[0].Probes
+			}, time.Second*10).Should(ContainElement(client.ProbeStatus{
+				Name:   "readiness",
+				Result: client.ProbeResultSuccess,
+			}))
+		})
+
+		It("should fail without handler", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(false, []string{"/busybox", "sleep", "10"}, nil)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, false)
+			tr.startContainer(sut)
+
+			Expect(sut.RegisterProbe(context.Background(), &client.RegisterProbeConfig{
+				ID:   tr.ctrID,
+				Name: "readiness",
+			})).NotTo(BeNil())
+		})
+	})
+
+	Describe("Namespaces", func() {
+		It("should create and remove the namespaces of a pod", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = tr.configGivenEnv()
+
+			basePath := MustDirInTempDir(tr.tmpDir, "namespaces")
+			resp, err := sut.CreateNamespaces(context.Background(), &client.CreateNamespacesConfig{
+				BasePath:   basePath,
+				PodID:      tr.ctrID,
+				Namespaces: []client.Namespace{client.NamespaceIPC, client.NamespaceUTS},
+			})
+			Expect(err).To(BeNil())
+			Expect(resp.Namespaces).To(HaveLen(2))
+			for _, namespace := range resp.Namespaces {
+				Expect(namespace.Path).To(HavePrefix(basePath))
+				Expect(namespace.Path).To(BeAnExistingFile())
+			}
+
+			Expect(sut.RemoveNamespaces(context.Background(), &client.RemoveNamespacesConfig{
+				BasePath: basePath,
+				PodID:    tr.ctrID,
+			})).To(BeNil())
+			for _, namespace := range resp.Namespaces {
+				Expect(namespace.Path).NotTo(BeAnExistingFile())
+			}
+		})
+
+		It("should fail to remove the namespaces of an invalid pod ID", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = tr.configGivenEnv()
+
+			Expect(sut.RemoveNamespaces(context.Background(), &client.RemoveNamespacesConfig{
+				BasePath: tr.tmpDir,
+				PodID:    "..",
+			})).NotTo(BeNil())
+		})
+	})
+
+	Describe("PortForward", func() {
+		It("should forward a port of the container", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(
+				false, []string{"/busybox", "httpd", "-f", "-p", "8080", "-h", "/"}, nil,
+			)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, false)
+			tr.startContainer(sut)
+
+			// Retry until the server within the container is listening.
+			Eventually(func() (string, error) {
+				local, remote := net.Pipe()
+				defer local.Close()
+				go func() {
+					defer GinkgoRecover()
+					if err := sut.PortForward(context.Background(), &client.PortForwardConfig{
+						ID:     tr.ctrID,
+						Port:   8080,
+						Stream: remote,
+					}); err != nil {
+						// Unblocks the local side for the next attempt.
+						Expect(remote.Close()).To(BeNil())
+					}
+				}()
+				go func() {
+					if _, err := fmt.Fprintf(local, "GET / HTTP/1.0\r\n\r\n"); err != nil {
+						GinkgoWriter.Printf("Unable to send request: %v\n", err)
+					}
+				}()
+
+				return bufio.NewReader(local).ReadString('\n')
+			}, time.Second*5).Should(HavePrefix("HTTP/1."))
+		})
+	})
+
+	Describe("TerminalOptions", func() {
+		It("should set the initial terminal size of an exec session", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(true, []string{"/busybox", "sleep", "10"}, nil)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, true)
+			tr.startContainer(sut)
+
+			result, err := sut.ExecSyncContainer(context.Background(), &client.ExecSyncConfig{
+				ID:       tr.ctrID,
+				Command:  []string{"/busybox", "stty", "size"},
+				Terminal: true,
+				Timeout:  timeoutUnlimited,
+				TerminalOptions: &client.TerminalOptions{
+					Width:  100,
+					Height: 40,
+				},
+			})
+			Expect(err).To(BeNil())
+			Expect(result.ExitCode).To(BeEquivalentTo(0))
+			Expect(string(result.Stdout)).To(ContainSubstring("40 100"))
+		})
+	})
+
+	Describe("Recording", func() {
+		It("should record an exec session", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(true, []string{"/busybox", "sleep", "10"}, nil)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, true)
+			tr.startContainer(sut)
+
+			recordingPath := filepath.Join(tr.tmpDir, "recording")
+			result, err := sut.ExecSyncContainer(context.Background(), &client.ExecSyncConfig{
+				ID:            tr.ctrID,
+				Command:       []string{"/busybox", "echo", "-n", "Hello world"},
+				Terminal:      true,
+				Timeout:       timeoutUnlimited,
+				RecordingPath: recordingPath,
+			})
+			Expect(err).To(BeNil())
+			Expect(result.ExitCode).To(BeEquivalentTo(0))
+
+			Eventually(func() string {
+				return fileContents(recordingPath)
+			}, time.Second*5).Should(ContainSubstring("Hello world"))
+			Expect(fileContents(recordingPath)).To(HavePrefix(`{"version": 2`))
+		})
+
+		It("should fail to record without terminal", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = tr.configGivenEnv()
+
+			_, err := sut.CreateContainer(context.Background(), &client.CreateContainerConfig{
+				ID:            tr.ctrID,
+				BundlePath:    tr.tmpDir,
+				RecordingPath: filepath.Join(tr.tmpDir, "recording"),
+			})
+			Expect(err).NotTo(BeNil())
+		})
+	})
+
 	Describe("ExecSync Stress", func() {
 		for _, terminal := range []bool{true, false} {
 			terminal := terminal
@@ -302,7 +717,11 @@ var _ = Describe("ConmonClient", func() {
 				tr = newTestRunner()
 				tr.createRuntimeConfigWithProcessArgs(terminal, []string{"/busybox", "sh"}, nil)
 				sut = tr.configGivenEnv()
-				tr.createContainer(sut, terminal)
+				tr.createContainerWithConfig(sut, &client.CreateContainerConfig{
+					Terminal:  terminal,
+					Stdin:     true,
+					StdinOnce: true,
+				})
 				tr.startContainer(sut)
 
 				stdin, stdinWrite := io.Pipe()
@@ -327,6 +746,115 @@ var _ = Describe("ConmonClient", func() {
 
 				testAttach(stdinWrite, stdoutRead, stderrRead)
 			})
+
+			It(testName("should succeed via RPC streams", terminal), func() {
+				tr = newTestRunner()
+				tr.createRuntimeConfigWithProcessArgs(terminal, []string{"/busybox", "sh"}, nil)
+				sut = tr.configGivenEnv()
+				tr.createContainerWithConfig(sut, &client.CreateContainerConfig{
+					Terminal:  terminal,
+					Stdin:     true,
+					StdinOnce: true,
+				})
+				tr.startContainer(sut)
+
+				stdin, stdinWrite := io.Pipe()
+				stdoutRead, stdout := io.Pipe()
+				stderrRead, stderr := io.Pipe()
+				go func() {
+					defer GinkgoRecover()
+					err := sut.AttachContainerStream(context.Background(), &client.AttachConfig{
+						ID:  tr.ctrID,
+						Tty: terminal,
+						Streams: client.AttachStreams{
+							Stdin:  &client.In{stdin},
+							Stdout: &client.Out{stdout},
+							Stderr: &client.Out{stderr},
+						},
+					})
+					Expect(err).To(BeNil())
+				}()
+
+				testAttach(stdinWrite, stdoutRead, stderrRead)
+			})
 		}
+
+		It("should detach on the server detach keys", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(false, []string{"/busybox", "sh"}, nil)
+			sut = tr.configGivenEnv()
+			tr.createContainerWithConfig(sut, &client.CreateContainerConfig{Stdin: true})
+			tr.startContainer(sut)
+
+			stdin, stdinWrite := io.Pipe()
+			stdoutRead, stdout := io.Pipe()
+			stderrRead, stderr := io.Pipe()
+			go io.Copy(io.Discard, stdoutRead) // nolint:errcheck // Output is not of interest
+			go io.Copy(io.Discard, stderrRead) // nolint:errcheck // Output is not of interest
+			done := make(chan error, 1)
+			go func() {
+				done <- sut.AttachContainer(context.Background(), &client.AttachConfig{
+					ID:               tr.ctrID,
+					SocketPath:       filepath.Join(tr.tmpDir, "attach"),
+					ServerDetachKeys: "ctrl-p,ctrl-q",
+					Streams: client.AttachStreams{
+						Stdin:  &client.In{stdin},
+						Stdout: &client.Out{stdout},
+						Stderr: &client.Out{stderr},
+					},
+				})
+			}()
+
+			_, err := stdinWrite.Write([]byte{0x10, 0x11})
+			Expect(err).To(BeNil())
+			Eventually(done, time.Second*5).Should(Receive(BeNil()))
+		})
+
+		It("should fail on invalid slow client policy", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, false)
+
+			Expect(sut.AttachContainer(context.Background(), &client.AttachConfig{
+				ID:               tr.ctrID,
+				SocketPath:       filepath.Join(tr.tmpDir, "attach"),
+				SlowClientPolicy: client.SlowClientPolicy(42),
+			})).NotTo(BeNil())
+		})
+
+		It("should replay the scrollback", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(
+				false, []string{"/busybox", "sh", "-c", "echo Hello world; sleep 10"}, nil,
+			)
+			sut = tr.configGivenEnv()
+			tr.createContainerWithConfig(sut, &client.CreateContainerConfig{ScrollbackSize: 1024})
+			tr.startContainer(sut)
+			Eventually(func() string {
+				return fileContents(tr.logPath())
+			}, time.Second*5).Should(ContainSubstring("Hello world"))
+
+			stdoutRead, stdout := io.Pipe()
+			stderrRead, stderr := io.Pipe()
+			go io.Copy(io.Discard, stderrRead) // nolint:errcheck // Only stdout is of interest
+			go func() {
+				defer GinkgoRecover()
+				err := sut.AttachContainer(context.Background(), &client.AttachConfig{
+					ID:             tr.ctrID,
+					SocketPath:     filepath.Join(tr.tmpDir, "attach"),
+					ScrollbackSize: 1024,
+					Streams: client.AttachStreams{
+						Stdout: &client.Out{stdout},
+						Stderr: &client.Out{stderr},
+					},
+				})
+				Expect(err).To(BeNil())
+			}()
+
+			line, err := bufio.NewReader(stdoutRead).ReadString('\n')
+			Expect(err).To(BeNil())
+			Expect(line).To(ContainSubstring("Hello world"))
+		})
 	})
 })
@@ -7,6 +7,22 @@ const (
 	// LogDriverSystemd is the log driver printing to systemd journald.
 	LogDriverSystemd = "systemd"
 
+	// LogDriverJSON is the log driver printing JSON lines including the span
+	// fields to stdio.
+	LogDriverJSON = "json"
+
+	// LogDriverFile is the log driver writing JSON lines to the rotated
+	// LogFile.
+	LogDriverFile = "file"
+
+	// CgroupManagerCgroupfs is the cgroup manager writing to the cgroup v2
+	// filesystem directly.
+	CgroupManagerCgroupfs = "cgroupfs"
+
+	// CgroupManagerSystemd is the cgroup manager creating a transient systemd
+	// scope.
+	CgroupManagerSystemd = "systemd"
+
 	// LogLevelTrace is the log level printing only "trace" messages.
 	LogLevelTrace = "trace"
 
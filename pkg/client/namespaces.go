@@ -0,0 +1,286 @@
+package client
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/containers/conmon-rs/internal/proto"
+)
+
+// Namespace specifies available Linux namespaces of a pod.
+type Namespace int
+
+const (
+	// NamespaceIPC is the IPC namespace.
+	NamespaceIPC Namespace = iota
+
+	// NamespaceNet is the network namespace.
+	NamespaceNet
+
+	// NamespaceUTS is the UTS namespace.
+	NamespaceUTS
+
+	// NamespaceUser is the user namespace.
+	NamespaceUser
+
+	// NamespaceCgroup is the cgroup namespace.
+	NamespaceCgroup
+)
+
+// CreateNamespacesConfig is the configuration for calling the
+// CreateNamespaces method.
+type CreateNamespacesConfig struct {
+	// BasePath is the directory where the namespaces get bind mounted to
+	// "<BasePath>/<type>ns/<PodID>".
+	BasePath string
+
+	// PodID is the identifier of the pod.
+	PodID string
+
+	// Namespaces is a slice of namespaces to be created.
+	Namespaces []Namespace
+
+	// UIDMappings are the mappings of the user namespace, like
+	// "0 1000 65536".
+	UIDMappings []string
+
+	// GIDMappings are the mappings of the user namespace, like
+	// "0 1000 65536".
+	GIDMappings []string
+
+	// Sysctls are applied within the created namespaces.
+	Sysctls map[string]string
+}
+
+// NamespacesResponse is the response of the CreateNamespaces method.
+type NamespacesResponse struct {
+	// Namespaces is a slice of the created namespaces.
+	Namespaces []*NamespacesResponseNamespace
+}
+
+// NamespacesResponseNamespace describes a created namespace.
+type NamespacesResponseNamespace struct {
+	// Type is the namespace type.
+	Type Namespace
+
+	// Path is the bind mount path of the namespace.
+	Path string
+}
+
+// CreateNamespaces can be used to create and pin the namespaces of a pod.
+func (c *ConmonClient) CreateNamespaces(
+	ctx context.Context, cfg *CreateNamespacesConfig,
+) (*NamespacesResponse, error) {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return nil, fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
+
+	future, free := client.CreateNamespaces(ctx, func(p proto.Conmon_createNamespaces_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
+
+		if err := req.SetBasePath(cfg.BasePath); err != nil {
+			return fmt.Errorf("set base path: %w", err)
+		}
+
+		if err := req.SetPodId(cfg.PodID); err != nil {
+			return fmt.Errorf("set pod ID: %w", err)
+		}
+
+		if err := initNamespacesRequest(&req, cfg); err != nil {
+			return err
+		}
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return nil, fmt.Errorf("create result: %w", err)
+	}
+
+	response, err := result.Response()
+	if err != nil {
+		return nil, fmt.Errorf("set response: %w", err)
+	}
+
+	namespaces, err := response.Namespaces()
+	if err != nil {
+		return nil, fmt.Errorf("get namespaces: %w", err)
+	}
+
+	res, err := namespacesFromProto(namespaces)
+	if err != nil {
+		return nil, fmt.Errorf("convert namespaces: %w", err)
+	}
+
+	return &NamespacesResponse{Namespaces: res}, nil
+}
+
+func initNamespacesRequest(req *proto.Conmon_CreateNamespacesRequest, cfg *CreateNamespacesConfig) error {
+	namespaces, err := req.NewNamespaces(int32(len(cfg.Namespaces)))
+	if err != nil {
+		return fmt.Errorf("create namespaces: %w", err)
+	}
+	for i, namespace := range cfg.Namespaces {
+		n, err := namespace.toProto()
+		if err != nil {
+			return err
+		}
+		namespaces.Set(i, n)
+	}
+
+	if err := stringSliceToTextList(cfg.UIDMappings, req.NewUidMappings); err != nil {
+		return fmt.Errorf("convert uid mappings string slice to text list: %w", err)
+	}
+
+	if err := stringSliceToTextList(cfg.GIDMappings, req.NewGidMappings); err != nil {
+		return fmt.Errorf("convert gid mappings string slice to text list: %w", err)
+	}
+
+	sysctls, err := req.NewSysctls(int32(len(cfg.Sysctls)))
+	if err != nil {
+		return fmt.Errorf("create sysctls: %w", err)
+	}
+	i := 0
+	for key, value := range cfg.Sysctls {
+		sysctl := sysctls.At(i)
+		if err := sysctl.SetKey(key); err != nil {
+			return fmt.Errorf("set sysctl key: %w", err)
+		}
+		if err := sysctl.SetValue(value); err != nil {
+			return fmt.Errorf("set sysctl value: %w", err)
+		}
+		i++
+	}
+
+	return nil
+}
+
+func namespacesFromProto(src proto.Conmon_NamespaceInfo_List) ([]*NamespacesResponseNamespace, error) {
+	res := make([]*NamespacesResponseNamespace, 0, src.Len())
+	for i := 0; i < src.Len(); i++ {
+		namespace := src.At(i)
+
+		typ, err := namespaceFromProto(namespace.Type())
+		if err != nil {
+			return nil, err
+		}
+
+		path, err := namespace.Path()
+		if err != nil {
+			return nil, fmt.Errorf("get namespace path: %w", err)
+		}
+
+		res = append(res, &NamespacesResponseNamespace{
+			Type: typ,
+			Path: path,
+		})
+	}
+
+	return res, nil
+}
+
+// RemoveNamespacesConfig is the configuration for calling the
+// RemoveNamespaces method.
+type RemoveNamespacesConfig struct {
+	// BasePath is the directory which has been used to create the
+	// namespaces.
+	BasePath string
+
+	// PodID is the identifier of the pod.
+	PodID string
+}
+
+// RemoveNamespaces can be used to unmount and remove the namespaces of a pod.
+func (c *ConmonClient) RemoveNamespaces(ctx context.Context, cfg *RemoveNamespacesConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
+
+	future, free := client.RemoveNamespaces(ctx, func(p proto.Conmon_removeNamespaces_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
+
+		if err := req.SetBasePath(cfg.BasePath); err != nil {
+			return fmt.Errorf("set base path: %w", err)
+		}
+
+		if err := req.SetPodId(cfg.PodID); err != nil {
+			return fmt.Errorf("set pod ID: %w", err)
+		}
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	return nil
+}
+
+func (n Namespace) toProto() (proto.Conmon_Namespace, error) {
+	switch n {
+	case NamespaceIPC:
+		return proto.Conmon_Namespace_ipc, nil
+	case NamespaceNet:
+		return proto.Conmon_Namespace_net, nil
+	case NamespaceUTS:
+		return proto.Conmon_Namespace_uts, nil
+	case NamespaceUser:
+		return proto.Conmon_Namespace_user, nil
+	case NamespaceCgroup:
+		return proto.Conmon_Namespace_cgroup, nil
+	}
+
+	return 0, fmt.Errorf("%w: namespace %d", errInvalidValue, n)
+}
+
+func namespaceFromProto(n proto.Conmon_Namespace) (Namespace, error) {
+	switch n {
+	case proto.Conmon_Namespace_ipc:
+		return NamespaceIPC, nil
+	case proto.Conmon_Namespace_net:
+		return NamespaceNet, nil
+	case proto.Conmon_Namespace_uts:
+		return NamespaceUTS, nil
+	case proto.Conmon_Namespace_user:
+		return NamespaceUser, nil
+	case proto.Conmon_Namespace_cgroup:
+		return NamespaceCgroup, nil
+	}
+
+	return 0, fmt.Errorf("%w: namespace %s", errInvalidValue, n)
+}
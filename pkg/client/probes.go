@@ -0,0 +1,164 @@
+package client
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/containers/conmon-rs/internal/proto"
+)
+
+// RegisterProbeConfig is the configuration for calling the RegisterProbe
+// method.
+type RegisterProbeConfig struct {
+	// ID is the container identifier.
+	ID string
+
+	// Name of the probe, which replaces an existing probe of the same name.
+	Name string
+
+	// Command is executed within the container for an exec probe, if not
+	// empty.
+	Command []string
+
+	// TCPPort is connected to within the network namespace of the container
+	// for a TCP probe, if not zero.
+	TCPPort uint16
+
+	// InitialDelay is the time to wait before the first probe run.
+	InitialDelay time.Duration
+
+	// Period is the time between two probe runs.
+	Period time.Duration
+
+	// Timeout is the maximum time of a single probe run.
+	Timeout time.Duration
+
+	// SuccessThreshold is the number of consecutive successful runs until
+	// the probe succeeds.
+	SuccessThreshold uint32
+
+	// FailureThreshold is the number of consecutive failed runs until the
+	// probe fails.
+	FailureThreshold uint32
+}
+
+// ProbeResult specifies the result of a probe after applying the thresholds.
+type ProbeResult int
+
+const (
+	// ProbeResultUnknown indicates that the probe did not reach any
+	// threshold yet.
+	ProbeResultUnknown ProbeResult = iota
+
+	// ProbeResultSuccess indicates that the probe reached the success
+	// threshold.
+	ProbeResultSuccess
+
+	// ProbeResultFailure indicates that the probe reached the failure
+	// threshold.
+	ProbeResultFailure
+)
+
+// ProbeStatus is the status of a registered probe.
+type ProbeStatus struct {
+	// Name of the probe.
+	Name string
+
+	// Result of the probe.
+	Result ProbeResult
+
+	// Message is the output or error of the last failed run.
+	Message string
+}
+
+// RegisterProbe can be used to register an exec or TCP probe for a running
+// container.
+func (c *ConmonClient) RegisterProbe(ctx context.Context, cfg *RegisterProbeConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon{Client: conn.Bootstrap(ctx)}
+
+	future, free := client.RegisterProbe(ctx, func(p proto.Conmon_registerProbe_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+		if err := c.initMetadata(req.NewMetadata); err != nil {
+			return fmt.Errorf("init metadata: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		if err := req.SetName(cfg.Name); err != nil {
+			return fmt.Errorf("set name: %w", err)
+		}
+
+		if err := stringSliceToTextList(cfg.Command, req.NewCommand); err != nil {
+			return fmt.Errorf("convert command string slice to text list: %w", err)
+		}
+
+		req.SetTcpPort(cfg.TCPPort)
+		req.SetInitialDelaySec(uint32(cfg.InitialDelay.Seconds()))
+		req.SetPeriodSec(uint32(cfg.Period.Seconds()))
+		req.SetTimeoutSec(uint32(cfg.Timeout.Seconds()))
+		req.SetSuccessThreshold(cfg.SuccessThreshold)
+		req.SetFailureThreshold(cfg.FailureThreshold)
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	return nil
+}
+
+func probeStatusesFromProto(src proto.Conmon_ProbeStatus_List) ([]ProbeStatus, error) {
+	res := make([]ProbeStatus, 0, src.Len())
+	for i := 0; i < src.Len(); i++ {
+		probe := src.At(i)
+
+		name, err := probe.Name()
+		if err != nil {
+			return nil, fmt.Errorf("get probe name: %w", err)
+		}
+
+		message, err := probe.Message()
+		if err != nil {
+			return nil, fmt.Errorf("get probe message: %w", err)
+		}
+
+		status := ProbeStatus{Name: name, Message: message}
+		switch probe.Result() {
+		case proto.Conmon_ProbeResult_unknown:
+			status.Result = ProbeResultUnknown
+		case proto.Conmon_ProbeResult_success:
+			status.Result = ProbeResultSuccess
+		case proto.Conmon_ProbeResult_failure:
+			status.Result = ProbeResultFailure
+		default:
+			return nil, fmt.Errorf("%w: probe result %s", errInvalidValue, probe.Result())
+		}
+
+		res = append(res, status)
+	}
+
+	return res, nil
+}
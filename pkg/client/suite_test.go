@@ -115,17 +115,19 @@ func fileContents(path string) string {
 }
 
 func (tr *testRunner) createContainer(sut *client.ConmonClient, terminal bool) {
-	resp, err := sut.CreateContainer(context.Background(), &client.CreateContainerConfig{
-		ID:           tr.ctrID,
-		BundlePath:   tr.tmpDir,
-		Terminal:     terminal,
-		ExitPaths:    []string{tr.exitPath()},
-		OOMExitPaths: []string{tr.oomExitPath()},
-		LogDrivers: []client.LogDriver{{
-			Type: client.LogDriverTypeContainerRuntimeInterface,
-			Path: tr.logPath(),
-		}},
-	})
+	tr.createContainerWithConfig(sut, &client.CreateContainerConfig{Terminal: terminal})
+}
+
+func (tr *testRunner) createContainerWithConfig(sut *client.ConmonClient, cfg *client.CreateContainerConfig) {
+	cfg.ID = tr.ctrID
+	cfg.BundlePath = tr.tmpDir
+	cfg.ExitPaths = []string{tr.exitPath()}
+	cfg.OOMExitPaths = []string{tr.oomExitPath()}
+	cfg.LogDrivers = []client.LogDriver{{
+		Type: client.LogDriverTypeContainerRuntimeInterface,
+		Path: tr.logPath(),
+	}}
+	resp, err := sut.CreateContainer(context.Background(), cfg)
 	Expect(err).To(BeNil())
 	Expect(resp.PID).NotTo(Equal(0))
 	Eventually(func() error {